@@ -1294,6 +1294,45 @@ mod compare_within_metrics_tests {
     }
 }
 
+/// SPEC §7.4.2 — `COMPARE`, the budget-free three-way comparison.
+#[cfg(test)]
+mod compare_tests {
+    use crate::interpreter::Interpreter;
+
+    async fn top(source: &str) -> String {
+        let mut interp = Interpreter::new();
+        interp.execute(source).await.unwrap();
+        assert_eq!(interp.get_stack().len(), 1);
+        format!("{}", interp.get_stack()[0])
+    }
+
+    #[tokio::test]
+    async fn compare_yields_each_ordering() {
+        assert_eq!(top("1/3 1/2 COMPARE").await, "-1/1");
+        assert_eq!(top("2/4 1/2 COMPARE").await, "0/1");
+        assert_eq!(top("[ 3 ] [ 2 ] COMPARE").await, "1/1");
+    }
+
+    #[tokio::test]
+    async fn compare_decides_algebraic_operands() {
+        assert_eq!(top("'math' IMPORT 2 SQRT 3/2 COMPARE").await, "-1/1");
+    }
+
+    #[tokio::test]
+    async fn compare_rejects_text_and_keeps_the_stack() {
+        let mut interp = Interpreter::new();
+        assert!(interp.execute("'a' 1 COMPARE").await.is_err());
+        assert_eq!(interp.get_stack().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn compare_nil_passes_through() {
+        let mut interp = Interpreter::new();
+        interp.execute("NIL 1 COMPARE").await.unwrap();
+        assert!(interp.get_stack()[0].is_nil());
+    }
+}
+
 #[cfg(test)]
 mod ragged_broadcast_tests {
     use crate::interpreter::Interpreter;
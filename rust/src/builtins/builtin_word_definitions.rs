@@ -3,81 +3,7 @@ use crate::coreword_registry::{
 };
 use crate::elastic::purity_table::EvalCost;
 
-use super::builtin_word_types::BuiltinExecutorKey;
-
-#[derive(Clone, Copy, Debug)]
-pub struct BuiltinSpec {
-    pub name: &'static str,
-    pub category: &'static str,
-    /// Layer 3 (hover): one-line "WORD — short verb phrase" shown in the
-    /// native button title attribute. See three-layer-documentation-model.md
-    /// §4.2.
-    pub hover_summary: &'static str,
-    /// Layer 3 (hover): shortest useful invocation (operands included, sugar
-    /// preferred when shorter) shown in the inline word-info strip. See
-    /// three-layer-documentation-model.md §4.3.
-    pub hover_syntax: &'static str,
-    pub executor_key: Option<BuiltinExecutorKey>,
-    /// Static flow-mass contract (SPEC §13.1). This is the canonical
-    /// per-builtin source consumed by the Coreword registry and analyzers.
-    pub mass: MassContract,
-    /// Static cost bucket used by optimization planners; authored here so
-    /// Elastic purity metadata does not keep a parallel builtin table.
-    pub eval_cost: EvalCost,
-    /// Whether this word is order-sensitive even when it is otherwise pure.
-    pub order_sensitive: bool,
-
-    // Layer 2 (LOOKUP) fields. Four-section template:
-    //   Category / Summary / Role / Stack Effect
-    // Stability is shown in the header (e.g. `# ADD  (experimental)`).
-    pub summary: &'static str,
-    pub role: &'static str,
-    pub stack_effect: &'static str,
-    /// Must agree with the `safety_level` field below. The mapping is:
-    ///   safety_level A or B  -> "stable"
-    ///   safety_level C or D  -> "experimental"
-    ///   safety_level Quarantined -> "experimental"
-    /// A consistency test asserts this invariant.
-    pub stability: &'static str,
-
-    // §7.14 contract metadata. Canonical per-word source of truth; the
-    // coreword registry reads these directly. `effects` is non-empty only
-    // for Observable / Effectful words.
-    pub purity: WordPurity,
-    pub effects: &'static [&'static str],
-    pub deterministic: bool,
-    pub safe_preview: bool,
-    pub partiality: Partiality,
-    pub nil_policy: NilPolicy,
-    pub safety_level: SafetyLevel,
-    /// How the word takes effect (SPEC §6.4). Defaults to `RuntimeWord`; the
-    /// lazy/no-op control directives (`VENT`, `FLOW`) set this so the
-    /// classification is machine-checkable rather than inferred from prose.
-    pub execution_form: ExecutionForm,
-}
-
-const SPEC_DEFAULT: BuiltinSpec = BuiltinSpec {
-    name: "",
-    category: "",
-    hover_summary: "",
-    hover_syntax: "",
-    executor_key: None,
-    mass: MassContract::Dynamic,
-    eval_cost: EvalCost::Trivial,
-    order_sensitive: false,
-    summary: "",
-    role: "",
-    stack_effect: "",
-    stability: "stable",
-    purity: WordPurity::Pure,
-    effects: &[],
-    deterministic: true,
-    safe_preview: true,
-    partiality: Partiality::Total,
-    nil_policy: NilPolicy::Passthrough,
-    safety_level: SafetyLevel::A,
-    execution_form: ExecutionForm::RuntimeWord,
-};
+use super::builtin_word_types::{BuiltinExecutorKey, BuiltinSpec, SPEC_DEFAULT};
 
 const BUILTIN_SPECS: &[BuiltinSpec] = &[
     // === Modifiers ===
@@ -170,6 +96,23 @@ const BUILTIN_SPECS: &[BuiltinSpec] = &[
         },
     BuiltinSpec {
 
+        name: "UPDATE",
+        category: "vector",
+        hover_summary: "UPDATE — transform the element at an index",
+        hover_syntax: "[ 1 2 3 ] [ 1 ] { 2 * } UPDATE",
+        executor_key: Some(BuiltinExecutorKey::Update),
+        eval_cost: EvalCost::Light,
+        summary: "Apply a block or word to the element at a given index and replace it with the result.",
+        role: "Functional update of a vector element; the transform sees only that element and must leave exactly one value.",
+
+        stack_effect: "[ vec ] [ idx ] { body } -> [ vec' ]",
+        partiality: Partiality::Partial,
+        nil_policy: NilPolicy::RejectsNil,
+        safety_level: SafetyLevel::B,
+        ..SPEC_DEFAULT
+        },
+    BuiltinSpec {
+
         name: "REPLACE",
         category: "vector",
         hover_summary: "REPLACE — replace element at index",
@@ -342,6 +285,54 @@ const BUILTIN_SPECS: &[BuiltinSpec] = &[
         safety_level: SafetyLevel::B,
         ..SPEC_DEFAULT
         },
+    BuiltinSpec {
+
+        name: "SNAPSHOT",
+        category: "vector",
+        hover_summary: "SNAPSHOT — copy the whole stack into a vector",
+        hover_syntax: "1 2 SNAPSHOT",
+        executor_key: Some(BuiltinExecutorKey::Snapshot),
+        eval_cost: EvalCost::Light,
+        summary: "Push a copy of the whole stack as one vector.",
+        role: "Vector primitive: Push a copy of the whole stack as one vector.",
+
+        stack_effect: "v1 ... vn -> v1 ... vn [ v1 ... vn ]",
+        ..SPEC_DEFAULT
+        },
+    BuiltinSpec {
+
+        name: "RESTORE",
+        category: "vector",
+        hover_summary: "RESTORE — replace the stack with a snapshot",
+        hover_syntax: "[ 1 2 ] RESTORE",
+        executor_key: Some(BuiltinExecutorKey::Restore),
+        eval_cost: EvalCost::Light,
+        summary: "Replace the whole stack with the elements of a snapshot vector.",
+        role: "Vector primitive: Replace the whole stack with the elements of a snapshot vector.",
+
+        stack_effect: "... [ v1 ... vn ] -> v1 ... vn",
+        partiality: Partiality::Partial,
+        nil_policy: NilPolicy::ConsumesNil,
+        safety_level: SafetyLevel::B,
+        ..SPEC_DEFAULT
+        },
+    BuiltinSpec {
+
+        name: "ENUMERATE",
+        category: "vector",
+        hover_summary: "ENUMERATE — pair elements with their indices",
+        hover_syntax: "[ 'a' 'b' 'c' ] ENUMERATE",
+        executor_key: Some(BuiltinExecutorKey::Enumerate),
+        eval_cost: EvalCost::Light,
+        summary: "Pair every element with its index, optionally from a start index.",
+        role: "Vector primitive: Pair every element with its index, optionally from a start index.",
+
+        stack_effect: "[ vec ] ( [ start ] ) -> [ [ i e ] ... ]",
+        partiality: Partiality::Partial,
+        nil_policy: NilPolicy::Passthrough,
+        safety_level: SafetyLevel::B,
+        ..SPEC_DEFAULT
+        },
 
     // === Constants ===
     BuiltinSpec {
@@ -605,6 +596,23 @@ const BUILTIN_SPECS: &[BuiltinSpec] = &[
         },
     BuiltinSpec {
 
+        name: "FORMAT-TEXT",
+        category: "cast",
+        hover_summary: "FORMAT-TEXT — fill {} placeholders in order",
+        hover_syntax: "'x={} y={}' [ 1 2 ] FORMAT-TEXT",
+        executor_key: Some(BuiltinExecutorKey::FormatText),
+        eval_cost: EvalCost::Light,
+        summary: "Fill each {} placeholder in a template with the next argument, rendered as by STR.",
+        role: "Cast primitive: Fill each {} placeholder in a template with the next argument, rendered as by STR.",
+
+        stack_effect: "[ template ] [ args ] -> [ str ]",
+        partiality: Partiality::Partial,
+        nil_policy: NilPolicy::RejectsNil,
+        safety_level: SafetyLevel::B,
+        ..SPEC_DEFAULT
+        },
+    BuiltinSpec {
+
         name: "STARTS-WITH?",
         category: "cast",
         hover_summary: "STARTS-WITH? — prefix predicate",
@@ -708,6 +716,40 @@ const BUILTIN_SPECS: &[BuiltinSpec] = &[
         safety_level: SafetyLevel::B,
         ..SPEC_DEFAULT
         },
+    BuiltinSpec {
+
+        name: "CODEPOINT",
+        category: "cast",
+        hover_summary: "CODEPOINT — character code",
+        hover_syntax: "'A' CODEPOINT",
+        executor_key: Some(BuiltinExecutorKey::Codepoint),
+        eval_cost: EvalCost::Light,
+        summary:
+            "Convert a single-character string to its Unicode code point.",
+        role: "Cast primitive: Convert a single-character string to its Unicode code point.",
+
+        stack_effect: "[ char ] -> [ n ]",
+        partiality: Partiality::Partial,
+        nil_policy: NilPolicy::RejectsNil,
+        safety_level: SafetyLevel::B,
+        ..SPEC_DEFAULT
+        },
+    BuiltinSpec {
+
+        name: "DECIMAL",
+        category: "cast",
+        hover_summary: "DECIMAL — number as decimal text",
+        hover_syntax: "[ 1/3 ] [ 5 ] DECIMAL",
+        executor_key: Some(BuiltinExecutorKey::Decimal),
+        eval_cost: EvalCost::Light,
+        summary: "Render a number as decimal text with at most the given number of fractional digits, truncated exactly.",
+        role: "Cast primitive: Render a number as decimal text with at most the given number of fractional digits, truncated exactly.",
+
+        stack_effect: "[ x ] [ digits ] -> [ text ]",
+        partiality: Partiality::Partial,
+        safety_level: SafetyLevel::B,
+        ..SPEC_DEFAULT
+        },
 
     // === Arithmetic ===
     BuiltinSpec {
@@ -774,6 +816,21 @@ const BUILTIN_SPECS: &[BuiltinSpec] = &[
         },
     BuiltinSpec {
 
+        name: "COMPARE",
+        category: "comparison",
+        hover_summary: "COMPARE — three-way compare",
+        hover_syntax: "1/3 1/2 COMPARE",
+        executor_key: Some(BuiltinExecutorKey::Compare),
+        summary: "Three-way compare two numbers, yielding -1, 0, or 1.",
+        role: "Comparison primitive: the budget-free form of COMPARE-WITHIN.",
+
+        stack_effect: "[ a ] [ b ] -> [ -1 | 0 | 1 | UNKNOWN ]",
+        partiality: Partiality::Projecting,
+        safety_level: SafetyLevel::B,
+        ..SPEC_DEFAULT
+        },
+    BuiltinSpec {
+
         name: "COMPARE-WITHIN",
         category: "comparison",
         hover_summary: "COMPARE-WITHIN — three-way compare within a budget",
@@ -1031,6 +1088,23 @@ const BUILTIN_SPECS: &[BuiltinSpec] = &[
         },
     BuiltinSpec {
 
+        name: "TREEMAP",
+        category: "higher-order",
+        hover_summary: "TREEMAP — apply block to every leaf, keeping nesting",
+        hover_syntax: "[ 1 [ 2 3 ] ] { 2 * } TREEMAP",
+        executor_key: Some(BuiltinExecutorKey::Treemap),
+        eval_cost: EvalCost::Medium,
+        summary: "Apply a code block to every scalar leaf of a nested vector, preserving its shape.",
+        role: "Higher-order primitive: Apply a code block to every scalar leaf of a nested vector, preserving its shape.",
+
+        stack_effect: "[ nested ] { body } -> [ mapped-nested ]",
+        partiality: Partiality::Partial,
+        nil_policy: NilPolicy::Passthrough,
+        safety_level: SafetyLevel::B,
+        ..SPEC_DEFAULT
+        },
+    BuiltinSpec {
+
         name: "FILTER",
         category: "higher-order",
         hover_summary: "FILTER — keep elements matching predicate",
@@ -1060,6 +1134,25 @@ const BUILTIN_SPECS: &[BuiltinSpec] = &[
             "Reduce a vector to a single value using an initial accumulator and combiner block.",
         role: "Higher-order primitive: Reduce a vector to a single value using an initial accumulator and combiner block.",
 
+        stack_effect: "[ vec ] [ init ] { combine } -> [ result ]",
+        partiality: Partiality::Partial,
+        nil_policy: NilPolicy::RejectsNil,
+        safety_level: SafetyLevel::B,
+        ..SPEC_DEFAULT
+        },
+    BuiltinSpec {
+
+        name: "FOLDR",
+        category: "higher-order",
+        hover_summary: "FOLDR — reduce from the right",
+        hover_syntax: "[ 1 2 3 ] 0 { - } FOLDR",
+        executor_key: Some(BuiltinExecutorKey::Foldr),
+        eval_cost: EvalCost::Medium,
+        order_sensitive: true,
+        summary:
+            "Reduce a vector right to left, combining each element with the accumulator.",
+        role: "Higher-order primitive: Reduce a vector right to left, combining each element with the accumulator.",
+
         stack_effect: "[ vec ] [ init ] { combine } -> [ result ]",
         partiality: Partiality::Partial,
         nil_policy: NilPolicy::RejectsNil,
@@ -1087,6 +1180,25 @@ const BUILTIN_SPECS: &[BuiltinSpec] = &[
         },
     BuiltinSpec {
 
+        name: "ITERATE",
+        category: "higher-order",
+        hover_summary: "ITERATE — apply a step N times",
+        hover_syntax: "1 4 { 2 * } ITERATE",
+        executor_key: Some(BuiltinExecutorKey::Iterate),
+        eval_cost: EvalCost::Medium,
+        order_sensitive: true,
+        summary:
+            "Apply a step N times to a seed, collecting the seed and every intermediate value.",
+        role: "Higher-order primitive: Apply a step N times to a seed, collecting the seed and every intermediate value.",
+
+        stack_effect: "[ seed ] [ n ] { step } -> [ seq ]",
+        partiality: Partiality::Partial,
+        nil_policy: NilPolicy::RejectsNil,
+        safety_level: SafetyLevel::B,
+        ..SPEC_DEFAULT
+        },
+    BuiltinSpec {
+
         name: "ANY",
         category: "higher-order",
         hover_summary: "ANY — true if any element matches",
@@ -1148,6 +1260,24 @@ const BUILTIN_SPECS: &[BuiltinSpec] = &[
         summary: "Return a vector of intermediate fold accumulators.",
         role: "Higher-order primitive: Return a vector of intermediate fold accumulators.",
 
+        stack_effect: "[ vec ] [ init ] { combine } -> [ acc-history ]",
+        partiality: Partiality::Partial,
+        nil_policy: NilPolicy::RejectsNil,
+        safety_level: SafetyLevel::B,
+        ..SPEC_DEFAULT
+        },
+    BuiltinSpec {
+
+        name: "SCANR",
+        category: "higher-order",
+        hover_summary: "SCANR — intermediate right-fold results",
+        hover_syntax: "[ 1 2 3 ] 0 { - } SCANR",
+        executor_key: Some(BuiltinExecutorKey::Scanr),
+        eval_cost: EvalCost::Medium,
+        order_sensitive: true,
+        summary: "Return the right fold of every suffix, in element order.",
+        role: "Higher-order primitive: Return the right fold of every suffix, in element order.",
+
         stack_effect: "[ vec ] [ init ] { combine } -> [ acc-history ]",
         partiality: Partiality::Partial,
         nil_policy: NilPolicy::RejectsNil,
@@ -1165,7 +1295,7 @@ const BUILTIN_SPECS: &[BuiltinSpec] = &[
         executor_key: Some(BuiltinExecutorKey::Print),
         eval_cost: EvalCost::Heavy,
         order_sensitive: true,
-        summary: "Output the top stack value. A string is written as its raw text, without the quotes the stack shows ('TEST' prints as TEST); nested strings keep their quotes, and numbers and other values print as they appear on the stack.",
+        summary: "Output the top stack value. A string is written as its raw text, without the quotes the stack shows ('TEST' prints as TEST); nested strings keep their quotes, and numbers and other values print as they appear on the stack. `..` prints the whole stack on one line, with an optional [ 'sep' ] separator on top.",
         role: "Io primitive: output the top stack value at the output boundary, where a string is emitted as its raw character content (the stack's surrounding quotes are a display affordance only).",
 
         stack_effect: "[ x ] -> [ x ]",
@@ -1179,6 +1309,29 @@ const BUILTIN_SPECS: &[BuiltinSpec] = &[
         safety_level: SafetyLevel::D,
         ..SPEC_DEFAULT
         },
+    BuiltinSpec {
+
+        name: "INSPECT",
+        category: "io",
+        hover_summary: "INSPECT — dump the stack to the output",
+        hover_syntax: "1 [ 2 3 ] INSPECT",
+        executor_key: Some(BuiltinExecutorKey::Inspect),
+        eval_cost: EvalCost::Heavy,
+        order_sensitive: true,
+        summary: "Write every stack value, one indexed line each with the shape of vectors, to the output without changing the stack. An empty stack prints <empty>.",
+        role: "Io primitive: a non-destructive debugging dump of the whole stack at the output boundary, rendered as the stack shows it.",
+
+        stack_effect: "no values popped or pushed",
+        stability: "experimental",
+        purity: WordPurity::Effectful,
+        effects: &["console-write"],
+        deterministic: false,
+        safe_preview: false,
+        partiality: Partiality::Total,
+        nil_policy: NilPolicy::Passthrough,
+        safety_level: SafetyLevel::D,
+        ..SPEC_DEFAULT
+        },
 
     // === Dictionary ===
     BuiltinSpec {
@@ -1364,6 +1517,74 @@ const BUILTIN_SPECS: &[BuiltinSpec] = &[
         },
     BuiltinSpec {
 
+        name: "SQUEEZE",
+        category: "tensor",
+        hover_summary: "SQUEEZE — drop size-1 axes",
+        hover_syntax: "[ [ [ 1 ] [ 2 ] [ 3 ] ] ] SQUEEZE",
+        executor_key: Some(BuiltinExecutorKey::Squeeze),
+        eval_cost: EvalCost::Light,
+        summary: "Remove every size-1 axis from a tensor.",
+        role: "Tensor primitive: Remove every size-1 axis from a tensor.",
+
+        stack_effect: "[ tensor ] -> [ tensor' ]",
+        partiality: Partiality::Partial,
+        nil_policy: NilPolicy::Passthrough,
+        safety_level: SafetyLevel::B,
+        ..SPEC_DEFAULT
+        },
+    BuiltinSpec {
+
+        name: "UNSQUEEZE",
+        category: "tensor",
+        hover_summary: "UNSQUEEZE — insert a size-1 axis",
+        hover_syntax: "[ 1 2 3 ] 0 UNSQUEEZE",
+        executor_key: Some(BuiltinExecutorKey::Unsqueeze),
+        eval_cost: EvalCost::Light,
+        summary: "Insert a size-1 axis into a tensor at the given axis.",
+        role: "Tensor primitive: Insert a size-1 axis into a tensor at the given axis.",
+
+        stack_effect: "[ tensor ] [ axis ] -> [ tensor' ]",
+        partiality: Partiality::Partial,
+        nil_policy: NilPolicy::Passthrough,
+        safety_level: SafetyLevel::B,
+        ..SPEC_DEFAULT
+        },
+    BuiltinSpec {
+
+        name: "AXISGET",
+        category: "tensor",
+        hover_summary: "AXISGET — slice a tensor along an axis",
+        hover_syntax: "[ [ 1 2 ] [ 3 4 ] ] 1 0 AXISGET",
+        executor_key: Some(BuiltinExecutorKey::AxisGet),
+        eval_cost: EvalCost::Light,
+        summary: "Take the sub-tensor at an index along an axis, removing that axis.",
+        role: "Tensor primitive: Take the sub-tensor at an index along an axis, removing that axis.",
+
+        stack_effect: "[ tensor ] [ axis ] [ index ] -> [ sub ]",
+        partiality: Partiality::Partial,
+        nil_policy: NilPolicy::Passthrough,
+        safety_level: SafetyLevel::B,
+        ..SPEC_DEFAULT
+        },
+    BuiltinSpec {
+
+        name: "TMAP",
+        category: "tensor",
+        hover_summary: "TMAP — apply a word to every scalar, keeping the shape",
+        hover_syntax: "[ [ 1 2 ] [ 3 4 ] ] { 2 * } TMAP",
+        executor_key: Some(BuiltinExecutorKey::Tmap),
+        eval_cost: EvalCost::Medium,
+        summary: "Apply a scalar-to-scalar word to every element of a rectangular tensor, preserving its shape.",
+        role: "Tensor primitive: shape-preserving counterpart to MAP. The word must consume one scalar and leave exactly one scalar.",
+
+        stack_effect: "[ tensor ] { body } -> [ mapped ]",
+        partiality: Partiality::Partial,
+        nil_policy: NilPolicy::Passthrough,
+        safety_level: SafetyLevel::B,
+        ..SPEC_DEFAULT
+        },
+    BuiltinSpec {
+
         name: "FILL",
         category: "tensor",
         hover_summary: "FILL — fill shape with value",
@@ -1615,6 +1836,41 @@ const BUILTIN_SPECS: &[BuiltinSpec] = &[
         safety_level: SafetyLevel::B,
         ..SPEC_DEFAULT
         },
+    BuiltinSpec {
+
+        name: "TRY",
+        category: "control",
+        hover_summary: "TRY — run a body, recovering from a raised error",
+        hover_syntax: "{ 1 0 MOD } { 0 } TRY",
+        executor_key: Some(BuiltinExecutorKey::Try),
+        eval_cost: EvalCost::Light,
+        order_sensitive: true,
+        summary: "Run the body; if it raises, restore the stack, push the error message, and run the recovery.",
+        role: "Control primitive: the error-level counterpart to OR-ELSE. Body and recovery are { ... } blocks or word names; the stack is rolled back to its state before the body, and step/recursion limits always propagate.",
+
+        stack_effect: "[ body ] [ recovery ] -> [ body-result... | recovery-result... ]",
+        partiality: Partiality::Partial,
+        nil_policy: NilPolicy::RejectsNil,
+        safety_level: SafetyLevel::B,
+        ..SPEC_DEFAULT
+        },
+    BuiltinSpec {
+
+        name: "RAISE",
+        category: "control",
+        hover_summary: "RAISE — abort with a user-defined error message",
+        hover_syntax: "{ 'negative input' RAISE } { } TRY",
+        executor_key: Some(BuiltinExecutorKey::Raise),
+        eval_cost: EvalCost::Light,
+        summary: "Abort the current evaluation with the given text as the error message.",
+        role: "Control primitive: signals a domain error from a user word. The message reaches a surrounding TRY recovery verbatim.",
+
+        stack_effect: "[ message ] -> !",
+        partiality: Partiality::Partial,
+        nil_policy: NilPolicy::RejectsNil,
+        safety_level: SafetyLevel::B,
+        ..SPEC_DEFAULT
+        },
 
     // === Module ops ===
     BuiltinSpec {
@@ -1875,102 +2131,3 @@ pub fn collect_core_builtin_definitions() -> Vec<(&'static str, &'static str, &'
         .map(|spec| (spec.name, spec.hover_summary, spec.hover_syntax))
         .collect()
 }
-
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn builtin_specs_do_not_contain_symbol_aliases_or_input_helpers() {
-        let forbidden = [
-            "+", "-", "*", "/", "%", "=", "<", "<=", ">", ">=", "<>", ".", "..", ",", ",,", "~",
-            "!", "'", "|", "?", "^",
-        ];
-
-        for spec in super::builtin_specs() {
-            assert!(
-                !forbidden.contains(&spec.name),
-                "builtin spec must not contain symbol/helper word: {}",
-                spec.name
-            );
-        }
-    }
-
-    #[test]
-    fn builtin_specs_contain_canonical_core_words() {
-        let required = [
-            "ADD", "SUB", "MUL", "DIV", "MOD", "EQ", "NEQ", "LT", "LTE", "GT", "GTE", "TOP",
-            "STAK", "EAT", "KEEP", "FORC", "LOOKUP", "FLOW", "VENT",
-        ];
-
-        for name in required {
-            assert!(
-                super::lookup_builtin_spec(name).is_some(),
-                "missing canonical core word: {}",
-                name
-            );
-        }
-    }
-
-    #[test]
-    fn builtin_specs_have_required_lookup_content() {
-        for spec in super::builtin_specs() {
-            assert!(!spec.summary.is_empty(), "{} missing summary", spec.name);
-            assert!(!spec.role.is_empty(), "{} missing role", spec.name);
-            assert!(!spec.category.is_empty(), "{} missing category", spec.name);
-            assert!(
-                !spec.stack_effect.is_empty(),
-                "{} missing stack_effect",
-                spec.name
-            );
-            assert!(
-                spec.stability == "stable" || spec.stability == "experimental",
-                "{} has invalid stability {}",
-                spec.name,
-                spec.stability
-            );
-        }
-    }
-
-    #[test]
-    fn builtin_specs_stack_effect_grammar() {
-        for spec in super::builtin_specs() {
-            // Control directives (SPEC §6.4) act positionally on the source
-            // stream, not as a stack `X -> Y` transformation, so the arrow
-            // grammar does not apply to them; their contract is carried by
-            // `execution_form` and a prose stack-effect note.
-            if spec.execution_form != crate::coreword_registry::ExecutionForm::RuntimeWord {
-                continue;
-            }
-            let s = spec.stack_effect;
-            let is_literal_no_op =
-                s == "no values popped or pushed" || s == "operands preserved; result pushed";
-            if is_literal_no_op {
-                continue;
-            }
-            assert!(
-                s.contains("->"),
-                "{} stack_effect missing '->' arrow: {:?}",
-                spec.name,
-                s
-            );
-        }
-    }
-
-    #[test]
-    fn builtin_specs_lookup_text_is_utf8_plain_text() {
-        let check = |label: &str, name: &str, text: &str| {
-            assert!(
-                !text.chars().any(|c| c.is_control() && c != '\n'),
-                "{} field of {} must be UTF-8 plain text without control characters; got: {:?}",
-                label,
-                name,
-                text
-            );
-        };
-        for spec in super::builtin_specs() {
-            check("summary", spec.name, spec.summary);
-            check("role", spec.name, spec.role);
-            check("stack_effect", spec.name, spec.stack_effect);
-            check("category", spec.name, spec.category);
-        }
-    }
-}
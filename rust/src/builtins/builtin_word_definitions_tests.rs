@@ -0,0 +1,100 @@
+//! Registry-level checks on `BUILTIN_SPECS`. Kept in a sibling file so
+//! `builtin_word_definitions.rs` stays within the §14.1 file-size budget.
+
+use super::builtin_word_definitions::{builtin_specs, lookup_builtin_spec};
+
+#[test]
+fn builtin_specs_do_not_contain_symbol_aliases_or_input_helpers() {
+    let forbidden = [
+        "+", "-", "*", "/", "%", "=", "<", "<=", ">", ">=", "<>", ".", "..", ",", ",,", "~", "!",
+        "'", "|", "?", "^",
+    ];
+
+    for spec in builtin_specs() {
+        assert!(
+            !forbidden.contains(&spec.name),
+            "builtin spec must not contain symbol/helper word: {}",
+            spec.name
+        );
+    }
+}
+
+#[test]
+fn builtin_specs_contain_canonical_core_words() {
+    let required = [
+        "ADD", "SUB", "MUL", "DIV", "MOD", "EQ", "NEQ", "LT", "LTE", "GT", "GTE", "TOP", "STAK",
+        "EAT", "KEEP", "FORC", "LOOKUP", "FLOW", "VENT",
+    ];
+
+    for name in required {
+        assert!(
+            lookup_builtin_spec(name).is_some(),
+            "missing canonical core word: {}",
+            name
+        );
+    }
+}
+
+#[test]
+fn builtin_specs_have_required_lookup_content() {
+    for spec in builtin_specs() {
+        assert!(!spec.summary.is_empty(), "{} missing summary", spec.name);
+        assert!(!spec.role.is_empty(), "{} missing role", spec.name);
+        assert!(!spec.category.is_empty(), "{} missing category", spec.name);
+        assert!(
+            !spec.stack_effect.is_empty(),
+            "{} missing stack_effect",
+            spec.name
+        );
+        assert!(
+            spec.stability == "stable" || spec.stability == "experimental",
+            "{} has invalid stability {}",
+            spec.name,
+            spec.stability
+        );
+    }
+}
+
+#[test]
+fn builtin_specs_stack_effect_grammar() {
+    for spec in builtin_specs() {
+        // Control directives (SPEC §6.4) act positionally on the source
+        // stream, not as a stack `X -> Y` transformation, so the arrow
+        // grammar does not apply to them; their contract is carried by
+        // `execution_form` and a prose stack-effect note.
+        if spec.execution_form != crate::coreword_registry::ExecutionForm::RuntimeWord {
+            continue;
+        }
+        let s = spec.stack_effect;
+        let is_literal_no_op =
+            s == "no values popped or pushed" || s == "operands preserved; result pushed";
+        if is_literal_no_op {
+            continue;
+        }
+        assert!(
+            s.contains("->"),
+            "{} stack_effect missing '->' arrow: {:?}",
+            spec.name,
+            s
+        );
+    }
+}
+
+#[test]
+fn builtin_specs_lookup_text_is_utf8_plain_text() {
+    let check = |label: &str, name: &str, text: &str| {
+        assert!(
+            !text.chars().any(|c| c.is_control() && c != '\n'),
+            "{} field of {} must be UTF-8 plain text without control characters; got: {:?}",
+            label,
+            name,
+            text
+        );
+    };
+    for spec in builtin_specs() {
+        check("summary", spec.name, spec.summary);
+        check("role", spec.name, spec.role);
+        check("stack_effect", spec.name, spec.stack_effect);
+        check("category", spec.name, spec.category);
+    }
+}
@@ -1,5 +1,6 @@
-use super::builtin_word_definitions::{lookup_builtin_spec, BuiltinSpec};
+use super::builtin_word_definitions::lookup_builtin_spec;
 use super::builtin_word_lookup_docs::lookup_builtin_lookup_doc;
+use super::builtin_word_types::BuiltinSpec;
 use crate::core_word_aliases::{lookup_core_word_alias, CoreWordAliasKind};
 use crate::coreword_registry::{ExecutionForm, NilPolicy, Partiality};
 
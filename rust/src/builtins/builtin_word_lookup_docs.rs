@@ -208,6 +208,16 @@ const BUILTIN_LOOKUP_DOCS: &[BuiltinLookupDoc] = &[
         failure_note: "",
         related: &["LTE", "GT", "GTE", "EQ", "COMPARE-WITHIN"],
     },
+    BuiltinLookupDoc {
+        word: "COMPARE",
+        behavior: "Pops two numbers and pushes -1, 0, or 1 for their\nordering. Equal values written differently compare as 0.",
+        examples: &[BuiltinExampleDoc {
+            code: "2/4 1/2 COMPARE",
+            result: "Pushes 0.",
+        }],
+        failure_note: "Text and other non-numeric operands raise an error.",
+        related: &["COMPARE-WITHIN", "LT", "GT", "EQ"],
+    },
     BuiltinLookupDoc {
         word: "COMPARE-WITHIN",
         behavior: "Pops the budget, then two values, and pushes -1, 0, or 1\nfor their ordering. Every value the current vocabulary can\nconstruct decides regardless of the budget; the budget bounds\nthe refinement of future general computable reals, whose\nexhaustion yields UNKNOWN.",
@@ -247,7 +257,27 @@ const BUILTIN_LOOKUP_DOCS: &[BuiltinLookupDoc] = &[
             result: "Pushes 'A'.",
         }],
         failure_note: "A number that is not a valid code point yields a\nBubble/NIL.",
-        related: &["NUM", "CHARS", "JOIN"],
+        related: &["NUM", "CODEPOINT", "CHARS", "JOIN"],
+    },
+    BuiltinLookupDoc {
+        word: "CODEPOINT",
+        behavior: "Pops a one-character text and pushes its Unicode code point.",
+        examples: &[BuiltinExampleDoc {
+            code: "'A' CODEPOINT",
+            result: "Pushes 65.",
+        }],
+        failure_note: "Text of any other length is an error.",
+        related: &["CHR", "CHARS", "NUM"],
+    },
+    BuiltinLookupDoc {
+        word: "DECIMAL",
+        behavior: "Pops a digit count and a number and pushes the number as\ndecimal text, truncated toward zero.",
+        examples: &[BuiltinExampleDoc {
+            code: "[ 1/3 ] [ 5 ] DECIMAL",
+            result: "Pushes '0.33333'.",
+        }],
+        failure_note: "A non-number or a negative digit count is an error.",
+        related: &["STR", "NUM", "ROUND"],
     },
     BuiltinLookupDoc {
         word: "CHARS",
@@ -308,7 +338,17 @@ const BUILTIN_LOOKUP_DOCS: &[BuiltinLookupDoc] = &[
             result: "Pushes [ 6 ].",
         }],
         failure_note: "",
-        related: &["MAP", "FILTER", "SCAN", "UNFOLD"],
+        related: &["MAP", "FILTER", "SCAN", "UNFOLD", "FOLDR"],
+    },
+    BuiltinLookupDoc {
+        word: "FOLDR",
+        behavior: "Like FOLD, but combines the elements right to left; the\nblock sees the element below the accumulator.",
+        examples: &[BuiltinExampleDoc {
+            code: "[ 1 2 3 ] 0 { - } FOLDR",
+            result: "Pushes 2, i.e. 1 - (2 - (3 - 0)).",
+        }],
+        failure_note: "",
+        related: &["FOLD", "SCANR"],
     },
     // ── Dictionary words ──────────────────────────────────────────────────
     BuiltinLookupDoc {
@@ -323,12 +363,12 @@ const BUILTIN_LOOKUP_DOCS: &[BuiltinLookupDoc] = &[
     },
     BuiltinLookupDoc {
         word: "DEL",
-        behavior: "Pops the name and deletes that user word from the\ndictionary.",
+        behavior: "Pops the name and deletes that user word from the\ndictionary. A vector of names deletes each of them.",
         examples: &[BuiltinExampleDoc {
             code: "{ [ 1 ] } 'W' DEF 'W' DEL",
             result: "Defines a word, then removes it from the dictionary.",
         }],
-        failure_note: "Deleting a built-in word is refused. Deleting a word other\nwords depend on requires FORC.",
+        failure_note: "Deleting a built-in word is refused. Deleting a word other\nwords depend on requires FORC. A vector with any such name\ndeletes nothing unless forced.",
         related: &["DEF", "FORC", "LOOKUP"],
     },
     BuiltinLookupDoc {
@@ -1,3 +1,8 @@
+use crate::coreword_registry::{
+    ExecutionForm, MassContract, NilPolicy, Partiality, SafetyLevel, WordPurity,
+};
+use crate::elastic::purity_table::EvalCost;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum BuiltinExecutorKey {
     Add,
@@ -10,15 +15,20 @@ pub enum BuiltinExecutorKey {
     Gt,
     Gte,
     Neq,
+    Compare,
     CompareWithin,
     Map,
+    Treemap,
     Filter,
     Fold,
+    Foldr,
     Unfold,
+    Iterate,
     Any,
     All,
     Count,
     Scan,
+    Scanr,
     Get,
     Length,
     Concat,
@@ -32,6 +42,8 @@ pub enum BuiltinExecutorKey {
     Exec,
     Eval,
     OrElse,
+    Try,
+    Raise,
     Cond,
     Conserve,
     Def,
@@ -44,8 +56,10 @@ pub enum BuiltinExecutorKey {
     Force,
     ToCf,
     Print,
+    Inspect,
     Insert,
     Replace,
+    Update,
     Remove,
     Take,
     Split,
@@ -53,10 +67,17 @@ pub enum BuiltinExecutorKey {
     Range,
     Reorder,
     Collect,
+    Snapshot,
+    Restore,
+    Enumerate,
     Shape,
     Rank,
     Reshape,
     Transpose,
+    Squeeze,
+    Unsqueeze,
+    AxisGet,
+    Tmap,
     Fill,
     Floor,
     Ceil,
@@ -71,6 +92,8 @@ pub enum BuiltinExecutorKey {
     Num,
     Bool,
     Chr,
+    Codepoint,
+    Decimal,
     Chars,
     Join,
     Trim,
@@ -78,6 +101,7 @@ pub enum BuiltinExecutorKey {
     TrimRight,
     Tokenize,
     Substitute,
+    FormatText,
     StartsWith,
     EndsWith,
     Spawn,
@@ -108,3 +132,77 @@ pub enum WordShape {
     Fold,
     Other,
 }
+
+#[derive(Clone, Copy, Debug)]
+pub struct BuiltinSpec {
+    pub name: &'static str,
+    pub category: &'static str,
+    /// Layer 3 (hover): one-line "WORD — short verb phrase" shown in the
+    /// native button title attribute. See three-layer-documentation-model.md
+    /// §4.2.
+    pub hover_summary: &'static str,
+    /// Layer 3 (hover): shortest useful invocation (operands included, sugar
+    /// preferred when shorter) shown in the inline word-info strip. See
+    /// three-layer-documentation-model.md §4.3.
+    pub hover_syntax: &'static str,
+    pub executor_key: Option<BuiltinExecutorKey>,
+    /// Static flow-mass contract (SPEC §13.1). This is the canonical
+    /// per-builtin source consumed by the Coreword registry and analyzers.
+    pub mass: MassContract,
+    /// Static cost bucket used by optimization planners; authored here so
+    /// Elastic purity metadata does not keep a parallel builtin table.
+    pub eval_cost: EvalCost,
+    /// Whether this word is order-sensitive even when it is otherwise pure.
+    pub order_sensitive: bool,
+
+    // Layer 2 (LOOKUP) fields. Four-section template:
+    //   Category / Summary / Role / Stack Effect
+    // Stability is shown in the header (e.g. `# ADD  (experimental)`).
+    pub summary: &'static str,
+    pub role: &'static str,
+    pub stack_effect: &'static str,
+    /// Must agree with the `safety_level` field below. The mapping is:
+    ///   safety_level A or B  -> "stable"
+    ///   safety_level C or D  -> "experimental"
+    ///   safety_level Quarantined -> "experimental"
+    /// A consistency test asserts this invariant.
+    pub stability: &'static str,
+
+    // §7.14 contract metadata. Canonical per-word source of truth; the
+    // coreword registry reads these directly. `effects` is non-empty only
+    // for Observable / Effectful words.
+    pub purity: WordPurity,
+    pub effects: &'static [&'static str],
+    pub deterministic: bool,
+    pub safe_preview: bool,
+    pub partiality: Partiality,
+    pub nil_policy: NilPolicy,
+    pub safety_level: SafetyLevel,
+    /// How the word takes effect (SPEC §6.4). Defaults to `RuntimeWord`; the
+    /// lazy/no-op control directives (`VENT`, `FLOW`) set this so the
+    /// classification is machine-checkable rather than inferred from prose.
+    pub execution_form: ExecutionForm,
+}
+
+pub(super) const SPEC_DEFAULT: BuiltinSpec = BuiltinSpec {
+    name: "",
+    category: "",
+    hover_summary: "",
+    hover_syntax: "",
+    executor_key: None,
+    mass: MassContract::Dynamic,
+    eval_cost: EvalCost::Trivial,
+    order_sensitive: false,
+    summary: "",
+    role: "",
+    stack_effect: "",
+    stability: "stable",
+    purity: WordPurity::Pure,
+    effects: &[],
+    deterministic: true,
+    safe_preview: true,
+    partiality: Partiality::Total,
+    nil_policy: NilPolicy::Passthrough,
+    safety_level: SafetyLevel::A,
+    execution_form: ExecutionForm::RuntimeWord,
+};
@@ -1,18 +1,20 @@
 mod builtin_word_definitions;
+#[cfg(test)]
+mod builtin_word_definitions_tests;
 mod builtin_word_details;
 #[cfg(test)]
 mod builtin_word_details_tests;
 mod builtin_word_lookup_docs;
 mod builtin_word_types;
 
-pub use builtin_word_definitions::{builtin_specs, lookup_builtin_spec, BuiltinSpec};
+pub use builtin_word_definitions::{builtin_specs, lookup_builtin_spec};
 // Re-exported for the wasm bindings (feature = "wasm") only; the re-export is
 // unused in a default build, so the lint is allowed there only.
 #[cfg_attr(not(feature = "wasm"), allow(unused_imports))]
 pub use builtin_word_definitions::collect_core_builtin_definitions;
 pub use builtin_word_details::lookup_builtin_detail;
 pub use builtin_word_details::render_four_section;
-pub use builtin_word_types::{BuiltinExecutorKey, WordShape};
+pub use builtin_word_types::{BuiltinExecutorKey, BuiltinSpec, WordShape};
 
 use crate::types::{Capabilities, Stability, Tier, WordDefinition};
 use std::collections::{HashMap, HashSet};
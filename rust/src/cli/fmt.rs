@@ -53,7 +53,7 @@ fn is_string_close_delimiter(ch: Option<char>) -> bool {
 /// Tokenize the source into lines of token strings. Strings and comments are
 /// captured verbatim as single tokens; delimiters and words each become their
 /// own token. Returns `None` when the source cannot be safely reformatted (an
-/// unterminated string, or a newline inside a string literal).
+/// unterminated string, or a newline inside a `'...'` or backtick string).
 fn scan_lines(source: &str) -> Option<Vec<Vec<String>>> {
     let chars: Vec<char> = source.chars().collect();
     let n = chars.len();
@@ -104,6 +104,13 @@ fn scan_lines(source: &str) -> Option<Vec<Vec<String>>> {
                     return None; // newline inside a string: refuse to reformat
                 }
                 s.push(cj);
+                if cj == '\\' {
+                    // An escape pair is copied as-is; its `'` never closes.
+                    let escaped = *chars.get(j + 1).filter(|&&c| c != '\n')?;
+                    s.push(escaped);
+                    j += 2;
+                    continue;
+                }
                 if cj == '\'' && is_string_close_delimiter(chars.get(j + 1).copied()) {
                     closed = true;
                     j += 1;
@@ -119,6 +126,19 @@ fn scan_lines(source: &str) -> Option<Vec<Vec<String>>> {
             continue;
         }
 
+        if c == '`' {
+            // A backtick string runs to the next backtick; kept verbatim.
+            push_word!();
+            let end = chars[i + 1..].iter().position(|&cj| cj == '`')? + i + 1;
+            let s: String = chars[i..=end].iter().collect();
+            if s.contains('\n') {
+                return None; // line breaks inside a string: refuse to reformat
+            }
+            line.push(s);
+            i = end + 1;
+            continue;
+        }
+
         if c.is_whitespace() {
             push_word!();
             i += 1;
@@ -189,15 +189,11 @@ pub(crate) fn check_plan(interp: &Interpreter, src: &str) -> Result<PlanCheck, S
         // comparison (LT/SORT/…) projects to logical U, not NIL, so it is
         // deliberately not flagged here.
         match meta.nil_policy {
-            NilPolicy::CreatesNil => {
-                if seen_bubble.insert(canonical.to_string()) {
-                    may_bubble.push(canonical.into_owned());
-                }
+            NilPolicy::CreatesNil if seen_bubble.insert(canonical.to_string()) => {
+                may_bubble.push(canonical.into_owned());
             }
-            NilPolicy::RejectsNil => {
-                if seen_reject.insert(canonical.to_string()) {
-                    rejects_nil.push(canonical.into_owned());
-                }
+            NilPolicy::RejectsNil if seen_reject.insert(canonical.to_string()) => {
+                rejects_nil.push(canonical.into_owned());
             }
             _ => {}
         }
@@ -207,10 +207,13 @@ impl CorewordMetadata {
 /// Entries: `(WORD, &[real_module_listings], &[category_listings])`.
 const CORE_BOUNDARY_LISTINGS: &[(&str, &[&str], &[&str])] = &[
     ("PRINT", &["IO"], &[]),
+    ("INSPECT", &["IO"], &[]),
     ("STR", &[], &["CAST"]),
     ("NUM", &[], &["CAST"]),
     ("BOOL", &[], &["CAST"]),
+    ("DECIMAL", &[], &["CAST"]),
     ("CHR", &[], &["TEXT"]),
+    ("CODEPOINT", &[], &["TEXT"]),
     ("CHARS", &[], &["TEXT"]),
     ("JOIN", &[], &["TEXT"]),
     ("TRIM", &[], &["TEXT"]),
@@ -218,6 +221,7 @@ const CORE_BOUNDARY_LISTINGS: &[(&str, &[&str], &[&str])] = &[
     ("TRIM-RIGHT", &[], &["TEXT"]),
     ("TOKENIZE", &[], &["TEXT"]),
     ("SUBSTITUTE", &[], &["TEXT"]),
+    ("FORMAT-TEXT", &[], &["TEXT"]),
     ("STARTS-WITH?", &[], &["TEXT"]),
     ("ENDS-WITH?", &[], &["TEXT"]),
     ("MOD", &["MATH"], &[]),
@@ -233,6 +237,9 @@ const CORE_BOUNDARY_LISTINGS: &[(&str, &[&str], &[&str])] = &[
     ("RANK", &[], &["TENSOR"]),
     ("RESHAPE", &[], &["TENSOR"]),
     ("TRANSPOSE", &[], &["TENSOR"]),
+    ("SQUEEZE", &[], &["TENSOR"]),
+    ("UNSQUEEZE", &[], &["TENSOR"]),
+    ("AXISGET", &[], &["TENSOR"]),
     ("FILL", &[], &["TENSOR"]),
     ("SPAWN", &[], &["RUNTIME"]),
     ("AWAIT", &[], &["RUNTIME"]),
@@ -522,7 +529,7 @@ impl std::hash::Hash for CanonicalHome {
 
 fn builtin_profile(name: &str) -> (WordProfile, Option<HostCapability>) {
     match name {
-        "PRINT" => (WordProfile::Hosted, Some(HostCapability::Effect)),
+        "PRINT" | "INSPECT" => (WordProfile::Hosted, Some(HostCapability::Effect)),
         _ => (WordProfile::Core, None),
     }
 }
@@ -1235,6 +1242,9 @@ mod tests {
             "RANK",
             "RESHAPE",
             "TRANSPOSE",
+            "SQUEEZE",
+            "UNSQUEEZE",
+            "AXISGET",
             "FILL",
             "SPAWN",
             "AWAIT",
@@ -2,6 +2,7 @@ use crate::elastic::purity_table::{purity_by_name, Purity};
 
 const DENY_WORDS: &[&str] = &[
     "PRINT",
+    "INSPECT",
     "INPUT",
     "IMPORT",
     "RESTORE-MODULE",
@@ -92,11 +92,13 @@ pub fn purity_by_name(name: &str) -> Option<PurityInfo> {
                 order_sensitive: true,
             })
         }
-        "CSPRNG" | "CRYPTO@CSPRNG" => Some(PurityInfo {
-            purity: Purity::Impure,
-            cost: EvalCost::Light,
-            order_sensitive: true,
-        }),
+        "CSPRNG" | "CRYPTO@CSPRNG" | "SHUFFLE" | "CRYPTO@SHUFFLE" | "SAMPLE" | "CRYPTO@SAMPLE" => {
+            Some(PurityInfo {
+                purity: Purity::Impure,
+                cost: EvalCost::Light,
+                order_sensitive: true,
+            })
+        }
         // Serial I/O drives external hardware: always impure, order-sensitive,
         // and never eligible for speculative reordering or caching.
         "SERIAL@LIST-PORTS" | "SERIAL@OPEN" | "SERIAL@CONFIGURE" | "SERIAL@WRITE"
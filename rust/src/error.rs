@@ -54,6 +54,7 @@ pub enum ErrorCategory {
     ModeUnsupported,
     BuiltinProtection,
     CondExhausted,
+    Breakpoint,
     Custom,
 }
 
@@ -72,6 +73,7 @@ impl ErrorCategory {
             ErrorCategory::ModeUnsupported => "modeUnsupported",
             ErrorCategory::BuiltinProtection => "builtinProtection",
             ErrorCategory::CondExhausted => "condExhausted",
+            ErrorCategory::Breakpoint => "breakpoint",
             ErrorCategory::Custom => "custom",
         }
     }
@@ -90,6 +92,7 @@ impl ErrorCategory {
             AjisaiError::ModeUnsupported { .. } => ErrorCategory::ModeUnsupported,
             AjisaiError::BuiltinProtection { .. } => ErrorCategory::BuiltinProtection,
             AjisaiError::CondExhausted => ErrorCategory::CondExhausted,
+            AjisaiError::Breakpoint { .. } => ErrorCategory::Breakpoint,
             AjisaiError::Custom(_) => ErrorCategory::Custom,
         }
     }
@@ -155,6 +158,11 @@ pub enum AjisaiError {
     Custom(String),
 
     CondExhausted,
+    /// `IO@BREAK` stopped execution because the host asked it to pause.
+    /// `depth` is the stack depth at the break; the stack is left as it was.
+    Breakpoint {
+        depth: usize,
+    },
 }
 
 impl AjisaiError {
@@ -202,6 +210,9 @@ impl fmt::Display for AjisaiError {
             AjisaiError::CondExhausted => {
                 write!(f, "COND: all guards failed and no else clause")
             }
+            AjisaiError::Breakpoint { depth } => {
+                write!(f, "Breakpoint (stack depth {})", depth)
+            }
         }
     }
 }
@@ -1,8 +1,15 @@
 use crate::error::{AjisaiError, NilReason, Result};
-use crate::interpreter::value_extraction_helpers::{extract_operands, push_result};
+use crate::interpreter::comparison::{push_comparison_unknown, three_way_compare, OrderOutcome};
+use crate::interpreter::higher_order::{
+    execute_executable_code, extract_executable_code, ExecutableCode,
+};
+use crate::interpreter::value_extraction_helpers::{
+    extract_count_from_value, extract_operands, push_result,
+};
 use crate::interpreter::{ConsumptionMode, Interpreter, OperationTargetMode};
 use crate::semantic::{AbsenceOrigin, Recoverability};
 use crate::types::{Interpretation, Value};
+use std::cmp::Ordering;
 
 fn require_stack_top(interp: &Interpreter, word: &str) -> Result<()> {
     if interp.operation_target_mode != OperationTargetMode::StackTop {
@@ -52,6 +59,66 @@ pub fn op_unique(interp: &mut Interpreter) -> Result<()> {
     Ok(())
 }
 
+/// Elements of a vector operand, with NIL read as the empty vector.
+fn vector_or_empty(word: &str, val: &Value, label: &str) -> Result<Vec<Value>> {
+    if val.is_nil() {
+        return Ok(Vec::new());
+    }
+    val.as_vector_view()
+        .map(|view| view.into_owned())
+        .ok_or_else(|| {
+            AjisaiError::create_structure_error(
+                &format!("{}: expected vector as {}", word, label),
+                "non-vector value",
+            )
+        })
+}
+
+fn compress_values(values: &Value, mask: &Value) -> Result<Value> {
+    let items = vector_or_empty("COMPRESS", values, "values")?;
+    let flags = vector_or_empty("COMPRESS", mask, "mask")?;
+    if items.len() != flags.len() {
+        return Err(AjisaiError::from(format!(
+            "COMPRESS: values has {} elements but mask has {}",
+            items.len(),
+            flags.len()
+        )));
+    }
+    let mut kept = Vec::new();
+    for (i, (item, flag)) in items.into_iter().zip(&flags).enumerate() {
+        match flag.as_truth() {
+            Some(true) => kept.push(item),
+            Some(false) => {}
+            None if flag.is_nil() || flag.is_unknown() => {}
+            None => {
+                return Err(AjisaiError::from(format!(
+                    "COMPRESS: mask element {} is not a truth value",
+                    i
+                )))
+            }
+        }
+    }
+    Ok(Value::from_vector_with_hint(kept, values.hint))
+}
+
+/// `values mask -- kept`. Keep the elements whose mask element is TRUE;
+/// FALSE, UNKNOWN and NIL drop the element, as in `DATA@WHERE`. The two
+/// vectors must have the same length. Nothing kept projects to NIL.
+pub fn op_compress(interp: &mut Interpreter) -> Result<()> {
+    require_stack_top(interp, "COMPRESS")?;
+    let operands = extract_operands(interp, 2)?;
+    match compress_values(&operands[0], &operands[1]) {
+        Ok(result) => {
+            push_result(interp, result);
+            Ok(())
+        }
+        Err(e) => {
+            restore_operands(interp, operands);
+            Err(e)
+        }
+    }
+}
+
 fn pop_vector_and_target(interp: &mut Interpreter, word: &str) -> Result<(Vec<Value>, Value)> {
     let operands = extract_operands(interp, 2)?;
     match operands[0].as_vector_view() {
@@ -104,3 +171,215 @@ pub fn op_index_of(interp: &mut Interpreter) -> Result<()> {
     }
     Ok(())
 }
+
+/// `sorted target -- index`. Lowest index of `target` in an ascending
+/// vector, found by lower-bound binary search in O(log n) comparisons.
+/// Sortedness is not checked. A miss projects to Bubble/NIL with
+/// `reason = missingField`, as for `INDEX-OF`; an undecidable comparison
+/// yields the logical Unknown (SPEC §7.4.3).
+pub fn op_binary_search(interp: &mut Interpreter) -> Result<()> {
+    require_stack_top(interp, "BINARY-SEARCH")?;
+    let operands = extract_operands(interp, 2)?;
+    let sorted: Vec<Value> = match operands[0].as_vector_view() {
+        Some(view) => view.into_owned(),
+        None if operands[0].is_nil() => Vec::new(),
+        None => {
+            restore_operands(interp, operands);
+            return Err(AjisaiError::create_structure_error(
+                "BINARY-SEARCH: expected vector as first operand",
+                "non-vector value",
+            ));
+        }
+    };
+
+    let (mut lo, mut hi) = (0, sorted.len());
+    let mut found = false;
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        match three_way_compare(&sorted[mid], &operands[1]) {
+            Ok(OrderOutcome::Decided(Ordering::Less)) => lo = mid + 1,
+            Ok(OrderOutcome::Decided(ord)) => {
+                found |= ord == Ordering::Equal;
+                hi = mid;
+            }
+            Ok(OrderOutcome::Undecided(prefix)) => {
+                push_comparison_unknown(interp, prefix);
+                return Ok(());
+            }
+            Err(e) => {
+                restore_operands(interp, operands);
+                return Err(e);
+            }
+        }
+    }
+
+    if found {
+        push_result(interp, Value::from_int(lo as i64));
+        interp.stack.set_last_role(Interpretation::RawNumber);
+    } else {
+        push_result(
+            interp,
+            Value::bubble_with_reason(
+                NilReason::MissingField,
+                AbsenceOrigin::ExecutionFailure,
+                Recoverability::Recoverable,
+            ),
+        );
+    }
+    Ok(())
+}
+
+/// Split an association vector `[ [ k v ] ... ]` into its pairs. NIL is the
+/// empty association. An entry that is not a 2-element vector is malformed
+/// use and the error names its index.
+fn assoc_pairs(word: &str, assoc: &Value) -> Result<Vec<(Value, Value)>> {
+    if assoc.is_nil() {
+        return Ok(Vec::new());
+    }
+    let view = assoc.as_vector_view().ok_or_else(|| {
+        AjisaiError::create_structure_error(
+            &format!("{}: expected a vector of [ key value ] pairs", word),
+            "non-vector value",
+        )
+    })?;
+    view.iter()
+        .enumerate()
+        .map(|(i, entry)| match (entry.child(0), entry.child(1)) {
+            (Some(k), Some(v)) if entry.is_vector() && entry.len() == 2 => Ok((k, v)),
+            _ => Err(AjisaiError::from(format!(
+                "{}: entry {} is not a [ key value ] pair",
+                word, i
+            ))),
+        })
+        .collect()
+}
+
+/// `assoc key -- value`. Value of the first pair whose key equals `key`,
+/// using the same equality as `INDEX-OF`. A missing key projects to
+/// Bubble/NIL with `reason = missingField`.
+pub fn op_assoc_get(interp: &mut Interpreter) -> Result<()> {
+    require_stack_top(interp, "ASSOC-GET")?;
+    let operands = extract_operands(interp, 2)?;
+    let pairs = match assoc_pairs("ASSOC-GET", &operands[0]) {
+        Ok(pairs) => pairs,
+        Err(e) => {
+            restore_operands(interp, operands);
+            return Err(e);
+        }
+    };
+    match pairs.into_iter().find(|(k, _)| k == &operands[1]) {
+        Some((_, value)) => push_result(interp, value),
+        None => push_result(
+            interp,
+            Value::bubble_with_reason(
+                NilReason::MissingField,
+                AbsenceOrigin::ExecutionFailure,
+                Recoverability::Recoverable,
+            ),
+        ),
+    }
+    Ok(())
+}
+
+/// `assoc key value -- assoc'`. Replace the value of the first pair whose
+/// key equals `key`, keeping its position, or append `[ key value ]` when
+/// no pair matches. NIL is accepted as the empty association.
+pub fn op_assoc_set(interp: &mut Interpreter) -> Result<()> {
+    require_stack_top(interp, "ASSOC-SET")?;
+    let operands = extract_operands(interp, 3)?;
+    let mut pairs = match assoc_pairs("ASSOC-SET", &operands[0]) {
+        Ok(pairs) => pairs,
+        Err(e) => {
+            restore_operands(interp, operands);
+            return Err(e);
+        }
+    };
+    let (key, value) = (operands[1].clone(), operands[2].clone());
+    match pairs.iter_mut().find(|(k, _)| k == &key) {
+        Some(slot) => slot.1 = value,
+        None => pairs.push((key, value)),
+    }
+    let entries = pairs
+        .into_iter()
+        .map(|(k, v)| Value::from_vector_promoted(vec![k, v]))
+        .collect();
+    push_result(interp, Value::from_vector_promoted(entries));
+    Ok(())
+}
+
+fn assoc_column(interp: &mut Interpreter, word: &str, take_key: bool) -> Result<()> {
+    require_stack_top(interp, word)?;
+    let operands = extract_operands(interp, 1)?;
+    let pairs = match assoc_pairs(word, &operands[0]) {
+        Ok(pairs) => pairs,
+        Err(e) => {
+            restore_operands(interp, operands);
+            return Err(e);
+        }
+    };
+    let column = pairs
+        .into_iter()
+        .map(|(k, v)| if take_key { k } else { v })
+        .collect();
+    push_result(interp, Value::from_vector_promoted(column));
+    Ok(())
+}
+
+/// `assoc -- keys`. First element of every pair, in order. NIL and an empty
+/// association give NIL.
+pub fn op_assoc_keys(interp: &mut Interpreter) -> Result<()> {
+    assoc_column(interp, "ASSOC-KEYS", true)
+}
+
+/// `assoc -- values`. Second element of every pair, in order.
+pub fn op_assoc_values(interp: &mut Interpreter) -> Result<()> {
+    assoc_column(interp, "ASSOC-VALUES", false)
+}
+
+/// Same bound as `ITERATE`: a mistyped count fails fast.
+const MAX_TIMES: usize = 10000;
+
+/// `n { body } --`. Run `body` `n` times on the live stack, pushing the
+/// 0-based iteration index before each run: `3 { } TIMES-INDEXED` leaves
+/// `0 1 2`. The body sees everything below the index, so it can accumulate
+/// into a value it leaves on the stack. Errors before the first run restore
+/// both operands; an error inside the body stops the loop where it is.
+pub fn op_times_indexed(interp: &mut Interpreter) -> Result<()> {
+    require_stack_top(interp, "TIMES-INDEXED")?;
+    if interp.stack.len() < 2 {
+        return Err(AjisaiError::StackUnderflow);
+    }
+    let code_val = interp.stack.pop().expect("length checked above");
+    let count_val = interp.stack.pop().expect("length checked above");
+
+    let prepared = extract_count_from_value(&count_val).and_then(|count| {
+        if count > MAX_TIMES {
+            return Err(AjisaiError::from(format!(
+                "TIMES-INDEXED: count {} exceeds the {} iteration limit",
+                count, MAX_TIMES
+            )));
+        }
+        let exec = extract_executable_code(interp, &code_val)?;
+        if let ExecutableCode::WordName(ref word_name) = exec {
+            if !interp.word_exists(word_name) {
+                return Err(AjisaiError::UnknownWord(word_name.clone()));
+            }
+        }
+        Ok((count, exec))
+    });
+    let (count, exec) = match prepared {
+        Ok(prepared) => prepared,
+        Err(err) => {
+            interp.stack.push(count_val);
+            interp.stack.push(code_val);
+            return Err(err);
+        }
+    };
+
+    for index in 0..count {
+        interp.stack.push(Value::from_int(index as i64));
+        execute_executable_code(interp, &exec)?;
+        interp.reset_execution_modes();
+    }
+    Ok(())
+}
@@ -1,4 +1,5 @@
-//! Test suite for `crate::interpreter::algo_ops` (ALGO UNIQUE/CONTAINS/INDEX-OF).
+//! Test suite for `crate::interpreter::algo_ops` (ALGO UNIQUE/CONTAINS/INDEX-OF,
+//! TIMES-INDEXED).
 
 #[cfg(test)]
 mod tests {
@@ -106,4 +107,188 @@ mod tests {
         assert_eq!(interp.stack.len(), 3);
         assert_eq!(interp.stack[2].as_truth(), Some(true));
     }
+
+    #[tokio::test]
+    async fn assoc_get_finds_value_or_projects_miss() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute("'algo' IMPORT [ [ 'a' 1 ] [ 'b' 2 ] ] 'b' ASSOC-GET")
+            .await
+            .expect("should succeed");
+        assert_eq!(interp.stack[0].as_scalar().unwrap().to_i64().unwrap(), 2);
+
+        interp.stack.clear();
+        interp
+            .execute("'algo' IMPORT [ [ 1 10 ] [ 2 20 ] ] 3 ASSOC-GET")
+            .await
+            .expect("a missing key is not an error");
+        assert!(interp.stack[0].is_nil());
+    }
+
+    #[tokio::test]
+    async fn assoc_set_updates_in_place_or_appends() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute("'algo' IMPORT [ [ 1 10 ] [ 2 20 ] [ 3 30 ] ] 2 99 ASSOC-SET")
+            .await
+            .expect("should succeed");
+        assert_eq!(
+            interp.stack[0].to_string(),
+            "[ [ 1/1 10/1 ] [ 2/1 99/1 ] [ 3/1 30/1 ] ]"
+        );
+
+        interp.stack.clear();
+        interp
+            .execute("'algo' IMPORT NIL 4 40 ASSOC-SET")
+            .await
+            .expect("NIL is the empty association");
+        assert_eq!(interp.stack[0].to_string(), "[ [ 4/1 40/1 ] ]");
+    }
+
+    #[tokio::test]
+    async fn assoc_get_rejects_malformed_entry() {
+        let mut interp = Interpreter::new();
+        let result = interp
+            .execute("'algo' IMPORT [ [ 1 10 ] [ 2 ] ] 1 ASSOC-GET")
+            .await;
+        let err = result.expect_err("a 1-element entry is malformed");
+        assert!(err.to_string().contains("entry 1"), "{}", err);
+        assert_eq!(interp.stack.len(), 2, "operands restored on error");
+    }
+
+    #[tokio::test]
+    async fn assoc_keys_and_values_split_the_pairs() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute("'algo' IMPORT [ [ 1 10 ] [ 2 20 ] [ 3 30 ] ] ASSOC-KEYS")
+            .await
+            .expect("should succeed");
+        assert_eq!(interp.stack[0].to_string(), "[ 1/1 2/1 3/1 ]");
+
+        let mut interp = Interpreter::new();
+        interp
+            .execute("'algo' IMPORT [ [ 1 10 ] [ 2 20 ] [ 3 30 ] ] ASSOC-VALUES")
+            .await
+            .expect("should succeed");
+        assert_eq!(interp.stack[0].to_string(), "[ 10/1 20/1 30/1 ]");
+    }
+
+    #[tokio::test]
+    async fn assoc_values_rejects_malformed_entry() {
+        let mut interp = Interpreter::new();
+        let result = interp
+            .execute("'algo' IMPORT [ [ 'a' 1 ] [ 'b' 2 ] 3 ] ASSOC-VALUES")
+            .await;
+        let err = result.expect_err("a scalar entry is malformed");
+        assert!(err.to_string().contains("entry 2"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn binary_search_returns_lowest_matching_index() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute("'algo' IMPORT [ 1 2 2 2 5 8 ] 2 BINARY-SEARCH")
+            .await
+            .expect("should succeed");
+        assert_eq!(interp.stack[0].as_scalar().unwrap().to_i64().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn binary_search_finds_last_element() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute("'algo' IMPORT [ 1/2 1 3 7 ] 7 BINARY-SEARCH")
+            .await
+            .expect("should succeed");
+        assert_eq!(interp.stack[0].as_scalar().unwrap().to_i64().unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn binary_search_misses_on_absent_values() {
+        for code in [
+            "'algo' IMPORT [ 1 3 5 ] 9 BINARY-SEARCH",
+            "'algo' IMPORT [ 1 3 5 ] 4 BINARY-SEARCH",
+        ] {
+            let mut interp = Interpreter::new();
+            interp.execute(code).await.expect("should succeed");
+            assert!(interp.stack[0].is_nil(), "{}", code);
+        }
+    }
+
+    #[tokio::test]
+    async fn compress_keeps_elements_under_a_true_mask() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute("'algo' IMPORT [ 1 2 3 4 ] ,, { 2 GT } MAP COMPRESS")
+            .await
+            .expect("should succeed");
+        assert_eq!(interp.stack.len(), 1);
+        assert_eq!(interp.stack[0].to_string(), "[ 3/1 4/1 ]");
+    }
+
+    #[tokio::test]
+    async fn compress_with_all_false_mask_is_empty() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute("'algo' IMPORT [ 1 2 3 ] [ FALSE FALSE FALSE ] COMPRESS")
+            .await
+            .expect("should succeed");
+        assert!(interp.stack[0].is_nil());
+    }
+
+    #[tokio::test]
+    async fn compress_rejects_length_mismatch() {
+        let mut interp = Interpreter::new();
+        let result = interp
+            .execute("'algo' IMPORT [ 1 2 3 ] [ TRUE FALSE ] COMPRESS")
+            .await;
+        let err = result.expect_err("lengths differ");
+        assert!(err.to_string().contains("mask has 2"), "{}", err);
+        assert_eq!(interp.stack.len(), 2, "operands are restored");
+    }
+
+    async fn stack_after(code: &str) -> Vec<String> {
+        let mut interp = Interpreter::new();
+        interp
+            .execute(&format!("'algo' IMPORT {}", code))
+            .await
+            .expect("should succeed");
+        interp.stack.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[tokio::test]
+    async fn times_indexed_pushes_each_index_before_the_body() {
+        assert_eq!(
+            stack_after("5 { } TIMES-INDEXED 5 COLLECT").await,
+            ["[ 0/1 1/1 2/1 3/1 4/1 ]"]
+        );
+    }
+
+    #[tokio::test]
+    async fn times_indexed_body_accumulates_into_a_vector() {
+        assert_eq!(
+            stack_after("[ 10 ] 3 { 1 COLLECT 2 CONCAT } TIMES-INDEXED").await,
+            ["[ 10/1 0/1 1/1 2/1 ]"]
+        );
+        assert_eq!(stack_after("0 5 { + } TIMES-INDEXED").await, ["10/1"]);
+    }
+
+    #[tokio::test]
+    async fn times_indexed_calls_a_named_word() {
+        assert_eq!(
+            stack_after("{ [ 2 ] * } 'TWICE' DEF 3 'TWICE' TIMES-INDEXED").await,
+            ["[ 0/1 ]", "[ 2/1 ]", "[ 4/1 ]"]
+        );
+        assert!(stack_after("0 { } TIMES-INDEXED").await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn times_indexed_restores_operands_on_bad_input() {
+        for code in ["3 'NO-SUCH-WORD' TIMES-INDEXED", "-1 { } TIMES-INDEXED"] {
+            let mut interp = Interpreter::new();
+            let result = interp.execute(&format!("'algo' IMPORT {}", code)).await;
+            assert!(result.is_err(), "{}", code);
+            assert_eq!(interp.stack.len(), 2, "operands are restored");
+        }
+    }
 }
@@ -859,3 +859,69 @@ async fn test_explain_describes_tuning_scope() {
         output
     );
 }
+
+#[tokio::test]
+async fn test_play_sequence_three_notes() {
+    let mut interp = Interpreter::new();
+    interp.execute("'music' IMPORT").await.unwrap();
+    let result = interp
+        .execute("[ [ 440 500 ] [ 0 250 ] [ 660 1000 ] ] MUSIC@PLAY-SEQUENCE")
+        .await;
+    assert!(result.is_ok(), "PLAY-SEQUENCE should succeed: {:?}", result);
+    assert!(interp.stack.is_empty());
+
+    let output = interp.collect_output();
+    assert!(output.contains("\"type\":\"seq\""), "{}", output);
+    assert!(
+        output.contains("{\"type\":\"tone\",\"frequency\":440.0,\"duration\":0.5}"),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains("{\"type\":\"rest\",\"duration\":0.25}"),
+        "{}",
+        output
+    );
+}
+
+#[tokio::test]
+async fn test_play_sequence_rejects_non_pairs() {
+    let mut interp = Interpreter::new();
+    interp.execute("'music' IMPORT").await.unwrap();
+    let err = interp
+        .execute("[ [ 440 500 ] 550 ] MUSIC@PLAY-SEQUENCE")
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("element 1"), "{}", err);
+    assert_eq!(interp.stack.len(), 1, "operand restored");
+    assert!(!interp.collect_output().contains("AUDIO:"));
+}
+
+#[tokio::test]
+async fn test_midi_hz_converts_note_numbers() {
+    let mut interp = Interpreter::new();
+    interp.execute("'music' IMPORT").await.unwrap();
+    interp
+        .execute("69 MUSIC@MIDI-HZ [ 81 ] MUSIC@MIDI-HZ 57 MUSIC@MIDI-HZ 60 MUSIC@MIDI-HZ")
+        .await
+        .unwrap();
+    let shown: Vec<String> = interp.stack.iter().map(|v| v.to_string()).collect();
+    // octaves of A4 are exact
+    assert_eq!(shown[..3], ["440/1", "880/1", "220/1"]);
+    // middle C is a bounded-denominator approximation of 261.6255653...
+    let c4 = interp.stack[3].as_scalar().unwrap().clone();
+    assert!(c4.denominator() <= num_bigint::BigInt::from(1000));
+    let hz = num_traits::ToPrimitive::to_f64(&c4).unwrap();
+    assert!((hz - 261.625_565_3).abs() < 1e-3);
+}
+
+#[tokio::test]
+async fn test_midi_hz_rejects_out_of_range_notes() {
+    let mut interp = Interpreter::new();
+    interp.execute("'music' IMPORT").await.unwrap();
+    for program in ["128 MUSIC@MIDI-HZ", "-1 MUSIC@MIDI-HZ", "1/2 MUSIC@MIDI-HZ"] {
+        assert!(interp.execute(program).await.is_err(), "{}", program);
+        assert_eq!(interp.stack.len(), 1, "operand kept: {}", program);
+        interp.stack.clear();
+    }
+}
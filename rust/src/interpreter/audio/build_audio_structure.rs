@@ -4,7 +4,7 @@ use super::audio_types::{
 };
 use crate::error::{AjisaiError, Result};
 use crate::interpreter::value_extraction_helpers::{is_string_value, value_as_string};
-use crate::types::{Value, ValueData};
+use crate::types::{Interpretation, Value, ValueData};
 use num_traits::ToPrimitive;
 
 pub fn op_play(interp: &mut Interpreter) -> Result<()> {
@@ -57,6 +57,96 @@ pub fn op_play(interp: &mut Interpreter) -> Result<()> {
     Ok(())
 }
 
+/// Lower one `[ frequency duration_ms ]` pair of `MUSIC@PLAY-SEQUENCE`. The
+/// duration is converted to seconds, as for a `music.note`; a frequency of 0
+/// is a rest.
+fn sequence_pair_to_structure(
+    pair: &Value,
+    position: usize,
+    output: &mut String,
+) -> Result<AudioStructure> {
+    let fields = match &pair.data {
+        ValueData::Vector(_) | ValueData::Tensor { .. } if pair.len() == 2 => {
+            (pair.child(0), pair.child(1))
+        }
+        _ => (None, None),
+    };
+    let (Some(frequency), Some(duration_ms)) = fields else {
+        return Err(AjisaiError::from(format!(
+            "MUSIC@PLAY-SEQUENCE: element {} is not a [ frequency duration_ms ] pair",
+            position
+        )));
+    };
+    let (Some(frequency), Some(duration_ms)) = (
+        frequency.as_scalar().and_then(|f| f.to_f64()),
+        duration_ms.as_scalar().and_then(|f| f.to_f64()),
+    ) else {
+        return Err(AjisaiError::from(format!(
+            "MUSIC@PLAY-SEQUENCE: element {} must hold two numbers",
+            position
+        )));
+    };
+    tone_or_rest(
+        frequency,
+        duration_ms / 1000.0,
+        None,
+        WaveformType::default(),
+        output,
+    )
+}
+
+/// `MUSIC@PLAY-SEQUENCE`: play a vector of `[ frequency duration_ms ]` pairs
+/// one after another, as a single sequential structure, so each note starts
+/// when the previous one ends.
+pub fn op_play_sequence(interp: &mut Interpreter) -> Result<()> {
+    interp.require_host_capability(
+        "MUSIC@PLAY-SEQUENCE",
+        crate::interpreter::HostCapability::Audio,
+    )?;
+    if interp.operation_target_mode == OperationTargetMode::Stack {
+        return Err(AjisaiError::ModeUnsupported {
+            word: "MUSIC@PLAY-SEQUENCE".into(),
+            mode: "Stack".into(),
+        });
+    }
+
+    let val = interp.stack.pop().ok_or(AjisaiError::StackUnderflow)?;
+    let pairs = match val.as_vector_view() {
+        Some(view) if val.hint != Interpretation::Text => view.into_owned(),
+        _ => {
+            interp.stack.push(val);
+            return Err(AjisaiError::from(
+                "MUSIC@PLAY-SEQUENCE requires a vector of [ frequency duration_ms ] pairs",
+            ));
+        }
+    };
+
+    let mut output = String::new();
+    let children = match pairs
+        .iter()
+        .enumerate()
+        .map(|(position, pair)| sequence_pair_to_structure(pair, position, &mut output))
+        .collect::<Result<Vec<_>>>()
+    {
+        Ok(children) => children,
+        Err(error) => {
+            interp.stack.push(val);
+            return Err(error);
+        }
+    };
+    interp.output_buffer.push_str(&output);
+
+    let structure = AudioStructure::Seq {
+        children,
+        envelope: None,
+        waveform: WaveformType::Sine,
+    };
+    if let Some(json) = emit_play_command(&structure, &mut interp.output_buffer) {
+        interp.emit_host_effect(crate::interpreter::HostEffect::Audio(json));
+    }
+    Ok(())
+}
+
 pub(crate) fn build_audio_structure(
     value: &Value,
     mode: PlayMode,
@@ -3,7 +3,8 @@ use super::audio_types::{update_play_mode, PlayMode, WaveformType};
 use super::music_group::{explain_value, make_group, operand_children, GroupMode};
 use super::music_values::{
     make_duration, make_edo_pitch, make_hz_pitch, make_note, make_rest, make_scope, make_tuning,
-    parse_ratio, record_kind, tuning_components, DURATION_KIND, PITCH_KIND, TUNING_KIND,
+    midi_note_hz, parse_ratio, record_kind, tuning_components, DURATION_KIND, PITCH_KIND,
+    TUNING_KIND,
 };
 use crate::error::{AjisaiError, Result};
 use crate::interpreter::value_extraction_helpers::extract_integer_from_value;
@@ -277,6 +278,25 @@ pub fn op_hz(interp: &mut Interpreter) -> Result<()> {
     Ok(())
 }
 
+/// Convert a MIDI note number (0-127, 69 = A4) to its 12-EDO frequency in
+/// Hz as a bounded-denominator rational (see `midi_note_hz`).
+pub fn op_midi_hz(interp: &mut Interpreter) -> Result<()> {
+    reject_stack_mode(interp, "MIDI-HZ")?;
+    let operands = peek_operands(interp, 1)?;
+
+    let note = extract_integer_from_value(&operands[0])
+        .map_err(|_| AjisaiError::from("MUSIC@MIDI-HZ requires an integer note number"))?;
+    if !(0..=127).contains(&note) {
+        return Err(AjisaiError::from(format!(
+            "MUSIC@MIDI-HZ note {} is outside the MIDI range 0-127",
+            note
+        )));
+    }
+
+    consume_and_push(interp, 1, Value::from_fraction(midi_note_hz(note)));
+    Ok(())
+}
+
 /// Build a `music.duration` from a number of seconds.
 pub fn op_dur(interp: &mut Interpreter) -> Result<()> {
     reject_stack_mode(interp, "DUR")?;
@@ -16,8 +16,10 @@
 //! lowered to `AudioStructure` at playback time.
 
 use crate::interpreter::value_extraction_helpers::value_as_string;
+use crate::types::exact::ExactReal;
 use crate::types::fraction::Fraction;
 use crate::types::{Interpretation, Value, ValueData};
+use num_bigint::BigInt;
 use num_traits::ToPrimitive;
 use std::sync::Arc;
 
@@ -198,6 +200,42 @@ pub(crate) fn tuning_components(value: &Value) -> Option<(Fraction, (Fraction, F
     Some((reference, equave, divisions))
 }
 
+/// Denominator bound for `MUSIC@MIDI-HZ`. A bound of 1000 keeps every
+/// frequency within a millihertz of the true equal-tempered value.
+pub(crate) const MIDI_HZ_MAX_DENOMINATOR: i64 = 1000;
+
+/// The 12-EDO frequency of a MIDI note number (69 = A4 = 440 Hz).
+///
+/// Octaves of A (`note - 69` divisible by 12) are exact. Otherwise
+/// `2^(k/12)` is irrational: its floor at 64 fractional bits is taken with an
+/// integer 12th root, scaled, and reduced to the best rational approximation
+/// whose denominator is at most [`MIDI_HZ_MAX_DENOMINATOR`].
+pub(crate) fn midi_note_hz(note: i64) -> Fraction {
+    const FRACTION_BITS: u32 = 64;
+    let semitones = note - 69;
+    let octaves = semitones.div_euclid(12);
+    let remainder = semitones.rem_euclid(12) as u32;
+
+    let octave_scale = if octaves >= 0 {
+        Fraction::new(BigInt::from(2).pow(octaves as u32), BigInt::from(1))
+    } else {
+        Fraction::new(BigInt::from(1), BigInt::from(2).pow((-octaves) as u32))
+    };
+    let a4 = Fraction::from(440);
+    if remainder == 0 {
+        return a4.mul(&octave_scale);
+    }
+
+    let root = BigInt::from(2)
+        .pow(remainder + 12 * FRACTION_BITS)
+        .nth_root(12);
+    let ratio = Fraction::new(root, BigInt::from(2).pow(FRACTION_BITS));
+    let hz = a4.mul(&octave_scale).mul(&ratio);
+    ExactReal::from_fraction(hz.clone())
+        .best_rational_approximation(&BigInt::from(MIDI_HZ_MAX_DENOMINATOR))
+        .unwrap_or(hz)
+}
+
 /// Resolve a `music.pitch` to a concrete frequency in Hz.
 ///
 /// This is the boundary where an exact rational or a symbolic equal-division
@@ -0,0 +1,39 @@
+//! `IO@BREAK`: an interactive breakpoint for debugging definitions.
+//!
+//! BREAK always records the stack as it stands in the interpreter's debug
+//! buffer, kept apart from the PRINT output so a debugging session does not
+//! disturb program output. While the host has turned on pause-on-break, it
+//! then stops execution with `AjisaiError::Breakpoint`, which the WASM layer
+//! surfaces as a breakpoint rather than a failure. With pausing off, BREAK is
+//! a stack snapshot and execution continues.
+
+use super::Interpreter;
+use crate::error::{AjisaiError, Result};
+
+/// `--`. Record the current stack in the debug buffer as one line,
+/// `BREAK [depth]: v1 v2 ...`, bottom first; with pause-on-break set, stop
+/// with a Breakpoint error. The stack itself is left untouched.
+pub fn op_break(interp: &mut Interpreter) -> Result<()> {
+    let depth = interp.stack.len();
+    let rendered: Vec<String> = interp.stack.iter().map(|value| value.to_string()).collect();
+    interp
+        .debug_buffer
+        .push_str(&format!("BREAK [{}]: {}\n", depth, rendered.join(" ")));
+    if interp.pause_on_break {
+        return Err(AjisaiError::Breakpoint { depth });
+    }
+    Ok(())
+}
+
+impl Interpreter {
+    /// Make `IO@BREAK` stop execution with a Breakpoint error, not only
+    /// record the stack.
+    pub fn set_pause_on_break(&mut self, pause: bool) {
+        self.pause_on_break = pause;
+    }
+
+    /// Return and clear the debug buffer BREAK writes to.
+    pub fn take_debug_output(&mut self) -> String {
+        std::mem::take(&mut self.debug_buffer)
+    }
+}
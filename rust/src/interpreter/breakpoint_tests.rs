@@ -0,0 +1,57 @@
+//! Test suite for `crate::interpreter::breakpoint` (IO@BREAK).
+
+#[cfg(test)]
+mod tests {
+    use crate::error::AjisaiError;
+    use crate::interpreter::Interpreter;
+
+    #[tokio::test]
+    async fn break_records_the_stack_and_continues_by_default() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute("'io' IMPORT [ 1 ] [ 2 3 ] BREAK [ 4 ]")
+            .await
+            .expect("without pausing BREAK only records");
+        assert_eq!(
+            interp.take_debug_output(),
+            "BREAK [2]: [ 1/1 ] [ 2/1 3/1 ]\n"
+        );
+        assert_eq!(interp.stack.len(), 3, "execution continued past BREAK");
+        assert!(
+            interp.collect_output().is_empty(),
+            "program output untouched"
+        );
+    }
+
+    #[tokio::test]
+    async fn break_signals_a_breakpoint_when_pausing() {
+        let mut interp = Interpreter::new();
+        interp.set_pause_on_break(true);
+        let err = interp
+            .execute("'io' IMPORT [ 7 ] BREAK [ 8 ]")
+            .await
+            .expect_err("a paused BREAK stops execution");
+        assert!(
+            matches!(err, AjisaiError::Breakpoint { depth: 1 }),
+            "got: {err:?}"
+        );
+        assert_eq!(interp.take_debug_output(), "BREAK [1]: [ 7/1 ]\n");
+        assert_eq!(interp.stack.len(), 1, "nothing after BREAK ran");
+    }
+
+    #[tokio::test]
+    async fn try_does_not_catch_a_paused_break() {
+        let mut interp = Interpreter::new();
+        interp.set_pause_on_break(true);
+        let err = interp
+            .execute("'io' IMPORT [ 7 ] { [ 8 ] BREAK } { 'recovered' } TRY")
+            .await
+            .expect_err("the breakpoint passes through TRY");
+        assert!(
+            matches!(err, AjisaiError::Breakpoint { depth: 2 }),
+            "got: {err:?}"
+        );
+        assert_eq!(interp.take_debug_output(), "BREAK [2]: [ 7/1 ] [ 8/1 ]\n");
+        assert_eq!(interp.stack.len(), 2, "no rollback and no recovery");
+    }
+}
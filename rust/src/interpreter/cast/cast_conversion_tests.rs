@@ -370,4 +370,78 @@ mod tests {
             assert!(val.is_nil(), "NIL STR should return NIL, not a string");
         }
     }
+
+    #[tokio::test]
+    async fn test_codepoint_ascii_and_japanese() {
+        let mut interp = Interpreter::new();
+
+        interp.execute("'A' CODEPOINT").await.unwrap();
+        let val = interp.stack.last().unwrap();
+        assert!(is_number_value(val));
+        assert_eq!(val.as_scalar().unwrap().to_i64(), Some(65));
+
+        interp.stack.clear();
+        interp.execute("'あ' CODEPOINT").await.unwrap();
+        assert_eq!(
+            interp.stack.last().unwrap().as_scalar().unwrap().to_i64(),
+            Some(0x3042)
+        );
+
+        interp.stack.clear();
+        interp.execute("'あ' CODEPOINT CHR").await.unwrap();
+        assert_eq!(value_as_string(interp.stack.last().unwrap()).unwrap(), "あ");
+    }
+
+    #[tokio::test]
+    async fn test_codepoint_rejects_other_lengths() {
+        let mut interp = Interpreter::new();
+
+        let err = interp.execute("'AB' CODEPOINT").await.unwrap_err();
+        assert!(err.to_string().contains("single character"), "{}", err);
+        assert_eq!(interp.stack.len(), 1, "operand restored on error");
+
+        interp.stack.clear();
+        assert!(interp.execute("65 CODEPOINT").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_chr_out_of_range_codepoint() {
+        let mut interp = Interpreter::new();
+
+        interp.execute("1114112 CHR").await.unwrap();
+        assert!(interp.stack.last().unwrap().is_nil());
+    }
+
+    async fn decimal(program: &str) -> String {
+        let mut interp = Interpreter::new();
+        interp.execute(program).await.unwrap();
+        assert_eq!(interp.stack.len(), 1, "{}", program);
+        value_as_string(interp.stack.last().unwrap()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_decimal_truncates_and_stops_when_exact() {
+        assert_eq!(decimal("[ 1/3 ] [ 5 ] DECIMAL").await, "0.33333");
+        assert_eq!(decimal("[ 2/3 ] [ 3 ] DECIMAL").await, "0.666");
+        assert_eq!(decimal("[ 1/4 ] [ 5 ] DECIMAL").await, "0.25");
+        assert_eq!(decimal("[ 7 ] [ 2 ] DECIMAL").await, "7");
+        assert_eq!(decimal("[ 22/7 ] [ 0 ] DECIMAL").await, "3");
+    }
+
+    #[tokio::test]
+    async fn test_decimal_negative_values() {
+        assert_eq!(decimal("[ -1/3 ] [ 4 ] DECIMAL").await, "-0.3333");
+        assert_eq!(decimal("[ -5/4 ] [ 5 ] DECIMAL").await, "-1.25");
+    }
+
+    #[tokio::test]
+    async fn test_decimal_rejects_bad_operands() {
+        let mut interp = Interpreter::new();
+        assert!(interp.execute("'x' [ 2 ] DECIMAL").await.is_err());
+        assert_eq!(interp.stack.len(), 2, "operands kept on error");
+
+        interp.stack.clear();
+        assert!(interp.execute("[ 1/3 ] [ -1 ] DECIMAL").await.is_err());
+        assert_eq!(interp.stack.len(), 2, "operands kept on error");
+    }
 }
@@ -3,11 +3,16 @@ use crate::interpreter::cast::cast_value_helpers::{
     apply_unary_cast, format_fraction_to_string, format_value_to_string_repr_with_hint,
     is_boolean_value, is_number_value, is_string_value_with_hint,
 };
-use crate::interpreter::value_extraction_helpers::{create_number_value, value_as_string};
-use crate::interpreter::{Interpreter, OperationTargetMode};
+use crate::interpreter::value_extraction_helpers::{
+    create_number_value, extract_count_from_value, value_as_string,
+};
+use crate::interpreter::{ConsumptionMode, Interpreter, OperationTargetMode};
 use crate::semantic::{AbsenceOrigin, Recoverability};
 use crate::types::fraction::Fraction;
 use crate::types::{Interpretation, Value};
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{Signed, Zero};
 
 fn convert_value_to_string(val: &Value, hint: Interpretation) -> Result<Value> {
     if val.is_nil() {
@@ -177,3 +182,190 @@ fn convert_codepoint_to_char(val: &Value, hint: Interpretation) -> Result<Value>
 pub fn op_chr(interp: &mut Interpreter) -> Result<()> {
     apply_unary_cast(interp, convert_codepoint_to_char)
 }
+
+fn convert_char_to_codepoint(val: &Value, hint: Interpretation) -> Result<Value> {
+    if is_string_value_with_hint(val, hint) {
+        let s = value_as_string(val).unwrap_or_default();
+        let mut chars = s.chars();
+        return match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(create_number_value(Fraction::from(c as i64))),
+            _ => Err(AjisaiError::from(format!(
+                "CODEPOINT: expected a single character, got {} characters",
+                s.chars().count()
+            ))),
+        };
+    }
+    if is_number_value(val) {
+        return Err(AjisaiError::from("CODEPOINT: expected String, got Number"));
+    }
+    if is_boolean_value(val) {
+        return Err(AjisaiError::from("CODEPOINT: expected String, got Boolean"));
+    }
+    if val.is_nil() {
+        return Err(AjisaiError::from("CODEPOINT: expected String, got Nil"));
+    }
+    Err(AjisaiError::from("CODEPOINT: expected String input"))
+}
+
+/// `CODEPOINT`: the inverse of `CHR` — a one-character text to its Unicode
+/// scalar value.
+pub fn op_codepoint(interp: &mut Interpreter) -> Result<()> {
+    apply_unary_cast(interp, convert_char_to_codepoint)
+}
+
+/// Render `f` in base ten with at most `digits` fractional digits, truncated
+/// toward zero by exact long division. A terminating expansion stops early, so
+/// `1/4` with five digits is `0.25`.
+fn format_fraction_as_decimal(f: &Fraction, digits: usize) -> String {
+    let numerator = f.numerator();
+    let denominator = f.denominator();
+    let (whole, mut remainder) = numerator.abs().div_rem(&denominator);
+
+    let mut out = String::new();
+    if numerator.is_negative() {
+        out.push('-');
+    }
+    out.push_str(&whole.to_string());
+    if digits > 0 && !remainder.is_zero() {
+        out.push('.');
+        let ten = BigInt::from(10);
+        for _ in 0..digits {
+            if remainder.is_zero() {
+                break;
+            }
+            let (digit, rest) = (remainder * &ten).div_rem(&denominator);
+            out.push_str(&digit.to_string());
+            remainder = rest;
+        }
+    }
+    out
+}
+
+fn decimal_operand(val: &Value) -> Option<Fraction> {
+    if val.hint == Interpretation::Text {
+        return None;
+    }
+    match val.as_scalar() {
+        Some(f) => Some(f.clone()),
+        None if val.is_vector() && val.len() == 1 => val.child(0)?.as_scalar().cloned(),
+        None => None,
+    }
+}
+
+/// `DECIMAL`: a number as decimal text with a caller-chosen digit count.
+///
+/// ```text
+/// [ 1/3 ] [ 5 ] DECIMAL -> '0.33333'
+/// ```
+///
+/// The digits are exact (truncated, never rounded); NIL passes through.
+pub fn op_decimal(interp: &mut Interpreter) -> Result<()> {
+    format_with_digits(interp, "DECIMAL", format_fraction_as_decimal)
+}
+
+/// `PERCENT`: a number times 100 as DECIMAL text with a percent sign.
+///
+/// ```text
+/// [ 1/3 ] [ 1 ] PERCENT -> '33.3%'
+/// ```
+///
+/// Digits truncate exactly as DECIMAL's do; NIL passes through.
+pub fn op_percent(interp: &mut Interpreter) -> Result<()> {
+    format_with_digits(interp, "PERCENT", |f, digits| {
+        let hundredfold = f.mul(&Fraction::from(100));
+        format!("{}%", format_fraction_as_decimal(&hundredfold, digits))
+    })
+}
+
+/// Shared body of DECIMAL and PERCENT: `value digits -- 'text'`.
+fn format_with_digits(
+    interp: &mut Interpreter,
+    word: &str,
+    render: fn(&Fraction, usize) -> String,
+) -> Result<()> {
+    if interp.operation_target_mode == OperationTargetMode::Stack {
+        return Err(AjisaiError::ModeUnsupported {
+            word: word.into(),
+            mode: "Stack".into(),
+        });
+    }
+    let is_keep_mode = interp.consumption_mode == ConsumptionMode::Keep;
+    let [.., value_val, digits_val] = interp.stack.as_slice() else {
+        return Err(AjisaiError::StackUnderflow);
+    };
+
+    let digits = extract_count_from_value(digits_val).map_err(|_| {
+        AjisaiError::from(format!(
+            "{}: digit count must be a non-negative integer",
+            word
+        ))
+    })?;
+    if digits > interp.runtime_limits.max_materialized_elements {
+        return Err(AjisaiError::from(format!(
+            "{}: digit count {} exceeds the materialization limit",
+            word, digits
+        )));
+    }
+    let result = if value_val.is_nil() {
+        Value::nil()
+    } else {
+        let f = decimal_operand(value_val)
+            .ok_or_else(|| AjisaiError::from(format!("{}: expected a number", word)))?;
+        Value::from_string(&render(&f, digits))
+    };
+
+    if !is_keep_mode {
+        let len = interp.stack.len();
+        interp.stack.truncate(len - 2);
+    }
+    interp.stack.push(result);
+    Ok(())
+}
+
+/// Render `f` as a mixed number: the whole part, a space, then the proper
+/// fraction, with one leading sign. `7/3` is `2 1/3`, `-7/3` is `-2 1/3`,
+/// `6/3` is `2` and `1/3` stays `1/3`.
+fn format_fraction_as_mixed(f: &Fraction) -> String {
+    let numerator = f.numerator();
+    let denominator = f.denominator();
+    if denominator == BigInt::from(1) {
+        return numerator.to_string();
+    }
+    let (whole, remainder) = numerator.abs().div_rem(&denominator);
+    let sign = if numerator.is_negative() { "-" } else { "" };
+    if whole.is_zero() {
+        format!("{}{}/{}", sign, remainder, denominator)
+    } else {
+        format!("{}{} {}/{}", sign, whole, remainder, denominator)
+    }
+}
+
+/// `TOMIXED`: a number as mixed-number text.
+///
+/// ```text
+/// [ 7/3 ] TOMIXED -> '2 1/3'
+/// ```
+///
+/// Integers render without a fraction part; NIL passes through.
+pub fn op_to_mixed(interp: &mut Interpreter) -> Result<()> {
+    if interp.operation_target_mode == OperationTargetMode::Stack {
+        return Err(AjisaiError::ModeUnsupported {
+            word: "TOMIXED".into(),
+            mode: "Stack".into(),
+        });
+    }
+    let value_val = interp.stack.last().ok_or(AjisaiError::StackUnderflow)?;
+    let result = if value_val.is_nil() {
+        Value::nil()
+    } else {
+        let f = decimal_operand(value_val)
+            .ok_or_else(|| AjisaiError::from("TOMIXED: expected a number"))?;
+        Value::from_string(&format_fraction_as_mixed(&f))
+    };
+
+    if interp.consumption_mode != ConsumptionMode::Keep {
+        interp.stack.pop();
+    }
+    interp.stack.push(result);
+    Ok(())
+}
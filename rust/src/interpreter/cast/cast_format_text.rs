@@ -0,0 +1,199 @@
+use crate::error::{AjisaiError, Result};
+use crate::interpreter::cast::cast_value_helpers::{
+    format_value_to_string_repr_with_hint, is_string_value,
+};
+use crate::interpreter::value_extraction_helpers::value_as_string;
+use crate::interpreter::Interpreter;
+use crate::types::{Interpretation, Value};
+
+enum TemplatePart {
+    Literal(String),
+    Placeholder,
+}
+
+/// Split a template into literal runs and `{}` placeholders. `{{` and `}}`
+/// stand for literal braces; any other brace is malformed.
+fn parse_template(template: &str) -> Result<Vec<TemplatePart>> {
+    let mut parts = Vec::new();
+    let mut literal = String::new();
+    let mut chars = template.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            ('{', Some('{')) | ('}', Some('}')) => {
+                chars.next();
+                literal.push(c);
+            }
+            ('{', Some('}')) => {
+                chars.next();
+                parts.push(TemplatePart::Literal(std::mem::take(&mut literal)));
+                parts.push(TemplatePart::Placeholder);
+            }
+            ('{', _) => {
+                return Err(AjisaiError::from(
+                    "FORMAT-TEXT: unclosed '{' in template (write '{{' for a literal brace)",
+                ))
+            }
+            ('}', _) => {
+                return Err(AjisaiError::from(
+                    "FORMAT-TEXT: unmatched '}' in template (write '}}' for a literal brace)",
+                ))
+            }
+            _ => literal.push(c),
+        }
+    }
+    parts.push(TemplatePart::Literal(literal));
+    Ok(parts)
+}
+
+fn fill_template(template: &str, args: &[Value]) -> Result<String> {
+    let parts = parse_template(template)?;
+    let placeholders = parts
+        .iter()
+        .filter(|p| matches!(p, TemplatePart::Placeholder))
+        .count();
+    if placeholders != args.len() {
+        return Err(AjisaiError::from(format!(
+            "FORMAT-TEXT: expected {} argument(s) for the template, got {}",
+            placeholders,
+            args.len()
+        )));
+    }
+
+    let mut args = args.iter();
+    let mut out = String::with_capacity(template.len());
+    for part in parts {
+        match part {
+            TemplatePart::Literal(s) => out.push_str(&s),
+            TemplatePart::Placeholder => {
+                let arg = args.next().expect("placeholder count checked above");
+                out.push_str(&format_value_to_string_repr_with_hint(
+                    arg,
+                    Interpretation::Unassigned,
+                ));
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// The template may be given bare (`'x={}'`) or wrapped (`[ 'x={}' ]`).
+fn template_text(val: &Value) -> Option<String> {
+    if is_string_value(val) {
+        return value_as_string(val);
+    }
+    match val.as_vector_view() {
+        Some(children) if children.len() == 1 && is_string_value(&children[0]) => {
+            value_as_string(&children[0])
+        }
+        _ => None,
+    }
+}
+
+/// `[ template ] [ args ] -- [ text ]`. Each argument is rendered exactly as
+/// `STR` renders it and substituted for the next `{}` in the template.
+pub fn op_format_text(interp: &mut Interpreter) -> Result<()> {
+    let args_val = interp.stack.pop().ok_or(AjisaiError::StackUnderflow)?;
+    let template_val = match interp.stack.pop() {
+        Some(v) => v,
+        None => {
+            interp.stack.push(args_val);
+            return Err(AjisaiError::StackUnderflow);
+        }
+    };
+
+    let result = match (template_text(&template_val), args_val.as_vector_view()) {
+        (None, _) => Err(AjisaiError::from(
+            "FORMAT-TEXT: expected template as String",
+        )),
+        (Some(_), None) => Err(AjisaiError::from(
+            "FORMAT-TEXT: expected arguments as Vector",
+        )),
+        (Some(template), Some(args)) => fill_template(&template, &args),
+    };
+
+    match result {
+        Ok(text) => {
+            interp.stack.push(Value::from_string(&text));
+            Ok(())
+        }
+        Err(err) => {
+            interp.stack.push(template_val);
+            interp.stack.push(args_val);
+            Err(err)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::interpreter::cast::cast_value_helpers::is_string_value;
+    use crate::interpreter::value_extraction_helpers::value_as_string;
+    use crate::interpreter::Interpreter;
+
+    async fn formatted(code: &str) -> String {
+        let mut interp = Interpreter::new();
+        interp.execute(code).await.unwrap();
+        let v = interp.stack.last().unwrap();
+        assert!(is_string_value(v));
+        value_as_string(v).unwrap()
+    }
+
+    #[tokio::test]
+    async fn format_text_substitutes_in_order() {
+        assert_eq!(
+            formatted("[ 'x={} y={}' ] [ 1 2 ] FORMAT-TEXT").await,
+            "x=1 y=2"
+        );
+    }
+
+    #[tokio::test]
+    async fn format_text_renders_like_str() {
+        assert_eq!(
+            formatted("'{} {} {} {}' [ 1/2 'ab' TRUE NIL ] FORMAT-TEXT").await,
+            "1/2 ab TRUE NIL"
+        );
+    }
+
+    #[tokio::test]
+    async fn format_text_escaped_braces_are_literal() {
+        assert_eq!(formatted("'{{{}}} }}' [ 7 ] FORMAT-TEXT").await, "{7} }");
+    }
+
+    #[tokio::test]
+    async fn format_text_extra_args_error() {
+        let mut interp = Interpreter::new();
+        let err = interp
+            .execute("'x={}' [ 1 2 ] FORMAT-TEXT")
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("expected 1 argument(s)"),
+            "{}",
+            err
+        );
+        assert_eq!(interp.stack.len(), 2, "operands are restored on error");
+    }
+
+    #[tokio::test]
+    async fn format_text_missing_args_error() {
+        let mut interp = Interpreter::new();
+        let err = interp
+            .execute("'{} {} {}' [ 1 ] FORMAT-TEXT")
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("expected 3 argument(s)"),
+            "{}",
+            err
+        );
+        assert_eq!(interp.stack.len(), 2, "operands are restored on error");
+    }
+
+    #[tokio::test]
+    async fn format_text_lone_brace_errors() {
+        let mut interp = Interpreter::new();
+        assert!(interp.execute("'a { b' [ 1 ] FORMAT-TEXT").await.is_err());
+        let mut interp = Interpreter::new();
+        assert!(interp.execute("'a } b' [ 1 ] FORMAT-TEXT").await.is_err());
+    }
+}
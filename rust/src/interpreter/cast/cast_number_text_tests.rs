@@ -0,0 +1,71 @@
+//! Tests for the MATH words that render numbers as text (`TOMIXED`,
+//! `PERCENT`).
+
+#[cfg(test)]
+mod tests {
+    use crate::interpreter::value_extraction_helpers::value_as_string;
+    use crate::interpreter::Interpreter;
+
+    async fn text_of(code: &str) -> String {
+        let mut interp = Interpreter::new();
+        interp
+            .execute(&format!("'math' IMPORT {}", code))
+            .await
+            .expect("should succeed");
+        let top = interp.stack.last().expect("a result");
+        value_as_string(top).expect("a text result")
+    }
+
+    #[tokio::test]
+    async fn test_to_mixed_improper_and_negative() {
+        assert_eq!(text_of("[ 7/3 ] TOMIXED").await, "2 1/3");
+        assert_eq!(text_of("[ -7/3 ] TOMIXED").await, "-2 1/3");
+        assert_eq!(text_of("22/7 TOMIXED").await, "3 1/7");
+    }
+
+    #[tokio::test]
+    async fn test_to_mixed_integers_and_proper_fractions() {
+        assert_eq!(text_of("[ 6/3 ] TOMIXED").await, "2");
+        assert_eq!(text_of("[ 0 ] TOMIXED").await, "0");
+        assert_eq!(text_of("[ 1/3 ] TOMIXED").await, "1/3");
+        assert_eq!(text_of("[ -1/3 ] TOMIXED").await, "-1/3");
+    }
+
+    #[tokio::test]
+    async fn test_to_mixed_nil_and_errors() {
+        let mut interp = Interpreter::new();
+        interp.execute("'math' IMPORT NIL TOMIXED").await.unwrap();
+        assert!(interp.stack[0].is_nil());
+
+        let mut interp = Interpreter::new();
+        let result = interp.execute("'math' IMPORT 'x' TOMIXED").await;
+        assert!(result.is_err());
+        assert_eq!(interp.stack.len(), 1, "operand kept on error");
+    }
+
+    #[tokio::test]
+    async fn test_percent_places() {
+        assert_eq!(text_of("[ 1/2 ] [ 0 ] PERCENT").await, "50%");
+        assert_eq!(text_of("[ 1/3 ] [ 1 ] PERCENT").await, "33.3%");
+        assert_eq!(text_of("[ 2/3 ] [ 2 ] PERCENT").await, "66.66%");
+        assert_eq!(text_of("[ 1/8 ] [ 3 ] PERCENT").await, "12.5%");
+    }
+
+    #[tokio::test]
+    async fn test_percent_negative_and_over_one_hundred() {
+        assert_eq!(text_of("[ -1/4 ] [ 0 ] PERCENT").await, "-25%");
+        assert_eq!(text_of("[ 3/2 ] [ 1 ] PERCENT").await, "150%");
+        assert_eq!(text_of("[ 7 ] [ 0 ] PERCENT").await, "700%");
+    }
+
+    #[tokio::test]
+    async fn test_percent_rejects_bad_operands() {
+        for code in ["'x' [ 1 ] PERCENT", "[ 1/3 ] [ -1 ] PERCENT"] {
+            let mut interp = Interpreter::new();
+            let result = interp.execute(&format!("'math' IMPORT {}", code)).await;
+            let err = result.expect_err(code).to_string();
+            assert!(err.starts_with("PERCENT"), "{}", err);
+            assert_eq!(interp.stack.len(), 2, "operands kept on error");
+        }
+    }
+}
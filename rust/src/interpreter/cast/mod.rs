@@ -1,11 +1,16 @@
 mod cast_chars_join;
 mod cast_conversion_tests;
 pub(crate) mod cast_conversions;
+mod cast_format_text;
+mod cast_number_text_tests;
 mod cast_text_ops;
 pub(crate) mod cast_value_helpers;
 
 pub use cast_chars_join::{op_chars, op_join};
-pub use cast_conversions::{op_bool, op_chr, op_nil, op_num, op_str};
+pub use cast_conversions::{
+    op_bool, op_chr, op_codepoint, op_decimal, op_nil, op_num, op_percent, op_str, op_to_mixed,
+};
+pub use cast_format_text::op_format_text;
 pub use cast_text_ops::{
     op_ends_with, op_starts_with, op_substitute, op_tokenize, op_trim, op_trim_left, op_trim_right,
 };
@@ -0,0 +1,82 @@
+//! Notebook-style batch evaluation: source split into cells at blank lines,
+//! each cell executed in turn on the same interpreter and reported with the
+//! output it printed, the stack it left and its error, if any.
+//!
+//! A cell boundary is a line holding only whitespace outside any string or
+//! open `[ ]` / `{ }`, as the tokenizer reads them, so a multi-line block or
+//! text keeps its blank lines.
+
+use super::Interpreter;
+use crate::error::AjisaiError;
+use crate::tokenizer_scan::scan_code;
+use crate::types::{Interpretation, Value};
+
+/// What one cell produced. `stack` is the whole stack after the cell, with
+/// each value's role, as the host observes it after `execute`.
+#[derive(Debug)]
+pub struct CellOutcome {
+    pub output: String,
+    pub stack: Vec<(Value, Interpretation)>,
+    pub error: Option<AjisaiError>,
+}
+
+/// The non-empty cells of `code`, split at whitespace-only lines that are
+/// not inside a string or an open bracket.
+pub fn split_cells(code: &str) -> Vec<String> {
+    // Whether each line starts at top level: outside every string and
+    // bracket, as the tokenizer reads the source.
+    let mut top_level = vec![true];
+    let mut depth = 0usize;
+    for (c, is_code) in scan_code(code) {
+        match c {
+            '\n' => top_level.push(is_code && depth == 0),
+            '[' | '{' if is_code => depth += 1,
+            ']' | '}' if is_code => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+
+    let mut cells = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+    for (line, at_top_level) in code.lines().zip(top_level) {
+        if at_top_level && line.trim().is_empty() {
+            if !current.is_empty() {
+                cells.push(current.join("\n"));
+                current.clear();
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        cells.push(current.join("\n"));
+    }
+    cells
+}
+
+impl Interpreter {
+    /// Execute each cell of `code` in order and report every cell's result.
+    /// A failing cell is reported with its error; later cells still run
+    /// unless `stop_on_error` is set, in which case they are not executed
+    /// and have no entry.
+    pub async fn execute_cells(&mut self, code: &str, stop_on_error: bool) -> Vec<CellOutcome> {
+        let mut outcomes = Vec::new();
+        for cell in split_cells(code) {
+            let error = self.execute(&cell).await.err();
+            let failed = error.is_some();
+            outcomes.push(CellOutcome {
+                output: self.collect_output(),
+                stack: self
+                    .stack
+                    .iter_slots()
+                    .map(|(value, role)| (value.clone(), role))
+                    .collect(),
+                error,
+            });
+            if failed && stop_on_error {
+                break;
+            }
+        }
+        outcomes
+    }
+}
@@ -0,0 +1,80 @@
+//! Test suite for `crate::interpreter::cell_eval` (batch cell evaluation).
+
+#[cfg(test)]
+mod tests {
+    use crate::interpreter::cell_eval::split_cells;
+    use crate::interpreter::Interpreter;
+
+    const THREE_CELLS: &str = "[ 1 ] [ 2 ] +\n'one' PRINT\n\n  \nUNKNOWN-WORD\n\n[ 10 ] *";
+
+    #[test]
+    fn cells_split_at_whitespace_only_lines() {
+        assert_eq!(
+            split_cells(THREE_CELLS),
+            vec!["[ 1 ] [ 2 ] +\n'one' PRINT", "UNKNOWN-WORD", "[ 10 ] *"]
+        );
+        assert!(split_cells("\n \n").is_empty());
+    }
+
+    #[test]
+    fn blank_lines_inside_blocks_and_backtick_strings_stay_in_the_cell() {
+        let block = "{\n  [ 1 ] +\n\n  [ 2 ] *\n} 'STEP' DEF";
+        let text = "`first\n\nsecond` PRINT";
+        let code = format!("{}\n\n{}\n\n'}}' PRINT # {{\n\nNEXT", block, text);
+        assert_eq!(
+            split_cells(&code),
+            vec![block, text, "'}' PRINT # {", "NEXT"]
+        );
+    }
+
+    #[test]
+    fn an_escaped_quote_next_to_a_bracket_does_not_open_it() {
+        let code = "'x\\' [ y' PRINT\n\n[ 1 ]\n\n'a#' [ 2 ]\n\nNEXT";
+        assert_eq!(
+            split_cells(code),
+            vec!["'x\\' [ y' PRINT", "[ 1 ]", "'a#' [ 2 ]", "NEXT"]
+        );
+    }
+
+    #[tokio::test]
+    async fn a_definition_with_a_blank_line_runs_as_one_cell() {
+        let mut interp = Interpreter::new();
+        let code = "{\n  [ 1 ] +\n\n  [ 2 ] *\n} 'STEP' DEF\n\n[ 4 ] STEP";
+        let outcomes = interp.execute_cells(code, true).await;
+        assert_eq!(outcomes.len(), 2);
+        assert!(outcomes.iter().all(|outcome| outcome.error.is_none()));
+        let (top, _) = outcomes[1].stack.last().expect("a result");
+        assert_eq!(top.to_string(), "[ 10/1 ]");
+    }
+
+    #[tokio::test]
+    async fn an_erroring_cell_does_not_abort_later_cells() {
+        let mut interp = Interpreter::new();
+        let outcomes = interp.execute_cells(THREE_CELLS, false).await;
+        assert_eq!(outcomes.len(), 3);
+
+        assert_eq!(outcomes[0].output, "one ");
+        assert!(outcomes[0].error.is_none());
+        assert_eq!(outcomes[0].stack.len(), 1);
+
+        let error = outcomes[1].error.as_ref().expect("middle cell fails");
+        assert!(error.to_string().contains("UNKNOWN-WORD"), "{}", error);
+
+        assert!(outcomes[2].error.is_none());
+        let stack: Vec<String> = outcomes[2]
+            .stack
+            .iter()
+            .map(|(v, _)| v.to_string())
+            .collect();
+        assert_eq!(stack, vec!["[ 30/1 ]"]);
+    }
+
+    #[tokio::test]
+    async fn stop_on_error_skips_the_remaining_cells() {
+        let mut interp = Interpreter::new();
+        let outcomes = interp.execute_cells(THREE_CELLS, true).await;
+        assert_eq!(outcomes.len(), 2);
+        assert!(outcomes[1].error.is_some());
+        assert_eq!(interp.stack.len(), 1, "the last cell never ran");
+    }
+}
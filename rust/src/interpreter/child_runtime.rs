@@ -6,7 +6,7 @@ use super::value_extraction_helpers::extract_integer_from_value;
 use super::Interpreter;
 
 impl Interpreter {
-    fn capture_runtime_snapshot(&self) -> RuntimeDictionarySnapshot {
+    pub(crate) fn capture_runtime_snapshot(&self) -> RuntimeDictionarySnapshot {
         RuntimeDictionarySnapshot {
             user_words: self.user_words.clone(),
             user_dictionaries: self.user_dictionaries.clone(),
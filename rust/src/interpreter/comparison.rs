@@ -708,7 +708,7 @@ fn apply_equality(interp: &mut Interpreter, invert: bool) -> Result<()> {
 }
 
 /// Push the three-way sign scalar (`-1` / `0` / `1`) produced by
-/// `COMPARE-WITHIN`, carrying the `RawNumber` interpretation role.
+/// `COMPARE` and `COMPARE-WITHIN`, carrying the `RawNumber` interpretation role.
 fn push_sign_result(interp: &mut Interpreter, sign: i64) {
     interp.stack.push(Value::from_int(sign));
     let stack_len = interp.stack.len();
@@ -717,6 +717,67 @@ fn push_sign_result(interp: &mut Interpreter, sign: i64) {
         .set_role_at(stack_len - 1, Interpretation::RawNumber);
 }
 
+/// `COMPARE` (SPEC §7.4.2): three-way compare two numeric values under the
+/// relations' own implicit water.
+///
+/// Stack effect: `[ a ] [ b ] -> [ -1 | 0 | 1 | UNKNOWN ]`.
+///
+/// Pushes the exact sign of `a − b`, so `2/4 1/2 COMPARE` is `0`. It is
+/// `COMPARE-WITHIN` without a named budget: over Tier ≤ 1 the result is
+/// always decided, and only a starved Tier 2 observation yields U. Text or
+/// other non-numeric operands are malformed use and raise an error with the
+/// stack intact; a NIL operand passes through per SPEC §7.12.
+pub fn op_compare(interp: &mut Interpreter) -> Result<()> {
+    if interp.operation_target_mode == OperationTargetMode::Stack {
+        return Err(AjisaiError::ModeUnsupported {
+            word: "COMPARE".into(),
+            mode: "Stack".into(),
+        });
+    }
+    let is_keep_mode = interp.consumption_mode == ConsumptionMode::Keep;
+    if interp.stack.len() < 2 {
+        return Err(AjisaiError::StackUnderflow);
+    }
+    let stack_len = interp.stack.len();
+    let b_val = interp.stack[stack_len - 1].clone();
+    let a_val = interp.stack[stack_len - 2].clone();
+
+    if let Some(nil) = nil_passthrough_value(&[a_val.clone(), b_val.clone()]) {
+        if !is_keep_mode {
+            interp.stack.truncate(stack_len - 2);
+        }
+        interp.stack.push(nil);
+        return Ok(());
+    }
+
+    if a_val.hint == Interpretation::Text || b_val.hint == Interpretation::Text {
+        return Err(AjisaiError::create_structure_error(
+            "numeric value",
+            "text value",
+        ));
+    }
+    let outcome = three_way_compare(&a_val, &b_val)?;
+
+    if !is_keep_mode {
+        interp.stack.truncate(stack_len - 2);
+    }
+    match outcome {
+        OrderOutcome::Decided(o) => {
+            use std::cmp::Ordering;
+            let sign = match o {
+                Ordering::Less => -1,
+                Ordering::Equal => 0,
+                Ordering::Greater => 1,
+            };
+            push_sign_result(interp, sign);
+        }
+        OrderOutcome::Undecided(agreed_prefix) => {
+            push_comparison_unknown(interp, agreed_prefix);
+        }
+    }
+    Ok(())
+}
+
 /// `COMPARE-WITHIN` (SPEC §7.4.2): three-way compare two values within an
 /// explicit observation budget (water).
 ///
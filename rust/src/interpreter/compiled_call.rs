@@ -22,7 +22,7 @@ pub struct CompiledCall {
     pub name: String,
     /// Pre-resolved executor, replacing the runtime alias scan + spec scan.
     pub key: Option<BuiltinExecutorKey>,
-    /// Precomputed `canonical != DEF/DEL/FORC` force-flag reset decision.
+    /// Precomputed `!keeps_force_flag(name)` force-flag reset decision.
     pub resets_force_flag: bool,
     /// Precomputed `modules::is_mode_preserving_word(name)` so the post-call
     /// cleanup skips the per-call uppercase allocation.
@@ -34,12 +34,20 @@ pub struct CompiledCall {
     pub shape_ic: ShapeIc,
 }
 
+/// Words that read the `!` force flag; every other word clears it first.
+pub(crate) fn keeps_force_flag(canonical: &str) -> bool {
+    matches!(
+        canonical,
+        "DEL" | "DEF" | "FORC" | "REFLECT@COMPOSE" | "REFLECT@PARTIAL"
+    )
+}
+
 impl CompiledCall {
     pub fn resolve(name: &str) -> Self {
         let canonical = crate::core_word_aliases::canonicalize_core_word_name(name).into_owned();
         let key = lookup_builtin_spec(&canonical).and_then(|spec| spec.executor_key);
         Self {
-            resets_force_flag: canonical != "DEL" && canonical != "DEF" && canonical != "FORC",
+            resets_force_flag: !keeps_force_flag(&canonical),
             mode_preserving: modules::is_mode_preserving_word(&canonical),
             ic_op: key.and_then(ShapeIcOp::from_executor_key),
             shape_ic: ShapeIc::default(),
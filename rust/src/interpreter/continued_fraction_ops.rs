@@ -0,0 +1,130 @@
+//! MATH module words that take a rational apart: its simple continued
+//! fraction and back, and its numerator and denominator.
+
+use num_bigint::BigInt;
+use num_traits::Signed;
+
+use crate::error::{AjisaiError, Result};
+use crate::interpreter::math_ops::{require_stack_top, restore_operands};
+use crate::interpreter::tensor_ops::apply_unary_flat_with_metrics;
+use crate::interpreter::value_extraction_helpers::{
+    extract_bigint_from_value, extract_operands, nil_passthrough_unary, push_result,
+};
+use crate::interpreter::Interpreter;
+use crate::types::exact::ExactReal;
+use crate::types::fraction::Fraction;
+use crate::types::{Interpretation, Value, ValueData};
+
+/// `CONTINUED-FRACTION` decomposes a rational into its simple continued
+/// fraction `[ a0 a1 ... an ]` by the Euclidean algorithm, in the canonical
+/// form whose last term is not `1` (`415/93` is `[ 4 2 6 7 ]`). An integer
+/// yields a single term. This is the coefficient view of the same expansion
+/// that `>CF` only displays (SPEC §12.2); an irrational operand has no finite
+/// expansion and is malformed use here. NIL-passthrough.
+pub(crate) fn op_continued_fraction(interp: &mut Interpreter) -> Result<()> {
+    require_stack_top(interp, "CONTINUED-FRACTION")?;
+    if nil_passthrough_unary(interp) {
+        return Ok(());
+    }
+    let operands = extract_operands(interp, 1)?;
+    let terms = match &operands[0].data {
+        ValueData::Scalar(f) => ExactReal::from_fraction(f.clone()).partial_quotients(),
+        _ => None,
+    };
+    let Some(terms) = terms else {
+        restore_operands(interp, operands);
+        return Err(AjisaiError::from(
+            "CONTINUED-FRACTION: expected a rational number",
+        ));
+    };
+    let values = terms
+        .into_iter()
+        .map(|a| Value::from_fraction(Fraction::new(a, BigInt::from(1))))
+        .collect();
+    push_result(interp, Value::from_vector(values));
+    interp.stack.set_last_role(Interpretation::RawNumber);
+    Ok(())
+}
+
+/// `FROM-CONTINUED` rebuilds the rational `a0 + 1/(a1 + 1/(... + 1/an))` from
+/// a vector of partial quotients, folding from the last term so the result is
+/// exact. `a0` may be any integer; every later term must be a positive
+/// integer, which keeps each reciprocal defined. It inverts
+/// `CONTINUED-FRACTION`. NIL-passthrough.
+pub(crate) fn op_from_continued(interp: &mut Interpreter) -> Result<()> {
+    require_stack_top(interp, "FROM-CONTINUED")?;
+    if nil_passthrough_unary(interp) {
+        return Ok(());
+    }
+    let operands = extract_operands(interp, 1)?;
+    let terms: Option<Vec<BigInt>> = if operands[0].hint == Interpretation::Text {
+        None
+    } else {
+        (0..operands[0].len())
+            .map(|i| {
+                let term = extract_bigint_from_value(&operands[0].child(i)?).ok()?;
+                (i == 0 || term.is_positive()).then_some(term)
+            })
+            .collect()
+    };
+    let Some(terms) = terms.filter(|t| !t.is_empty()) else {
+        restore_operands(interp, operands);
+        return Err(AjisaiError::from(
+            "FROM-CONTINUED: expected integer terms, positive after the first",
+        ));
+    };
+    let mut terms = terms.into_iter().rev();
+    let last = terms.next().expect("terms is non-empty");
+    let mut acc = Fraction::new(last, BigInt::from(1));
+    for a in terms {
+        let (num, den) = acc.to_bigint_pair();
+        acc = Fraction::new(a * &num + den, num);
+    }
+    push_result(interp, Value::from_fraction(acc));
+    interp.stack.set_last_role(Interpretation::RawNumber);
+    Ok(())
+}
+
+/// Shared body of NUMERATOR / DENOMINATOR: map `part` over a rational or a
+/// vector of rationals. Text and irrational values have no fraction parts.
+fn apply_fraction_part(
+    interp: &mut Interpreter,
+    word: &str,
+    part: fn(&Fraction) -> Fraction,
+) -> Result<()> {
+    require_stack_top(interp, word)?;
+    if nil_passthrough_unary(interp) {
+        return Ok(());
+    }
+    let operands = extract_operands(interp, 1)?;
+    let result = match operands[0].hint {
+        Interpretation::Text => None,
+        _ => apply_unary_flat_with_metrics(&operands[0], part, Some(&mut interp.runtime_metrics))
+            .ok(),
+    };
+    let Some(result) = result else {
+        restore_operands(interp, operands);
+        return Err(AjisaiError::from(format!(
+            "{}: expected a rational number or vector",
+            word
+        )));
+    };
+    push_result(interp, result);
+    Ok(())
+}
+
+/// `[ p/q ] -- [ p ]`. Numerator of the reduced fraction, mapped over a
+/// vector; the sign lives here, so `[ -3/4 ]` gives `[ -3 ]`.
+pub(crate) fn op_numerator(interp: &mut Interpreter) -> Result<()> {
+    apply_fraction_part(interp, "NUMERATOR", |f| {
+        Fraction::new(f.numerator(), BigInt::from(1))
+    })
+}
+
+/// `[ p/q ] -- [ q ]`. Denominator of the reduced fraction, mapped over a
+/// vector; always positive, and 1 for an integer.
+pub(crate) fn op_denominator(interp: &mut Interpreter) -> Result<()> {
+    apply_fraction_part(interp, "DENOMINATOR", |f| {
+        Fraction::new(f.denominator(), BigInt::from(1))
+    })
+}
@@ -1,8 +1,11 @@
+use std::borrow::Cow;
+
 use crate::error::{AjisaiError, Result};
-use crate::interpreter::value_extraction_helpers::value_as_string;
-use crate::interpreter::Interpreter;
+use crate::interpreter::higher_order::{execute_executable_code, extract_executable_code};
+use crate::interpreter::value_extraction_helpers::{extract_word_name_from_value, value_as_string};
 use crate::interpreter::OperationTargetMode;
-use crate::types::{Token, Value, ValueData};
+use crate::interpreter::{ConsumptionMode, Interpreter};
+use crate::types::{Interpretation, Token, Value, ValueData};
 
 pub(crate) fn op_exec(interp: &mut Interpreter) -> Result<()> {
     let target_vector: Value = match interp.operation_target_mode {
@@ -97,3 +100,303 @@ pub(crate) fn op_eval(interp: &mut Interpreter) -> Result<()> {
 
     Ok(())
 }
+
+/// `TRY`: run a body and, if it raises, run a recovery with the error text.
+///
+/// ```text
+/// [ ...  body recovery ] -> [ ...body-result ]                 (body succeeds)
+/// [ ...  body recovery ] -> [ ... 'message' ] then recovery   (body raises)
+/// ```
+///
+/// Body and recovery are each a `{ ... }` block or a word name. On error the
+/// stack is restored to what it was just before the body ran and the error's
+/// message is pushed as text for the recovery to consume. NIL is a value, not
+/// an error, so a body that bubbles to NIL succeeds — `OR-ELSE` handles that.
+/// The step and recursion limits are runtime safety controls rather than
+/// program errors and always propagate, as does the Breakpoint a paused
+/// `IO@BREAK` raises, so the host still sees where execution stopped.
+pub(crate) fn op_try(interp: &mut Interpreter) -> Result<()> {
+    let recovery_val: Value = interp.stack.pop().ok_or(AjisaiError::StackUnderflow)?;
+    let body_val: Value = match interp.stack.pop() {
+        Some(v) => v,
+        None => {
+            interp.stack.push(recovery_val);
+            return Err(AjisaiError::StackUnderflow);
+        }
+    };
+
+    let codes = extract_executable_code(interp, &body_val)
+        .and_then(|body| Ok((body, extract_executable_code(interp, &recovery_val)?)));
+    let (body, recovery) = match codes {
+        Ok(codes) => codes,
+        Err(_) => {
+            interp.stack.push(body_val);
+            interp.stack.push(recovery_val);
+            return Err(AjisaiError::from(
+                "TRY: expected body and recovery as code blocks { ... } or word names",
+            ));
+        }
+    };
+
+    let snapshot = interp.stack.clone();
+    let mode = interp.operation_target_mode;
+    match execute_executable_code(interp, &body) {
+        Ok(()) => Ok(()),
+        Err(
+            err @ (AjisaiError::ExecutionLimitExceeded { .. }
+            | AjisaiError::RecursionLimitExceeded { .. }
+            | AjisaiError::Breakpoint { .. }),
+        ) => Err(err),
+        Err(err) => {
+            interp.stack = snapshot;
+            interp.operation_target_mode = mode;
+            interp.stack.push(Value::from_string(&err.to_string()));
+            interp.stack.set_last_role(Interpretation::Text);
+            execute_executable_code(interp, &recovery)
+        }
+    }
+}
+
+/// `RAISE`: abort the current evaluation with a user-defined error message.
+///
+/// The popped text becomes the error message verbatim, so a surrounding `TRY`
+/// hands exactly that text to its recovery. This is for domain errors in user
+/// words; "no value" outcomes should bubble to NIL instead (SPEC §11.2).
+pub(crate) fn op_raise(interp: &mut Interpreter) -> Result<()> {
+    let val: Value = interp.stack.pop().ok_or(AjisaiError::StackUnderflow)?;
+    match value_as_string(&val) {
+        Some(message) => Err(AjisaiError::from(message)),
+        None => {
+            interp.stack.push(val);
+            Err(AjisaiError::from("RAISE: expected error message as String"))
+        }
+    }
+}
+
+/// `CASE`: run the action paired with the first candidate equal to a value.
+///
+/// ```text
+/// [ 2 ] [ 1 2 3 ] { 'one' } { 'two' } { 'three' } { 'other' } CASE -> [ 'two' ]
+/// ```
+///
+/// Every consecutive code block on top of the stack is an action, in source
+/// order; the vector beneath them holds one candidate per action and the
+/// value beneath that is the scrutinee. One extra trailing block is the
+/// default. A candidate matches when it equals the scrutinee (the equality
+/// `INDEX-OF` uses) or the sole element of a one-element scrutinee, so
+/// `[ 2 ]` and `2` both select candidate `2`. As with a `COND` body, the
+/// chosen action runs on a stack holding only the scrutinee, and the value
+/// it leaves on top replaces it. No match without a default is an error,
+/// and so is an action count that fits neither form; both restore every
+/// operand.
+pub fn op_case(interp: &mut Interpreter) -> Result<()> {
+    if interp.operation_target_mode != OperationTargetMode::StackTop {
+        return Err(AjisaiError::ModeUnsupported {
+            word: "CASE".into(),
+            mode: "Stack".into(),
+        });
+    }
+    let mut actions: Vec<Value> = Vec::new();
+    while interp
+        .stack
+        .last()
+        .is_some_and(|v| matches!(v.data, ValueData::CodeBlock(_)))
+    {
+        actions.push(interp.stack.pop().expect("checked by last()"));
+    }
+    actions.reverse();
+    if interp.stack.len() < 2 {
+        interp.stack.extend(actions);
+        return Err(AjisaiError::StackUnderflow);
+    }
+    let candidates_val = interp.stack.pop().expect("length checked above");
+    let (scrutinee, role) = interp.stack.pop_slot().expect("length checked above");
+
+    let chosen = select_case_action(&scrutinee, &candidates_val, actions.len());
+    let index = match chosen {
+        Ok(index) => index,
+        Err(err) => {
+            interp.stack.push_with_role(scrutinee, role);
+            interp.stack.push(candidates_val);
+            interp.stack.extend(actions);
+            return Err(err);
+        }
+    };
+    let ValueData::CodeBlock(tokens) = &actions[index].data else {
+        unreachable!("only code blocks are collected as actions");
+    };
+    run_case_action(interp, tokens, scrutinee)
+}
+
+/// Index of the action to run for `scrutinee`: the matching candidate's, or
+/// the trailing default's when nothing matches.
+fn select_case_action(scrutinee: &Value, candidates_val: &Value, actions: usize) -> Result<usize> {
+    let candidates = if candidates_val.is_nil() {
+        Cow::Borrowed(&[][..])
+    } else {
+        candidates_val.as_vector_view().ok_or_else(|| {
+            AjisaiError::from("CASE: expected a vector of candidates beneath the actions")
+        })?
+    };
+    let count = candidates.len();
+    if actions != count && actions != count + 1 {
+        return Err(AjisaiError::from(format!(
+            "CASE: {} candidates need {} actions, or {} with a default; got {}",
+            count,
+            count,
+            count + 1,
+            actions
+        )));
+    }
+    let sole = match scrutinee.as_vector_view() {
+        Some(elements) if elements.len() == 1 && scrutinee.hint != Interpretation::Text => {
+            Some(elements[0].clone())
+        }
+        _ => None,
+    };
+    candidates
+        .iter()
+        .position(|candidate| candidate == scrutinee || sole.as_ref() == Some(candidate))
+        .or((actions > count).then_some(count))
+        .ok_or_else(|| AjisaiError::from("CASE: no candidate matches and there is no default"))
+}
+
+fn run_case_action(interp: &mut Interpreter, tokens: &[Token], scrutinee: Value) -> Result<()> {
+    let saved_stack = interp.stack.clone();
+    let saved_consumption_mode = interp.consumption_mode;
+    interp.stack.clear();
+    interp.stack.push(scrutinee);
+    interp.consumption_mode = ConsumptionMode::Consume;
+
+    let executed = interp.execute_section_core(tokens, 0);
+    let result = interp.stack.pop_slot();
+    interp.stack = saved_stack;
+    interp.operation_target_mode = OperationTargetMode::StackTop;
+    interp.consumption_mode = saved_consumption_mode;
+
+    executed?;
+    let (value, role) =
+        result.ok_or_else(|| AjisaiError::from("CASE: action must return a value"))?;
+    interp.stack.push_with_role(value, role);
+    Ok(())
+}
+
+/// `DISPATCH`: run the action word of the first clause whose condition word
+/// holds.
+///
+/// ```text
+/// [ [ 'NEGATIVE?' 'ON-NEGATIVE' ] [ 'ZERO?' 'ON-ZERO' ] [ 'ON-OTHER' ] ] DISPATCH
+/// ```
+///
+/// Each clause is a `[ 'condition' 'action' ]` pair of word names, tried in
+/// order; a trailing one-name clause is the default. A condition runs on a
+/// copy of the stack, so it never changes it, and must leave `TRUE` or
+/// `FALSE` (bare or in a one-element vector) on top; U does not hold, as for
+/// a `COND` guard. The first action whose condition holds runs on the live
+/// stack. With no match and no default nothing runs. Malformed clauses and
+/// unknown words are reported before anything runs, with the clause vector
+/// restored.
+pub fn op_dispatch(interp: &mut Interpreter) -> Result<()> {
+    if interp.operation_target_mode != OperationTargetMode::StackTop {
+        return Err(AjisaiError::ModeUnsupported {
+            word: "DISPATCH".into(),
+            mode: "Stack".into(),
+        });
+    }
+    let clauses_val = interp.stack.pop().ok_or(AjisaiError::StackUnderflow)?;
+    let clauses = match parse_dispatch_clauses(interp, &clauses_val) {
+        Ok(clauses) => clauses,
+        Err(err) => {
+            interp.stack.push(clauses_val);
+            return Err(err);
+        }
+    };
+    for (condition, action) in clauses {
+        let holds = match condition {
+            Some(condition) => dispatch_condition_holds(interp, &condition)?,
+            None => true,
+        };
+        if holds {
+            return interp.execute_word_core(&action);
+        }
+    }
+    Ok(())
+}
+
+/// `(condition, action)` word names; the default clause has no condition.
+fn parse_dispatch_clauses(
+    interp: &Interpreter,
+    clauses_val: &Value,
+) -> Result<Vec<(Option<String>, String)>> {
+    let malformed =
+        || AjisaiError::from("DISPATCH: expected a vector of [ 'condition' 'action' ] clauses");
+    let entries = match clauses_val.as_vector_view() {
+        Some(entries) if clauses_val.hint != Interpretation::Text => entries,
+        _ => return Err(malformed()),
+    };
+    let last = entries.len().saturating_sub(1);
+    let mut clauses = Vec::with_capacity(entries.len());
+    for (i, entry) in entries.iter().enumerate() {
+        let names = match entry.as_vector_view() {
+            Some(names) if entry.hint != Interpretation::Text => names,
+            _ => return Err(malformed()),
+        };
+        if names.iter().any(|name| name.hint != Interpretation::Text) {
+            return Err(malformed());
+        }
+        let mut names = names
+            .iter()
+            .map(extract_word_name_from_value)
+            .collect::<Result<Vec<String>>>()?;
+        if let Some(unknown) = names.iter().find(|name| !interp.word_exists(name)) {
+            return Err(AjisaiError::UnknownWord(unknown.clone()));
+        }
+        let clause = match (names.len(), i == last) {
+            (2, _) => {
+                let action = names.pop().expect("two names");
+                (names.pop(), action)
+            }
+            (1, true) => (None, names.pop().expect("one name")),
+            (1, false) => {
+                return Err(AjisaiError::from(
+                    "DISPATCH: only the last clause may be a default [ 'action' ]",
+                ))
+            }
+            _ => return Err(malformed()),
+        };
+        clauses.push(clause);
+    }
+    Ok(clauses)
+}
+
+fn dispatch_condition_holds(interp: &mut Interpreter, condition: &str) -> Result<bool> {
+    let saved_stack = interp.stack.clone();
+    let saved_consumption_mode = interp.consumption_mode;
+    interp.consumption_mode = ConsumptionMode::Consume;
+
+    let executed = interp.execute_word_core(condition);
+    let verdict = interp.stack.pop();
+    interp.stack = saved_stack;
+    interp.operation_target_mode = OperationTargetMode::StackTop;
+    interp.consumption_mode = saved_consumption_mode;
+
+    executed?;
+    let not_boolean = || {
+        AjisaiError::from(format!(
+            "DISPATCH: condition '{}' must leave TRUE or FALSE",
+            condition
+        ))
+    };
+    let verdict = verdict.ok_or_else(not_boolean)?;
+    if verdict.is_unknown() {
+        return Ok(false);
+    }
+    let wrapped = match verdict.len() {
+        1 => verdict.get_child(0),
+        _ => None,
+    };
+    verdict
+        .as_truth()
+        .or_else(|| wrapped.and_then(Value::as_truth))
+        .ok_or_else(not_boolean)
+}
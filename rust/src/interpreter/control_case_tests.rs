@@ -0,0 +1,66 @@
+//! Test suite for `crate::interpreter::control::op_case` (ALGO@CASE).
+
+#[cfg(test)]
+mod tests {
+    use crate::interpreter::Interpreter;
+
+    const DISPATCH: &str = "[ 1 2 3 ] { 'one' } { 'two' } { 'three' } { 'other' } CASE";
+
+    async fn run(code: &str) -> Vec<String> {
+        let mut interp = Interpreter::new();
+        interp.execute("'algo' IMPORT").await.unwrap();
+        interp.execute(code).await.unwrap();
+        interp.stack.iter().map(|v| v.to_string()).collect()
+    }
+
+    async fn run_err(code: &str) -> (String, usize) {
+        let mut interp = Interpreter::new();
+        interp.execute("'algo' IMPORT").await.unwrap();
+        let err = interp.execute(code).await.unwrap_err();
+        (err.to_string(), interp.stack.len())
+    }
+
+    #[tokio::test]
+    async fn each_candidate_selects_its_action() {
+        for (value, expected) in [(1, "'one'"), (2, "'two'"), (3, "'three'")] {
+            let code = format!("'below' [ {} ] {}", value, DISPATCH);
+            assert_eq!(run(&code).await, ["'below'", expected], "for {}", value);
+        }
+    }
+
+    #[tokio::test]
+    async fn an_unmatched_value_falls_through_to_the_default() {
+        assert_eq!(run(&format!("[ 7 ] {}", DISPATCH)).await, ["'other'"]);
+        assert_eq!(run(&format!("7 {}", DISPATCH)).await, ["'other'"]);
+    }
+
+    #[tokio::test]
+    async fn the_action_sees_the_scrutinee() {
+        let code = "[ 5 ] [ 5 6 ] { [ 10 ] * } { [ 0 ] * } CASE";
+        assert_eq!(run(code).await, ["[ 50/1 ]"]);
+    }
+
+    #[tokio::test]
+    async fn text_scrutinees_match_text_candidates() {
+        let code = "'b' [ 'a' 'b' ] { 'first' } { 'second' } CASE";
+        assert_eq!(run(code).await, ["'second'"]);
+    }
+
+    #[tokio::test]
+    async fn no_match_without_a_default_is_an_error_that_restores_operands() {
+        let (message, depth) = run_err("[ 9 ] [ 1 2 ] { 'one' } { 'two' } CASE").await;
+        assert!(message.contains("no candidate matches"), "{}", message);
+        assert_eq!(depth, 4);
+    }
+
+    #[tokio::test]
+    async fn an_action_count_that_fits_neither_form_is_an_error() {
+        let (message, depth) = run_err("[ 1 ] [ 1 2 3 ] { 'one' } CASE").await;
+        assert!(
+            message.contains("3 candidates need 3 actions"),
+            "{}",
+            message
+        );
+        assert_eq!(depth, 3);
+    }
+}
@@ -0,0 +1,79 @@
+//! Test suite for `crate::interpreter::control::op_dispatch` (ALGO@DISPATCH).
+
+#[cfg(test)]
+mod tests {
+    use crate::interpreter::Interpreter;
+
+    const WORDS: &str = "'algo' IMPORT
+{ [ 0 ] < } 'NEG?' DEF
+{ [ 0 ] = } 'ZERO?' DEF
+{ [ 0 ] > } 'POS?' DEF
+{ 'negative' } 'ON-NEG' DEF
+{ 'zero' } 'ON-ZERO' DEF
+{ 'positive' } 'ON-POS' DEF
+{ 'other' } 'ON-OTHER' DEF";
+
+    const CLAUSES: &str = "[ [ 'NEG?' 'ON-NEG' ] [ 'ZERO?' 'ON-ZERO' ] [ 'POS?' 'ON-POS' ] ]";
+
+    async fn interpreter() -> Interpreter {
+        let mut interp = Interpreter::new();
+        interp.execute(WORDS).await.unwrap();
+        interp
+    }
+
+    fn rendered(interp: &Interpreter) -> Vec<String> {
+        interp.stack.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[tokio::test]
+    async fn the_first_holding_clause_runs_on_the_live_stack() {
+        let mut interp = interpreter().await;
+        interp
+            .execute(&format!("[ 0 ] {} DISPATCH", CLAUSES))
+            .await
+            .unwrap();
+        // ZERO? is the second clause; the conditions left the stack as it was.
+        assert_eq!(rendered(&interp), ["[ 0/1 ]", "'zero'"]);
+    }
+
+    #[tokio::test]
+    async fn no_match_runs_nothing_unless_there_is_a_default() {
+        let mut interp = interpreter().await;
+        interp
+            .execute("[ 5 ] [ [ 'NEG?' 'ON-NEG' ] [ 'ZERO?' 'ON-ZERO' ] ] DISPATCH")
+            .await
+            .unwrap();
+        assert_eq!(rendered(&interp), ["[ 5/1 ]"]);
+
+        interp
+            .execute("[ [ 'NEG?' 'ON-NEG' ] [ 'ON-OTHER' ] ] DISPATCH")
+            .await
+            .unwrap();
+        assert_eq!(rendered(&interp), ["[ 5/1 ]", "'other'"]);
+    }
+
+    #[tokio::test]
+    async fn unknown_words_are_reported_before_anything_runs() {
+        let mut interp = interpreter().await;
+        let err = interp
+            .execute("[ 0 ] [ [ 'ZERO?' 'ON-ZERO' ] [ 'MISSING?' 'ON-POS' ] ] DISPATCH")
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("MISSING?"), "{}", err);
+        assert_eq!(interp.stack.len(), 2, "the clause vector is restored");
+    }
+
+    #[tokio::test]
+    async fn a_non_boolean_condition_is_an_error() {
+        let mut interp = interpreter().await;
+        let err = interp
+            .execute("[ 0 ] [ [ 'ON-ZERO' 'ON-POS' ] ] DISPATCH")
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("must leave TRUE or FALSE"),
+            "{}",
+            err
+        );
+    }
+}
@@ -0,0 +1,60 @@
+//! Test suite for `crate::interpreter::control::op_raise` (RAISE).
+//!
+//! RAISE is how user words signal domain errors. These tests pin that the
+//! message travels unchanged — to the top level and into a surrounding TRY —
+//! and that RAISE works from inside a COND guard/body clause.
+
+#[cfg(test)]
+mod tests {
+    use crate::interpreter::value_extraction_helpers::value_as_string;
+    use crate::interpreter::Interpreter;
+
+    const CHECKED: &str =
+        "{ { [ 0 ] < } { 'negative input' RAISE } { TRUE } { [ 2 ] * } COND } 'CHECKED' DEF";
+
+    #[tokio::test]
+    async fn raise_aborts_with_the_message_verbatim() {
+        let mut interp = Interpreter::new();
+        let err = interp
+            .execute("'quota exceeded: 3/2' RAISE")
+            .await
+            .unwrap_err();
+        assert_eq!(err.to_string(), "quota exceeded: 3/2");
+    }
+
+    #[tokio::test]
+    async fn raise_from_a_guard_clause_aborts_the_word() {
+        let mut interp = Interpreter::new();
+        interp.execute(CHECKED).await.unwrap();
+        let err = interp.execute("[ -1 ] CHECKED").await.unwrap_err();
+        assert_eq!(err.to_string(), "negative input");
+    }
+
+    #[tokio::test]
+    async fn guard_clause_that_does_not_raise_runs_normally() {
+        let mut interp = Interpreter::new();
+        interp.execute(CHECKED).await.unwrap();
+        let result = interp.execute("[ 4 ] CHECKED").await;
+        assert!(result.is_ok(), "CHECKED should succeed: {:?}", result);
+    }
+
+    #[tokio::test]
+    async fn try_catches_the_custom_message_verbatim() {
+        let mut interp = Interpreter::new();
+        interp.execute(CHECKED).await.unwrap();
+        interp.execute("[ -1 ] 'CHECKED' { } TRY").await.unwrap();
+        // The stack is rolled back to the argument, and the recovery sees the
+        // RAISE message unchanged on top of it.
+        assert_eq!(interp.stack.len(), 2);
+        let message = value_as_string(interp.stack.last().unwrap()).expect("text");
+        assert_eq!(message, "negative input");
+    }
+
+    #[tokio::test]
+    async fn non_text_message_is_rejected() {
+        let mut interp = Interpreter::new();
+        let err = interp.execute("TRUE RAISE").await.unwrap_err();
+        assert!(err.to_string().contains("RAISE"), "{}", err);
+        assert_eq!(interp.stack.len(), 1, "operand is restored on error");
+    }
+}
@@ -0,0 +1,107 @@
+//! Test suite for `crate::interpreter::control::op_try` (TRY).
+//!
+//! TRY is the error-level counterpart to OR-ELSE: where OR-ELSE recovers a
+//! NIL *value*, TRY recovers a raised *error*. These tests pin the rollback
+//! contract — the stack is restored to its pre-body state before the recovery
+//! runs with the error message — and the boundary with the Bubble Rule: `/`
+//! by zero bubbles to NIL and is not an error, while `MOD` by zero raises.
+
+#[cfg(test)]
+mod tests {
+    use crate::interpreter::value_extraction_helpers::value_as_string;
+    use crate::interpreter::Interpreter;
+
+    fn top_i64(interp: &Interpreter) -> i64 {
+        interp
+            .stack
+            .last()
+            .and_then(|v| v.as_scalar())
+            .and_then(|f| f.to_i64())
+            .expect("integer on top of the stack")
+    }
+
+    #[tokio::test]
+    async fn recovery_word_supplies_a_default_after_division_by_zero() {
+        let mut interp = Interpreter::new();
+        interp.execute("{ 1 0 MOD } 'RISKY' DEF").await.unwrap();
+        // RECOVER logs the error message and pushes a default.
+        interp.execute("{ PRINT -1 } 'RECOVER' DEF").await.unwrap();
+        let result = interp.execute("'RISKY' 'RECOVER' TRY").await;
+        assert!(result.is_ok(), "TRY should recover: {:?}", result);
+        assert_eq!(interp.stack.len(), 1);
+        assert_eq!(
+            top_i64(&interp),
+            -1,
+            "recovery default replaces the failure"
+        );
+        assert!(interp.collect_output().contains("Modulo by zero"));
+    }
+
+    #[tokio::test]
+    async fn recovery_receives_the_error_message_as_text() {
+        let mut interp = Interpreter::new();
+        interp.execute("{ 1 0 MOD } { } TRY").await.unwrap();
+        assert_eq!(interp.stack.len(), 1);
+        let message = value_as_string(interp.stack.last().unwrap()).expect("text");
+        assert_eq!(message, "Modulo by zero");
+    }
+
+    #[tokio::test]
+    async fn successful_body_skips_the_recovery() {
+        let mut interp = Interpreter::new();
+        interp.execute("{ 2 3 + } { 0 } TRY").await.unwrap();
+        assert_eq!(interp.stack.len(), 1);
+        assert_eq!(top_i64(&interp), 5);
+    }
+
+    #[tokio::test]
+    async fn stack_is_rolled_back_before_the_recovery_runs() {
+        let mut interp = Interpreter::new();
+        // The body consumes 10, pushes 99, then raises; the recovery must see
+        // the original 10 again, with the message on top.
+        interp.execute("10 { 99 1 0 MOD } { } TRY").await.unwrap();
+        assert_eq!(interp.stack.len(), 2);
+        let bottom = interp.stack.first().and_then(|v| v.as_scalar()).unwrap();
+        assert_eq!(bottom.to_i64().unwrap(), 10);
+    }
+
+    #[tokio::test]
+    async fn nil_bubble_is_not_an_error() {
+        let mut interp = Interpreter::new();
+        // `/` by zero projects onto NIL (Bubble Rule), so the body succeeds and
+        // the recovery never runs.
+        interp.execute("{ 1 0 / } { 0 } TRY").await.unwrap();
+        assert_eq!(interp.stack.len(), 1);
+        assert!(interp.stack.last().unwrap().is_nil());
+    }
+
+    #[tokio::test]
+    async fn error_in_recovery_propagates() {
+        let mut interp = Interpreter::new();
+        let err = interp
+            .execute("{ 1 0 MOD } { NO-SUCH-WORD } TRY")
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("NO-SUCH-WORD"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn step_limit_is_not_caught() {
+        let mut interp = Interpreter::new();
+        interp.execute("{ LOOP } 'LOOP' DEF").await.unwrap();
+        let err = interp.execute("'LOOP' { 0 } TRY").await.unwrap_err();
+        assert!(
+            err.to_string().to_lowercase().contains("limit"),
+            "runtime limits must propagate through TRY: {}",
+            err
+        );
+    }
+
+    #[tokio::test]
+    async fn non_executable_operands_are_rejected() {
+        let mut interp = Interpreter::new();
+        let err = interp.execute("1 { 0 } TRY").await.unwrap_err();
+        assert!(err.to_string().contains("TRY"), "{}", err);
+        assert_eq!(interp.stack.len(), 2, "operands are restored on error");
+    }
+}
@@ -6,6 +6,8 @@
 //! Unit 1 — CSV ↔ Record-vector conversion:
 //! - `DATA@CSV-PARSE`     text → a vector of Records (first CSV row = header).
 //! - `DATA@CSV-STRINGIFY` a vector of Records → CSV text.
+//! - `DATA@CSV-PARSE-ROWS` / `DATA@CSV-STRINGIFY-ROWS` the same conversion
+//!   without a header: text ⇄ a vector of row vectors of text cells.
 //!
 //! Unit 2 — column and row selection:
 //! - `DATA@SELECT` `[ table ] [ columns ] SELECT` → keep only the named columns.
@@ -98,6 +100,39 @@ pub fn op_csv_stringify(interp: &mut Interpreter) -> Result<()> {
     Ok(())
 }
 
+/// `DATA@CSV-PARSE-ROWS`: text → a vector of rows, each a vector of text
+/// cells. No header is assumed and rows may be ragged; only an unterminated
+/// quoted field projects to a reasoned NIL. An empty cell is empty text (NIL).
+pub fn op_csv_parse_rows(interp: &mut Interpreter) -> Result<()> {
+    let is_keep = interp.consumption_mode == ConsumptionMode::Keep;
+    let val = extract_stack_value(interp, is_keep, 0)?;
+    let text = value_as_string(&val).unwrap_or_default();
+
+    let parsed = parse_csv_rows(&text).map(|rows| {
+        vector_of(
+            rows.iter()
+                .map(|row| vector_of(row.iter().map(|cell| Value::from_string(cell)).collect()))
+                .collect(),
+        )
+    });
+    interp.stack.push(parsed.unwrap_or_else(encoding_bubble));
+    Ok(())
+}
+
+/// `DATA@CSV-STRINGIFY-ROWS`: a vector of rows of text cells → CSV text, one
+/// line per row. A NIL cell is written as an empty field; a non-vector row or
+/// a non-text cell (convert numbers with `STR` first) projects to a reasoned NIL.
+pub fn op_csv_stringify_rows(interp: &mut Interpreter) -> Result<()> {
+    let is_keep = interp.consumption_mode == ConsumptionMode::Keep;
+    let val = extract_stack_value(interp, is_keep, 0)?;
+
+    match rows_to_csv(&val) {
+        Some(text) => interp.stack.push(Value::from_string(&text)),
+        None => interp.stack.push(encoding_bubble()),
+    }
+    Ok(())
+}
+
 // --- pure CSV core (RFC 4180), independent of the interpreter ---------------
 
 /// Parse CSV text into rows of string fields. Returns `None` on an unterminated
@@ -206,6 +241,31 @@ fn record_vector_to_csv(val: &Value) -> Option<String> {
     Some(out)
 }
 
+/// Render rows of text cells as CSV text. `None` if the input or a row is not
+/// a vector, or a cell is neither NIL nor text.
+fn rows_to_csv(val: &Value) -> Option<String> {
+    let ValueData::Vector(rows) = &val.data else {
+        return None;
+    };
+    let mut out = String::new();
+    for row in rows.iter() {
+        let ValueData::Vector(cells) = &row.data else {
+            return None;
+        };
+        let fields = cells
+            .iter()
+            .map(|cell| match &cell.data {
+                ValueData::Nil => Some(String::new()),
+                ValueData::Vector(_) => value_as_string(cell),
+                _ => None,
+            })
+            .collect::<Option<Vec<String>>>()?;
+        out.push_str(&encode_row(&fields));
+        out.push('\n');
+    }
+    Some(out)
+}
+
 /// A record's `(key, value)` pairs as text, in pair order. `None` if the value
 /// is not a Record of well-formed `[key value]` pairs. An empty or NIL cell
 /// reads as the empty string.
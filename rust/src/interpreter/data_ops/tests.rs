@@ -268,3 +268,80 @@ async fn join_of_a_non_table_bubbles_to_nil() {
     let top = interp.get_stack().last().expect("a value on the stack");
     assert!(top.is_absent(), "expected a NIL, got {:?}", top);
 }
+
+#[test]
+fn rows_round_trip_quoted_commas_quotes_and_newlines() {
+    let csv = "id,note\n1,\"x, \"\"y\"\"\"\n2,\"multi\nline\"\n";
+    let rows = parse_csv_rows(csv).unwrap();
+    let table = vector_of(
+        rows.iter()
+            .map(|row| vector_of(row.iter().map(|c| Value::from_string(c)).collect()))
+            .collect(),
+    );
+    assert_eq!(rows_to_csv(&table).as_deref(), Some(csv));
+}
+
+#[test]
+fn rows_stringify_rejects_numeric_cells() {
+    let table = vector_of(vec![vector_of(vec![Value::from_int(1)])]);
+    assert!(rows_to_csv(&table).is_none());
+}
+
+#[tokio::test]
+async fn csv_parse_rows_keeps_quoted_commas_in_one_cell() {
+    let mut interp = Interpreter::new();
+    interp
+        .execute("'a,\"b,c\"\n1,2,3' 'DATA' IMPORT CSV-PARSE-ROWS")
+        .await
+        .unwrap();
+    let rows = interp.get_stack().last().unwrap().as_vector_view().unwrap();
+    let first: Vec<String> = rows[0]
+        .as_vector_view()
+        .unwrap()
+        .iter()
+        .map(|cell| value_as_string(cell).unwrap())
+        .collect();
+    assert_eq!(first, vec!["a", "b,c"]);
+    // Rows are not required to share a width.
+    assert_eq!(rows[1].as_vector_view().unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn csv_parse_rows_keeps_embedded_newlines_in_one_cell() {
+    let mut interp = Interpreter::new();
+    interp
+        .execute("'1,\"two\nlines\"' 'DATA' IMPORT CSV-PARSE-ROWS")
+        .await
+        .unwrap();
+    let rows = interp.get_stack().last().unwrap().as_vector_view().unwrap();
+    assert_eq!(rows.len(), 1);
+    let cell = &rows[0].as_vector_view().unwrap()[1];
+    assert_eq!(value_as_string(cell).unwrap(), "two\nlines");
+}
+
+#[tokio::test]
+async fn csv_rows_round_trip_through_the_interpreter() {
+    let mut interp = Interpreter::new();
+    interp
+        .execute(
+            "'h1,h2\n\"a \"\"q\"\"\",\"b,c\"' 'DATA' IMPORT \
+             CSV-PARSE-ROWS CSV-STRINGIFY-ROWS PRINT",
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        interp.collect_output().trim_end(),
+        "h1,h2\n\"a \"\"q\"\"\",\"b,c\""
+    );
+}
+
+#[tokio::test]
+async fn csv_parse_rows_of_an_unterminated_quote_bubbles_to_nil() {
+    let mut interp = Interpreter::new();
+    interp
+        .execute("'a,\"open' 'DATA' IMPORT CSV-PARSE-ROWS")
+        .await
+        .unwrap();
+    let top = interp.get_stack().last().expect("a value on the stack");
+    assert!(top.is_absent(), "expected a NIL, got {:?}", top);
+}
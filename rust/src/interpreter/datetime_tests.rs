@@ -123,6 +123,34 @@ mod tests {
         assert_eq!(number("[ 14 30 5 ] HOUR").await, (14, 1));
     }
 
+    #[tokio::test]
+    async fn field_accessors_on_a_timestamp_read_at_utc() {
+        // 1700000000 is 2023-11-14T22:13:20Z; the offset is always explicit
+        let at_utc = "1700000000 0 DATETIME";
+        for (word, expected) in [
+            ("YEAR", 2023),
+            ("MONTH", 11),
+            ("DAY", 14),
+            ("HOUR", 22),
+            ("MINUTE", 13),
+            ("SECOND", 20),
+        ] {
+            let program = format!("{} {}", at_utc, word);
+            assert_eq!(number(&program).await, (expected, 1), "{}", word);
+        }
+    }
+
+    #[tokio::test]
+    async fn field_accessors_reject_a_bare_timestamp() {
+        let mut interp = Interpreter::new();
+        let err = interp
+            .execute("'time' IMPORT 1700000000 YEAR")
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("YEAR: expected a civil vector"));
+        assert_eq!(interp.stack.len(), 1, "operand restored");
+    }
+
     #[tokio::test]
     async fn weekday_is_iso() {
         assert_eq!(number("[ 2024 11 25 ] WEEKDAY").await, (1, 1)); // Monday
@@ -146,6 +174,21 @@ mod tests {
         // leap year
     }
 
+    #[tokio::test]
+    async fn day_arithmetic_on_timestamps() {
+        // an instant shifts by exactly 86400 seconds per day
+        assert_eq!(number("1700000000 1 ADD-DAYS").await, (1700086400, 1));
+        assert_eq!(number("1700000000 -2 ADD-DAYS").await, (1699827200, 1));
+        assert_eq!(number("86400 0 DIFF-DAYS").await, (1, 1));
+        // partial days are not counted, in either direction
+        assert_eq!(number("172799 0 DIFF-DAYS").await, (1, 1));
+        assert_eq!(number("0 172799 DIFF-DAYS").await, (-1, 1));
+        assert_eq!(
+            number("1700000000 1 ADD-DAYS 1700000000 DIFF-DAYS").await,
+            (1, 1)
+        );
+    }
+
     #[tokio::test]
     async fn format_iso() {
         assert_eq!(text("[ 2024 11 25 ] FORMAT").await, "'2024-11-25'");
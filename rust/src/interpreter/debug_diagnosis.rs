@@ -152,6 +152,7 @@ impl CauseClass {
             ErrorCategory::ModeUnsupported => CauseClass::ContractViolation,
             ErrorCategory::BuiltinProtection => CauseClass::ContractViolation,
             ErrorCategory::CondExhausted => CauseClass::UserLogic,
+            ErrorCategory::Breakpoint => CauseClass::UserLogic,
             ErrorCategory::Custom => CauseClass::Unknown,
         }
     }
@@ -316,6 +317,7 @@ fn recoverability_for(why: &CauseClass, category: Option<&ErrorCategory>) -> &'s
         Some(ErrorCategory::BuiltinProtection) => "fixCapabilityOrForce",
         Some(ErrorCategory::ExecutionLimitExceeded)
         | Some(ErrorCategory::RecursionLimitExceeded) => "addBudgetOrFixRecursion",
+        Some(ErrorCategory::Breakpoint) => "inspectContext",
         Some(ErrorCategory::Custom) | None => match why {
             CauseClass::Environment | CauseClass::Effect => "fixHost",
             CauseClass::NilFlow => "handleUnknownOrNil",
@@ -349,7 +351,7 @@ fn semantic_area_for(word: Option<&str>, why: &CauseClass) -> &'static str {
         Some("ADD" | "SUB" | "MUL" | "DIV" | "MOD" | "SQRT" | "FLOOR" | "CEIL" | "ROUND") => {
             "exact-real-arithmetic"
         }
-        Some("EQ" | "NEQ" | "LT" | "LTE" | "GT" | "GTE" | "COMPARE-WITHIN") => {
+        Some("EQ" | "NEQ" | "LT" | "LTE" | "GT" | "GTE" | "COMPARE" | "COMPARE-WITHIN") => {
             "exact-real-comparison"
         }
         Some("AND" | "OR" | "NOT") => "k3-truth",
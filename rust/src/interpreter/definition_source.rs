@@ -0,0 +1,166 @@
+//! User definitions as re-runnable Ajisai source.
+//!
+//! `export_definitions_source` renders every user word as a
+//! `{ body } 'NAME' DEF` line, preceded by the module imports the bodies rely
+//! on. Executing the result in a fresh interpreter reproduces the definitions;
+//! unlike `export_state` it carries no stack or session settings, so it is
+//! meant to be read, edited, and pasted back.
+
+use std::collections::{BTreeSet, HashMap};
+
+use crate::error::{AjisaiError, Result};
+use crate::types::Token;
+
+use super::Interpreter;
+
+impl Interpreter {
+    pub(crate) fn format_token_to_string(&self, token: &Token) -> String {
+        match token {
+            Token::Number(n) => n.to_string(),
+            Token::String(s) => crate::tokenizer::quote_string_literal(s),
+            Token::Symbol(s) => s.to_string(),
+            Token::VectorStart => "[".to_string(),
+            Token::VectorEnd => "]".to_string(),
+            Token::BlockStart => "{".to_string(),
+            Token::BlockEnd => "}".to_string(),
+            Token::Pipeline => "~".to_string(),
+            Token::NilCoalesce => "^".to_string(),
+            Token::CondClauseSep => "|".to_string(),
+            Token::LineBreak => "\n".to_string(),
+        }
+    }
+
+    pub fn lookup_word_definition_tokens(&self, name: &str) -> Option<String> {
+        let (_, def) = self.resolve_word_entry_readonly(name)?;
+        if def.is_builtin || def.lines.is_empty() {
+            return None;
+        }
+
+        let mut result = String::new();
+        for (i, line) in def.lines.iter().enumerate() {
+            if i > 0 {
+                result.push('\n');
+            }
+            for token in line.body_tokens.iter() {
+                result.push_str(&self.format_token_to_string(token));
+                result.push(' ');
+            }
+        }
+        Some(result.trim().to_string())
+    }
+
+    /// Render all user words as Ajisai source, ordered so that every word is
+    /// defined after the words it depends on.
+    ///
+    /// The order is a topological sort over the `dependents` index, with
+    /// definition order breaking ties. Words of the active dictionary are
+    /// emitted under their short names and land in whichever dictionary is
+    /// active when the source is run; words of any other dictionary are
+    /// emitted as `'DICT@NAME' DEF`, so they return to their own dictionary.
+    pub fn export_definitions_source(&self) -> Result<String> {
+        let mut out = String::new();
+        let mut modules: Vec<_> = self.import_table.modules.iter().collect();
+        modules.sort_by(|a, b| a.0.cmp(b.0));
+        for (module, entry) in modules {
+            if entry.import_all_public {
+                out.push_str(&format!("'{}' IMPORT\n", module.to_lowercase()));
+            } else if !entry.imported_words.is_empty() {
+                let mut words: Vec<_> = entry.imported_words.iter().collect();
+                words.sort();
+                let selectors: Vec<String> = words
+                    .iter()
+                    .map(|w| format!("'{}'", w.to_lowercase()))
+                    .collect();
+                out.push_str(&format!(
+                    "'{}' [ {} ] IMPORT-ONLY\n",
+                    module.to_lowercase(),
+                    selectors.join(" ")
+                ));
+            }
+        }
+
+        let active_prefix = format!("{}@", self.active_user_dictionary);
+        for (fq_name, name) in self.definitions_in_dependency_order() {
+            let body = self
+                .lookup_word_definition_tokens(&fq_name)
+                .ok_or_else(|| {
+                    AjisaiError::from(format!(
+                        "export_definitions_source: no source for '{}'",
+                        fq_name
+                    ))
+                })?;
+            let def_name = if fq_name.starts_with(&active_prefix) {
+                name.as_str()
+            } else {
+                fq_name.as_str()
+            };
+            out.push_str(&format!("{{ {} }} '{}' DEF\n", body, def_name));
+        }
+        Ok(out)
+    }
+
+    /// `(fq_name, short_name)` of every user word, dependencies first.
+    pub(super) fn definitions_in_dependency_order(&self) -> Vec<(String, String)> {
+        let mut order: HashMap<String, (u64, String)> = HashMap::new();
+        for (dict, d) in &self.user_dictionaries {
+            for (name, def) in &d.words {
+                order.insert(
+                    format!("{}@{}", dict, name),
+                    (def.registration_order, name.clone()),
+                );
+            }
+        }
+
+        let mut in_degree: HashMap<&str, usize> = order.keys().map(|fq| (fq.as_str(), 0)).collect();
+        for (dependency, dependents) in &self.dependents {
+            if !order.contains_key(dependency) {
+                continue;
+            }
+            for dependent in dependents {
+                if dependent != dependency {
+                    if let Some(count) = in_degree.get_mut(dependent.as_str()) {
+                        *count += 1;
+                    }
+                }
+            }
+        }
+
+        let mut ready: BTreeSet<(u64, &str)> = in_degree
+            .iter()
+            .filter(|(_, &count)| count == 0)
+            .map(|(&fq, _)| (order[fq].0, fq))
+            .collect();
+        let mut sorted = Vec::with_capacity(order.len());
+        while let Some(next) = ready.pop_first() {
+            let fq = next.1;
+            sorted.push((fq.to_string(), order[fq].1.clone()));
+            for dependent in self.dependents.get(fq).into_iter().flatten() {
+                if dependent == fq {
+                    continue;
+                }
+                if let Some(count) = in_degree.get_mut(dependent.as_str()) {
+                    *count -= 1;
+                    if *count == 0 {
+                        ready.insert((order[dependent].0, dependent.as_str()));
+                    }
+                }
+            }
+        }
+
+        // A dependency cycle cannot be ordered; keep its words in definition
+        // order rather than dropping them.
+        if sorted.len() < order.len() {
+            let mut rest: Vec<_> = in_degree
+                .iter()
+                .filter(|(_, &count)| count > 0)
+                .map(|(&fq, _)| (order[fq].0, fq))
+                .collect();
+            rest.sort();
+            sorted.extend(
+                rest.into_iter()
+                    .map(|(_, fq)| (fq.to_string(), order[fq].1.clone())),
+            );
+        }
+        sorted
+    }
+}
@@ -0,0 +1,87 @@
+//! Test suite for `Interpreter::export_definitions_source`.
+//!
+//! The exported text must be plain Ajisai: running it in a fresh interpreter
+//! reproduces every user word, which only works if each word is emitted after
+//! the words (and module imports) it depends on.
+
+#[cfg(test)]
+mod tests {
+    use crate::interpreter::Interpreter;
+
+    fn top_i64(interp: &Interpreter) -> i64 {
+        interp
+            .stack
+            .last()
+            .and_then(|v| v.as_scalar())
+            .and_then(|f| f.to_i64())
+            .expect("integer on top of the stack")
+    }
+
+    #[tokio::test]
+    async fn dependencies_are_emitted_before_dependents() {
+        let mut interp = Interpreter::new();
+        // INC is defined first, then redefined after its dependent exists, so
+        // definition order alone would put it in the wrong place.
+        interp.execute("{ 1 + } 'INC' DEF").await.unwrap();
+        interp.execute("{ INC INC } 'INC2' DEF").await.unwrap();
+        interp.execute("{ 1 + } 'STEP' DEF").await.unwrap();
+        interp.execute("{ STEP INC2 } 'INC3' DEF").await.unwrap();
+        interp.execute("! { STEP } 'INC' DEF").await.unwrap();
+
+        let source = interp.export_definitions_source().unwrap();
+        let position = |name: &str| {
+            source
+                .find(&format!("'{}' DEF", name))
+                .unwrap_or_else(|| panic!("{} missing from:\n{}", name, source))
+        };
+        assert!(position("STEP") < position("INC"), "{}", source);
+        assert!(position("INC") < position("INC2"), "{}", source);
+        assert!(position("INC2") < position("INC3"), "{}", source);
+    }
+
+    #[tokio::test]
+    async fn emitted_source_reproduces_definitions_in_a_fresh_interpreter() {
+        let mut interp = Interpreter::new();
+        interp.execute("'math' IMPORT").await.unwrap();
+        interp.execute("{ 2 POW } 'SQUARE' DEF").await.unwrap();
+        interp
+            .execute("{ SQUARE\n1 + } 'SQUARE-PLUS-ONE' DEF")
+            .await
+            .unwrap();
+        let source = interp.export_definitions_source().unwrap();
+
+        let mut fresh = Interpreter::new();
+        fresh.execute(&source).await.unwrap();
+        fresh.execute("3 SQUARE-PLUS-ONE").await.unwrap();
+        assert_eq!(top_i64(&fresh), 10);
+        assert_eq!(fresh.export_definitions_source().unwrap(), source);
+    }
+
+    #[tokio::test]
+    async fn same_short_name_in_two_dictionaries_round_trips() {
+        let mut interp = Interpreter::new();
+        interp.execute("{ 1 + } 'STEP' DEF").await.unwrap();
+        interp.execute("{ 10 + } 'LIB@STEP' DEF").await.unwrap();
+        interp
+            .execute("{ STEP STEP } 'LIB@TWICE' DEF")
+            .await
+            .unwrap();
+        let source = interp.export_definitions_source().unwrap();
+        assert!(source.contains("'STEP' DEF"), "{}", source);
+        assert!(source.contains("'LIB@STEP' DEF"), "{}", source);
+
+        let mut fresh = Interpreter::new();
+        fresh.execute(&source).await.unwrap();
+        fresh.execute("1 EXAMPLE@STEP").await.unwrap();
+        assert_eq!(top_i64(&fresh), 2);
+        fresh.execute("1 LIB@TWICE").await.unwrap();
+        assert_eq!(top_i64(&fresh), 21);
+        assert_eq!(fresh.export_definitions_source().unwrap(), source);
+    }
+
+    #[tokio::test]
+    async fn no_user_words_exports_empty_source() {
+        let interp = Interpreter::new();
+        assert_eq!(interp.export_definitions_source().unwrap(), "");
+    }
+}
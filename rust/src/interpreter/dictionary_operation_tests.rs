@@ -775,4 +775,25 @@ mod tests {
             err_msg
         );
     }
+
+    #[tokio::test]
+    async fn test_qualified_def_targets_the_named_dictionary() {
+        let mut interp = Interpreter::new();
+        interp.execute("{ [ 1 ] } 'SAY' DEF").await.unwrap();
+        interp.execute("{ [ 2 ] } 'test@say' DEF").await.unwrap();
+        assert_eq!(interp.active_user_dictionary, "EXAMPLE");
+        assert!(interp.user_dictionaries["TEST"].words.contains_key("SAY"));
+
+        interp.execute("TEST@SAY").await.unwrap();
+        let top = interp.stack.last().expect("result present");
+        let only = top.child(0).expect("vector has one child");
+        assert_eq!(only.as_scalar().unwrap().to_i64().unwrap(), 2);
+
+        let result = interp.execute("{ [ 1 ] } 'MATH@SAY' DEF").await;
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("MATH is a module dictionary"));
+        assert!(!interp.user_dictionaries.contains_key("MATH"));
+    }
 }
@@ -2,14 +2,14 @@ use crate::builtins::{lookup_builtin_spec, BuiltinExecutorKey};
 #[cfg(feature = "elastic-engine")]
 use crate::elastic::ElasticMode;
 use crate::error::{AjisaiError, Result};
-use crate::types::{Interpretation, Token, Value};
+use crate::types::{Interpretation, Value};
 
 use super::compiled_plan::{execute_compiled_plan, is_plan_valid};
 
 use super::{
     arithmetic, cast, comparison, control, control_cond, execute_def, execute_del, execute_lookup,
     higher_order, higher_order_fold, interval_ops, io, logic, modules, nil_diagnostics,
-    tensor_cmds, vector_ops, Interpreter,
+    tensor_axis_ops, tensor_cmds, tensor_map, vector_ops, Interpreter,
 };
 
 #[cfg(feature = "trace-compile")]
@@ -46,10 +46,19 @@ impl Interpreter {
 
     /// Public entry point for word execution.
     ///
+    /// Records a word-trace line when `set_word_trace(true)` is active (see
+    /// `word_trace.rs`); otherwise goes straight to the memoization check.
+    pub(crate) fn execute_word_core(&mut self, name: &str) -> Result<()> {
+        if self.word_trace.is_enabled() {
+            return self.execute_word_recorded(name);
+        }
+        self.execute_word_memoized(name)
+    }
+
     /// When `AJISAI_TRACE=1` (or `set_trace_enabled(true)`) is active this
     /// wraps the call with timing instrumentation.  All existing greedy
     /// semantics are preserved unchanged.
-    pub(crate) fn execute_word_core(&mut self, name: &str) -> Result<()> {
+    pub(super) fn execute_word_traced(&mut self, name: &str) -> Result<()> {
         if crate::elastic::tracer::is_enabled() {
             #[cfg(not(target_arch = "wasm32"))]
             {
@@ -71,7 +80,8 @@ impl Interpreter {
 
     /// Core word-execution logic (greedy, always).
     ///
-    /// Never call directly — use `execute_word_core` so tracing applies.
+    /// Never call directly — use `execute_word_core` so tracing and
+    /// memoization apply.
     fn execute_word_core_inner(&mut self, name: &str) -> Result<()> {
         let canonical_name = crate::core_word_aliases::canonicalize_core_word_name(name);
         let name = canonical_name.as_ref();
@@ -244,7 +254,7 @@ impl Interpreter {
 
     pub(crate) fn execute_builtin(&mut self, name: &str) -> Result<()> {
         let canonical = crate::core_word_aliases::canonicalize_core_word_name(name);
-        if canonical != "DEL" && canonical != "DEF" && canonical != "FORC" {
+        if !super::compiled_call::keeps_force_flag(&canonical) {
             self.force_flag = false;
         }
 
@@ -274,15 +284,20 @@ impl Interpreter {
             BuiltinExecutorKey::Gt => comparison::op_gt(self),
             BuiltinExecutorKey::Gte => comparison::op_gte(self),
             BuiltinExecutorKey::Neq => comparison::op_neq(self),
+            BuiltinExecutorKey::Compare => comparison::op_compare(self),
             BuiltinExecutorKey::CompareWithin => comparison::op_compare_within(self),
             BuiltinExecutorKey::Map => higher_order::op_map(self),
+            BuiltinExecutorKey::Treemap => higher_order::op_treemap(self),
             BuiltinExecutorKey::Filter => higher_order::op_filter(self),
             BuiltinExecutorKey::Fold => higher_order_fold::op_fold(self),
+            BuiltinExecutorKey::Foldr => higher_order::op_foldr(self),
             BuiltinExecutorKey::Unfold => higher_order_fold::op_unfold(self),
+            BuiltinExecutorKey::Iterate => higher_order::op_iterate(self),
             BuiltinExecutorKey::Any => higher_order::op_any(self),
             BuiltinExecutorKey::All => higher_order::op_all(self),
             BuiltinExecutorKey::Count => higher_order::op_count(self),
             BuiltinExecutorKey::Scan => higher_order_fold::op_scan(self),
+            BuiltinExecutorKey::Scanr => higher_order::op_scanr(self),
             BuiltinExecutorKey::Get => vector_ops::op_get(self),
             BuiltinExecutorKey::Length => vector_ops::op_length(self),
             BuiltinExecutorKey::Concat => vector_ops::op_concat(self),
@@ -307,6 +322,8 @@ impl Interpreter {
             BuiltinExecutorKey::Exec => control::op_exec(self),
             BuiltinExecutorKey::Eval => control::op_eval(self),
             BuiltinExecutorKey::OrElse => control::op_or_else(self),
+            BuiltinExecutorKey::Try => control::op_try(self),
+            BuiltinExecutorKey::Raise => control::op_raise(self),
             BuiltinExecutorKey::Cond => control_cond::op_cond(self),
             BuiltinExecutorKey::Def => execute_def::op_def(self),
             BuiltinExecutorKey::Del => execute_del::op_del(self),
@@ -320,8 +337,10 @@ impl Interpreter {
                 Ok(())
             }
             BuiltinExecutorKey::Print => io::op_print(self),
+            BuiltinExecutorKey::Inspect => io::op_inspect(self),
             BuiltinExecutorKey::Insert => vector_ops::op_insert(self),
             BuiltinExecutorKey::Replace => vector_ops::op_replace(self),
+            BuiltinExecutorKey::Update => vector_ops::op_update(self),
             BuiltinExecutorKey::Remove => vector_ops::op_remove(self),
             BuiltinExecutorKey::Take => vector_ops::op_take(self),
             BuiltinExecutorKey::Split => vector_ops::op_split(self),
@@ -329,10 +348,17 @@ impl Interpreter {
             BuiltinExecutorKey::Range => vector_ops::op_range(self),
             BuiltinExecutorKey::Reorder => vector_ops::op_reorder(self),
             BuiltinExecutorKey::Collect => vector_ops::op_collect(self),
+            BuiltinExecutorKey::Snapshot => vector_ops::op_snapshot(self),
+            BuiltinExecutorKey::Restore => vector_ops::op_restore(self),
+            BuiltinExecutorKey::Enumerate => vector_ops::op_enumerate(self),
             BuiltinExecutorKey::Shape => tensor_cmds::op_shape(self),
             BuiltinExecutorKey::Rank => tensor_cmds::op_rank(self),
             BuiltinExecutorKey::Reshape => tensor_cmds::op_reshape(self),
             BuiltinExecutorKey::Transpose => tensor_cmds::op_transpose(self),
+            BuiltinExecutorKey::Squeeze => tensor_axis_ops::op_squeeze(self),
+            BuiltinExecutorKey::Unsqueeze => tensor_axis_ops::op_unsqueeze(self),
+            BuiltinExecutorKey::AxisGet => tensor_axis_ops::op_axis_get(self),
+            BuiltinExecutorKey::Tmap => tensor_map::op_tmap(self),
             BuiltinExecutorKey::Fill => tensor_cmds::op_fill(self),
             BuiltinExecutorKey::Floor => tensor_cmds::op_floor(self),
             BuiltinExecutorKey::Ceil => tensor_cmds::op_ceil(self),
@@ -348,6 +374,8 @@ impl Interpreter {
             BuiltinExecutorKey::Num => cast::op_num(self),
             BuiltinExecutorKey::Bool => cast::op_bool(self),
             BuiltinExecutorKey::Chr => cast::op_chr(self),
+            BuiltinExecutorKey::Codepoint => cast::op_codepoint(self),
+            BuiltinExecutorKey::Decimal => cast::op_decimal(self),
             BuiltinExecutorKey::Chars => cast::op_chars(self),
             BuiltinExecutorKey::Join => cast::op_join(self),
             BuiltinExecutorKey::Trim => cast::op_trim(self),
@@ -355,6 +383,7 @@ impl Interpreter {
             BuiltinExecutorKey::TrimRight => cast::op_trim_right(self),
             BuiltinExecutorKey::Tokenize => cast::op_tokenize(self),
             BuiltinExecutorKey::Substitute => cast::op_substitute(self),
+            BuiltinExecutorKey::FormatText => cast::op_format_text(self),
             BuiltinExecutorKey::StartsWith => cast::op_starts_with(self),
             BuiltinExecutorKey::EndsWith => cast::op_ends_with(self),
             BuiltinExecutorKey::Spawn => self.op_spawn(),
@@ -454,39 +483,4 @@ impl Interpreter {
             }
         }
     }
-
-    pub(crate) fn format_token_to_string(&self, token: &Token) -> String {
-        match token {
-            Token::Number(n) => n.to_string(),
-            Token::String(s) => format!("'{}'", s),
-            Token::Symbol(s) => s.to_string(),
-            Token::VectorStart => "[".to_string(),
-            Token::VectorEnd => "]".to_string(),
-            Token::BlockStart => "{".to_string(),
-            Token::BlockEnd => "}".to_string(),
-            Token::Pipeline => "~".to_string(),
-            Token::NilCoalesce => "^".to_string(),
-            Token::CondClauseSep => "|".to_string(),
-            Token::LineBreak => "\n".to_string(),
-        }
-    }
-
-    pub fn lookup_word_definition_tokens(&self, name: &str) -> Option<String> {
-        let (_, def) = self.resolve_word_entry_readonly(name)?;
-        if def.is_builtin || def.lines.is_empty() {
-            return None;
-        }
-
-        let mut result = String::new();
-        for (i, line) in def.lines.iter().enumerate() {
-            if i > 0 {
-                result.push('\n');
-            }
-            for token in line.body_tokens.iter() {
-                result.push_str(&self.format_token_to_string(token));
-                result.push(' ');
-            }
-        }
-        Some(result.trim().to_string())
-    }
 }
@@ -15,7 +15,7 @@ fn code_block_tokens_to_source(tokens: &[Token]) -> String {
         .iter()
         .map(|t| match t {
             Token::Number(n) => n.to_string(),
-            Token::String(s) => format!("'{}'", s),
+            Token::String(s) => crate::tokenizer::quote_string_literal(s),
             Token::Symbol(s) => s.to_string(),
             Token::VectorStart => "[".to_string(),
             Token::VectorEnd => "]".to_string(),
@@ -71,6 +71,27 @@ pub fn op_def(interp: &mut Interpreter) -> Result<()> {
 }
 
 pub(crate) fn op_def_inner(interp: &mut Interpreter, name: &str, tokens: &[Token]) -> Result<()> {
+    // `'DICT@NAME' DEF` defines into DICT, the way `'DICT@NAME' DEL` deletes
+    // from it; a bare name goes to the active dictionary.
+    let (dict_name, name) = match (interp.split_qualified_name(name), name.rsplit_once('@')) {
+        (Some((dict, _)), Some((_, word))) => (dict, word),
+        _ => (interp.active_user_dictionary.clone(), name),
+    };
+    if dict_name.is_empty() || name.is_empty() {
+        interp.force_flag = false;
+        return Err(AjisaiError::from(format!(
+            "Cannot define '{}@{}': a qualified name needs a dictionary and a word",
+            dict_name, name
+        )));
+    }
+    if crate::interpreter::modules::is_known_module(&dict_name) {
+        interp.force_flag = false;
+        return Err(AjisaiError::from(format!(
+            "Cannot define '{}@{}': {} is a module dictionary",
+            dict_name, name, dict_name
+        )));
+    }
+
     if let Some(message) =
         crate::interpreter::naming_convention_checker::check_reserved_word_name(name)
     {
@@ -96,7 +117,6 @@ pub(crate) fn op_def_inner(interp: &mut Interpreter, name: &str, tokens: &[Token
 
     let collision_modules: Vec<String> = Vec::new();
 
-    let dict_name = interp.active_user_dictionary.clone();
     let fq_name = format!("{}@{}", dict_name, upper_name);
 
     if let Some(existing) = interp
@@ -149,9 +169,7 @@ pub(crate) fn op_def_inner(interp: &mut Interpreter, name: &str, tokens: &[Token
     // Section 8.6: resolve this word's references through its own dictionary
     // first, so the dependency it records is its own dictionary's word rather
     // than a same-named word in another (e.g. earlier-loaded) dictionary.
-    let prev_owning = interp
-        .owning_dictionary_context
-        .replace(dict_name.clone());
+    let prev_owning = interp.owning_dictionary_context.replace(dict_name.clone());
     let mut new_dependencies = HashSet::new();
     for line in lines.iter() {
         for token in line.body_tokens.iter() {
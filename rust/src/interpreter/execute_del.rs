@@ -1,6 +1,7 @@
 use crate::error::{AjisaiError, Result};
 use crate::interpreter::value_extraction_helpers::extract_word_name_from_value;
 use crate::interpreter::{Interpreter, OperationTargetMode};
+use crate::types::{Interpretation, Value};
 
 pub fn op_del(interp: &mut Interpreter) -> Result<()> {
     if interp.operation_target_mode != OperationTargetMode::StackTop {
@@ -12,8 +13,97 @@ pub fn op_del(interp: &mut Interpreter) -> Result<()> {
 
     let val = interp.stack.pop().ok_or(AjisaiError::StackUnderflow)?;
 
+    if is_name_batch(&val) {
+        return delete_batch(interp, val);
+    }
+
     let name = extract_word_name_from_value(&val)?;
+    delete_word(interp, &name)
+}
+
+/// `[ 'A' 'B' ]` as opposed to the single name `'AB'`, which is itself a
+/// vector of code points.
+fn is_name_batch(val: &Value) -> bool {
+    val.hint != Interpretation::Text
+        && val.as_vector_view().is_some_and(|names| {
+            !names.is_empty() && names.iter().all(|n| n.hint == Interpretation::Text)
+        })
+}
+
+/// Delete every name in `batch`. Names are retried while any deletion
+/// succeeds, so a batch may hold a word together with the words that call
+/// it. Without `!` a batch with any undeletable name deletes nothing and
+/// raises one error listing them all; with `!` the deletable names are
+/// deleted and the rest are reported in one warning.
+fn delete_batch(interp: &mut Interpreter, batch: Value) -> Result<()> {
+    let names: Result<Vec<String>> = batch
+        .as_vector_view()
+        .map(|names| names.iter().map(extract_word_name_from_value).collect())
+        .unwrap_or_else(|| Ok(Vec::new()));
+    let names = match names {
+        Ok(names) => names,
+        Err(err) => {
+            interp.stack.push(batch);
+            return Err(err);
+        }
+    };
+    let force = interp.force_flag;
+    let saved_words = interp.user_words.clone();
+    let saved_dictionaries = interp.user_dictionaries.clone();
+    let saved_dependents = interp.dependents.clone();
+    let saved_dictionary_dependencies = interp.dictionary_dependencies.clone();
+    let saved_output_len = interp.output_buffer.len();
+    let prev_defer = std::mem::replace(&mut interp.defer_identity_recompute, true);
+
+    let total = names.len();
+    let mut pending = names;
+    let mut failures = Vec::new();
+    loop {
+        let attempted = pending.len();
+        failures.clear();
+        for name in std::mem::take(&mut pending) {
+            interp.force_flag = force;
+            if let Err(err) = delete_word(interp, &name) {
+                failures.push(err.to_string());
+                pending.push(name);
+            }
+        }
+        if pending.is_empty() || pending.len() == attempted {
+            break;
+        }
+    }
+    interp.defer_identity_recompute = prev_defer;
+    interp.force_flag = false;
+
+    if !failures.is_empty() && !force {
+        interp.user_words = saved_words;
+        interp.user_dictionaries = saved_dictionaries;
+        interp.dependents = saved_dependents;
+        interp.dictionary_dependencies = saved_dictionary_dependencies;
+        interp.output_buffer.truncate(saved_output_len);
+        interp.bump_dictionary_epoch();
+        interp.stack.push(batch);
+        return Err(AjisaiError::from(format!(
+            "DEL: {} of {} words cannot be deleted, so none were: {}",
+            failures.len(),
+            total,
+            failures.join("; ")
+        )));
+    }
+    if !failures.is_empty() {
+        interp.output_buffer.push_str(&format!(
+            "Warning: {} of {} words were not deleted: {}\n",
+            failures.len(),
+            total,
+            failures.join("; ")
+        ));
+    }
+    interp.recompute_word_identities();
+    interp.gc_body_store();
+    Ok(())
+}
 
+fn delete_word(interp: &mut Interpreter, name: &str) -> Result<()> {
     let upper_name = name.to_uppercase();
 
     let (target_dict, word_name) = if let Some((ns, w)) = interp.split_qualified_name(&upper_name) {
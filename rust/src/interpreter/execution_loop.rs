@@ -43,11 +43,12 @@ pub(crate) fn end_of_source_unit(tokens: &[Token], start: usize) -> usize {
 /// table (e.g. user words).
 pub(crate) fn apply_word_hint_override(interp: &mut Interpreter, word: &str) {
     let hint: Option<Interpretation> = match word {
-        "STR" | "CHR" | "JOIN" | "TRIM" | "TRIM-LEFT" | "TRIM-RIGHT" | "SUBSTITUTE" => {
+        "STR" | "CHR" | "JOIN" | "TRIM" | "TRIM-LEFT" | "TRIM-RIGHT" | "SUBSTITUTE"
+        | "FORMAT-TEXT" | "DECIMAL" => {
             Some(Interpretation::Text)
         }
-        "NUM" | "ADD" | "SUB" | "MUL" | "DIV" | "MOD" | "FLOOR" | "CEIL" | "ROUND" | "QUANTIZE"
-        | "QUANTIZE-HALF-AWAY" | "QUANTIZE-FLOOR" | "QUANTIZE-CEIL" | "QUANTIZE-TRUNC" | "FOLD" => {
+        "NUM" | "CODEPOINT" | "ADD" | "SUB" | "MUL" | "DIV" | "MOD" | "FLOOR" | "CEIL" | "ROUND"
+        | "QUANTIZE" | "QUANTIZE-HALF-AWAY" | "QUANTIZE-FLOOR" | "QUANTIZE-CEIL" | "QUANTIZE-TRUNC" | "FOLD" | "FOLDR" => {
             Some(Interpretation::RawNumber)
         }
         "SQRT" | "SQRT_EPS" | "INTERVAL" | "MATH@SQRT" | "MATH@SQRT-EPS" | "MATH@INTERVAL" => {
@@ -59,8 +60,8 @@ pub(crate) fn apply_word_hint_override(interp: &mut Interpreter, word: &str) {
         "BOOL" | "LT" | "LTE" | "GT" | "GTE" | "EQ" | "NEQ" | "AND" | "OR" | "NOT"
         | "STARTS-WITH?" | "ENDS-WITH?" => Some(Interpretation::TruthValue),
         "NOW" | "TIMESTAMP" => Some(Interpretation::Timestamp),
-        "CHARS" | "MAP" | "FILTER" | "SCAN" | "UNFOLD" | "REVERSE" | "CONCAT" | "SORT" | "TAKE"
-        | "REORDER" | "SPLIT" | "COLLECT" | "RESHAPE" | "TRANSPOSE" | "FILL" | "TOKENIZE"
+        "CHARS" | "MAP" | "FILTER" | "SCAN" | "SCANR" | "UNFOLD" | "ITERATE" | "REVERSE" | "CONCAT" | "SORT" | "TAKE"
+        | "REORDER" | "SPLIT" | "COLLECT" | "SNAPSHOT" | "ENUMERATE" | "RESHAPE" | "TRANSPOSE" | "SQUEEZE" | "UNSQUEEZE" | "AXISGET" | "FILL" | "TOKENIZE"
         | "CONSERVE" => Some(Interpretation::Unassigned),
         _ => None,
     };
@@ -509,6 +510,7 @@ impl Interpreter {
         // before `Fraction::from_str` parses it into a (potentially enormous)
         // BigInt-backed value.
         self.runtime_limits.check_source_bytes(code.len())?;
+        self.record_undo_point();
         self.execution_step_count = 0;
         self.numeric_work_used = 0;
         let tokens: Vec<Token> = crate::tokenizer::tokenize(code)?;
@@ -30,7 +30,7 @@ lazy_static::lazy_static! {
     static ref HASH_BASE: BigInt = BigInt::from(257u32);
 }
 
-fn serialize_value_for_hash(value: &Value) -> Vec<u8> {
+pub(crate) fn serialize_value_for_hash(value: &Value) -> Vec<u8> {
     let mut bytes = Vec::new();
     serialize_value_inner_for_hash(value, &mut bytes);
     bytes
@@ -164,6 +164,24 @@ mod tests {
         assert_eq!(interp.stack.len(), 1);
     }
 
+    #[tokio::test]
+    async fn test_hash_distinguishes_nesting() {
+        let mut hashes = Vec::new();
+        for code in ["[ 1 2 ]", "[ [ 1 2 ] ]", "[ [ 1 ] 2 ]", "[ [ 1 ] [ 2 ] ]"] {
+            let mut interp = Interpreter::new();
+            interp
+                .execute(&format!("'crypto' IMPORT {} HASH", code))
+                .await
+                .unwrap();
+            hashes.push(interp.stack.pop().unwrap().to_string());
+        }
+        for i in 0..hashes.len() {
+            for j in i + 1..hashes.len() {
+                assert_ne!(hashes[i], hashes[j], "same elements, different structure");
+            }
+        }
+    }
+
     #[tokio::test]
     async fn test_hash_empty_string() {
         let mut interp = Interpreter::new();
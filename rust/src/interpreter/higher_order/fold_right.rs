@@ -0,0 +1,151 @@
+use super::common::{execute_executable_code, extract_executable_code, ExecutableCode};
+use crate::error::{AjisaiError, Result};
+use crate::interpreter::value_extraction_helpers::is_vector_value;
+use crate::interpreter::{ConsumptionMode, Interpreter, OperationTargetMode};
+use crate::types::Value;
+
+/// Combine `elems` right to left, pushing `elem acc` for each step, and
+/// return every intermediate accumulator in element order (`history[i]` is
+/// the right fold of `elems[i..]`). Expects `interp.stack` to be isolated.
+fn fold_right_history(
+    interp: &mut Interpreter,
+    word: &str,
+    exec: &ExecutableCode,
+    elems: &[Value],
+    init: Value,
+) -> Result<Vec<Value>> {
+    let mut history = Vec::with_capacity(elems.len());
+    let mut accumulator = init;
+    for elem in elems.iter().rev() {
+        interp.stack.clear();
+        interp.stack.push(elem.clone());
+        interp.stack.push(accumulator);
+        execute_executable_code(interp, exec)?;
+        accumulator = interp.stack.pop().ok_or_else(|| {
+            AjisaiError::from(format!("{}: expected return value, got empty stack", word))
+        })?;
+        history.push(accumulator.clone());
+    }
+    history.reverse();
+    Ok(history)
+}
+
+/// Shared driver for FOLDR / SCANR: pops `target init code`, runs the right
+/// fold on an isolated stack, and restores all three operands on error.
+/// `finish` turns the accumulator history into the pushed result.
+fn run_fold_right(
+    interp: &mut Interpreter,
+    word: &str,
+    finish: fn(Value, Vec<Value>) -> Value,
+) -> Result<()> {
+    if interp.operation_target_mode == OperationTargetMode::Stack {
+        return Err(AjisaiError::ModeUnsupported {
+            word: word.into(),
+            mode: "Stack".into(),
+        });
+    }
+    let is_keep_mode = interp.consumption_mode == ConsumptionMode::Keep;
+
+    if interp.stack.len() < 3 {
+        return Err(AjisaiError::StackUnderflow);
+    }
+    let code_val = interp.stack.pop().expect("length checked above");
+    let init_val = interp.stack.pop().expect("length checked above");
+    let target_val = interp.stack.pop().expect("length checked above");
+    let restore = |interp: &mut Interpreter, target: Value, init: Value, code: Value| {
+        interp.stack.push(target);
+        interp.stack.push(init);
+        interp.stack.push(code);
+    };
+
+    let executable = match extract_executable_code(interp, &code_val) {
+        Ok(exec) => exec,
+        Err(e) => {
+            restore(interp, target_val, init_val, code_val);
+            return Err(e);
+        }
+    };
+    if let ExecutableCode::WordName(ref word_name) = executable {
+        if !interp.word_exists(word_name) {
+            let err = AjisaiError::UnknownWord(word_name.clone());
+            restore(interp, target_val, init_val, code_val);
+            return Err(err);
+        }
+    }
+    if !target_val.is_nil() && !is_vector_value(&target_val) {
+        restore(interp, target_val, init_val, code_val);
+        return Err(AjisaiError::create_structure_error(
+            "vector",
+            "other format",
+        ));
+    }
+
+    let elems: Vec<Value> = match target_val.as_vector_view() {
+        Some(view) => view.into_owned(),
+        None => Vec::new(),
+    };
+
+    let saved_stack = std::mem::take(&mut interp.stack);
+    let saved_no_change_check = interp.disable_no_change_check;
+    interp.disable_no_change_check = true;
+    let result = fold_right_history(interp, word, &executable, &elems, init_val.clone());
+    interp.disable_no_change_check = saved_no_change_check;
+    interp.operation_target_mode = OperationTargetMode::StackTop;
+    interp.stack = saved_stack;
+
+    match result {
+        Ok(history) => {
+            if is_keep_mode {
+                interp.stack.push(target_val);
+            }
+            interp.stack.push(finish(init_val, history));
+            Ok(())
+        }
+        Err(e) => {
+            restore(interp, target_val, init_val, code_val);
+            Err(e)
+        }
+    }
+}
+
+/// `FOLDR`: right-associative `FOLD`.
+///
+/// ```text
+/// [ 1 2 3 ] 0 { - } FOLDR -> 1 - (2 - (3 - 0)) = 2
+/// ```
+///
+/// The block sees `elem acc` (element below, accumulator on top), starting
+/// from the last element. An empty or NIL target yields the initial value.
+pub fn op_foldr(interp: &mut Interpreter) -> Result<()> {
+    run_fold_right(interp, "FOLDR", |init, history| {
+        history.into_iter().next().unwrap_or(init)
+    })
+}
+
+/// `SCANR`: the intermediate accumulators of `FOLDR`, in element order.
+///
+/// ```text
+/// [ 1 2 3 ] 0 { - } SCANR -> [ 2 -1 3 ]
+/// ```
+///
+/// Element `i` of the result is the right fold of the suffix starting at
+/// `i`, so the first element equals `FOLDR`. As with `SCAN`, the initial
+/// value itself is not included and an empty or NIL target yields NIL.
+pub fn op_scanr(interp: &mut Interpreter) -> Result<()> {
+    run_fold_right(interp, "SCANR", |_, history| {
+        if history.is_empty() {
+            return Value::nil();
+        }
+        let flattened = history
+            .into_iter()
+            .map(|v| {
+                if is_vector_value(&v) && v.len() == 1 {
+                    v.child(0).expect("len==1 implies child(0) exists")
+                } else {
+                    v
+                }
+            })
+            .collect();
+        Value::from_vector(flattened)
+    })
+}
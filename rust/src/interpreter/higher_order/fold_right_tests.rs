@@ -0,0 +1,82 @@
+//! Tests for the right folds (`fold_right.rs`, FOLDR / SCANR).
+//!
+//! Subtraction is the contrast case: FOLD associates to the left and FOLDR
+//! to the right, so the same operands give different answers.
+
+#[cfg(test)]
+mod tests {
+    use crate::interpreter::Interpreter;
+    use crate::types::display::format_with_hint;
+
+    fn rendered_top(interp: &Interpreter) -> String {
+        let (value, role) = interp.stack.iter_slots().last().expect("non-empty stack");
+        format_with_hint(value, role)
+    }
+
+    async fn rendered(code: &str) -> String {
+        let mut interp = Interpreter::new();
+        interp.execute(code).await.expect("code should execute");
+        rendered_top(&interp)
+    }
+
+    #[tokio::test]
+    async fn foldr_associates_to_the_right() {
+        // 1 - (2 - (3 - 0)) = 2, while FOLD gives ((0 - 1) - 2) - 3 = -6
+        assert_eq!(
+            rendered("[ 1 2 3 ] 0 { - } FOLDR").await,
+            rendered("2").await
+        );
+        assert_eq!(
+            rendered("[ 1 2 3 ] 0 { - } FOLD").await,
+            rendered("-6").await
+        );
+        assert_eq!(
+            rendered("[ 1 2 3 ] [ 0 ] '-' FOLDR").await,
+            rendered("[ 2 ]").await
+        );
+    }
+
+    #[tokio::test]
+    async fn foldr_of_nil_is_the_initial_value() {
+        assert_eq!(rendered("NIL 7 { - } FOLDR").await, rendered("7").await);
+    }
+
+    #[tokio::test]
+    async fn scanr_lists_every_suffix_fold() {
+        assert_eq!(
+            rendered("[ 1 2 3 ] 0 { - } SCANR").await,
+            rendered("[ 2 -1 3 ]").await
+        );
+        assert_eq!(
+            rendered("[ 1 2 3 ] 0 { - } SCAN").await,
+            rendered("[ -1 -3 -6 ]").await
+        );
+        assert_eq!(rendered("NIL 0 { - } SCANR").await, rendered("NIL").await);
+    }
+
+    #[tokio::test]
+    async fn errors_restore_all_three_operands() {
+        for word in ["FOLDR", "SCANR"] {
+            let mut interp = Interpreter::new();
+            interp
+                .execute("{ NO-SUCH-WORD } 'BROKEN' DEF [ 1 2 ] 0 { BROKEN }")
+                .await
+                .unwrap();
+            assert!(interp.execute(word).await.is_err());
+            assert_eq!(interp.stack.len(), 3, "{}", word);
+
+            let mut interp = Interpreter::new();
+            interp.execute("[ 1 2 ] 0 'NO-SUCH-WORD'").await.unwrap();
+            assert!(interp.execute(word).await.is_err());
+            assert_eq!(interp.stack.len(), 3, "{}", word);
+        }
+    }
+
+    #[tokio::test]
+    async fn stack_mode_is_unsupported() {
+        let mut interp = Interpreter::new();
+        interp.execute("[ 1 ] 0 { - }").await.unwrap();
+        let err = interp.execute(".. FOLDR").await.unwrap_err();
+        assert!(err.to_string().contains("FOLDR"), "{}", err);
+    }
+}
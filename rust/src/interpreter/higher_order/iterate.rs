@@ -0,0 +1,107 @@
+use super::common::{execute_executable_code, extract_executable_code, ExecutableCode};
+use crate::error::{AjisaiError, Result};
+use crate::interpreter::value_extraction_helpers::extract_count_from_value;
+use crate::interpreter::{ConsumptionMode, Interpreter, OperationTargetMode};
+use crate::types::Value;
+
+/// Same bound as `UNFOLD`: an accidental huge count fails fast instead of
+/// materializing an enormous vector.
+const MAX_ITERATIONS: usize = 10000;
+
+/// Apply `exec` to `seed` `count` times on the isolated stack, collecting the
+/// seed and every intermediate value.
+fn iterate_values(
+    interp: &mut Interpreter,
+    exec: &ExecutableCode,
+    seed: Value,
+    count: usize,
+) -> Result<Vec<Value>> {
+    let mut values = Vec::with_capacity(count + 1);
+    values.push(seed);
+    for _ in 0..count {
+        interp.stack.clear();
+        interp
+            .stack
+            .push(values.last().expect("seed pushed above").clone());
+        execute_executable_code(interp, exec)?;
+        if interp.stack.len() != 1 {
+            return Err(AjisaiError::from(format!(
+                "ITERATE: expected the word to leave exactly one value, got {}",
+                interp.stack.len()
+            )));
+        }
+        values.push(interp.stack.pop().expect("length checked above"));
+    }
+    Ok(values)
+}
+
+/// `ITERATE`: the first `n + 1` values of the orbit of a seed.
+///
+/// ```text
+/// 1 4 { 2 * } ITERATE -> [ 1 2 4 8 16 ]
+/// ```
+///
+/// Where `UNFOLD` runs until its step returns NIL, `ITERATE` runs a fixed
+/// number of times and keeps the seed as the first element, so a count of
+/// zero yields the one-element vector of the seed. Errors restore all three
+/// operands.
+pub fn op_iterate(interp: &mut Interpreter) -> Result<()> {
+    if interp.operation_target_mode == OperationTargetMode::Stack {
+        return Err(AjisaiError::ModeUnsupported {
+            word: "ITERATE".into(),
+            mode: "Stack".into(),
+        });
+    }
+    let is_keep_mode = interp.consumption_mode == ConsumptionMode::Keep;
+
+    if interp.stack.len() < 3 {
+        return Err(AjisaiError::StackUnderflow);
+    }
+    let code_val = interp.stack.pop().expect("length checked above");
+    let count_val = interp.stack.pop().expect("length checked above");
+    let seed_val = interp.stack.pop().expect("length checked above");
+
+    let prepared = extract_count_from_value(&count_val).and_then(|count| {
+        if count > MAX_ITERATIONS {
+            return Err(AjisaiError::from(format!(
+                "ITERATE: count {} exceeds the {} iteration limit",
+                count, MAX_ITERATIONS
+            )));
+        }
+        let exec = extract_executable_code(interp, &code_val)?;
+        if let ExecutableCode::WordName(ref word_name) = exec {
+            if !interp.word_exists(word_name) {
+                return Err(AjisaiError::UnknownWord(word_name.clone()));
+            }
+        }
+        Ok((count, exec))
+    });
+
+    let result = prepared.and_then(|(count, exec)| {
+        let saved_stack = std::mem::take(&mut interp.stack);
+        let saved_no_change_check = interp.disable_no_change_check;
+        interp.disable_no_change_check = true;
+        let values = iterate_values(interp, &exec, seed_val.clone(), count);
+        interp.disable_no_change_check = saved_no_change_check;
+        interp.operation_target_mode = OperationTargetMode::StackTop;
+        interp.stack = saved_stack;
+        values
+    });
+
+    match result {
+        Ok(values) => {
+            if is_keep_mode {
+                interp.stack.push(seed_val);
+                interp.stack.push(count_val);
+            }
+            interp.stack.push(Value::from_vector(values));
+            Ok(())
+        }
+        Err(e) => {
+            interp.stack.push(seed_val);
+            interp.stack.push(count_val);
+            interp.stack.push(code_val);
+            Err(e)
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//! Tests for the fixed-count orbit (`iterate.rs`, ITERATE).
+
+#[cfg(test)]
+mod tests {
+    use crate::interpreter::Interpreter;
+    use crate::types::display::format_with_hint;
+
+    fn rendered_top(interp: &Interpreter) -> String {
+        let (value, role) = interp.stack.iter_slots().last().expect("non-empty stack");
+        format_with_hint(value, role)
+    }
+
+    async fn rendered(code: &str) -> String {
+        let mut interp = Interpreter::new();
+        interp.execute(code).await.expect("code should execute");
+        rendered_top(&interp)
+    }
+
+    #[tokio::test]
+    async fn doubling_a_seed_four_times() {
+        assert_eq!(
+            rendered("1 4 { 2 * } ITERATE").await,
+            rendered("[ 1 2 4 8 16 ]").await
+        );
+    }
+
+    #[tokio::test]
+    async fn a_named_word_can_be_iterated() {
+        assert_eq!(
+            rendered("{ 2 * } 'DOUBLE' DEF 3 2 'DOUBLE' ITERATE").await,
+            rendered("[ 3 6 12 ]").await
+        );
+    }
+
+    #[tokio::test]
+    async fn zero_count_yields_just_the_seed() {
+        assert_eq!(
+            rendered("5 0 { 2 * } ITERATE").await,
+            rendered("[ 5 ]").await
+        );
+        assert_eq!(
+            rendered("[ 1 2 ] 1 { 2 * } ITERATE").await,
+            rendered("[ [ 1 2 ] [ 2 4 ] ]").await
+        );
+    }
+
+    #[tokio::test]
+    async fn errors_restore_the_original_stack() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute("{ NO-SUCH-WORD } 'BROKEN' DEF 9 1 3 { BROKEN }")
+            .await
+            .unwrap();
+        assert!(interp.execute("ITERATE").await.is_err());
+        assert_eq!(interp.stack.len(), 4);
+
+        let mut interp = Interpreter::new();
+        interp.execute("1 -1 { 2 * }").await.unwrap();
+        assert!(interp.execute("ITERATE").await.is_err());
+        assert_eq!(interp.stack.len(), 3);
+
+        let mut interp = Interpreter::new();
+        let err = interp.execute("1 10001 { 2 * } ITERATE").await.unwrap_err();
+        assert!(err.to_string().contains("iteration limit"), "{}", err);
+    }
+}
@@ -4,12 +4,24 @@ mod common;
 mod count;
 mod fast_kernels;
 mod filter;
+mod fold_right;
+#[cfg(test)]
+mod fold_right_tests;
 mod hedged;
+mod iterate;
+#[cfg(test)]
+mod iterate_tests;
 mod map;
 mod memo;
 #[cfg(test)]
 mod memo_tests;
 mod runners;
+mod scan_init;
+#[cfg(test)]
+mod scan_init_tests;
+mod treemap;
+#[cfg(test)]
+mod treemap_tests;
 
 pub(crate) use common::{execute_executable_code, extract_executable_code, ExecutableCode};
 pub(crate) use hedged::execute_hedged_fold_kernel;
@@ -21,7 +33,11 @@ pub use all::op_all;
 pub use any::op_any;
 pub use count::op_count;
 pub use filter::op_filter;
+pub use fold_right::{op_foldr, op_scanr};
+pub use iterate::op_iterate;
 pub use map::op_map;
+pub use scan_init::op_scan_init;
+pub use treemap::op_treemap;
 
 use crate::interpreter::quantized_block::QuantizedBlock;
 use crate::interpreter::Interpreter;
@@ -0,0 +1,41 @@
+//! SCANINIT: `SCAN` with the initial accumulator as the first result.
+
+use crate::error::{AjisaiError, Result};
+use crate::interpreter::higher_order_fold::op_scan;
+use crate::interpreter::math_ops::require_stack_top;
+use crate::interpreter::value_extraction_helpers::is_vector_value;
+use crate::interpreter::Interpreter;
+use crate::types::Value;
+
+/// `[ vec ] [ init ] 'WORD' -- [ acc0 acc1 ... accN ]`. The running
+/// accumulations of `SCAN`, preceded by `init` itself, so the result always
+/// has one element more than the vector:
+///
+/// ```text
+/// [ 1 2 3 ] [ 0 ] '+' SCANINIT -> [ 0 1 3 6 ]
+/// ```
+///
+/// A NIL vector gives `[ init ]`. Single-element accumulators are unwrapped
+/// as `SCAN` unwraps them. Errors are `SCAN`'s and leave the operands as
+/// `SCAN` leaves them.
+pub fn op_scan_init(interp: &mut Interpreter) -> Result<()> {
+    require_stack_top(interp, "SCANINIT")?;
+    if interp.stack.len() < 3 {
+        return Err(AjisaiError::StackUnderflow);
+    }
+    let init = interp.stack[interp.stack.len() - 2].clone();
+    op_scan(interp)?;
+
+    let scanned = interp.stack.pop().expect("SCAN pushes its result");
+    let first = if is_vector_value(&init) && init.len() == 1 {
+        init.child(0).expect("len==1 implies child(0) exists")
+    } else {
+        init
+    };
+    let mut accumulations = vec![first];
+    if let Some(rest) = scanned.as_vector_view() {
+        accumulations.extend(rest.iter().cloned());
+    }
+    interp.stack.push(Value::from_vector(accumulations));
+    Ok(())
+}
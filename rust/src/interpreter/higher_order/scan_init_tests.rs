@@ -0,0 +1,36 @@
+//! Tests for `scan_init.rs` (ALGO@SCANINIT).
+
+#[cfg(test)]
+mod tests {
+    use crate::interpreter::Interpreter;
+
+    async fn rendered(code: &str) -> Vec<String> {
+        let mut interp = Interpreter::new();
+        interp.execute("'algo' IMPORT").await.unwrap();
+        interp.execute(code).await.expect("code should execute");
+        interp.stack.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[tokio::test]
+    async fn the_seed_leads_the_running_totals() {
+        assert_eq!(
+            rendered("[ 1 2 3 ] [ 0 ] '+' SCANINIT").await,
+            ["[ 0/1 1/1 3/1 6/1 ]"]
+        );
+    }
+
+    #[tokio::test]
+    async fn the_result_is_one_longer_than_the_vector() {
+        assert_eq!(
+            rendered("[ 1 2 3 4 ] [ 1 ] '*' SCANINIT").await,
+            ["[ 1/1 1/1 2/1 6/1 24/1 ]"]
+        );
+        assert_eq!(rendered("NIL [ 5 ] '+' SCANINIT").await, ["[ 5/1 ]"]);
+    }
+
+    #[tokio::test]
+    async fn custom_words_and_lower_values_are_respected() {
+        let code = "{ + } 'MYSUM' DEF [ 100 ] [ 1 2 3 ] [ 0 ] 'MYSUM' SCANINIT";
+        assert_eq!(rendered(code).await, ["[ 100/1 ]", "[ 0/1 1/1 3/1 6/1 ]"]);
+    }
+}
@@ -0,0 +1,81 @@
+use super::common::{execute_executable_code, extract_executable_code, ExecutableCode};
+use crate::error::{AjisaiError, Result};
+use crate::interpreter::{ConsumptionMode, Interpreter, OperationTargetMode};
+use crate::types::Value;
+
+/// Rebuild `val` with `exec` applied to every non-vector leaf. NIL leaves are
+/// kept as they are. Expects `interp.stack` to be the isolated leaf stack.
+fn map_leaves(interp: &mut Interpreter, exec: &ExecutableCode, val: &Value) -> Result<Value> {
+    if val.is_nil() {
+        return Ok(val.clone());
+    }
+    if let Some(children) = val.as_vector_view() {
+        let mapped = children
+            .iter()
+            .map(|child| map_leaves(interp, exec, child))
+            .collect::<Result<Vec<Value>>>()?;
+        return Ok(Value::from_vector(mapped));
+    }
+
+    interp.stack.clear();
+    interp.stack.push(val.clone());
+    execute_executable_code(interp, exec)?;
+    if interp.stack.len() != 1 {
+        return Err(AjisaiError::from(format!(
+            "TREEMAP: expected the word to leave exactly one value per leaf, got {}",
+            interp.stack.len()
+        )));
+    }
+    Ok(interp.stack.pop().expect("length checked above"))
+}
+
+/// `TREEMAP`: the structure-preserving map over nested vectors.
+///
+/// ```text
+/// [ 1 [ 2 3 ] ] { 2 * } TREEMAP -> [ 2 [ 4 6 ] ]
+/// ```
+///
+/// Where `MAP` applies its body to each top-level element, `TREEMAP` descends
+/// through every level and applies it to each leaf, rebuilding the original
+/// nesting — ragged or not. Each leaf runs on a stack holding only that leaf
+/// and must leave exactly one value. Text nested inside the structure is a
+/// vector of codepoints, so its codepoints are leaves too.
+pub fn op_treemap(interp: &mut Interpreter) -> Result<()> {
+    if interp.operation_target_mode == OperationTargetMode::Stack {
+        return Err(AjisaiError::ModeUnsupported {
+            word: "TREEMAP".into(),
+            mode: "Stack".into(),
+        });
+    }
+    let is_keep_mode = interp.consumption_mode == ConsumptionMode::Keep;
+
+    let code_val = interp.stack.pop().ok_or(AjisaiError::StackUnderflow)?;
+    let Some(target_val) = interp.stack.pop() else {
+        interp.stack.push(code_val);
+        return Err(AjisaiError::StackUnderflow);
+    };
+
+    let saved_stack = std::mem::take(&mut interp.stack);
+    let saved_no_change_check = interp.disable_no_change_check;
+    interp.disable_no_change_check = true;
+    let result = extract_executable_code(interp, &code_val)
+        .and_then(|exec| map_leaves(interp, &exec, &target_val));
+    interp.disable_no_change_check = saved_no_change_check;
+    interp.operation_target_mode = OperationTargetMode::StackTop;
+    interp.stack = saved_stack;
+
+    match result {
+        Ok(mapped) => {
+            if is_keep_mode {
+                interp.stack.push(target_val);
+            }
+            interp.stack.push(mapped);
+            Ok(())
+        }
+        Err(e) => {
+            interp.stack.push(target_val);
+            interp.stack.push(code_val);
+            Err(e)
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//! Tests for the structure-preserving leaf map (`treemap.rs`, TREEMAP).
+//!
+//! TREEMAP must rebuild exactly the nesting it was given — ragged or flat —
+//! apply the word to every non-NIL leaf, and leave both operands on the stack
+//! when any leaf fails.
+
+#[cfg(test)]
+mod tests {
+    use crate::interpreter::Interpreter;
+    use crate::types::display::format_with_hint;
+
+    fn rendered_top(interp: &Interpreter) -> String {
+        let (value, role) = interp.stack.iter_slots().last().expect("non-empty stack");
+        format_with_hint(value, role)
+    }
+
+    async fn run(code: &str) -> Interpreter {
+        let mut interp = Interpreter::new();
+        interp.execute(code).await.expect("code should execute");
+        interp
+    }
+
+    #[tokio::test]
+    async fn deeply_nested_ragged_input_keeps_its_shape() {
+        let mut interp = run("{ 2 * } 'DOUBLE' DEF").await;
+        interp
+            .execute("[ 1 [ 2 3 ] [ [ 4 ] [ 5 [ 6 ] ] ] ] 'DOUBLE' TREEMAP")
+            .await
+            .unwrap();
+        let expected = run("[ 2 [ 4 6 ] [ [ 8 ] [ 10 [ 12 ] ] ] ]").await;
+        assert_eq!(interp.stack.len(), 1);
+        assert_eq!(rendered_top(&interp), rendered_top(&expected));
+    }
+
+    #[tokio::test]
+    async fn flat_input_matches_map() {
+        let treemap = run("[ 1 2 3 ] { 1 + } TREEMAP").await;
+        let map = run("[ 1 2 3 ] { 1 + } MAP").await;
+        assert_eq!(rendered_top(&treemap), rendered_top(&map));
+    }
+
+    #[tokio::test]
+    async fn nil_leaves_pass_through_untouched() {
+        // The block logs every leaf it sees; the NIL leaf must never reach it.
+        let mut interp = run("[ 1 [ NIL 3 ] ] { PRINT 0 } TREEMAP").await;
+        let expected = run("[ 0 [ NIL 0 ] ]").await;
+        assert_eq!(rendered_top(&interp), rendered_top(&expected));
+        assert!(!interp.collect_output().contains("NIL"));
+    }
+
+    #[tokio::test]
+    async fn failing_leaf_restores_both_operands() {
+        let mut interp = Interpreter::new();
+        let err = interp
+            .execute("[ 1 [ 2 0 ] ] { NO-SUCH-WORD } TREEMAP")
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("NO-SUCH-WORD"), "{}", err);
+        assert_eq!(interp.stack.len(), 2, "operands are restored on error");
+    }
+
+    #[tokio::test]
+    async fn leaf_must_yield_exactly_one_value() {
+        let mut interp = Interpreter::new();
+        let err = interp
+            .execute("[ 1 [ 2 ] ] { 1 } TREEMAP")
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("exactly one value"), "{}", err);
+        assert_eq!(interp.stack.len(), 2);
+    }
+}
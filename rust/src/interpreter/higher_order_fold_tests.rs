@@ -27,6 +27,34 @@ mod tests {
         interp.stack.last().map(|v| v.is_nil()).unwrap_or(false)
     }
 
+    #[tokio::test]
+    async fn test_fold_with_custom_binary_word() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute("'math' IMPORT { MAX } 'MAX2' DEF")
+            .await
+            .unwrap();
+        interp
+            .execute("[ 3 1 4 1 5 ] [ 0 ] 'MAX2' FOLD")
+            .await
+            .unwrap();
+        assert_eq!(top_scalar_i64(&interp), 5);
+        assert_eq!(interp.stack.len(), 1);
+
+        // Stack mode folds the top `count` values, leaving those below.
+        let mut interp = Interpreter::new();
+        interp
+            .execute("'math' IMPORT { MAX } 'MAX2' DEF")
+            .await
+            .unwrap();
+        interp
+            .execute("9 3 1 4 1 5 [ 5 ] [ 0 ] 'MAX2' .. FOLD")
+            .await
+            .unwrap();
+        assert_eq!(top_scalar_i64(&interp), 5);
+        assert_eq!(interp.stack.len(), 2);
+    }
+
     #[tokio::test]
     async fn test_fold_basic() {
         let mut interp = Interpreter::new();
@@ -218,6 +246,23 @@ mod tests {
         assert_eq!(top_scalar_i64(&interp5), 2);
     }
 
+    #[tokio::test]
+    async fn test_count_named_predicate_counts_every_match() {
+        // COUNT already counts predicate matches; a named word works in place
+        // of a block and every element is tested.
+        let mut interp = Interpreter::new();
+        interp
+            .execute("{ [ 2 ] MOD [ 0 ] = } 'ISEVEN' DEF")
+            .await
+            .unwrap();
+        interp
+            .execute("[ 1 2 3 4 5 6 ] 'ISEVEN' COUNT")
+            .await
+            .unwrap();
+        assert_eq!(interp.stack.len(), 1);
+        assert_eq!(format!("{}", interp.stack[0]), "[ 3/1 ]");
+    }
+
     #[tokio::test]
     async fn test_count_percent_alias_matches_mod() {
         let mut mod_interp = Interpreter::new();
@@ -385,6 +385,9 @@ pub struct Interpreter {
     pub(crate) numeric_work_used: u64,
     pub(crate) input_buffer: String,
     pub(crate) io_output_buffer: String,
+    /// Stack snapshots recorded by `IO@BREAK`; stops there when `pause_on_break`.
+    pub(crate) debug_buffer: String,
+    pub(crate) pause_on_break: bool,
 
     /// Host-injected serial receive buffers, keyed by opaque port id. Filled
     /// before execution from the platform serial adapter (Section 9.4); drained
@@ -451,6 +454,10 @@ pub struct Interpreter {
     /// batch and recompute once at the end, avoiding O(N^2) identity hashing.
     pub(crate) defer_identity_recompute: bool,
 
+    /// Result caches of the words marked by `REFLECT@MEMOIZE`, keyed by
+    /// fully-qualified name. See `memoize.rs`.
+    pub(crate) memo_tables: HashMap<String, super::memoize::MemoTable>,
+
     // ── Internal tail-call elimination ("internal GOTO") ──────────────────
     // Guarded tail self-recursion (a self-call in the tail position of a
     // COND clause body) is run as an internal backward jump instead of a
@@ -533,6 +540,12 @@ pub struct Interpreter {
     /// Opt-in execution provenance recorder for receipts (Phase 6). Off unless a
     /// receipt is requested; observational only, never affects results.
     pub(crate) receipt_recorder: super::receipt_recorder::ReceiptRecorder,
+
+    /// Opt-in ordered word trace for debugging (`set_word_trace`).
+    pub(crate) word_trace: super::word_trace::WordTrace,
+
+    /// Bounded undo/redo of top-level evaluations (`undo` / `redo`).
+    pub(crate) undo_history: super::undo_history::UndoHistory,
 }
 
 impl Default for Interpreter {
@@ -573,6 +586,8 @@ impl Interpreter {
             numeric_work_used: 0,
             input_buffer: String::new(),
             io_output_buffer: String::new(),
+            debug_buffer: String::new(),
+            pause_on_break: false,
             serial_inbox: HashMap::new(),
             serial_disconnected: HashSet::new(),
             module_vocabulary: HashMap::new(),
@@ -602,6 +617,7 @@ impl Interpreter {
             word_identities: HashMap::new(),
             body_store: HashMap::new(),
             defer_identity_recompute: false,
+            memo_tables: HashMap::new(),
             tail_call_enabled: std::env::var("AJISAI_NO_TAIL_CALL").is_err(),
             tail_self_word: None,
             in_tail_context: false,
@@ -616,6 +632,8 @@ impl Interpreter {
             artifact_store: super::artifact_store::ArtifactStore::default(),
             artifact_reuse_enabled: std::env::var("AJISAI_NO_ARTIFACT_REUSE").is_err(),
             receipt_recorder: super::receipt_recorder::ReceiptRecorder::default(),
+            word_trace: super::word_trace::WordTrace::default(),
+            undo_history: super::undo_history::UndoHistory::default(),
         };
         crate::elastic::tracer::init_from_env();
         crate::builtins::register_builtins(&mut interpreter.core_vocabulary);
@@ -917,6 +935,14 @@ impl Interpreter {
         self.max_execution_steps = steps;
     }
 
+    /// Host-facing step limit for interactive sessions, where `0` means
+    /// unlimited rather than "no word may run". A runaway non-tail recursion
+    /// is still stopped by `MAX_USER_WORD_DEPTH`; only a tail loop can then
+    /// run until the host interrupts it.
+    pub fn set_step_limit(&mut self, limit: usize) {
+        self.max_execution_steps = if limit == 0 { usize::MAX } else { limit };
+    }
+
     /// The unified internal-computation-cost ceilings (CS5) in force.
     pub fn runtime_limits(&self) -> &super::runtime_limits::RuntimeLimits {
         &self.runtime_limits
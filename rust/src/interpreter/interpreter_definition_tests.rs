@@ -157,6 +157,59 @@ mod tests {
         assert!(result.is_err());
     }
 
+    async fn define_double_quad_inc() -> Interpreter {
+        let mut interp = Interpreter::new();
+        interp
+            .execute("{ [ 2 ] * } 'DOUBLE' DEF { DOUBLE DOUBLE } 'QUAD' DEF { [ 1 ] + } 'INC' DEF")
+            .await
+            .unwrap();
+        interp
+    }
+
+    #[tokio::test]
+    async fn test_batch_del_orders_dependents_first() {
+        let mut interp = define_double_quad_inc().await;
+        interp
+            .execute("[ 'DOUBLE' 'QUAD' 'INC' ] DEL")
+            .await
+            .unwrap();
+        assert!(interp.user_words.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_batch_del_with_protected_names_deletes_nothing() {
+        let mut interp = define_double_quad_inc().await;
+        let err = interp
+            .execute("[ 'DOUBLE' 'INC' 'ADD' ] DEL")
+            .await
+            .unwrap_err();
+        let msg = err.to_string();
+        assert!(
+            msg.contains("2 of 3") && msg.contains("DOUBLE") && msg.contains("ADD"),
+            "{}",
+            msg
+        );
+        assert!(interp.user_words.contains_key("INC"));
+        assert!(interp.user_words.contains_key("DOUBLE"));
+        assert_eq!(interp.stack.len(), 1, "the batch is left in place");
+    }
+
+    #[tokio::test]
+    async fn test_batch_del_forced_deletes_the_deletable_names() {
+        let mut interp = define_double_quad_inc().await;
+        interp
+            .execute("! [ 'DOUBLE' 'INC' 'ADD' ] DEL")
+            .await
+            .unwrap();
+        assert!(!interp.user_words.contains_key("DOUBLE"));
+        assert!(!interp.user_words.contains_key("INC"));
+        assert!(interp.user_words.contains_key("QUAD"));
+        assert!(interp
+            .output_buffer
+            .contains("1 of 3 words were not deleted"));
+        assert!(!interp.force_flag);
+    }
+
     #[tokio::test]
     async fn test_nil_keyword_works() {
         let mut interp = Interpreter::new();
@@ -660,4 +713,16 @@ mod tests {
             "42"
         );
     }
+
+    #[tokio::test]
+    async fn test_comments_inside_definition_body_are_ignored() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute("{ # scale, then shift\n  [ 2 ] * # double\n  [ 1 ] + # add one\n} 'F' DEF")
+            .await
+            .unwrap();
+        interp.execute("[ 3 ] F").await.unwrap();
+        let top = interp.stack.last().unwrap().child(0).unwrap();
+        assert_eq!(top.as_scalar().unwrap().numerator().to_string(), "7");
+    }
 }
@@ -1,6 +1,12 @@
 use crate::error::{AjisaiError, Result};
-use crate::interpreter::{ConsumptionMode, HostCapability, HostEffect, Interpreter};
-use crate::types::Value;
+use crate::interpreter::cast::cast_value_helpers::{
+    format_value_to_string_repr_with_hint, is_number_value,
+};
+use crate::interpreter::{
+    ConsumptionMode, HostCapability, HostEffect, Interpreter, OperationTargetMode,
+};
+use crate::types::display::format_for_output;
+use crate::types::{Interpretation, Value};
 use std::fmt::Write;
 
 fn extract_value_for_print(interp: &mut Interpreter, keep_mode: bool) -> Result<Value> {
@@ -14,20 +20,193 @@ fn extract_value_for_print(interp: &mut Interpreter, keep_mode: bool) -> Result<
     interp.stack.pop().ok_or(AjisaiError::StackUnderflow)
 }
 
+/// The `[ 'sep' ]` option of `.. PRINT`: a vector holding exactly one
+/// string, read as the separator. Any other vector on top is data.
+fn parse_print_separator(top: &Value) -> Option<String> {
+    let view = top.as_vector_view()?;
+    match view.as_ref() {
+        [separator] if separator.hint == Interpretation::Text => Some(format_for_output(separator)),
+        _ => None,
+    }
+}
+
+/// `.. PRINT`: every stack value on one line, bottom to top, joined by the
+/// separator (a space by default) and ended by a space, as after a single
+/// `PRINT`.
+fn print_whole_stack(interp: &mut Interpreter, is_keep_mode: bool) -> Result<String> {
+    let separator = interp.stack.last().and_then(parse_print_separator);
+    let options_val = separator.as_ref().and_then(|_| interp.stack.pop());
+    if interp.stack.is_empty() {
+        if let Some(val) = options_val {
+            interp.stack.push(val);
+        }
+        return Err(AjisaiError::StackUnderflow);
+    }
+    let separator = separator.unwrap_or_else(|| " ".to_string());
+
+    let payload = interp
+        .stack
+        .iter()
+        .map(format_for_output)
+        .collect::<Vec<_>>()
+        .join(&separator);
+    if is_keep_mode {
+        interp.stack.extend(options_val);
+    } else {
+        interp.stack.clear();
+    }
+    write!(&mut interp.output_buffer, "{} ", payload)
+        .map_err(|e| AjisaiError::from(format!("PRINT failed: {}", e)))?;
+    Ok(payload)
+}
+
 pub fn op_print(interp: &mut Interpreter) -> Result<()> {
     interp.run_hosted_effect_schema("PRINT", HostCapability::Effect, |interp| {
         let is_keep_mode = interp.consumption_mode == ConsumptionMode::Keep;
+        if interp.operation_target_mode == OperationTargetMode::Stack {
+            return print_whole_stack(interp, is_keep_mode).map(HostEffect::Print);
+        }
         let val = extract_value_for_print(interp, is_keep_mode)?;
         // PRINT is an output boundary: a Text-role value is emitted as its raw
         // character content, without the `'...'` quotes the Stack projection
         // uses to mark it as a string (SPEC §7.9).
-        let payload = crate::types::display::format_for_output(&val);
+        let payload = format_for_output(&val);
         write!(&mut interp.output_buffer, "{} ", payload)
             .map_err(|e| AjisaiError::from(format!("PRINT failed: {}", e)))?;
         Ok(HostEffect::Print(payload))
     })
 }
 
+/// One `INSPECT` line: the stack index (0 is the bottom), the stack
+/// rendering, and the shape of a vector.
+fn inspect_line(index: usize, value: &Value) -> String {
+    if value.is_vector() {
+        let dims: Vec<String> = value.shape().iter().map(|d| d.to_string()).collect();
+        format!("{}: {}  (shape {})", index, value, dims.join(" "))
+    } else {
+        format!("{}: {}", index, value)
+    }
+}
+
+/// `INSPECT`: write the whole stack, one indexed line per value, to the
+/// output without touching it. Values render as on the stack, so strings
+/// keep their quotes; an empty stack prints `<empty>`.
+pub fn op_inspect(interp: &mut Interpreter) -> Result<()> {
+    if interp.operation_target_mode == OperationTargetMode::Stack {
+        return Err(AjisaiError::ModeUnsupported {
+            word: "INSPECT".into(),
+            mode: "Stack".into(),
+        });
+    }
+    interp.run_hosted_effect_schema("INSPECT", HostCapability::Effect, |interp| {
+        let payload = if interp.stack.is_empty() {
+            "<empty>".to_string()
+        } else {
+            interp
+                .stack
+                .iter()
+                .enumerate()
+                .map(|(index, value)| inspect_line(index, value))
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        writeln!(&mut interp.output_buffer, "{}", payload)
+            .map_err(|e| AjisaiError::from(format!("INSPECT failed: {}", e)))?;
+        Ok(HostEffect::Print(payload))
+    })
+}
+
+/// The cells of a vector of equal-length row vectors, rendered as STR
+/// renders them, with a flag per column that is set when every cell in it
+/// is a number. A ragged or non-vector row is named in the error.
+fn table_cells(value: &Value) -> Result<(Vec<Vec<String>>, Vec<bool>)> {
+    let not_rows = || AjisaiError::from("TABLE expects a vector of row vectors");
+    if value.hint == Interpretation::Text {
+        return Err(not_rows());
+    }
+    let rows = value.as_vector_view().ok_or_else(not_rows)?;
+    let mut cells: Vec<Vec<String>> = Vec::with_capacity(rows.len());
+    let mut numeric: Vec<bool> = Vec::new();
+    for (index, row) in rows.iter().enumerate() {
+        let row_cells = match row.as_vector_view() {
+            Some(cells) if row.hint != Interpretation::Text => cells,
+            _ => {
+                return Err(AjisaiError::from(format!(
+                    "TABLE: row {} is not a vector",
+                    index
+                )))
+            }
+        };
+        if index == 0 {
+            numeric = vec![true; row_cells.len()];
+        } else if row_cells.len() != numeric.len() {
+            return Err(AjisaiError::from(format!(
+                "TABLE: row {} has {} cells, expected {}",
+                index,
+                row_cells.len(),
+                numeric.len()
+            )));
+        }
+        for (column, cell) in row_cells.iter().enumerate() {
+            numeric[column] &= is_number_value(cell);
+        }
+        cells.push(
+            row_cells
+                .iter()
+                .map(|cell| format_value_to_string_repr_with_hint(cell, cell.hint))
+                .collect(),
+        );
+    }
+    Ok((cells, numeric))
+}
+
+/// `[ [ row ] ... ] --`. Print a vector of equal-length rows as an aligned
+/// grid, one line per row with columns separated by a space: numeric
+/// columns are right-aligned and the rest left-aligned. Cells render as
+/// STR renders them. A ragged row is an error naming its (0-based) index.
+pub fn op_table(interp: &mut Interpreter) -> Result<()> {
+    interp.run_hosted_effect_schema("TABLE", HostCapability::Effect, |interp| {
+        let is_keep_mode = interp.consumption_mode == ConsumptionMode::Keep;
+        let val = extract_value_for_print(interp, is_keep_mode)?;
+        let (cells, numeric) = match table_cells(&val) {
+            Ok(parsed) => parsed,
+            Err(error) => {
+                if !is_keep_mode {
+                    interp.stack.push(val);
+                }
+                return Err(error);
+            }
+        };
+        let widths: Vec<usize> = (0..numeric.len())
+            .map(|column| {
+                cells
+                    .iter()
+                    .map(|row| row[column].chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        let lines: Vec<String> = cells
+            .iter()
+            .map(|row| {
+                let padded: Vec<String> = row
+                    .iter()
+                    .enumerate()
+                    .map(|(column, text)| match numeric[column] {
+                        true => format!("{:>width$}", text, width = widths[column]),
+                        false => format!("{:<width$}", text, width = widths[column]),
+                    })
+                    .collect();
+                padded.join(" ").trim_end().to_string()
+            })
+            .collect();
+        let payload = lines.join("\n");
+        writeln!(&mut interp.output_buffer, "{}", payload)
+            .map_err(|e| AjisaiError::from(format!("TABLE failed: {}", e)))?;
+        Ok(HostEffect::Print(payload))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use crate::interpreter::Interpreter;
@@ -96,4 +275,107 @@ mod tests {
         // The Stack projection shows the same structure.
         assert_eq!(interp.stack.last().unwrap().to_string(), "[ 'mix' 42/1 ]");
     }
+
+    /// `.. PRINT` joins the whole stack on one line with single spaces and
+    /// consumes it.
+    #[tokio::test]
+    async fn test_stack_mode_print_joins_with_spaces() {
+        let mut interp = Interpreter::new();
+        interp.execute("'a' [ 1 ] 'c' .. PRINT").await.unwrap();
+        assert_eq!(interp.collect_output(), "a [ 1/1 ] c ");
+        assert!(interp.stack.is_empty());
+    }
+
+    /// A `[ 'sep' ]` option sets the separator. KEEP leaves the values and
+    /// the option in place.
+    #[tokio::test]
+    async fn test_stack_mode_print_separator() {
+        let mut interp = Interpreter::new();
+        interp.execute("1 2 3 [ ', ' ] .. PRINT").await.unwrap();
+        assert_eq!(interp.collect_output(), "1/1, 2/1, 3/1 ");
+
+        interp.execute("'x' 'y' [ '-' ] ,, .. PRINT").await.unwrap();
+        assert_eq!(interp.collect_output(), "x-y ");
+        assert_eq!(interp.stack.len(), 3);
+    }
+
+    /// Only a one-string vector is an option: a longer vector of strings on
+    /// top is printed like any other value.
+    #[tokio::test]
+    async fn test_stack_mode_print_keeps_string_vectors_as_data() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute("[ 1 ] [ 'red' 'green' ] .. PRINT")
+            .await
+            .unwrap();
+        assert_eq!(interp.collect_output(), "[ 1/1 ] [ 'red' 'green' ] ");
+
+        interp.execute("[ 'red' 'green' ] .. PRINT").await.unwrap();
+        assert_eq!(interp.collect_output(), "[ 'red' 'green' ] ");
+        assert!(interp.stack.is_empty());
+    }
+
+    /// Options with nothing beneath them are an underflow and are restored.
+    #[tokio::test]
+    async fn test_stack_mode_print_needs_values() {
+        let mut interp = Interpreter::new();
+        assert!(interp.execute("[ ', ' ] .. PRINT").await.is_err());
+        assert_eq!(interp.stack.len(), 1);
+        assert_eq!(interp.collect_output(), "");
+    }
+
+    /// INSPECT lists every value with its index and shape and leaves the
+    /// stack exactly as it was.
+    #[tokio::test]
+    async fn test_inspect_lists_stack_without_consuming() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute("[ [ 1 2 ] [ 3 4 ] ] 'ab' 7 INSPECT")
+            .await
+            .unwrap();
+        assert_eq!(
+            interp.collect_output(),
+            "0: [ [ 1/1 2/1 ] [ 3/1 4/1 ] ]  (shape 2 2)\n1: 'ab'  (shape 2)\n2: 7/1\n"
+        );
+        assert_eq!(interp.stack.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_inspect_empty_stack() {
+        let mut interp = Interpreter::new();
+        interp.execute("INSPECT").await.unwrap();
+        assert_eq!(interp.collect_output(), "<empty>\n");
+        assert!(interp.stack.is_empty());
+    }
+
+    /// TABLE aligns a numeric grid right and text columns left, one line
+    /// per row, and consumes the vector.
+    #[tokio::test]
+    async fn test_table_aligns_columns() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute("'io' IMPORT [ [ 1 20 300 ] [ 4000 5 1/2 ] ] TABLE")
+            .await
+            .unwrap();
+        assert_eq!(interp.collect_output(), "   1 20 300\n4000  5 1/2\n");
+        assert!(interp.stack.is_empty());
+
+        interp
+            .execute("'io' IMPORT [ [ 'ab' 1 ] [ 'c' 10 ] ] TABLE")
+            .await
+            .unwrap();
+        assert_eq!(interp.collect_output(), "ab  1\nc  10\n");
+    }
+
+    #[tokio::test]
+    async fn test_table_ragged_row_names_the_row() {
+        let mut interp = Interpreter::new();
+        let err = interp
+            .execute("'io' IMPORT [ [ 1 2 ] [ 3 4 ] [ 5 ] ] TABLE")
+            .await
+            .unwrap_err();
+        assert_eq!(err.to_string(), "TABLE: row 2 has 1 cells, expected 2");
+        assert_eq!(interp.stack.len(), 1, "the vector is restored");
+        assert_eq!(interp.collect_output(), "");
+    }
 }
@@ -5,7 +5,7 @@ use num_traits::Zero;
 use crate::error::{AjisaiError, NilReason, Result};
 use crate::interpreter::value_extraction_helpers::{
     extract_bigint_from_value, extract_operands, nil_passthrough_binary, nil_passthrough_unary,
-    push_result,
+    nil_passthrough_value, push_result,
 };
 use crate::interpreter::{ConsumptionMode, Interpreter, OperationTargetMode};
 use crate::semantic::{AbsenceOrigin, Recoverability};
@@ -19,7 +19,7 @@ use crate::types::{Interpretation, Value, ValueData};
 /// exhausting memory; it is not a language-level semantic constraint.
 const MAX_POW_EXPONENT: i64 = 1_000_000;
 
-fn require_stack_top(interp: &Interpreter, word: &str) -> Result<()> {
+pub(crate) fn require_stack_top(interp: &Interpreter, word: &str) -> Result<()> {
     if interp.operation_target_mode != OperationTargetMode::StackTop {
         return Err(AjisaiError::from(format!(
             "{}: Stack mode is not supported",
@@ -203,7 +203,123 @@ pub(crate) fn op_max(interp: &mut Interpreter) -> Result<()> {
     apply_selecting(interp, "MAX", |ord| ord != std::cmp::Ordering::Less)
 }
 
-fn restore_operands(interp: &mut Interpreter, operands: Vec<Value>) {
+/// One clamped element, or the agreed prefix of an undecided comparison.
+enum Clamped {
+    Value(Box<Value>),
+    Unknown(usize),
+}
+
+fn clamp_element(x: &Value, lo: &Value, hi: &Value) -> Result<Clamped> {
+    use crate::interpreter::comparison::{three_way_compare, OrderOutcome};
+    match three_way_compare(x, lo)? {
+        OrderOutcome::Undecided(agreed_prefix) => return Ok(Clamped::Unknown(agreed_prefix)),
+        OrderOutcome::Decided(std::cmp::Ordering::Less) => {
+            return Ok(Clamped::Value(Box::new(lo.clone())))
+        }
+        OrderOutcome::Decided(_) => {}
+    }
+    Ok(match three_way_compare(x, hi)? {
+        OrderOutcome::Undecided(agreed_prefix) => Clamped::Unknown(agreed_prefix),
+        OrderOutcome::Decided(std::cmp::Ordering::Greater) => Clamped::Value(Box::new(hi.clone())),
+        OrderOutcome::Decided(_) => Clamped::Value(Box::new(x.clone())),
+    })
+}
+
+/// Clamp `x` (or each element of a flat vector `x`) into `[lo, hi]`.
+fn clamp_value(x: &Value, lo: &Value, hi: &Value) -> Result<Clamped> {
+    if !x.is_vector() || x.len() < 2 {
+        return clamp_element(x, lo, hi);
+    }
+    let mut out = Vec::with_capacity(x.len());
+    for i in 0..x.len() {
+        let child = x.child(i).expect("index within length");
+        match clamp_element(&child, lo, hi)? {
+            Clamped::Value(v) => out.push(*v),
+            unknown @ Clamped::Unknown(_) => return Ok(unknown),
+        }
+    }
+    Ok(Clamped::Value(Box::new(Value::from_vector_promoted(out))))
+}
+
+/// `CLAMP` bounds `x` to the closed range `[lo, hi]`: it returns `lo` when
+/// `x < lo`, `hi` when `x > hi`, and `x` unchanged otherwise. A vector `x`
+/// is clamped element by element. Like `MIN`/`MAX`, the order is decided
+/// through the budgeted comparison, so an undecided order yields the logical
+/// `Unknown` (U). `lo > hi` and text or other non-numeric operands are
+/// malformed use and raise an error. NIL-passthrough.
+pub(crate) fn op_clamp(interp: &mut Interpreter) -> Result<()> {
+    require_stack_top(interp, "CLAMP")?;
+    let operands = extract_operands(interp, 3)?;
+    if let Some(nil) = nil_passthrough_value(&operands) {
+        push_result(interp, nil);
+        return Ok(());
+    }
+    let result = if operands.iter().any(|v| v.hint == Interpretation::Text) {
+        Err(AjisaiError::from("CLAMP: expected numbers"))
+    } else {
+        clamp_bounds_ordered(&operands[1], &operands[2])
+            .and_then(|()| clamp_value(&operands[0], &operands[1], &operands[2]))
+    };
+    match result {
+        Ok(Clamped::Value(v)) => {
+            push_result(interp, *v);
+            interp.stack.set_last_role(Interpretation::RawNumber);
+            Ok(())
+        }
+        Ok(Clamped::Unknown(agreed_prefix)) => {
+            crate::interpreter::comparison::push_comparison_unknown(interp, agreed_prefix);
+            Ok(())
+        }
+        Err(e) => {
+            restore_operands(interp, operands);
+            Err(e)
+        }
+    }
+}
+
+fn clamp_bounds_ordered(lo: &Value, hi: &Value) -> Result<()> {
+    use crate::interpreter::comparison::{three_way_compare, OrderOutcome};
+    match three_way_compare(lo, hi)? {
+        OrderOutcome::Decided(std::cmp::Ordering::Greater) => Err(AjisaiError::from(
+            "CLAMP: lower bound is greater than upper bound",
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// `LERP` is exact linear interpolation `a + (b - a) * t`. `t` is not
+/// clamped, so values outside `[0, 1]` extrapolate along the same line. It
+/// computes on the exact-real representation like `NEG`, so rational inputs
+/// give an exact rational (`0 10 1/3 LERP` is `10/3`). Text or other
+/// non-numeric operands are malformed use and raise an error.
+/// NIL-passthrough.
+pub(crate) fn op_lerp(interp: &mut Interpreter) -> Result<()> {
+    require_stack_top(interp, "LERP")?;
+    let operands = extract_operands(interp, 3)?;
+    if let Some(nil) = nil_passthrough_value(&operands) {
+        push_result(interp, nil);
+        return Ok(());
+    }
+    let reals: Option<Vec<ExactReal>> = operands
+        .iter()
+        .map(|v| {
+            if v.hint == Interpretation::Text {
+                return None;
+            }
+            crate::interpreter::comparison::extract_exact_real_for_comparison(v).ok()
+        })
+        .collect();
+    let Some(reals) = reals else {
+        restore_operands(interp, operands);
+        return Err(AjisaiError::from("LERP: expected three numbers"));
+    };
+    let (a, b, t) = (&reals[0], &reals[1], &reals[2]);
+    push_result(interp, Value::from_exact_real(a.add(&b.sub(a).mul(t))));
+    interp.stack.set_last_role(Interpretation::RawNumber);
+    Ok(())
+}
+
+pub(crate) fn restore_operands(interp: &mut Interpreter, operands: Vec<Value>) {
     if interp.consumption_mode != ConsumptionMode::Keep {
         interp.stack.extend(operands);
     }
@@ -286,6 +402,32 @@ pub(crate) fn op_pow(interp: &mut Interpreter) -> Result<()> {
     Ok(())
 }
 
+/// `x -- 1/x`. Exact reciprocal, computed as `1 x /` so it shares DIV's
+/// behavior: it broadcasts over a numeric vector (`[ 3/4 2 ]` gives
+/// `[ 4/3 1/2 ]`), keeps lazy roots exact, and a zero anywhere projects
+/// to Bubble/NIL with `reason = divisionByZero`. Non-numeric operands are
+/// malformed use and raise an error. NIL-passthrough.
+pub(crate) fn op_reciprocal(interp: &mut Interpreter) -> Result<()> {
+    require_stack_top(interp, "RECIPROCAL")?;
+    if nil_passthrough_unary(interp) {
+        return Ok(());
+    }
+    let operands = extract_operands(interp, 1)?;
+    // DIV consumes the pair it is given; in KEEP mode `x` is already kept.
+    let mode = std::mem::replace(&mut interp.consumption_mode, ConsumptionMode::Consume);
+    let depth = interp.stack.len();
+    interp.stack.push(Value::from_int(1));
+    interp.stack.push(operands[0].clone());
+    let result = crate::interpreter::arithmetic::op_div(interp);
+    interp.consumption_mode = mode;
+    if result.is_err() {
+        interp.stack.truncate(depth);
+        restore_operands(interp, operands);
+        return Err(AjisaiError::from("RECIPROCAL: expected a number"));
+    }
+    Ok(())
+}
+
 fn apply_integer_binary<F>(interp: &mut Interpreter, word: &str, op: F) -> Result<()>
 where
     F: Fn(&BigInt, &BigInt) -> BigInt,
@@ -263,4 +263,164 @@ mod tests {
         assert_eq!(interp.stack.len(), 3, "operands retained plus result");
         assert_eq!(interp.stack[2].as_scalar().unwrap().to_i64().unwrap(), 3);
     }
+
+    #[tokio::test]
+    async fn continued_fraction_decomposes_rationals() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute("'math' IMPORT 415/93 CONTINUED-FRACTION")
+            .await
+            .expect("CONTINUED-FRACTION should succeed");
+        assert_eq!(format!("{}", interp.stack[0]), "[ 4/1 2/1 6/1 7/1 ]");
+
+        let mut interp = Interpreter::new();
+        interp
+            .execute("'math' IMPORT 5 CONTINUED-FRACTION")
+            .await
+            .expect("an integer has a single term");
+        assert_eq!(interp.stack[0].len(), 1);
+    }
+
+    #[tokio::test]
+    async fn continued_fraction_round_trips() {
+        for source in ["415/93", "-7/3", "1/2", "12"] {
+            let mut interp = Interpreter::new();
+            interp
+                .execute(&format!(
+                    "'math' IMPORT {} CONTINUED-FRACTION FROM-CONTINUED {} =",
+                    source, source
+                ))
+                .await
+                .expect("round trip should succeed");
+            assert_eq!(interp.stack[0].as_truth(), Some(true), "{}", source);
+        }
+    }
+
+    #[tokio::test]
+    async fn from_continued_rejects_non_positive_tail_terms() {
+        let mut interp = Interpreter::new();
+        let result = interp
+            .execute("'math' IMPORT [ 1 0 2 ] FROM-CONTINUED")
+            .await;
+        assert!(result.is_err(), "a zero tail term has no reciprocal");
+        assert_eq!(interp.stack.len(), 1, "operand restored on error");
+    }
+
+    #[tokio::test]
+    async fn clamp_bounds_below_inside_and_above() {
+        assert_eq!(top_i64("'math' IMPORT -3 0 10 CLAMP").await, 0);
+        assert_eq!(top_i64("'math' IMPORT 7 0 10 CLAMP").await, 7);
+        assert_eq!(top_i64("'math' IMPORT 12 0 10 CLAMP").await, 10);
+    }
+
+    #[tokio::test]
+    async fn clamp_broadcasts_over_a_vector() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute("'math' IMPORT [ -1 1/2 3 ] 0 1 CLAMP")
+            .await
+            .expect("vector CLAMP should succeed");
+        assert_eq!(format!("{}", interp.stack[0]), "[ 0/1 1/2 1/1 ]");
+    }
+
+    #[tokio::test]
+    async fn clamp_rejects_inverted_bounds() {
+        let mut interp = Interpreter::new();
+        let result = interp.execute("'math' IMPORT 5 10 0 CLAMP").await;
+        assert!(result.is_err(), "lo > hi is malformed use");
+        assert_eq!(interp.stack.len(), 3, "operands restored on error");
+    }
+
+    #[tokio::test]
+    async fn lerp_hits_endpoints_and_exact_midpoint() {
+        assert_eq!(top_i64("'math' IMPORT 3 7 0 LERP").await, 3);
+        assert_eq!(top_i64("'math' IMPORT 3 7 1 LERP").await, 7);
+
+        let mut interp = Interpreter::new();
+        interp
+            .execute("'math' IMPORT 1/3 1 1/2 LERP")
+            .await
+            .expect("LERP should succeed");
+        assert_eq!(format!("{}", interp.stack[0]), "2/3");
+    }
+
+    #[tokio::test]
+    async fn lerp_extrapolates_and_rejects_text() {
+        assert_eq!(top_i64("'math' IMPORT 0 10 -1/2 LERP").await, -5);
+
+        let mut interp = Interpreter::new();
+        let result = interp.execute("'math' IMPORT 0 'a' 1/2 LERP").await;
+        assert!(result.is_err(), "text operands are malformed use");
+        assert_eq!(interp.stack.len(), 3, "operands restored on error");
+    }
+
+    #[tokio::test]
+    async fn numerator_and_denominator_split_the_reduced_fraction() {
+        assert_eq!(
+            render_top("'math' IMPORT [ 3/4 ] NUMERATOR").await,
+            "[ 3/1 ]"
+        );
+        assert_eq!(
+            render_top("'math' IMPORT [ 3/4 ] DENOMINATOR").await,
+            "[ 4/1 ]"
+        );
+        assert_eq!(
+            render_top("'math' IMPORT [ 5 ] DENOMINATOR").await,
+            "[ 1/1 ]"
+        );
+        assert_eq!(top_i64("'math' IMPORT 6/8 DENOMINATOR").await, 4);
+        assert_eq!(top_i64("'math' IMPORT -3/4 NUMERATOR").await, -3);
+        assert_eq!(
+            render_top("'math' IMPORT [ 1/2 2/3 4 ] DENOMINATOR").await,
+            "[ 2/1 3/1 1/1 ]"
+        );
+    }
+
+    #[tokio::test]
+    async fn fraction_parts_reject_non_rationals() {
+        for program in [
+            "'math' IMPORT 'ab' NUMERATOR",
+            "'math' IMPORT 2 SQRT DENOMINATOR",
+        ] {
+            let mut interp = Interpreter::new();
+            let result = interp.execute(program).await;
+            assert!(result.is_err(), "{program}");
+            assert_eq!(interp.stack.len(), 1, "operand restored on error");
+        }
+    }
+
+    #[tokio::test]
+    async fn reciprocal_inverts_exactly() {
+        assert_eq!(
+            render_top("'math' IMPORT [ 3/4 ] RECIPROCAL").await,
+            "[ 4/3 ]"
+        );
+        assert_eq!(render_top("'math' IMPORT 5 RECIPROCAL").await, "1/5");
+        assert_eq!(render_top("'math' IMPORT -2/7 RECIPROCAL").await, "-7/2");
+        assert_eq!(
+            render_top("'math' IMPORT [ 3/4 2 -1 ] RECIPROCAL").await,
+            "[ 4/3 1/2 -1/1 ]"
+        );
+    }
+
+    #[tokio::test]
+    async fn reciprocal_of_zero_is_division_by_zero_nil() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute("'math' IMPORT 0 RECIPROCAL")
+            .await
+            .expect("zero is a domain miss, not an error");
+        assert_eq!(interp.stack.len(), 1);
+        assert!(interp.stack[0].is_nil());
+    }
+
+    #[tokio::test]
+    async fn reciprocal_keep_mode_and_text_error() {
+        assert_eq!(render_top("'math' IMPORT 4 ,, RECIPROCAL").await, "1/4");
+
+        let mut interp = Interpreter::new();
+        let result = interp.execute("'math' IMPORT 'a' RECIPROCAL").await;
+        assert!(result.is_err(), "text is malformed use");
+        assert_eq!(interp.stack.len(), 1, "operand restored on error");
+    }
 }
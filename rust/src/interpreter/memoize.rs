@@ -0,0 +1,172 @@
+//! Result caching for user words marked by `REFLECT@MEMOIZE`.
+//!
+//! A memoized word is treated as a function of the top stack value: the
+//! first call with a given argument runs the body and records what it left
+//! in place of that value; later calls with an equal argument replay the
+//! record without running the body, so its output and other effects are
+//! not repeated. A call whose body reads or changes a slot below the
+//! argument (`{ + }` takes two) depends on more than its key, so it always
+//! runs and is never recorded. Arguments are keyed by the canonical encoding CRYPTO@HASH
+//! hashes, so `[ 2/4 ]` and `[ 1/2 ]` share an entry. Any DEF or DEL
+//! advances `dictionary_epoch` and empties the table, because redefining
+//! the word or one it calls can change its results.
+
+use std::collections::HashMap;
+
+use super::hash::serialize_value_for_hash;
+use super::value_extraction_helpers::extract_word_name_from_value;
+use super::{ConsumptionMode, Interpreter, OperationTargetMode};
+use crate::error::{AjisaiError, Result};
+use crate::types::{Interpretation, Value, ValueData};
+
+#[derive(Clone)]
+pub(crate) struct MemoTable {
+    epoch: u64,
+    results: HashMap<Vec<u8>, Vec<(Value, Interpretation)>>,
+}
+
+/// A cache miss waiting for the body to finish: the result is recorded
+/// under `key` once the call returns. `enclosing_watch` is the stack depth
+/// watch this call's watch replaced.
+pub(crate) struct PendingMemo {
+    word: String,
+    key: Vec<u8>,
+    epoch: u64,
+    base: usize,
+    enclosing_watch: usize,
+}
+
+pub(crate) enum MemoLookup {
+    Hit,
+    Miss(PendingMemo),
+}
+
+/// Only values the hash encoding fully describes make safe keys; code
+/// blocks, records, irrational scalars and handles encode to nothing.
+fn is_keyable(value: &Value) -> bool {
+    match &value.data {
+        ValueData::Nil | ValueData::Boolean(_) | ValueData::Scalar(_) => true,
+        ValueData::Tensor { .. } => true,
+        ValueData::Vector(children) => children.iter().all(is_keyable),
+        _ => false,
+    }
+}
+
+impl Interpreter {
+    /// Run a word, consulting its result cache first when it is marked by
+    /// `REFLECT@MEMOIZE`; the check is skipped while none are marked.
+    pub(crate) fn execute_word_memoized(&mut self, name: &str) -> Result<()> {
+        if self.memo_tables.is_empty() {
+            return self.execute_word_traced(name);
+        }
+        match self.memo_lookup(name) {
+            Some(MemoLookup::Hit) => Ok(()),
+            Some(MemoLookup::Miss(pending)) => {
+                let result = self.execute_word_traced(name);
+                self.memo_store(pending, result.is_ok());
+                result
+            }
+            None => self.execute_word_traced(name),
+        }
+    }
+
+    /// Consult the cache for a call to `name`. `None` means the call is not
+    /// memoized (or its argument cannot be keyed) and runs normally.
+    pub(crate) fn memo_lookup(&mut self, name: &str) -> Option<MemoLookup> {
+        if self.operation_target_mode != OperationTargetMode::StackTop
+            || self.consumption_mode != ConsumptionMode::Consume
+        {
+            return None;
+        }
+        let canonical = crate::core_word_aliases::canonicalize_core_word_name(name);
+        let (word, _) = self.resolve_word_entry_readonly(&canonical)?;
+        let epoch = self.dictionary_epoch;
+        let table = self.memo_tables.get_mut(&word)?;
+        let (arg, role) = self.stack.iter_slots().last()?;
+        if !is_keyable(arg) {
+            return None;
+        }
+        if table.epoch != epoch {
+            table.results.clear();
+            table.epoch = epoch;
+        }
+
+        let mut key = serialize_value_for_hash(arg);
+        key.push(role as u8);
+        let Some(result) = table.results.get(&key).cloned() else {
+            let base = self.stack.len() - 1;
+            let enclosing_watch = self.stack.start_depth_watch();
+            return Some(MemoLookup::Miss(PendingMemo {
+                word,
+                key,
+                epoch,
+                base,
+                enclosing_watch,
+            }));
+        };
+        self.stack.pop();
+        for (value, role) in result {
+            self.stack.push_with_role(value, role);
+        }
+        Some(MemoLookup::Hit)
+    }
+
+    /// End the depth watch of a call started by a miss and, if it
+    /// `completed`, record the slots it left above `pending.base`. Nothing
+    /// is recorded when the body touched a slot below its argument (the
+    /// result would depend on more than the key) or changed the dictionary
+    /// mid-call.
+    pub(crate) fn memo_store(&mut self, pending: PendingMemo, completed: bool) {
+        let reached = self.stack.finish_depth_watch(pending.enclosing_watch);
+        if !completed || reached < pending.base || self.dictionary_epoch != pending.epoch {
+            return;
+        }
+        let result: Vec<(Value, Interpretation)> = self
+            .stack
+            .iter_slots()
+            .skip(pending.base)
+            .map(|(value, role)| (value.clone(), role))
+            .collect();
+        if let Some(table) = self.memo_tables.get_mut(&pending.word) {
+            table.results.insert(pending.key, result);
+        }
+    }
+}
+
+/// `name --`. Cache the named user word's results by its top-of-stack
+/// argument. Marking a word again empties its cache.
+pub fn op_memoize(interp: &mut Interpreter) -> Result<()> {
+    if interp.operation_target_mode != OperationTargetMode::StackTop {
+        return Err(AjisaiError::ModeUnsupported {
+            word: "MEMOIZE".into(),
+            mode: "Stack".into(),
+        });
+    }
+    let name_val = interp.stack.pop().ok_or(AjisaiError::StackUnderflow)?;
+    let resolved = extract_word_name_from_value(&name_val).and_then(|name| {
+        let (word, def) = interp
+            .resolve_word_entry_readonly(&name)
+            .ok_or_else(|| AjisaiError::UnknownWord(name.clone()))?;
+        if def.is_builtin {
+            return Err(AjisaiError::BuiltinProtection {
+                word: name,
+                operation: "memoize".into(),
+            });
+        }
+        Ok(word)
+    });
+    let word = match resolved {
+        Ok(word) => word,
+        Err(err) => {
+            interp.stack.push(name_val);
+            return Err(err);
+        }
+    };
+
+    let table = MemoTable {
+        epoch: interp.dictionary_epoch,
+        results: HashMap::new(),
+    };
+    interp.memo_tables.insert(word, table);
+    Ok(())
+}
@@ -0,0 +1,95 @@
+//! Test suite for `crate::interpreter::memoize` (REFLECT@MEMOIZE).
+
+#[cfg(test)]
+mod tests {
+    use crate::interpreter::Interpreter;
+
+    const DOUBLE: &str = "'reflect' IMPORT { 'run' PRINT [ 2 ] * } 'DOUBLE' DEF";
+
+    async fn run(code: &str) -> Interpreter {
+        let mut interp = Interpreter::new();
+        interp.execute(code).await.expect("should succeed");
+        interp
+    }
+
+    fn stack_strings(interp: &Interpreter) -> Vec<String> {
+        interp.stack.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[tokio::test]
+    async fn repeated_argument_does_not_rerun_the_body() {
+        let mut interp = run(&format!(
+            "{} 'DOUBLE' MEMOIZE [ 3 ] DOUBLE [ 3 ] DOUBLE [ 4 ] DOUBLE",
+            DOUBLE
+        ))
+        .await;
+        assert_eq!(interp.collect_output().matches("run").count(), 2);
+        assert_eq!(stack_strings(&interp), ["[ 6/1 ]", "[ 6/1 ]", "[ 8/1 ]"]);
+    }
+
+    #[tokio::test]
+    async fn equal_fractions_share_an_entry() {
+        let mut interp = run(&format!(
+            "{} 'DOUBLE' MEMOIZE [ 1/2 ] DOUBLE [ 2/4 ] DOUBLE",
+            DOUBLE
+        ))
+        .await;
+        assert_eq!(interp.collect_output().matches("run").count(), 1);
+        assert_eq!(stack_strings(&interp), ["[ 1/1 ]", "[ 1/1 ]"]);
+    }
+
+    #[tokio::test]
+    async fn unmemoized_word_runs_every_time() {
+        let mut interp = run(&format!("{} [ 3 ] DOUBLE [ 3 ] DOUBLE", DOUBLE)).await;
+        assert_eq!(interp.collect_output().matches("run").count(), 2);
+    }
+
+    #[tokio::test]
+    async fn redefinition_clears_the_cache() {
+        let mut interp = run(&format!(
+            "{} 'DOUBLE' MEMOIZE [ 3 ] DOUBLE \
+             {{ 'run' PRINT [ 3 ] * }} 'DOUBLE' ! DEF [ 3 ] DOUBLE",
+            DOUBLE
+        ))
+        .await;
+        assert_eq!(interp.collect_output().matches("run").count(), 2);
+        assert_eq!(stack_strings(&interp), ["[ 6/1 ]", "[ 9/1 ]"]);
+    }
+
+    #[tokio::test]
+    async fn a_body_that_reads_below_its_argument_is_not_cached() {
+        let mut interp = run(
+            "'reflect' IMPORT { 'run' PRINT + } 'ADD2' DEF 'ADD2' MEMOIZE \
+             [ 1 ] [ 2 ] ADD2 [ 5 ] [ 2 ] ADD2",
+        )
+        .await;
+        assert_eq!(interp.collect_output().matches("run").count(), 2);
+        assert_eq!(stack_strings(&interp), ["[ 3/1 ]", "[ 7/1 ]"]);
+    }
+
+    #[tokio::test]
+    async fn a_nested_memoized_call_keeps_the_outer_call_cacheable() {
+        let mut interp = run(&format!(
+            "{} 'DOUBLE' MEMOIZE {{ 'quad' PRINT DOUBLE DOUBLE }} 'QUAD' DEF 'QUAD' MEMOIZE \
+             [ 3 ] QUAD [ 3 ] QUAD",
+            DOUBLE
+        ))
+        .await;
+        let output = interp.collect_output();
+        assert_eq!(output.matches("quad").count(), 1);
+        assert_eq!(output.matches("run").count(), 2);
+        assert_eq!(stack_strings(&interp), ["[ 12/1 ]", "[ 12/1 ]"]);
+    }
+
+    #[tokio::test]
+    async fn builtins_and_unknown_words_are_rejected() {
+        for name in ["'LENGTH'", "'NOPE'"] {
+            let mut interp = Interpreter::new();
+            let result = interp
+                .execute(&format!("'reflect' IMPORT {} MEMOIZE", name))
+                .await;
+            assert!(result.is_err(), "{}", name);
+            assert_eq!(interp.stack.len(), 1, "the name is left in place");
+        }
+    }
+}
@@ -2,17 +2,21 @@ pub mod algo_ops;
 pub mod arithmetic;
 pub mod artifact_store;
 pub mod audio;
+pub mod breakpoint;
 pub mod cast;
+pub mod cell_eval;
 pub mod child_runtime;
 pub mod comparison;
 pub(crate) mod compiled_call;
 pub mod compiled_plan;
 pub mod comptime;
+pub mod continued_fraction_ops;
 pub mod control;
 pub mod control_cond;
 pub mod data_ops;
 pub mod datetime;
 pub mod debug_diagnosis;
+mod definition_source;
 pub mod energy_proxy;
 pub mod epoch;
 pub mod error_flow_trace;
@@ -31,25 +35,38 @@ pub mod logic;
 pub mod logic_kleene;
 pub mod mass_conservation;
 pub mod math_ops;
+pub mod memoize;
 pub mod modules;
 pub(crate) mod naming_convention_checker;
 pub mod parallel;
 pub mod quantized_block;
 pub mod random;
 pub mod receipt_recorder;
+pub mod reflect_ops;
 mod resolve_cache;
+pub mod rounding_ops;
 pub mod runtime_limits;
 pub mod serial;
 mod session_lifecycle;
+#[cfg(any(test, feature = "wasm"))]
+mod session_state;
 mod shadow_validation;
 pub(crate) mod shape_ic;
 pub(crate) mod simd_ops;
 pub mod sort;
+mod stack_json;
+mod stack_view;
+pub mod stats;
+pub mod storage;
+pub mod tensor_axis_ops;
 pub mod tensor_cmds;
+pub mod tensor_map;
 pub mod tensor_ops;
 pub mod tier2_ops;
 pub mod time_calendar;
 pub mod time_ops;
+pub mod time_pattern;
+pub mod undo_history;
 pub(crate) mod value_extraction_helpers;
 pub mod vector_exec;
 pub mod vector_ops;
@@ -61,6 +78,9 @@ mod word_identity;
 pub mod word_space;
 #[cfg(test)]
 mod word_space_tests;
+pub mod word_trace;
+#[cfg(test)]
+mod word_trace_tests;
 // Re-exported only for the host-only `cli` consumers (receipt / lockfile source
 // identity); `content_digest` itself is used internally by `word_identity`, so
 // gate just this re-export to the same target as `cli` to stay wasm-clean.
@@ -84,16 +104,30 @@ mod arithmetic_exact_div_tests;
 #[cfg(test)]
 mod artifact_store_tests;
 #[cfg(test)]
+mod breakpoint_tests;
+#[cfg(test)]
+mod cell_eval_tests;
+#[cfg(test)]
 mod child_runtime_tests;
 #[cfg(test)]
+mod control_case_tests;
+#[cfg(test)]
 mod control_cond_tests;
 #[cfg(test)]
+mod control_dispatch_tests;
+#[cfg(test)]
 mod control_exec_eval_tests;
 #[cfg(test)]
 mod control_or_else_tests;
 #[cfg(test)]
+mod control_raise_tests;
+#[cfg(test)]
+mod control_try_tests;
+#[cfg(test)]
 mod datetime_tests;
 #[cfg(test)]
+mod definition_source_tests;
+#[cfg(test)]
 mod dependents_index_tests;
 #[cfg(test)]
 mod dictionary_operation_tests;
@@ -124,6 +158,8 @@ mod interpreter_mode_tests;
 #[cfg(test)]
 mod math_ops_tests;
 #[cfg(test)]
+mod memoize_tests;
+#[cfg(test)]
 mod module_catalog_tests;
 #[cfg(test)]
 mod module_unimport_tests;
@@ -136,13 +172,33 @@ mod nil_reason_tests;
 #[cfg(test)]
 mod nil_unknown_firewall_tests;
 #[cfg(test)]
+mod reflect_ops_tests;
+#[cfg(test)]
 mod route_equivalence;
 #[cfg(test)]
+mod rounding_ops_tests;
+#[cfg(test)]
+mod session_state_tests;
+#[cfg(test)]
 mod shape_ic_tests;
 #[cfg(test)]
+mod stack_json_tests;
+#[cfg(test)]
+mod stack_view_tests;
+#[cfg(test)]
+mod storage_tests;
+#[cfg(test)]
+mod stats_tests;
+#[cfg(test)]
+mod tensor_axis_ops_tests;
+#[cfg(test)]
 mod tier2_isolation_tests;
 #[cfg(test)]
 mod tier2_vocabulary_tests;
+#[cfg(test)]
+mod time_pattern_tests;
+#[cfg(test)]
+mod undo_history_tests;
 
 pub use interpreter_core::*;
 pub use runtime_limits::RuntimeLimits;
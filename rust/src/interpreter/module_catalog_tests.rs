@@ -12,7 +12,7 @@ mod tests {
     fn available_modules_cover_all_specced_modules() {
         let names = available_module_names();
         for expected in [
-            "MUSIC", "JSON", "IO", "TIME", "CRYPTO", "ALGO", "MATH", "SERIAL",
+            "MUSIC", "JSON", "IO", "TIME", "CRYPTO", "ALGO", "MATH", "SERIAL", "REFLECT", "STATS",
         ] {
             assert!(
                 names.contains(&expected),
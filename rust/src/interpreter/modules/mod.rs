@@ -1,7 +1,13 @@
 mod module_builtins;
+mod module_builtins_music;
+mod module_builtins_tests;
+mod module_catalog;
 mod module_import_execution;
 mod module_registry;
+mod module_word_contracts;
 mod module_word_docs;
+mod module_word_docs_math;
+mod module_word_docs_music;
 mod module_word_types;
 
 use crate::coreword_registry::CorewordMetadata;
@@ -36,16 +42,16 @@ pub fn restore_module(interp: &mut Interpreter, module_name: &str) -> bool {
     module_import_execution::restore_module(interp, module_name)
 }
 
-pub(crate) use module_builtins::CatalogWord;
+pub(crate) use module_catalog::CatalogWord;
 
 /// All importable module names, in specification order.
 pub fn available_module_names() -> Vec<&'static str> {
-    module_builtins::available_module_names()
+    module_catalog::available_module_names()
 }
 
 /// Full word + sample catalog for a module, regardless of import state.
 pub(crate) fn module_catalog_words(module_name: &str) -> Option<Vec<CatalogWord>> {
-    module_builtins::module_catalog_words(module_name)
+    module_catalog::module_catalog_words(module_name)
 }
 
 /// Restore a precise (possibly partial) import state for one module.
@@ -73,19 +79,19 @@ pub(crate) fn is_known_module(module_name: &str) -> bool {
 }
 
 pub(crate) fn module_word_metadata_entries() -> Vec<CorewordMetadata> {
-    module_builtins::module_word_metadata_entries()
+    module_catalog::module_word_metadata_entries()
 }
 
 /// Look up a module word's user-facing description by qualified name
 /// (e.g. `"ALGO@SORT"`) or by `(module, short_name)`. Returns `None` if no
 /// such canonical module word exists.
 pub fn module_word_description(module_name: &str, short_name: &str) -> Option<&'static str> {
-    module_builtins::module_word_description(module_name, short_name)
+    module_catalog::module_word_description(module_name, short_name)
 }
 
 /// Render the four-section LOOKUP body for a module word, accepting either
 /// a qualified `MODULE@WORD` name or a bare module word name. Returns
 /// `None` if no such word exists.
 pub fn lookup_module_word_detail(name: &str) -> Option<String> {
-    module_builtins::lookup_module_word_detail(name)
+    module_catalog::lookup_module_word_detail(name)
 }
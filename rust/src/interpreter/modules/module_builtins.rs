@@ -1,411 +1,14 @@
 use crate::builtins::WordShape;
-use crate::coreword_registry::{
-    self, CanonicalHome, CorewordMetadata, NilPolicy, Partiality, SafetyLevel, WordProfile,
-    WordPurity,
-};
+use crate::coreword_registry::WordPurity;
 use crate::interpreter::{
-    algo_ops, audio, data_ops, datetime, hash, interval_ops, json, math_ops, random, serial, sort,
-    tier2_ops, time_ops, HostCapability,
+    algo_ops, breakpoint, cast, continued_fraction_ops, control, data_ops, datetime, hash,
+    higher_order, interval_ops, io, json, math_ops, memoize, random, reflect_ops, rounding_ops,
+    serial, sort, stats, storage, tier2_ops, time_ops, time_pattern, vector_ops,
 };
 use crate::types::{Capabilities, Stability};
 
-use super::module_word_types::{ModuleSpec, ModuleWord};
-
-macro_rules! module_word {
-    ($name:expr, $word_shape:expr, $description:expr, $executor:expr, $purity:expr, $effects:expr, $det:expr, $preview:expr, $preserves:expr, $stability:expr, $caps:expr) => {
-        ModuleWord {
-            short_name: $name,
-            description: $description,
-            word_shape: Some($word_shape),
-            executor: $executor,
-            purity: $purity,
-            effects: $effects,
-            deterministic: $det,
-            safe_preview: $preview,
-            preserves_modes: $preserves,
-            stability: $stability,
-            capabilities: $caps,
-        }
-    };
-    ($name:expr, $description:expr, $executor:expr, $purity:expr, $effects:expr, $det:expr, $preview:expr, $preserves:expr, $stability:expr, $caps:expr) => {
-        ModuleWord {
-            short_name: $name,
-            description: $description,
-            word_shape: None,
-            executor: $executor,
-            purity: $purity,
-            effects: $effects,
-            deterministic: $det,
-            safe_preview: $preview,
-            preserves_modes: $preserves,
-            stability: $stability,
-            capabilities: $caps,
-        }
-    };
-}
-
-const MUSIC_WORDS: &[ModuleWord] = &[
-    module_word!(
-        "SEQ",
-        "Set sequential playback mode",
-        audio::op_seq,
-        WordPurity::Effectful,
-        &["audio-control"],
-        false,
-        false,
-        true,
-        Stability::Stable,
-        Capabilities::IO
-    ),
-    module_word!(
-        "SIM",
-        "Set simultaneous playback mode",
-        audio::op_sim,
-        WordPurity::Effectful,
-        &["audio-control"],
-        false,
-        false,
-        true,
-        Stability::Stable,
-        Capabilities::IO
-    ),
-    module_word!(
-        "SLOT",
-        "Set slot duration in seconds",
-        audio::op_slot,
-        WordPurity::Effectful,
-        &["audio-control"],
-        false,
-        false,
-        false,
-        Stability::Stable,
-        Capabilities::IO
-    ),
-    module_word!(
-        "GAIN",
-        "Set volume level (0.0-1.0)",
-        audio::op_gain,
-        WordPurity::Effectful,
-        &["audio-control"],
-        false,
-        false,
-        false,
-        Stability::Stable,
-        Capabilities::IO
-    ),
-    module_word!(
-        "GAIN-RESET",
-        "Reset volume to default (1.0)",
-        audio::op_gain_reset,
-        WordPurity::Effectful,
-        &["audio-control"],
-        false,
-        false,
-        false,
-        Stability::Stable,
-        Capabilities::IO
-    ),
-    module_word!(
-        "PAN",
-        "Set stereo position (-1.0 left to 1.0 right)",
-        audio::op_pan,
-        WordPurity::Effectful,
-        &["audio-control"],
-        false,
-        false,
-        false,
-        Stability::Stable,
-        Capabilities::IO
-    ),
-    module_word!(
-        "PAN-RESET",
-        "Reset pan to center (0.0)",
-        audio::op_pan_reset,
-        WordPurity::Effectful,
-        &["audio-control"],
-        false,
-        false,
-        false,
-        Stability::Stable,
-        Capabilities::IO
-    ),
-    module_word!(
-        "FX-RESET",
-        "Reset all audio effects to defaults",
-        audio::op_fx_reset,
-        WordPurity::Effectful,
-        &["audio-control"],
-        false,
-        false,
-        false,
-        Stability::Stable,
-        Capabilities::IO
-    ),
-    module_word!(
-        "PLAY",
-        "Play audio",
-        audio::op_play,
-        WordPurity::Effectful,
-        &["audio-output"],
-        false,
-        false,
-        false,
-        Stability::Stable,
-        Capabilities::IO
-    ),
-    module_word!(
-        "SEQ-GROUP",
-        "Build an explicit sequential music group from a vector",
-        audio::op_seq_group,
-        WordPurity::Pure,
-        &[],
-        true,
-        true,
-        false,
-        Stability::Experimental,
-        Capabilities::PURE
-    ),
-    module_word!(
-        "SIM-GROUP",
-        "Build an explicit simultaneous music group from a vector",
-        audio::op_sim_group,
-        WordPurity::Pure,
-        &[],
-        true,
-        true,
-        false,
-        Stability::Experimental,
-        Capabilities::PURE
-    ),
-    module_word!(
-        "CHORD",
-        "Build an explicit chord group (simultaneous) from a vector",
-        audio::op_chord,
-        WordPurity::Pure,
-        &[],
-        true,
-        true,
-        false,
-        Stability::Experimental,
-        Capabilities::PURE
-    ),
-    module_word!(
-        "HZ",
-        "Build a music.pitch from a frequency in Hz (exact rational)",
-        audio::op_hz,
-        WordPurity::Pure,
-        &[],
-        true,
-        true,
-        false,
-        Stability::Experimental,
-        Capabilities::PURE
-    ),
-    module_word!(
-        "DUR",
-        "Build a music.duration from a number of seconds",
-        audio::op_dur,
-        WordPurity::Pure,
-        &[],
-        true,
-        true,
-        false,
-        Stability::Experimental,
-        Capabilities::PURE
-    ),
-    module_word!(
-        "NOTE",
-        "Combine a music.pitch and a music.duration into a music.note",
-        audio::op_note,
-        WordPurity::Pure,
-        &[],
-        true,
-        true,
-        false,
-        Stability::Experimental,
-        Capabilities::PURE
-    ),
-    module_word!(
-        "REST",
-        "Build a music.rest from a music.duration",
-        audio::op_rest,
-        WordPurity::Pure,
-        &[],
-        true,
-        true,
-        false,
-        Stability::Experimental,
-        Capabilities::PURE
-    ),
-    module_word!(
-        "EDO",
-        "Build an equal-division-of-the-octave music.tuning",
-        audio::op_edo,
-        WordPurity::Pure,
-        &[],
-        true,
-        true,
-        false,
-        Stability::Experimental,
-        Capabilities::PURE
-    ),
-    module_word!(
-        "EDR",
-        "Build an equal-division-of-a-ratio music.tuning (non-octave)",
-        audio::op_edr,
-        WordPurity::Pure,
-        &[],
-        true,
-        true,
-        false,
-        Stability::Experimental,
-        Capabilities::PURE
-    ),
-    module_word!(
-        "STEP",
-        "Resolve a step within a music.tuning into a music.pitch",
-        audio::op_step,
-        WordPurity::Pure,
-        &[],
-        true,
-        true,
-        false,
-        Stability::Experimental,
-        Capabilities::PURE
-    ),
-    module_word!(
-        "VOICE",
-        "Build a music group with the role of a single melodic voice",
-        audio::op_voice,
-        WordPurity::Pure,
-        &[],
-        true,
-        true,
-        false,
-        Stability::Experimental,
-        Capabilities::PURE
-    ),
-    module_word!(
-        "TRACK",
-        "Build a music group with the role of an instrument track",
-        audio::op_track,
-        WordPurity::Pure,
-        &[],
-        true,
-        true,
-        false,
-        Stability::Experimental,
-        Capabilities::PURE
-    ),
-    module_word!(
-        "MEASURE",
-        "Build a music group with the role of a measure (bar)",
-        audio::op_measure,
-        WordPurity::Pure,
-        &[],
-        true,
-        true,
-        false,
-        Stability::Experimental,
-        Capabilities::PURE
-    ),
-    module_word!(
-        "PHRASE",
-        "Build a music group with the role of a phrase",
-        audio::op_phrase,
-        WordPurity::Pure,
-        &[],
-        true,
-        true,
-        false,
-        Stability::Experimental,
-        Capabilities::PURE
-    ),
-    module_word!(
-        "WITH-TUNING",
-        "Bind a tuning over a body so bare integers become tuning steps",
-        audio::op_with_tuning,
-        WordPurity::Pure,
-        &[],
-        true,
-        true,
-        false,
-        Stability::Experimental,
-        Capabilities::PURE
-    ),
-    module_word!(
-        "EXPLAIN",
-        "Explain how MUSIC@PLAY would interpret a value",
-        audio::op_explain,
-        WordPurity::Effectful,
-        &["audio-output"],
-        true,
-        false,
-        false,
-        Stability::Experimental,
-        Capabilities::IO
-    ),
-    module_word!(
-        "ADSR",
-        "Set ADSR envelope",
-        audio::op_adsr,
-        WordPurity::Effectful,
-        &["audio-control"],
-        false,
-        false,
-        false,
-        Stability::Stable,
-        Capabilities::IO
-    ),
-    module_word!(
-        "SINE",
-        "Set sine waveform",
-        audio::op_sine,
-        WordPurity::Effectful,
-        &["audio-control"],
-        false,
-        false,
-        false,
-        Stability::Stable,
-        Capabilities::IO
-    ),
-    module_word!(
-        "SQUARE",
-        "Set square waveform",
-        audio::op_square,
-        WordPurity::Effectful,
-        &["audio-control"],
-        false,
-        false,
-        false,
-        Stability::Stable,
-        Capabilities::IO
-    ),
-    module_word!(
-        "SAW",
-        "Set sawtooth waveform",
-        audio::op_saw,
-        WordPurity::Effectful,
-        &["audio-control"],
-        false,
-        false,
-        false,
-        Stability::Stable,
-        Capabilities::IO
-    ),
-    module_word!(
-        "TRI",
-        "Set triangle waveform",
-        audio::op_tri,
-        WordPurity::Effectful,
-        &["audio-control"],
-        false,
-        false,
-        false,
-        Stability::Stable,
-        Capabilities::IO
-    ),
-];
+use super::module_builtins_music::MUSIC_WORDS;
+use super::module_word_types::{module_word, ModuleSpec, ModuleWord};
 
 const JSON_WORDS: &[ModuleWord] = &[
     module_word!(
@@ -555,6 +158,30 @@ const DATA_WORDS: &[ModuleWord] = &[
         Stability::Experimental,
         Capabilities::PURE
     ),
+    module_word!(
+        "CSV-PARSE-ROWS",
+        "Parse CSV text into a vector of row vectors of text cells",
+        data_ops::op_csv_parse_rows,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "CSV-STRINGIFY-ROWS",
+        "Convert a vector of row vectors of text cells into CSV text",
+        data_ops::op_csv_stringify_rows,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
     module_word!(
         "SELECT",
         "Project a table onto the named columns",
@@ -592,42 +219,90 @@ const DATA_WORDS: &[ModuleWord] = &[
         Capabilities::PURE
     ),
     module_word!(
-        "JOIN",
-        "Left-join two tables on a shared key column",
-        data_ops::op_join,
-        WordPurity::Pure,
-        &[],
-        true,
-        true,
+        "JOIN",
+        "Left-join two tables on a shared key column",
+        data_ops::op_join,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+];
+
+const IO_WORDS: &[ModuleWord] = &[
+    module_word!(
+        "INPUT",
+        "Read text from input buffer",
+        json::op_input,
+        WordPurity::Observable,
+        &["io-read"],
+        false,
+        false,
+        false,
+        Stability::Stable,
+        Capabilities::IO
+    ),
+    module_word!(
+        "OUTPUT",
+        "Write value to output buffer",
+        json::op_output,
+        WordPurity::Effectful,
+        &["io-write"],
+        false,
+        false,
+        false,
+        Stability::Stable,
+        Capabilities::IO
+    ),
+    module_word!(
+        "TABLE",
+        "Print a vector of equal-length rows as an aligned grid",
+        io::op_table,
+        WordPurity::Effectful,
+        &["io-write"],
+        false,
+        false,
+        false,
+        Stability::Experimental,
+        Capabilities::IO
+    ),
+    module_word!(
+        "BREAK",
+        "Record the stack for debugging; pause here when the host asks",
+        breakpoint::op_break,
+        WordPurity::Effectful,
+        &["io-write"],
+        false,
+        false,
         false,
         Stability::Experimental,
-        Capabilities::PURE
+        Capabilities::IO
     ),
-];
-
-const IO_WORDS: &[ModuleWord] = &[
     module_word!(
-        "INPUT",
-        "Read text from input buffer",
-        json::op_input,
-        WordPurity::Observable,
-        &["io-read"],
+        "SAVE",
+        "Save the user dictionaries to browser local storage under a key",
+        storage::op_save,
+        WordPurity::Effectful,
+        &["io-write", "dictionary-read"],
         false,
         false,
         false,
-        Stability::Stable,
+        Stability::Experimental,
         Capabilities::IO
     ),
     module_word!(
-        "OUTPUT",
-        "Write value to output buffer",
-        json::op_output,
+        "LOAD",
+        "Define the words saved in browser local storage under a key",
+        storage::op_load,
         WordPurity::Effectful,
-        &["io-write"],
+        &["io-read", "dictionary-write", "dictionary-register"],
         false,
         false,
         false,
-        Stability::Stable,
+        Stability::Experimental,
         Capabilities::IO
     ),
 ];
@@ -779,7 +454,7 @@ const TIME_WORDS: &[ModuleWord] = &[
     ),
     module_word!(
         "ADD-DAYS",
-        "Shift a date or datetime by N whole days",
+        "Shift a date, datetime, or timestamp by N whole days",
         time_ops::op_add_days,
         WordPurity::Pure,
         &[],
@@ -791,7 +466,7 @@ const TIME_WORDS: &[ModuleWord] = &[
     ),
     module_word!(
         "DIFF-DAYS",
-        "Whole-day difference a-b between two dates/datetimes",
+        "Whole-day difference a-b between two dates/datetimes or timestamps",
         time_ops::op_diff_days,
         WordPurity::Pure,
         &[],
@@ -825,6 +500,30 @@ const TIME_WORDS: &[ModuleWord] = &[
         Stability::Stable,
         Capabilities::TIME
     ),
+    module_word!(
+        "FORMAT-PATTERN",
+        "Render a date/datetime through a strftime-like pattern (%Y %m %d %H %M %S %%)",
+        time_pattern::op_format_pattern,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Stable,
+        Capabilities::TIME
+    ),
+    module_word!(
+        "PARSE-PATTERN",
+        "Parse text through a strftime-like pattern into a datetime; errors on mismatch",
+        time_pattern::op_parse_pattern,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Stable,
+        Capabilities::TIME
+    ),
     module_word!(
         "ADD-MONTHS",
         "Add N months to a date/datetime, clamping to the month end",
@@ -865,6 +564,30 @@ const CRYPTO_WORDS: &[ModuleWord] = &[
         Stability::Stable,
         Capabilities::RANDOM.union(Capabilities::CRYPTO)
     ),
+    module_word!(
+        "SHUFFLE",
+        "Randomly permute a vector",
+        random::op_shuffle,
+        WordPurity::Observable,
+        &["random-read"],
+        false,
+        false,
+        false,
+        Stability::Stable,
+        Capabilities::RANDOM.union(Capabilities::CRYPTO)
+    ),
+    module_word!(
+        "SAMPLE",
+        "Pick k distinct elements of a vector at random",
+        random::op_sample,
+        WordPurity::Observable,
+        &["random-read"],
+        false,
+        false,
+        false,
+        Stability::Stable,
+        Capabilities::RANDOM.union(Capabilities::CRYPTO)
+    ),
     module_word!(
         "HASH",
         "Compute hash value",
@@ -893,6 +616,19 @@ const ALGO_WORDS: &[ModuleWord] = &[
         Stability::Stable,
         Capabilities::PURE
     ),
+    module_word!(
+        "MERGE-SORTED",
+        WordShape::Form,
+        "Merge two ascending vectors into one ascending vector",
+        sort::op_merge_sorted,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Stable,
+        Capabilities::PURE
+    ),
     module_word!(
         "UNIQUE",
         WordShape::Form,
@@ -906,6 +642,19 @@ const ALGO_WORDS: &[ModuleWord] = &[
         Stability::Stable,
         Capabilities::PURE
     ),
+    module_word!(
+        "COMPRESS",
+        WordShape::Form,
+        "Keep the elements whose boolean mask element is TRUE",
+        algo_ops::op_compress,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Stable,
+        Capabilities::PURE
+    ),
     module_word!(
         "CONTAINS",
         WordShape::Form,
@@ -916,20 +665,237 @@ const ALGO_WORDS: &[ModuleWord] = &[
         true,
         true,
         false,
-        Stability::Stable,
+        Stability::Stable,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "INDEX-OF",
+        WordShape::Form,
+        "Index of the first element equal to the value; Bubble/NIL if absent",
+        algo_ops::op_index_of,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Stable,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "BINARY-SEARCH",
+        WordShape::Form,
+        "Lowest index of a value in an ascending vector, by binary search",
+        algo_ops::op_binary_search,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Stable,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "ASSOC-GET",
+        WordShape::Form,
+        "Value paired with a key in a [ key value ] vector; Bubble/NIL if absent",
+        algo_ops::op_assoc_get,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Stable,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "ASSOC-SET",
+        WordShape::Form,
+        "Insert or update a key in a [ key value ] vector",
+        algo_ops::op_assoc_set,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Stable,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "ASSOC-KEYS",
+        WordShape::Form,
+        "Keys of a [ key value ] vector, in order",
+        algo_ops::op_assoc_keys,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Stable,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "ASSOC-VALUES",
+        WordShape::Form,
+        "Values of a [ key value ] vector, in order",
+        algo_ops::op_assoc_values,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Stable,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "UPTO",
+        WordShape::Form,
+        "Half-open integer sequence from a [start, end) pair",
+        vector_ops::op_upto,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "REPEAT-VALUE",
+        WordShape::Form,
+        "Vector of n copies of a value",
+        vector_ops::op_repeat_value,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "ROTATE",
+        WordShape::Form,
+        "Cyclic shift: left by k, right for negative k",
+        vector_ops::op_rotate,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "CARTESIAN",
+        WordShape::Form,
+        "Cartesian product of two vectors as a vector of pairs",
+        vector_ops::op_cartesian,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "GETOR",
+        WordShape::Form,
+        "Element at an index, or a default when out of range",
+        vector_ops::op_getor,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "SWAPAT",
+        WordShape::Form,
+        "Copy of a vector with the elements at two indices exchanged",
+        vector_ops::op_swapat,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "PATHGET",
+        WordShape::Form,
+        "Element a path of indices addresses in nested vectors",
+        vector_ops::op_pathget,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "PATHSET",
+        WordShape::Form,
+        "Copy of nested vectors with the element a path addresses replaced",
+        vector_ops::op_pathset,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "TIMES-INDEXED",
+        "Run a body N times, pushing the 0-based index before each run",
+        algo_ops::op_times_indexed,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "CASE",
+        "Run the action paired with the first candidate equal to a value",
+        control::op_case,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "DISPATCH",
+        "Run the action word of the first clause whose condition word holds",
+        control::op_dispatch,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
         Capabilities::PURE
     ),
     module_word!(
-        "INDEX-OF",
-        WordShape::Form,
-        "Index of the first element equal to the value; Bubble/NIL if absent",
-        algo_ops::op_index_of,
+        "SCANINIT",
+        "SCAN with the initial accumulator as the first result",
+        higher_order::op_scan_init,
         WordPurity::Pure,
         &[],
         true,
         true,
         false,
-        Stability::Stable,
+        Stability::Experimental,
         Capabilities::PURE
     ),
 ];
@@ -1091,6 +1057,45 @@ const MATH_WORDS: &[ModuleWord] = &[
         Stability::Stable,
         Capabilities::PURE
     ),
+    module_word!(
+        "CLAMP",
+        WordShape::Form,
+        "Bound a number to the closed range [lo, hi].",
+        math_ops::op_clamp,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Stable,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "LERP",
+        WordShape::Form,
+        "Exact linear interpolation a + (b - a) * t.",
+        math_ops::op_lerp,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Stable,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "LINSPACE",
+        WordShape::Form,
+        "n evenly spaced exact points from start to end inclusive.",
+        vector_ops::op_linspace,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
     module_word!(
         "POW",
         WordShape::Form,
@@ -1104,6 +1109,58 @@ const MATH_WORDS: &[ModuleWord] = &[
         Stability::Stable,
         Capabilities::PURE
     ),
+    module_word!(
+        "RECIPROCAL",
+        WordShape::Map,
+        "Exact reciprocal 1/x, elementwise.",
+        math_ops::op_reciprocal,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "ROUNDTO",
+        WordShape::Form,
+        "Round to the nearest multiple of 1/d, ties away from zero.",
+        rounding_ops::op_round_to,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "TOMIXED",
+        WordShape::Form,
+        "A number as mixed-number text: 7/3 gives '2 1/3'.",
+        cast::op_to_mixed,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "PERCENT",
+        WordShape::Form,
+        "A number times 100 as truncated decimal text with a percent sign.",
+        cast::op_percent,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
     module_word!(
         "GCD",
         WordShape::Form,
@@ -1130,6 +1187,58 @@ const MATH_WORDS: &[ModuleWord] = &[
         Stability::Stable,
         Capabilities::PURE
     ),
+    module_word!(
+        "CONTINUED-FRACTION",
+        WordShape::Form,
+        "Partial quotients of a rational's simple continued fraction.",
+        continued_fraction_ops::op_continued_fraction,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Stable,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "FROM-CONTINUED",
+        WordShape::Form,
+        "Rebuild a rational from its continued-fraction terms.",
+        continued_fraction_ops::op_from_continued,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Stable,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "NUMERATOR",
+        WordShape::Map,
+        "Numerator of the reduced fraction, elementwise.",
+        continued_fraction_ops::op_numerator,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "DENOMINATOR",
+        WordShape::Map,
+        "Denominator of the reduced fraction, elementwise.",
+        continued_fraction_ops::op_denominator,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
     module_word!(
         "PI",
         WordShape::Form,
@@ -1245,6 +1354,183 @@ const SERIAL_WORDS: &[ModuleWord] = &[
     ),
 ];
 
+const REFLECT_WORDS: &[ModuleWord] = &[
+    module_word!(
+        "ROUNDTRIP?",
+        "True if a value survives its stack rendering being re-evaluated",
+        reflect_ops::op_roundtrip,
+        WordPurity::Observable,
+        &["io-write"],
+        false,
+        false,
+        false,
+        Stability::Experimental,
+        Capabilities::IO
+    ),
+    module_word!(
+        "TYPE",
+        "Name of the kind a value renders as, such as number or string",
+        reflect_ops::op_type,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "SOURCE",
+        "Definition source of a named word as text",
+        reflect_ops::op_source,
+        WordPurity::Observable,
+        &["dictionary-read"],
+        false,
+        false,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "ALIAS",
+        "Define a new user word that behaves like an existing word",
+        reflect_ops::op_alias,
+        WordPurity::Effectful,
+        &["dictionary-write", "dictionary-register"],
+        false,
+        false,
+        false,
+        Stability::Experimental,
+        Capabilities::MUTATES_DICT
+    ),
+    module_word!(
+        "COMPOSE",
+        "Define a new user word that runs two words in sequence",
+        reflect_ops::op_compose,
+        WordPurity::Effectful,
+        &["dictionary-write", "dictionary-register"],
+        false,
+        false,
+        false,
+        Stability::Experimental,
+        Capabilities::MUTATES_DICT
+    ),
+    module_word!(
+        "PARTIAL",
+        "Define a new user word that pushes a bound argument, then calls a word",
+        reflect_ops::op_partial,
+        WordPurity::Effectful,
+        &["dictionary-write", "dictionary-register"],
+        false,
+        false,
+        false,
+        Stability::Experimental,
+        Capabilities::MUTATES_DICT
+    ),
+    module_word!(
+        "DESCRIBE",
+        "Replace the description of a user word",
+        reflect_ops::op_describe,
+        WordPurity::Effectful,
+        &["dictionary-write"],
+        false,
+        false,
+        false,
+        Stability::Experimental,
+        Capabilities::MUTATES_DICT
+    ),
+    module_word!(
+        "MEMOIZE",
+        "Cache a user word's results by its top-of-stack argument",
+        memoize::op_memoize,
+        WordPurity::Effectful,
+        &["dictionary-write"],
+        false,
+        false,
+        false,
+        Stability::Experimental,
+        Capabilities::MUTATES_DICT
+    ),
+    module_word!(
+        "DEPENDENCIES",
+        "Qualified names of the words a named word calls",
+        reflect_ops::op_dependencies,
+        WordPurity::Observable,
+        &["dictionary-read"],
+        false,
+        false,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "DEPENDENTS",
+        "Qualified names of the user words that call a named word",
+        reflect_ops::op_dependents,
+        WordPurity::Observable,
+        &["dictionary-read"],
+        false,
+        false,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "WORDS",
+        "Sorted names of the user words, or of every callable word",
+        reflect_ops::op_words,
+        WordPurity::Observable,
+        &["dictionary-read"],
+        false,
+        false,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+];
+
+const STATS_WORDS: &[ModuleWord] = &[
+    module_word!(
+        "BUCKET",
+        WordShape::Form,
+        "Histogram counts of values over the bins between sorted edges",
+        stats::op_bucket,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "VARIANCE",
+        WordShape::Form,
+        "Exact population variance of a numeric vector",
+        stats::op_variance,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "STDDEV",
+        WordShape::Form,
+        "Population standard deviation as an exact square root",
+        stats::op_stddev,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+];
+
 pub(super) const MODULE_SPECS: &[ModuleSpec] = &[
     ModuleSpec {
         name: "MUSIC",
@@ -1282,279 +1568,12 @@ pub(super) const MODULE_SPECS: &[ModuleSpec] = &[
         name: "SERIAL",
         words: SERIAL_WORDS,
     },
+    ModuleSpec {
+        name: "REFLECT",
+        words: REFLECT_WORDS,
+    },
+    ModuleSpec {
+        name: "STATS",
+        words: STATS_WORDS,
+    },
 ];
-
-/// One catalog entry for a module word as declared in
-/// `MODULE_SPECS`, independent of whether it is currently imported. The GUI
-/// uses this to render the full module dictionary (active + inactive words)
-/// so an inactive word can be surfaced greyed-out and toggled with IMPORT-ONLY.
-pub(crate) struct CatalogWord {
-    pub short_name: &'static str,
-    // Read only by the wasm catalog view (feature = "wasm"); populated in all
-    // builds, so the dead-code lint is allowed only when wasm is off.
-    #[cfg_attr(not(feature = "wasm"), allow(dead_code))]
-    pub description: &'static str,
-}
-
-/// All importable module names, in specification order.
-pub(crate) fn available_module_names() -> Vec<&'static str> {
-    MODULE_SPECS.iter().map(|m| m.name).collect()
-}
-
-/// Full word catalog for a module, read directly from `MODULE_SPECS`
-/// so it is available even when the module has never been imported. Returns
-/// `None` when the module name is unknown.
-pub(crate) fn module_catalog_words(module_name: &str) -> Option<Vec<CatalogWord>> {
-    let upper = module_name.to_uppercase();
-    let module = MODULE_SPECS.iter().find(|m| m.name == upper)?;
-    let out: Vec<CatalogWord> = module
-        .words
-        .iter()
-        .map(|w| CatalogWord {
-            short_name: w.short_name,
-            description: w.description,
-        })
-        .collect();
-    Some(out)
-}
-
-pub(crate) fn module_word_description(module_name: &str, short_name: &str) -> Option<&'static str> {
-    let module = MODULE_SPECS.iter().find(|m| m.name == module_name)?;
-    module
-        .words
-        .iter()
-        .find(|w| w.short_name == short_name)
-        .map(|w| w.description)
-}
-
-/// Render the four-section LOOKUP body for a module word, given a
-/// qualified `MODULE@WORD` name (e.g. `"JSON@PARSE"`). Returns `None` if
-/// the word does not exist.
-///
-/// `Category / Summary / Role / Stack Effect` come from the authored
-/// table in `super::module_word_docs`. Category is derived from the
-/// module name; the other three are looked up by `(module, short_name)`.
-pub(crate) fn lookup_module_word_detail(name: &str) -> Option<String> {
-    let upper = name.to_uppercase();
-    let (module_name, short_name) = upper.split_once('@')?;
-    let module = MODULE_SPECS.iter().find(|m| m.name == module_name)?;
-    let word = module.words.iter().find(|w| w.short_name == short_name)?;
-    let stability_str = match word.stability {
-        Stability::Stable => "stable",
-        Stability::Experimental => "experimental",
-    };
-    let category = module.name.to_lowercase();
-    let doc = super::module_word_docs::lookup_module_word_doc(module.name, word.short_name)?;
-    Some(crate::builtins::render_four_section(
-        "",
-        &format!("{}@{}", module.name, word.short_name),
-        stability_str,
-        &category,
-        doc.summary,
-        doc.role,
-        doc.stack_effect,
-    ))
-}
-
-/// Per-word contract overrides for module words whose `partiality` /
-/// `nil_policy` differ from the purity-class default produced by
-/// `coreword_registry::{pure,observable,effectful}`.
-fn contract_override(module: &str, word: &str) -> Option<(Partiality, NilPolicy)> {
-    match (module, word) {
-        // SERIAL@READ projects the no-data / disconnected condition onto
-        // Bubble/NIL (Section 9.4), so it is Projecting/CreatesNil rather
-        // than the effectful default of Partial/RejectsNil.
-        ("SERIAL", "READ") => Some((Partiality::Projecting, NilPolicy::CreatesNil)),
-        // MATH@POW projects 0 raised to a negative exponent onto Bubble/NIL
-        // (reason = divisionByZero) while erroring on malformed use.
-        ("MATH", "POW") => Some((Partiality::Projecting, NilPolicy::CreatesNil)),
-        // MATH@GCD / MATH@LCM raise an error on non-integer numeric inputs
-        // (malformed use, cf. CHR) and pass NIL operands through.
-        ("MATH", "GCD") | ("MATH", "LCM") => Some((Partiality::Partial, NilPolicy::Passthrough)),
-        // ALGO@INDEX-OF projects a well-formed miss (value absent from a
-        // valid vector) onto Bubble/NIL with reason = missingField.
-        ("ALGO", "INDEX-OF") => Some((Partiality::Projecting, NilPolicy::CreatesNil)),
-        // MIN / MAX / SORT are total-by-projection (SPEC §7.4.3, §7.14): an
-        // undecidable governing comparison is projected onto the logical
-        // Unknown (U), so they are `Projecting`, not the pure-class default
-        // `Total`. NIL operands pass through (with NIL taking priority over a
-        // U-producing comparison, §4.5.2), so `nil_policy` stays `Passthrough`.
-        ("MATH", "MIN") | ("MATH", "MAX") | ("ALGO", "SORT") => {
-            Some((Partiality::Projecting, NilPolicy::Passthrough))
-        }
-        // TIME@PARSE-ISO projects an unparseable-but-well-formed text value
-        // onto Bubble/NIL with reason = invalidEncoding (cf. NUM).
-        ("TIME", "PARSE-ISO") => Some((Partiality::Projecting, NilPolicy::CreatesNil)),
-        _ => None,
-    }
-}
-
-fn host_capability_for_module_word(module: &str, word: &str) -> Option<HostCapability> {
-    match (module, word) {
-        ("TIME", "NOW") => Some(HostCapability::Clock),
-        ("CRYPTO", "CSPRNG") => Some(HostCapability::SecureRandom),
-        ("SERIAL", _) => Some(HostCapability::Serial),
-        ("MUSIC", _) => Some(HostCapability::Audio),
-        ("JSON", "EXPORT") => Some(HostCapability::JsonExport),
-        ("IO", "INPUT") | ("IO", "OUTPUT") => Some(HostCapability::Effect),
-        _ => None,
-    }
-}
-
-pub(crate) fn module_word_metadata_entries() -> Vec<CorewordMetadata> {
-    MODULE_SPECS
-        .iter()
-        .flat_map(|spec| {
-            spec.words.iter().map(move |word| {
-                let mut metadata = match word.purity {
-                    WordPurity::Pure => {
-                        coreword_registry::pure(word.short_name, &spec.name.to_lowercase())
-                    }
-                    WordPurity::Observable => coreword_registry::observable(
-                        word.short_name,
-                        &spec.name.to_lowercase(),
-                        word.effects,
-                        Some(word.deterministic),
-                    ),
-                    WordPurity::Effectful => coreword_registry::effectful(
-                        word.short_name,
-                        &spec.name.to_lowercase(),
-                        word.effects,
-                    ),
-                };
-                metadata.deterministic = word.deterministic;
-                metadata.safe_preview = word.safe_preview;
-                if let Some(capability) =
-                    host_capability_for_module_word(spec.name, word.short_name)
-                {
-                    metadata.profile = WordProfile::Hosted;
-                    metadata.required_capability = Some(capability);
-                }
-                metadata.canonical_home = CanonicalHome::Module(spec.name.to_string());
-                metadata.listed_in_core = false;
-                metadata.listed_in_modules = vec![spec.name.to_string()];
-                metadata.listed_in_categories = Vec::new();
-                if let Some((partiality, nil_policy)) =
-                    contract_override(spec.name, word.short_name)
-                {
-                    metadata.partiality = partiality;
-                    metadata.nil_policy = nil_policy;
-                }
-                // SPEC §7.14: safety A is reserved for *total* words. A pure
-                // word that the override makes `Partial` (it raises on some
-                // well-shaped input, e.g. MATH@GCD / MATH@LCM on non-integers)
-                // must be safety B ("partial but with explicit error
-                // categories"). `Projecting` is total-by-projection and stays A.
-                if metadata.safety_level == SafetyLevel::A
-                    && metadata.partiality == Partiality::Partial
-                {
-                    metadata.safety_level = SafetyLevel::B;
-                }
-                metadata
-            })
-        })
-        .collect()
-}
-
-#[cfg(test)]
-mod tests {
-    use super::MODULE_SPECS;
-
-    #[test]
-    fn every_module_word_has_authored_four_section_doc() {
-        if let Err(msg) = super::super::module_word_docs::assert_every_word_has_doc(MODULE_SPECS) {
-            panic!("{}", msg);
-        }
-    }
-
-    #[test]
-    fn every_module_word_lookup_contains_all_four_sections() {
-        for module in MODULE_SPECS {
-            for word in module.words {
-                let qualified = format!("{}@{}", module.name, word.short_name);
-                let body = super::lookup_module_word_detail(&qualified)
-                    .unwrap_or_else(|| panic!("{} produced no body", qualified));
-                for section in ["Category:", "Summary:", "Role:", "Stack Effect:"] {
-                    assert!(
-                        body.contains(section),
-                        "{} LOOKUP body missing section {}:\n{}",
-                        qualified,
-                        section,
-                        body
-                    );
-                }
-            }
-        }
-    }
-
-    #[test]
-    fn every_module_word_stack_effect_grammar() {
-        for module in MODULE_SPECS {
-            for word in module.words {
-                let doc = super::super::module_word_docs::lookup_module_word_doc(
-                    module.name,
-                    word.short_name,
-                )
-                .expect("doc lookup");
-                let s = doc.stack_effect;
-                let is_literal_no_op = s == "no values popped or pushed";
-                if is_literal_no_op {
-                    continue;
-                }
-                assert!(
-                    s.contains("->"),
-                    "{}@{} stack_effect missing '->' arrow: {:?}",
-                    module.name,
-                    word.short_name,
-                    s
-                );
-            }
-        }
-    }
-
-    #[test]
-    fn every_module_word_doc_is_utf8_plain_text() {
-        for module in MODULE_SPECS {
-            for word in module.words {
-                let doc = super::super::module_word_docs::lookup_module_word_doc(
-                    module.name,
-                    word.short_name,
-                )
-                .expect("doc lookup");
-                let q = format!("{}@{}", module.name, word.short_name);
-                assert!(
-                    !doc.summary.chars().any(|c| c.is_control() && c != '\n'),
-                    "{} summary must be UTF-8 plain text without control characters",
-                    q
-                );
-                assert!(
-                    !doc.role.chars().any(|c| c.is_control() && c != '\n'),
-                    "{} role must be UTF-8 plain text without control characters",
-                    q
-                );
-                assert!(
-                    !doc.stack_effect.chars().any(|c| c.is_control() && c != '\n'),
-                    "{} stack_effect must be UTF-8 plain text without control characters",
-                    q
-                );
-            }
-        }
-    }
-
-    // The word-info area renders a module word's `description` on a single
-    // line (CSS nowrap + ellipsis). A multi-line description overflows and
-    // gets clipped, so descriptions must stay single-line.
-    #[test]
-    fn module_descriptions_are_single_line() {
-        for module in MODULE_SPECS {
-            for word in module.words {
-                assert!(
-                    !word.description.contains('\n'),
-                    "module {} word {} has a multi-line description",
-                    module.name,
-                    word.short_name
-                );
-            }
-        }
-    }
-}
@@ -0,0 +1,394 @@
+//! Module word table for the MUSIC module.
+
+use crate::coreword_registry::WordPurity;
+use crate::interpreter::audio;
+use crate::types::{Capabilities, Stability};
+
+use super::module_word_types::{module_word, ModuleWord};
+
+pub(super) const MUSIC_WORDS: &[ModuleWord] = &[
+    module_word!(
+        "SEQ",
+        "Set sequential playback mode",
+        audio::op_seq,
+        WordPurity::Effectful,
+        &["audio-control"],
+        false,
+        false,
+        true,
+        Stability::Stable,
+        Capabilities::IO
+    ),
+    module_word!(
+        "SIM",
+        "Set simultaneous playback mode",
+        audio::op_sim,
+        WordPurity::Effectful,
+        &["audio-control"],
+        false,
+        false,
+        true,
+        Stability::Stable,
+        Capabilities::IO
+    ),
+    module_word!(
+        "SLOT",
+        "Set slot duration in seconds",
+        audio::op_slot,
+        WordPurity::Effectful,
+        &["audio-control"],
+        false,
+        false,
+        false,
+        Stability::Stable,
+        Capabilities::IO
+    ),
+    module_word!(
+        "GAIN",
+        "Set volume level (0.0-1.0)",
+        audio::op_gain,
+        WordPurity::Effectful,
+        &["audio-control"],
+        false,
+        false,
+        false,
+        Stability::Stable,
+        Capabilities::IO
+    ),
+    module_word!(
+        "GAIN-RESET",
+        "Reset volume to default (1.0)",
+        audio::op_gain_reset,
+        WordPurity::Effectful,
+        &["audio-control"],
+        false,
+        false,
+        false,
+        Stability::Stable,
+        Capabilities::IO
+    ),
+    module_word!(
+        "PAN",
+        "Set stereo position (-1.0 left to 1.0 right)",
+        audio::op_pan,
+        WordPurity::Effectful,
+        &["audio-control"],
+        false,
+        false,
+        false,
+        Stability::Stable,
+        Capabilities::IO
+    ),
+    module_word!(
+        "PAN-RESET",
+        "Reset pan to center (0.0)",
+        audio::op_pan_reset,
+        WordPurity::Effectful,
+        &["audio-control"],
+        false,
+        false,
+        false,
+        Stability::Stable,
+        Capabilities::IO
+    ),
+    module_word!(
+        "FX-RESET",
+        "Reset all audio effects to defaults",
+        audio::op_fx_reset,
+        WordPurity::Effectful,
+        &["audio-control"],
+        false,
+        false,
+        false,
+        Stability::Stable,
+        Capabilities::IO
+    ),
+    module_word!(
+        "PLAY",
+        "Play audio",
+        audio::op_play,
+        WordPurity::Effectful,
+        &["audio-output"],
+        false,
+        false,
+        false,
+        Stability::Stable,
+        Capabilities::IO
+    ),
+    module_word!(
+        "PLAY-SEQUENCE",
+        "Play [ frequency duration_ms ] pairs in order",
+        audio::op_play_sequence,
+        WordPurity::Effectful,
+        &["audio-output"],
+        false,
+        false,
+        false,
+        Stability::Experimental,
+        Capabilities::IO
+    ),
+    module_word!(
+        "SEQ-GROUP",
+        "Build an explicit sequential music group from a vector",
+        audio::op_seq_group,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "SIM-GROUP",
+        "Build an explicit simultaneous music group from a vector",
+        audio::op_sim_group,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "CHORD",
+        "Build an explicit chord group (simultaneous) from a vector",
+        audio::op_chord,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "HZ",
+        "Build a music.pitch from a frequency in Hz (exact rational)",
+        audio::op_hz,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "MIDI-HZ",
+        "Convert a MIDI note number to its 12-EDO frequency in Hz",
+        audio::op_midi_hz,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "DUR",
+        "Build a music.duration from a number of seconds",
+        audio::op_dur,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "NOTE",
+        "Combine a music.pitch and a music.duration into a music.note",
+        audio::op_note,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "REST",
+        "Build a music.rest from a music.duration",
+        audio::op_rest,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "EDO",
+        "Build an equal-division-of-the-octave music.tuning",
+        audio::op_edo,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "EDR",
+        "Build an equal-division-of-a-ratio music.tuning (non-octave)",
+        audio::op_edr,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "STEP",
+        "Resolve a step within a music.tuning into a music.pitch",
+        audio::op_step,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "VOICE",
+        "Build a music group with the role of a single melodic voice",
+        audio::op_voice,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "TRACK",
+        "Build a music group with the role of an instrument track",
+        audio::op_track,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "MEASURE",
+        "Build a music group with the role of a measure (bar)",
+        audio::op_measure,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "PHRASE",
+        "Build a music group with the role of a phrase",
+        audio::op_phrase,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "WITH-TUNING",
+        "Bind a tuning over a body so bare integers become tuning steps",
+        audio::op_with_tuning,
+        WordPurity::Pure,
+        &[],
+        true,
+        true,
+        false,
+        Stability::Experimental,
+        Capabilities::PURE
+    ),
+    module_word!(
+        "EXPLAIN",
+        "Explain how MUSIC@PLAY would interpret a value",
+        audio::op_explain,
+        WordPurity::Effectful,
+        &["audio-output"],
+        true,
+        false,
+        false,
+        Stability::Experimental,
+        Capabilities::IO
+    ),
+    module_word!(
+        "ADSR",
+        "Set ADSR envelope",
+        audio::op_adsr,
+        WordPurity::Effectful,
+        &["audio-control"],
+        false,
+        false,
+        false,
+        Stability::Stable,
+        Capabilities::IO
+    ),
+    module_word!(
+        "SINE",
+        "Set sine waveform",
+        audio::op_sine,
+        WordPurity::Effectful,
+        &["audio-control"],
+        false,
+        false,
+        false,
+        Stability::Stable,
+        Capabilities::IO
+    ),
+    module_word!(
+        "SQUARE",
+        "Set square waveform",
+        audio::op_square,
+        WordPurity::Effectful,
+        &["audio-control"],
+        false,
+        false,
+        false,
+        Stability::Stable,
+        Capabilities::IO
+    ),
+    module_word!(
+        "SAW",
+        "Set sawtooth waveform",
+        audio::op_saw,
+        WordPurity::Effectful,
+        &["audio-control"],
+        false,
+        false,
+        false,
+        Stability::Stable,
+        Capabilities::IO
+    ),
+    module_word!(
+        "TRI",
+        "Set triangle waveform",
+        audio::op_tri,
+        WordPurity::Effectful,
+        &["audio-control"],
+        false,
+        false,
+        false,
+        Stability::Stable,
+        Capabilities::IO
+    ),
+];
@@ -0,0 +1,102 @@
+//! Test suite for `crate::interpreter::modules::module_builtins`.
+
+#[cfg(test)]
+mod tests {
+    use crate::interpreter::modules::module_builtins::MODULE_SPECS;
+    use crate::interpreter::modules::module_catalog::lookup_module_word_detail;
+    use crate::interpreter::modules::module_word_docs;
+
+    #[test]
+    fn every_module_word_has_authored_four_section_doc() {
+        if let Err(msg) = module_word_docs::assert_every_word_has_doc(MODULE_SPECS) {
+            panic!("{}", msg);
+        }
+    }
+
+    #[test]
+    fn every_module_word_lookup_contains_all_four_sections() {
+        for module in MODULE_SPECS {
+            for word in module.words {
+                let qualified = format!("{}@{}", module.name, word.short_name);
+                let body = lookup_module_word_detail(&qualified)
+                    .unwrap_or_else(|| panic!("{} produced no body", qualified));
+                for section in ["Category:", "Summary:", "Role:", "Stack Effect:"] {
+                    assert!(
+                        body.contains(section),
+                        "{} LOOKUP body missing section {}:\n{}",
+                        qualified,
+                        section,
+                        body
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn every_module_word_stack_effect_grammar() {
+        for module in MODULE_SPECS {
+            for word in module.words {
+                let doc = module_word_docs::lookup_module_word_doc(module.name, word.short_name)
+                    .expect("doc lookup");
+                let s = doc.stack_effect;
+                let is_literal_no_op = s == "no values popped or pushed";
+                if is_literal_no_op {
+                    continue;
+                }
+                assert!(
+                    s.contains("->"),
+                    "{}@{} stack_effect missing '->' arrow: {:?}",
+                    module.name,
+                    word.short_name,
+                    s
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn every_module_word_doc_is_utf8_plain_text() {
+        for module in MODULE_SPECS {
+            for word in module.words {
+                let doc = module_word_docs::lookup_module_word_doc(module.name, word.short_name)
+                    .expect("doc lookup");
+                let q = format!("{}@{}", module.name, word.short_name);
+                assert!(
+                    !doc.summary.chars().any(|c| c.is_control() && c != '\n'),
+                    "{} summary must be UTF-8 plain text without control characters",
+                    q
+                );
+                assert!(
+                    !doc.role.chars().any(|c| c.is_control() && c != '\n'),
+                    "{} role must be UTF-8 plain text without control characters",
+                    q
+                );
+                assert!(
+                    !doc.stack_effect
+                        .chars()
+                        .any(|c| c.is_control() && c != '\n'),
+                    "{} stack_effect must be UTF-8 plain text without control characters",
+                    q
+                );
+            }
+        }
+    }
+
+    // The word-info area renders a module word's `description` on a single
+    // line (CSS nowrap + ellipsis). A multi-line description overflows and
+    // gets clipped, so descriptions must stay single-line.
+    #[test]
+    fn module_descriptions_are_single_line() {
+        for module in MODULE_SPECS {
+            for word in module.words {
+                assert!(
+                    !word.description.contains('\n'),
+                    "module {} word {} has a multi-line description",
+                    module.name,
+                    word.short_name
+                );
+            }
+        }
+    }
+}
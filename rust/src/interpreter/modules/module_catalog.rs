@@ -0,0 +1,137 @@
+//! Read-only views over `MODULE_SPECS`: the GUI catalog, LOOKUP bodies,
+//! and the coreword metadata rows for module words.
+
+use crate::coreword_registry::{
+    self, CanonicalHome, CorewordMetadata, Partiality, SafetyLevel, WordProfile, WordPurity,
+};
+use crate::types::Stability;
+
+use super::module_builtins::MODULE_SPECS;
+use super::module_word_contracts::{contract_override, host_capability_for_module_word};
+
+/// One catalog entry for a module word as declared in
+/// `MODULE_SPECS`, independent of whether it is currently imported. The GUI
+/// uses this to render the full module dictionary (active + inactive words)
+/// so an inactive word can be surfaced greyed-out and toggled with IMPORT-ONLY.
+pub(crate) struct CatalogWord {
+    pub short_name: &'static str,
+    // Read only by the wasm catalog view (feature = "wasm"); populated in all
+    // builds, so the dead-code lint is allowed only when wasm is off.
+    #[cfg_attr(not(feature = "wasm"), allow(dead_code))]
+    pub description: &'static str,
+}
+
+/// All importable module names, in specification order.
+pub(crate) fn available_module_names() -> Vec<&'static str> {
+    MODULE_SPECS.iter().map(|m| m.name).collect()
+}
+
+/// Full word catalog for a module, read directly from `MODULE_SPECS`
+/// so it is available even when the module has never been imported. Returns
+/// `None` when the module name is unknown.
+pub(crate) fn module_catalog_words(module_name: &str) -> Option<Vec<CatalogWord>> {
+    let upper = module_name.to_uppercase();
+    let module = MODULE_SPECS.iter().find(|m| m.name == upper)?;
+    let out: Vec<CatalogWord> = module
+        .words
+        .iter()
+        .map(|w| CatalogWord {
+            short_name: w.short_name,
+            description: w.description,
+        })
+        .collect();
+    Some(out)
+}
+
+pub(crate) fn module_word_description(module_name: &str, short_name: &str) -> Option<&'static str> {
+    let module = MODULE_SPECS.iter().find(|m| m.name == module_name)?;
+    module
+        .words
+        .iter()
+        .find(|w| w.short_name == short_name)
+        .map(|w| w.description)
+}
+
+/// Render the four-section LOOKUP body for a module word, given a
+/// qualified `MODULE@WORD` name (e.g. `"JSON@PARSE"`). Returns `None` if
+/// the word does not exist.
+///
+/// `Category / Summary / Role / Stack Effect` come from the authored
+/// table in `super::module_word_docs`. Category is derived from the
+/// module name; the other three are looked up by `(module, short_name)`.
+pub(crate) fn lookup_module_word_detail(name: &str) -> Option<String> {
+    let upper = name.to_uppercase();
+    let (module_name, short_name) = upper.split_once('@')?;
+    let module = MODULE_SPECS.iter().find(|m| m.name == module_name)?;
+    let word = module.words.iter().find(|w| w.short_name == short_name)?;
+    let stability_str = match word.stability {
+        Stability::Stable => "stable",
+        Stability::Experimental => "experimental",
+    };
+    let category = module.name.to_lowercase();
+    let doc = super::module_word_docs::lookup_module_word_doc(module.name, word.short_name)?;
+    Some(crate::builtins::render_four_section(
+        "",
+        &format!("{}@{}", module.name, word.short_name),
+        stability_str,
+        &category,
+        doc.summary,
+        doc.role,
+        doc.stack_effect,
+    ))
+}
+
+pub(crate) fn module_word_metadata_entries() -> Vec<CorewordMetadata> {
+    MODULE_SPECS
+        .iter()
+        .flat_map(|spec| {
+            spec.words.iter().map(move |word| {
+                let mut metadata = match word.purity {
+                    WordPurity::Pure => {
+                        coreword_registry::pure(word.short_name, &spec.name.to_lowercase())
+                    }
+                    WordPurity::Observable => coreword_registry::observable(
+                        word.short_name,
+                        &spec.name.to_lowercase(),
+                        word.effects,
+                        Some(word.deterministic),
+                    ),
+                    WordPurity::Effectful => coreword_registry::effectful(
+                        word.short_name,
+                        &spec.name.to_lowercase(),
+                        word.effects,
+                    ),
+                };
+                metadata.deterministic = word.deterministic;
+                metadata.safe_preview = word.safe_preview;
+                if let Some(capability) =
+                    host_capability_for_module_word(spec.name, word.short_name)
+                {
+                    metadata.profile = WordProfile::Hosted;
+                    metadata.required_capability = Some(capability);
+                }
+                metadata.canonical_home = CanonicalHome::Module(spec.name.to_string());
+                metadata.listed_in_core = false;
+                metadata.listed_in_modules = vec![spec.name.to_string()];
+                metadata.listed_in_categories = Vec::new();
+                if let Some((partiality, nil_policy)) =
+                    contract_override(spec.name, word.short_name)
+                {
+                    metadata.partiality = partiality;
+                    metadata.nil_policy = nil_policy;
+                }
+                // SPEC §7.14: safety A is reserved for *total* words. A pure
+                // word that the override makes `Partial` (it raises on some
+                // well-shaped input, e.g. MATH@GCD / MATH@LCM on non-integers)
+                // must be safety B ("partial but with explicit error
+                // categories"). `Projecting` is total-by-projection and stays A.
+                if metadata.safety_level == SafetyLevel::A
+                    && metadata.partiality == Partiality::Partial
+                {
+                    metadata.safety_level = SafetyLevel::B;
+                }
+                metadata
+            })
+        })
+        .collect()
+}
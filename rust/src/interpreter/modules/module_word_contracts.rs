@@ -0,0 +1,129 @@
+//! Registry contract overrides and host capabilities for module words.
+
+use crate::coreword_registry::{NilPolicy, Partiality};
+use crate::interpreter::HostCapability;
+
+/// Per-word contract overrides for module words whose `partiality` /
+/// `nil_policy` differ from the purity-class default produced by
+/// `coreword_registry::{pure,observable,effectful}`.
+pub(super) fn contract_override(module: &str, word: &str) -> Option<(Partiality, NilPolicy)> {
+    match (module, word) {
+        // SERIAL@READ projects the no-data / disconnected condition onto
+        // Bubble/NIL (Section 9.4), so it is Projecting/CreatesNil rather
+        // than the effectful default of Partial/RejectsNil.
+        ("SERIAL", "READ") => Some((Partiality::Projecting, NilPolicy::CreatesNil)),
+        // MATH@POW projects 0 raised to a negative exponent onto Bubble/NIL
+        // (reason = divisionByZero) while erroring on malformed use, and
+        // MATH@RECIPROCAL projects a zero the same way DIV does.
+        ("MATH", "POW") | ("MATH", "RECIPROCAL") => {
+            Some((Partiality::Projecting, NilPolicy::CreatesNil))
+        }
+        // MATH@LINSPACE projects an over-budget count onto Bubble/NIL
+        // (reason = spaceExhausted), like RANGE.
+        ("MATH", "LINSPACE") => Some((Partiality::Projecting, NilPolicy::CreatesNil)),
+        // MATH@GCD / MATH@LCM raise an error on non-integer numeric inputs
+        // (malformed use, cf. CHR) and pass NIL operands through.
+        ("MATH", "GCD") | ("MATH", "LCM") => Some((Partiality::Partial, NilPolicy::Passthrough)),
+        // MATH@CONTINUED-FRACTION rejects irrational operands and
+        // MATH@FROM-CONTINUED rejects non-positive tail terms.
+        ("MATH", "CONTINUED-FRACTION") | ("MATH", "FROM-CONTINUED") => {
+            Some((Partiality::Partial, NilPolicy::Passthrough))
+        }
+        // MATH@NUMERATOR / MATH@DENOMINATOR reject text and irrational
+        // operands, which have no fraction parts.
+        // MATH@TOMIXED and MATH@PERCENT reject non-numeric operands the
+        // same way, and PERCENT also a negative digit count; MATH@ROUNDTO
+        // also rejects a denominator that is not a positive integer.
+        ("MATH", "NUMERATOR")
+        | ("MATH", "DENOMINATOR")
+        | ("MATH", "TOMIXED")
+        | ("MATH", "PERCENT")
+        | ("MATH", "ROUNDTO") => Some((Partiality::Partial, NilPolicy::Passthrough)),
+        // ALGO@COMPRESS raises an error when the mask length differs from
+        // the values length.
+        ("ALGO", "COMPRESS") => Some((Partiality::Partial, NilPolicy::Passthrough)),
+        // ALGO@UPTO shares RANGE's contract: an over-budget sequence
+        // projects onto Bubble/NIL (reason = spaceExhausted).
+        // ALGO@REPEAT-VALUE and ALGO@CARTESIAN do the same for an
+        // over-budget count or product.
+        ("ALGO", "UPTO") | ("ALGO", "REPEAT-VALUE") | ("ALGO", "CARTESIAN") => {
+            Some((Partiality::Projecting, NilPolicy::CreatesNil))
+        }
+        // ALGO@GETOR answers the default for an out-of-range index or a
+        // NIL vector, and raises an error only on a malformed index.
+        ("ALGO", "GETOR") => Some((Partiality::Partial, NilPolicy::ConsumesNil)),
+        // ALGO@SWAPAT raises an error on an out-of-range index, a NIL
+        // (empty) vector included.
+        ("ALGO", "SWAPAT") => Some((Partiality::Partial, NilPolicy::RejectsNil)),
+        // ALGO@ROTATE raises an error on a non-integer shift.
+        ("ALGO", "ROTATE") => Some((Partiality::Partial, NilPolicy::Passthrough)),
+        // ALGO@PATHGET projects a path that runs off the structure onto
+        // Bubble/NIL (reason = indexOutOfBounds), like GET.
+        ("ALGO", "PATHGET") => Some((Partiality::Projecting, NilPolicy::CreatesNil)),
+        // ALGO@PATHSET raises an error naming the step where the path
+        // leaves the structure, a NIL structure included.
+        ("ALGO", "PATHSET") => Some((Partiality::Partial, NilPolicy::RejectsNil)),
+        // ALGO@TIMES-INDEXED raises an error on a malformed count or an
+        // unknown body word, NIL count included.
+        ("ALGO", "TIMES-INDEXED") => Some((Partiality::Partial, NilPolicy::RejectsNil)),
+        // ALGO@CASE raises an error when nothing matches and there is no
+        // default; a NIL scrutinee is matched like any other value.
+        ("ALGO", "CASE") => Some((Partiality::Partial, NilPolicy::ConsumesNil)),
+        // ALGO@DISPATCH raises an error on malformed clauses, unknown words
+        // or a non-Boolean condition result, NIL clauses included.
+        ("ALGO", "DISPATCH") => Some((Partiality::Partial, NilPolicy::RejectsNil)),
+        // ALGO@SCANINIT raises SCAN's errors; a NIL vector is the empty
+        // sequence and yields [ init ].
+        ("ALGO", "SCANINIT") => Some((Partiality::Partial, NilPolicy::ConsumesNil)),
+        // ALGO@INDEX-OF, ALGO@BINARY-SEARCH and ALGO@ASSOC-GET project a
+        // well-formed miss (value or key absent from a valid vector) onto
+        // Bubble/NIL with reason = missingField.
+        ("ALGO", "INDEX-OF") | ("ALGO", "BINARY-SEARCH") | ("ALGO", "ASSOC-GET") => {
+            Some((Partiality::Projecting, NilPolicy::CreatesNil))
+        }
+        // MIN / MAX / SORT / MERGE-SORTED are total-by-projection (SPEC
+        // §7.4.3, §7.14): an undecidable governing comparison is projected
+        // onto the logical Unknown (U), so they are `Projecting`, not the
+        // pure-class default `Total`. NIL operands pass through (with NIL
+        // taking priority over a U-producing comparison, §4.5.2), so
+        // `nil_policy` stays `Passthrough`.
+        ("MATH", "MIN")
+        | ("MATH", "MAX")
+        | ("MATH", "CLAMP")
+        | ("ALGO", "SORT")
+        | ("ALGO", "MERGE-SORTED") => Some((Partiality::Projecting, NilPolicy::Passthrough)),
+        // TIME@PARSE-ISO projects an unparseable-but-well-formed text value
+        // onto Bubble/NIL with reason = invalidEncoding (cf. NUM).
+        ("TIME", "PARSE-ISO") => Some((Partiality::Projecting, NilPolicy::CreatesNil)),
+        // REFLECT@ROUNDTRIP? inspects any value, NIL included, and always
+        // answers with a Boolean.
+        ("REFLECT", "ROUNDTRIP?") => Some((Partiality::Total, NilPolicy::ConsumesNil)),
+        // REFLECT@TYPE names any value, answering `'nil'` for NIL.
+        ("REFLECT", "TYPE") => Some((Partiality::Total, NilPolicy::ConsumesNil)),
+        // STATS@BUCKET raises an error on malformed edges and reads NIL
+        // values as an empty dataset.
+        ("STATS", "BUCKET") => Some((Partiality::Partial, NilPolicy::ConsumesNil)),
+        // STATS@VARIANCE / STATS@STDDEV raise an error on non-numeric
+        // elements and pass a NIL dataset through.
+        ("STATS", "VARIANCE") | ("STATS", "STDDEV") => {
+            Some((Partiality::Partial, NilPolicy::Passthrough))
+        }
+        _ => None,
+    }
+}
+
+pub(super) fn host_capability_for_module_word(module: &str, word: &str) -> Option<HostCapability> {
+    match (module, word) {
+        ("TIME", "NOW") => Some(HostCapability::Clock),
+        ("CRYPTO", "CSPRNG") | ("CRYPTO", "SHUFFLE") | ("CRYPTO", "SAMPLE") => {
+            Some(HostCapability::SecureRandom)
+        }
+        ("SERIAL", _) => Some(HostCapability::Serial),
+        ("MUSIC", _) => Some(HostCapability::Audio),
+        ("JSON", "EXPORT") => Some(HostCapability::JsonExport),
+        ("IO", "INPUT") | ("IO", "OUTPUT") | ("IO", "TABLE") | ("IO", "SAVE") | ("IO", "LOAD") => {
+            Some(HostCapability::Effect)
+        }
+        _ => None,
+    }
+}
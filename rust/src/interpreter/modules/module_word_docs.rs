@@ -1,3 +1,5 @@
+use super::module_word_docs_math::MATH_WORD_DOCS;
+use super::module_word_docs_music::MUSIC_WORD_DOCS;
 #[cfg(test)]
 use super::module_word_types::ModuleSpec;
 
@@ -14,18 +16,24 @@ pub(super) struct ModuleWordDoc {
     pub stack_effect: &'static str,
 }
 
-pub(super) fn lookup_module_word_doc(module: &str, word: &str) -> Option<&'static ModuleWordDoc> {
+/// Every authored doc: the table below followed by the MATH and MUSIC
+/// tables, which live in their own files because of their size.
+fn all_module_word_docs() -> impl Iterator<Item = &'static ModuleWordDoc> {
     MODULE_WORD_DOCS
         .iter()
-        .find(|d| d.module == module && d.word == word)
+        .chain(MATH_WORD_DOCS)
+        .chain(MUSIC_WORD_DOCS)
+}
+
+pub(super) fn lookup_module_word_doc(module: &str, word: &str) -> Option<&'static ModuleWordDoc> {
+    all_module_word_docs().find(|d| d.module == module && d.word == word)
 }
 
 #[cfg(test)]
 pub(super) fn assert_every_word_has_doc(specs: &[ModuleSpec]) -> Result<(), String> {
     for module in specs {
         for word in module.words {
-            let doc = MODULE_WORD_DOCS
-                .iter()
+            let doc = all_module_word_docs()
                 .find(|d| d.module == module.name && d.word == word.short_name);
             match doc {
                 None => {
@@ -69,6 +77,20 @@ const MODULE_WORD_DOCS: &[ModuleWordDoc] = &[
         role: "Pure table writer: the inverse of CSV-PARSE. A non-table or shape-mismatched input projects to a reasoned NIL.",
         stack_effect: "[ records ] -> [ text ]",
     },
+    ModuleWordDoc {
+        module: "DATA",
+        word: "CSV-PARSE-ROWS",
+        summary: "Parse CSV text into a vector of rows, each a vector of text cells (no header).",
+        role: "Pure row reader: quoted fields may hold commas, doubled quotes, and line breaks; cells stay text (use NUM). An unterminated quote projects to a reasoned NIL.",
+        stack_effect: "[ text ] -> [ rows ]",
+    },
+    ModuleWordDoc {
+        module: "DATA",
+        word: "CSV-STRINGIFY-ROWS",
+        summary: "Render a vector of rows of text cells as CSV text, one line per row.",
+        role: "Pure row writer: the inverse of CSV-PARSE-ROWS, quoting only where needed. A non-vector row or non-text cell projects to a reasoned NIL.",
+        stack_effect: "[ rows ] -> [ text ]",
+    },
     ModuleWordDoc {
         module: "DATA",
         word: "SELECT",
@@ -98,219 +120,6 @@ const MODULE_WORD_DOCS: &[ModuleWordDoc] = &[
         stack_effect: "[ left ] [ right ] [ key ] -> [ table ]",
     },
     // ==================================================================
-    // MUSIC
-    // ==================================================================
-    ModuleWordDoc {
-        module: "MUSIC",
-        word: "SEQ",
-        summary: "Set the active playback mode to sequential.",
-        role: "Playback-mode modifier: subsequent grouping words emit notes in order.",
-        stack_effect: "no values popped or pushed",
-    },
-    ModuleWordDoc {
-        module: "MUSIC",
-        word: "SIM",
-        summary: "Set the active playback mode to simultaneous.",
-        role: "Playback-mode modifier: subsequent grouping words emit notes in parallel.",
-        stack_effect: "no values popped or pushed",
-    },
-    ModuleWordDoc {
-        module: "MUSIC",
-        word: "SLOT",
-        summary: "Set the slot duration (in seconds) used by bare notes.",
-        role: "Timing control for the default slot length.",
-        stack_effect: "[ secs ] -> no values popped or pushed",
-    },
-    ModuleWordDoc {
-        module: "MUSIC",
-        word: "GAIN",
-        summary: "Set the master output gain (0.0-1.0).",
-        role: "Output-level control for the audio engine.",
-        stack_effect: "[ level ] -> no values popped or pushed",
-    },
-    ModuleWordDoc {
-        module: "MUSIC",
-        word: "GAIN-RESET",
-        summary: "Reset the master gain to the default 1.0.",
-        role: "Audio control that restores the default output level.",
-        stack_effect: "no values popped or pushed",
-    },
-    ModuleWordDoc {
-        module: "MUSIC",
-        word: "PAN",
-        summary: "Set the stereo pan position (-1.0 left .. 1.0 right).",
-        role: "Stereo-placement control for the audio engine.",
-        stack_effect: "[ pan ] -> no values popped or pushed",
-    },
-    ModuleWordDoc {
-        module: "MUSIC",
-        word: "PAN-RESET",
-        summary: "Reset pan to center (0.0).",
-        role: "Audio control that restores the default stereo position.",
-        stack_effect: "no values popped or pushed",
-    },
-    ModuleWordDoc {
-        module: "MUSIC",
-        word: "FX-RESET",
-        summary: "Reset all audio effects (gain, pan, envelope, waveform) to defaults.",
-        role: "Bulk audio-control reset.",
-        stack_effect: "no values popped or pushed",
-    },
-    ModuleWordDoc {
-        module: "MUSIC",
-        word: "PLAY",
-        summary: "Play a music value (note, group, voice, ...).",
-        role: "Primary audio-output trigger for the music module.",
-        stack_effect: "[ music ] -> no values popped or pushed",
-    },
-    ModuleWordDoc {
-        module: "MUSIC",
-        word: "SEQ-GROUP",
-        summary: "Build an explicit sequential music group from a vector of notes.",
-        role: "Structural grouping word that fixes sequential semantics independent of the ambient mode.",
-        stack_effect: "[ notes ] -> [ group ]",
-    },
-    ModuleWordDoc {
-        module: "MUSIC",
-        word: "SIM-GROUP",
-        summary: "Build an explicit simultaneous music group from a vector of notes.",
-        role: "Structural grouping word that fixes simultaneous semantics independent of the ambient mode.",
-        stack_effect: "[ notes ] -> [ group ]",
-    },
-    ModuleWordDoc {
-        module: "MUSIC",
-        word: "CHORD",
-        summary: "Build a chord (simultaneous group) from a vector of pitches or notes.",
-        role: "Convenience constructor for harmonic groupings.",
-        stack_effect: "[ pitches ] -> [ chord ]",
-    },
-    ModuleWordDoc {
-        module: "MUSIC",
-        word: "HZ",
-        summary: "Build a music.pitch from a frequency in hertz.",
-        role: "Pitch constructor that names a frequency exactly as a rational.",
-        stack_effect: "[ hz ] -> [ pitch ]",
-    },
-    ModuleWordDoc {
-        module: "MUSIC",
-        word: "DUR",
-        summary: "Build a music.duration from a number of seconds.",
-        role: "Duration constructor for use in NOTE / REST.",
-        stack_effect: "[ secs ] -> [ duration ]",
-    },
-    ModuleWordDoc {
-        module: "MUSIC",
-        word: "NOTE",
-        summary: "Combine a music.pitch and a music.duration into a music.note.",
-        role: "Primary note constructor.",
-        stack_effect: "[ pitch ] [ duration ] -> [ note ]",
-    },
-    ModuleWordDoc {
-        module: "MUSIC",
-        word: "REST",
-        summary: "Build a music.rest from a music.duration.",
-        role: "Constructor for silence within a sequence.",
-        stack_effect: "[ duration ] -> [ rest ]",
-    },
-    ModuleWordDoc {
-        module: "MUSIC",
-        word: "EDO",
-        summary: "Build an equal-division-of-the-octave tuning.",
-        role: "Tuning constructor for N-equal divisions of 2/1.",
-        stack_effect: "[ ref-hz ] [ divisions ] -> [ tuning ]",
-    },
-    ModuleWordDoc {
-        module: "MUSIC",
-        word: "EDR",
-        summary: "Build an equal-division-of-a-ratio tuning (non-octave).",
-        role: "Tuning constructor for N-equal divisions of an arbitrary equave.",
-        stack_effect: "[ ref-hz ] [ equave ] [ divisions ] -> [ tuning ]",
-    },
-    ModuleWordDoc {
-        module: "MUSIC",
-        word: "STEP",
-        summary: "Resolve a tuning step into an exact music.pitch.",
-        role: "Index-into-tuning operator.",
-        stack_effect: "[ tuning ] [ step ] -> [ pitch ]",
-    },
-    ModuleWordDoc {
-        module: "MUSIC",
-        word: "VOICE",
-        summary: "Build a music group with the role of a single melodic voice.",
-        role: "Structural role tag for a single-line voice.",
-        stack_effect: "[ notes ] -> [ voice ]",
-    },
-    ModuleWordDoc {
-        module: "MUSIC",
-        word: "TRACK",
-        summary: "Build a music group with the role of an instrument track.",
-        role: "Structural role tag for an instrument track.",
-        stack_effect: "[ notes ] -> [ track ]",
-    },
-    ModuleWordDoc {
-        module: "MUSIC",
-        word: "MEASURE",
-        summary: "Build a music group with the role of a measure (bar).",
-        role: "Structural role tag marking a measure boundary.",
-        stack_effect: "[ notes ] -> [ measure ]",
-    },
-    ModuleWordDoc {
-        module: "MUSIC",
-        word: "PHRASE",
-        summary: "Build a music group with the role of a phrase.",
-        role: "Structural role tag for a phrase grouping.",
-        stack_effect: "[ notes ] -> [ phrase ]",
-    },
-    ModuleWordDoc {
-        module: "MUSIC",
-        word: "WITH-TUNING",
-        summary: "Bind a tuning over a body so bare integers are read as tuning steps.",
-        role: "Scoped binding that re-interprets numeric literals as steps within a tuning.",
-        stack_effect: "[ tuning ] [ body ] -> [ scope ]",
-    },
-    ModuleWordDoc {
-        module: "MUSIC",
-        word: "EXPLAIN",
-        summary: "Describe how MUSIC@PLAY would interpret a value, without playing it.",
-        role: "Diagnostic / inspection word for music values.",
-        stack_effect: "[ music ] -> [ music ]",
-    },
-    ModuleWordDoc {
-        module: "MUSIC",
-        word: "ADSR",
-        summary: "Set the ADSR envelope used by subsequent notes.",
-        role: "Envelope control for the audio engine.",
-        stack_effect: "[ target ] [ params ] -> [ target ]",
-    },
-    ModuleWordDoc {
-        module: "MUSIC",
-        word: "SINE",
-        summary: "Select the sine waveform on a target.",
-        role: "Waveform control for synthesised notes.",
-        stack_effect: "[ target ] -> [ target ]",
-    },
-    ModuleWordDoc {
-        module: "MUSIC",
-        word: "SQUARE",
-        summary: "Select the square waveform on a target.",
-        role: "Waveform control for synthesised notes.",
-        stack_effect: "[ target ] -> [ target ]",
-    },
-    ModuleWordDoc {
-        module: "MUSIC",
-        word: "SAW",
-        summary: "Select the sawtooth waveform on a target.",
-        role: "Waveform control for synthesised notes.",
-        stack_effect: "[ target ] -> [ target ]",
-    },
-    ModuleWordDoc {
-        module: "MUSIC",
-        word: "TRI",
-        summary: "Select the triangle waveform on a target.",
-        role: "Waveform control for synthesised notes.",
-        stack_effect: "[ target ] -> [ target ]",
-    },
-    // ==================================================================
     // JSON
     // ==================================================================
     ModuleWordDoc {
@@ -400,6 +209,34 @@ const MODULE_WORD_DOCS: &[ModuleWordDoc] = &[
         role: "Effectful host I/O egress for textual output.",
         stack_effect: "[ value ] -> no values popped or pushed",
     },
+    ModuleWordDoc {
+        module: "IO",
+        word: "TABLE",
+        summary: "Print a vector of equal-length row vectors as a column-aligned grid.",
+        role: "Readable output for tabular data; numeric columns are right-aligned, cells render as STR.",
+        stack_effect: "[ [ row ] ... ] -> no values popped or pushed",
+    },
+    ModuleWordDoc {
+        module: "IO",
+        word: "BREAK",
+        summary: "Record the current stack in the debug buffer; stop with a Breakpoint error while the host has pausing on.",
+        role: "Interactive breakpoint for debugging definitions; separate from PRINT output.",
+        stack_effect: "no values popped or pushed",
+    },
+    ModuleWordDoc {
+        module: "IO",
+        word: "SAVE",
+        summary: "Save every user word, with the imports their bodies use, to browser localStorage under a text key.",
+        role: "Browser persistence of definitions; the native build raises an error.",
+        stack_effect: "[ 'key' ] -> no values popped or pushed",
+    },
+    ModuleWordDoc {
+        module: "IO",
+        word: "LOAD",
+        summary: "Define the words saved under a text key, replacing same-named words and leaving the stack as it is.",
+        role: "Browser persistence of definitions; the native build raises an error.",
+        stack_effect: "[ 'key' ] -> no values popped or pushed",
+    },
     // ==================================================================
     // TIME
     // ==================================================================
@@ -490,14 +327,14 @@ const MODULE_WORD_DOCS: &[ModuleWordDoc] = &[
     ModuleWordDoc {
         module: "TIME",
         word: "ADD-DAYS",
-        summary: "Shift a date or datetime by N whole days.",
+        summary: "Shift a date, datetime, or timestamp by N whole days.",
         role: "Calendar arithmetic in whole-day units.",
-        stack_effect: "[ date-or-datetime ] [ n ] -> [ date-or-datetime' ]",
+        stack_effect: "[ date-datetime-or-timestamp ] [ n ] -> [ same-shape' ]",
     },
     ModuleWordDoc {
         module: "TIME",
         word: "DIFF-DAYS",
-        summary: "Whole-day difference (a - b) between two dates or datetimes.",
+        summary: "Whole-day difference (a - b) between two dates, datetimes, or timestamps.",
         role: "Calendar arithmetic returning a signed day count.",
         stack_effect: "[ a ] [ b ] -> [ days ]",
     },
@@ -515,6 +352,20 @@ const MODULE_WORD_DOCS: &[ModuleWordDoc] = &[
         role: "ISO-8601 text ingress (projecting).",
         stack_effect: "[ text ] -> [ datetime | NIL ]",
     },
+    ModuleWordDoc {
+        module: "TIME",
+        word: "FORMAT-PATTERN",
+        summary: "Render a date or datetime through a strftime-like pattern.",
+        role: "Patterned text egress; the timezone is fixed earlier by DATETIME.",
+        stack_effect: "[ date-or-datetime ] [ pattern ] -> [ text ]",
+    },
+    ModuleWordDoc {
+        module: "TIME",
+        word: "PARSE-PATTERN",
+        summary: "Parse text through a strftime-like pattern into a datetime.",
+        role: "Patterned text ingress; mismatches and impossible dates are errors.",
+        stack_effect: "[ text ] [ pattern ] -> [ datetime ]",
+    },
     ModuleWordDoc {
         module: "TIME",
         word: "ADD-MONTHS",
@@ -539,6 +390,20 @@ const MODULE_WORD_DOCS: &[ModuleWordDoc] = &[
         role: "Observable host source of cryptographic randomness.",
         stack_effect: "[ denom ] [ count ] -> [ randoms ]",
     },
+    ModuleWordDoc {
+        module: "CRYPTO",
+        word: "SHUFFLE",
+        summary: "Randomly permute a vector with a Fisher-Yates shuffle.",
+        role: "Observable consumer of the host's secure random source.",
+        stack_effect: "[ vec ] -> [ permuted ]",
+    },
+    ModuleWordDoc {
+        module: "CRYPTO",
+        word: "SAMPLE",
+        summary: "Pick k distinct elements of a vector at random, without replacement.",
+        role: "Observable consumer of the host's secure random source.",
+        stack_effect: "[ vec ] [ k ] -> [ picked ]",
+    },
     ModuleWordDoc {
         module: "CRYPTO",
         word: "HASH",
@@ -556,6 +421,13 @@ const MODULE_WORD_DOCS: &[ModuleWordDoc] = &[
         role: "General sorting primitive for the algo module.",
         stack_effect: "[ vec ] -> [ sorted ]",
     },
+    ModuleWordDoc {
+        module: "ALGO",
+        word: "MERGE-SORTED",
+        summary: "Merge two ascending vectors into one ascending vector.",
+        role: "Two-pointer merge step for merge-sort-style algorithms; inputs are not checked for sortedness.",
+        stack_effect: "[ left ] [ right ] -> [ merged ]",
+    },
     ModuleWordDoc {
         module: "ALGO",
         word: "UNIQUE",
@@ -563,6 +435,13 @@ const MODULE_WORD_DOCS: &[ModuleWordDoc] = &[
         role: "Deduplication primitive.",
         stack_effect: "[ vec ] -> [ unique ]",
     },
+    ModuleWordDoc {
+        module: "ALGO",
+        word: "COMPRESS",
+        summary: "Keep the elements whose mask element is TRUE; FALSE, UNKNOWN and NIL drop them.",
+        role: "Mask-driven selection, pairing with a boolean vector built by MAP; lengths must match.",
+        stack_effect: "[ values ] [ mask ] -> [ kept ]",
+    },
     ModuleWordDoc {
         module: "ALGO",
         word: "CONTAINS",
@@ -577,127 +456,124 @@ const MODULE_WORD_DOCS: &[ModuleWordDoc] = &[
         role: "Linear-search primitive that projects misses onto NIL.",
         stack_effect: "[ vec ] [ value ] -> [ index | NIL ]",
     },
-    // ==================================================================
-    // MATH
-    // ==================================================================
     ModuleWordDoc {
-        module: "MATH",
-        word: "SQRT",
-        summary: "Square root. Exact rational roots stay exact; otherwise returns a sound interval.",
-        role: "Numeric primitive with exact/interval dispatch.",
-        stack_effect: "[ x ] -> [ root ]",
+        module: "ALGO",
+        word: "BINARY-SEARCH",
+        summary: "Lowest index of the value in an ascending vector; Bubble/NIL if absent.",
+        role: "Logarithmic-search counterpart of INDEX-OF for sorted data; sortedness is not checked.",
+        stack_effect: "[ sorted ] [ value ] -> [ index | NIL ]",
     },
     ModuleWordDoc {
-        module: "MATH",
-        word: "SQRT-EPS",
-        summary: "Square root with an explicit interval width bound eps.",
-        role: "Width-controlled variant of SQRT for interval arithmetic.",
-        stack_effect: "[ x ] [ eps ] -> [ root ]",
+        module: "ALGO",
+        word: "ASSOC-GET",
+        summary: "Value paired with a key in a [ key value ] vector; Bubble/NIL if absent.",
+        role: "Association-list lookup with the same key equality as INDEX-OF.",
+        stack_effect: "[ [ [ k v ] ... ] ] [ key ] -> [ value | NIL ]",
     },
     ModuleWordDoc {
-        module: "MATH",
-        word: "INTERVAL",
-        summary: "Create a sound interval [ lo, hi ].",
-        role: "Interval constructor.",
-        stack_effect: "[ lo ] [ hi ] -> [ interval ]",
+        module: "ALGO",
+        word: "ASSOC-SET",
+        summary: "Insert or update a key in a [ key value ] vector.",
+        role: "Functional association-list update; an existing key keeps its position.",
+        stack_effect: "[ [ [ k v ] ... ] ] [ key ] [ value ] -> [ [ [ k v ] ... ] ]",
     },
     ModuleWordDoc {
-        module: "MATH",
-        word: "LOWER",
-        summary: "Lower endpoint of a number or interval.",
-        role: "Endpoint projection for interval values.",
-        stack_effect: "[ x ] -> [ lo ]",
+        module: "ALGO",
+        word: "ASSOC-KEYS",
+        summary: "Keys of a [ key value ] vector, in order.",
+        role: "Association-list projection onto its first column.",
+        stack_effect: "[ [ [ k v ] ... ] ] -> [ [ k ... ] ]",
     },
     ModuleWordDoc {
-        module: "MATH",
-        word: "UPPER",
-        summary: "Upper endpoint of a number or interval.",
-        role: "Endpoint projection for interval values.",
-        stack_effect: "[ x ] -> [ hi ]",
+        module: "ALGO",
+        word: "ASSOC-VALUES",
+        summary: "Values of a [ key value ] vector, in order.",
+        role: "Association-list projection onto its second column.",
+        stack_effect: "[ [ [ k v ] ... ] ] -> [ [ v ... ] ]",
     },
     ModuleWordDoc {
-        module: "MATH",
-        word: "WIDTH",
-        summary: "Width of an interval (hi - lo).",
-        role: "Interval-width projection.",
-        stack_effect: "[ x ] -> [ width ]",
+        module: "ALGO",
+        word: "UPTO",
+        summary: "Half-open integer sequence from a [start, end) pair.",
+        role: "RANGE with the end excluded; same optional step and errors, NIL for equal bounds.",
+        stack_effect: "[ start end ] -> [ seq ]",
     },
     ModuleWordDoc {
-        module: "MATH",
-        word: "IS-EXACT",
-        summary: "True for an exact number or a degenerate (zero-width) interval.",
-        role: "Predicate distinguishing exact values from sound intervals.",
-        stack_effect: "[ x ] -> [ bool ]",
+        module: "ALGO",
+        word: "REPEAT-VALUE",
+        summary: "Vector of n copies of any value; a count of 0 gives NIL.",
+        role: "Constructor for repeated values, nested vectors included; core FILL fills a tensor shape with a scalar.",
+        stack_effect: "value [ n ] -> [ value ... ]",
     },
     ModuleWordDoc {
-        module: "MATH",
-        word: "ABS",
-        summary: "Absolute value of a number.",
-        role: "Sign-stripping numeric primitive.",
-        stack_effect: "[ x ] -> [ abs ]",
+        module: "ALGO",
+        word: "ROTATE",
+        summary: "Rotate a vector left by k (right for negative k), with k taken modulo the length.",
+        role: "Cyclic shift for ring buffers and ciphers; a zero or full-length shift returns the vector unchanged.",
+        stack_effect: "[ vec ] [ k ] -> [ vec' ]",
     },
     ModuleWordDoc {
-        module: "MATH",
-        word: "NEG",
-        summary: "Numeric negation.",
-        role: "Sign-flipping numeric primitive.",
-        stack_effect: "[ x ] -> [ -x ]",
+        module: "ALGO",
+        word: "CARTESIAN",
+        summary: "Every [ a b ] pair from two vectors, ordered by the first then the second.",
+        role: "Cartesian product for combinatorics; an empty (NIL) factor gives NIL.",
+        stack_effect: "[ a ] [ b ] -> [ [ a0 b0 ] [ a0 b1 ] ... ]",
     },
     ModuleWordDoc {
-        module: "MATH",
-        word: "SIGN",
-        summary: "Sign of a number: -1, 0, or 1.",
-        role: "Sign extraction primitive.",
-        stack_effect: "[ x ] -> [ sign ]",
+        module: "ALGO",
+        word: "GETOR",
+        summary: "Element at an index as GET resolves it, or a default when out of range.",
+        role: "Bounds-safe lookup for iteration; a NIL vector yields the default.",
+        stack_effect: "[ vec ] [ index ] default -> element | default",
     },
     ModuleWordDoc {
-        module: "MATH",
-        word: "MIN",
-        summary: "Smaller of two numbers.",
-        role: "Ordering primitive returning the lesser operand.",
-        stack_effect: "[ a ] [ b ] -> [ min ]",
+        module: "ALGO",
+        word: "SWAPAT",
+        summary: "Copy of a vector with the elements at two indices exchanged.",
+        role: "Element swap for sorting-algorithm demos; negative indices count from the end as in GET.",
+        stack_effect: "[ vec ] [ i ] [ j ] -> [ vec' ]",
     },
     ModuleWordDoc {
-        module: "MATH",
-        word: "MAX",
-        summary: "Larger of two numbers.",
-        role: "Ordering primitive returning the greater operand.",
-        stack_effect: "[ a ] [ b ] -> [ max ]",
+        module: "ALGO",
+        word: "PATHGET",
+        summary: "Element addressed by a path of indices into nested vectors; Bubble/NIL if a step runs off.",
+        role: "Deep lookup without chained GETs; negative steps count from the end as in GET.",
+        stack_effect: "[ nested ] [ path ] -> element | NIL",
     },
     ModuleWordDoc {
-        module: "MATH",
-        word: "POW",
-        summary: "Integer-exponent exact power: base^exp.",
-        role: "Exact-power primitive; projects 0^negative onto Bubble/NIL.",
-        stack_effect: "[ base ] [ exp ] -> [ result ]",
+        module: "ALGO",
+        word: "PATHSET",
+        summary: "Copy of nested vectors with the element a path of indices addresses replaced.",
+        role: "Functional deep update complementing PATHGET; an out-of-range step is an error naming it.",
+        stack_effect: "[ nested ] [ path ] value -> [ nested' ]",
     },
     ModuleWordDoc {
-        module: "MATH",
-        word: "GCD",
-        summary: "Greatest common divisor of two integers.",
-        role: "Integer number-theory primitive.",
-        stack_effect: "[ a ] [ b ] -> [ gcd ]",
+        module: "ALGO",
+        word: "TIMES-INDEXED",
+        summary: "Run a body N times, pushing the 0-based index before each run.",
+        role: "Counted loop on the live stack; the body sees the index on top and everything below it.",
+        stack_effect: "[ n ] { body } -> [ results of body on 0 .. n-1 ]",
     },
     ModuleWordDoc {
-        module: "MATH",
-        word: "LCM",
-        summary: "Least common multiple of two integers.",
-        role: "Integer number-theory primitive.",
-        stack_effect: "[ a ] [ b ] -> [ lcm ]",
+        module: "ALGO",
+        word: "CASE",
+        summary: "Run the action paired with the first candidate equal to the scrutinee, or a trailing default.",
+        role: "Switch over constants; like a COND body, the action sees only the scrutinee and its result replaces it.",
+        stack_effect: "value [ candidates ] { action } ... { default }? -> [ action result ]",
     },
     ModuleWordDoc {
-        module: "MATH",
-        word: "PI",
-        summary: "Push the exact real pi as a refinable rational enclosure.",
-        role: "Tier 2 numeric constant; its order can be observed within a water budget.",
-        stack_effect: "[ ] -> [ pi ]",
+        module: "ALGO",
+        word: "DISPATCH",
+        summary: "Run the action word of the first [ 'condition' 'action' ] clause whose condition leaves TRUE; a trailing [ 'action' ] is the default.",
+        role: "Data-driven dispatch by word name; conditions run on a copy of the stack, the chosen action on the live stack.",
+        stack_effect: "[ [ 'condition' 'action' ] ... ] -> [ effect of the chosen action ]",
     },
     ModuleWordDoc {
-        module: "MATH",
-        word: "ENCLOSE",
-        summary: "Observe a value's rational enclosure within an explicit water budget.",
-        role: "Water-explicit observation returning a sound [ lo, hi ] interval.",
-        stack_effect: "[ x ] [ budget ] -> [ interval ]",
+        module: "ALGO",
+        word: "SCANINIT",
+        summary: "Running accumulations of SCAN preceded by the initial value, one element longer than the vector.",
+        role: "Prefix sums that include the seed; a NIL vector gives [ init ].",
+        stack_effect: "[ vec ] [ init ] 'WORD' -> [ init acc1 ... accN ]",
     },
     // ==================================================================
     // SERIAL
@@ -751,4 +627,105 @@ const MODULE_WORD_DOCS: &[ModuleWordDoc] = &[
         role: "Effectful host-resource release.",
         stack_effect: "[ handle ] -> no values popped or pushed",
     },
+    // ==================================================================
+    // REFLECT
+    // ==================================================================
+    ModuleWordDoc {
+        module: "REFLECT",
+        word: "ROUNDTRIP?",
+        summary: "Re-evaluate a value's stack rendering and test that it reconstructs the same value and role.",
+        role: "Representation self-check; prints a diagnostic on mismatch and leaves the value in place.",
+        stack_effect: "[ value ] -> [ value ] [ bool ]",
+    },
+    ModuleWordDoc {
+        module: "REFLECT",
+        word: "TYPE",
+        summary: "Push the name of the kind a value renders as.",
+        role: "Value introspection through the value protocol: 'number', 'string', 'boolean', 'nil', 'vector', 'datetime', or 'code' for a code block.",
+        stack_effect: "[ value ] -> [ 'type' ]",
+    },
+    ModuleWordDoc {
+        module: "REFLECT",
+        word: "SOURCE",
+        summary: "Push the definition of a named word as text.",
+        role: "Dictionary introspection; a user word renders as a re-runnable DEF line, a built-in as its description.",
+        stack_effect: "[ 'name' ] -> [ 'source' ]",
+    },
+    ModuleWordDoc {
+        module: "REFLECT",
+        word: "WORDS",
+        summary: "Push the sorted names of the user words, or of every callable word.",
+        role: "Dictionary introspection; TRUE lists user words only, FALSE adds the core and imported module words.",
+        stack_effect: "[ flag ] -> [ [ 'name' ... ] ]",
+    },
+    ModuleWordDoc {
+        module: "REFLECT",
+        word: "ALIAS",
+        summary: "Define a new user word that behaves like an existing word.",
+        role: "Dictionary metaprogramming through the DEF path; a user word's body is copied, a built-in or module word is called.",
+        stack_effect: "[ 'existing' ] [ 'new' ] -> no values pushed",
+    },
+    ModuleWordDoc {
+        module: "REFLECT",
+        word: "COMPOSE",
+        summary: "Define a new user word that runs the first word, then the second.",
+        role: "Point-free definition through the DEF path; an existing target name is refused unless forced with !.",
+        stack_effect: "[ 'first' ] [ 'second' ] [ 'new' ] -> no values pushed",
+    },
+    ModuleWordDoc {
+        module: "REFLECT",
+        word: "PARTIAL",
+        summary: "Define a new user word that pushes a bound argument, then calls a word.",
+        role: "Partial application through the DEF path; the argument is stored as literal text and must round-trip, and an existing target name is refused unless forced with !.",
+        stack_effect: "[ arg ] [ 'word' ] [ 'new' ] -> no values pushed",
+    },
+    ModuleWordDoc {
+        module: "REFLECT",
+        word: "DESCRIBE",
+        summary: "Replace the description of a user word.",
+        role: "Dictionary metadata; the text is what ? shows for the word, NIL clears it, built-in words are rejected.",
+        stack_effect: "[ 'name' ] [ 'text' ] -> no values pushed",
+    },
+    ModuleWordDoc {
+        module: "REFLECT",
+        word: "MEMOIZE",
+        summary: "Cache a user word's results by its top-of-stack argument.",
+        role: "Call-level caching; a repeated argument replays the recorded result without running the body, and any DEF or DEL empties the cache.",
+        stack_effect: "[ 'name' ] -> no values pushed",
+    },
+    ModuleWordDoc {
+        module: "REFLECT",
+        word: "DEPENDENCIES",
+        summary: "Push the qualified names of the user and module words a named word calls.",
+        role: "Dictionary introspection over the dependency graph recorded at DEF time.",
+        stack_effect: "[ 'name' ] -> [ [ 'DICT@WORD' ... ] ]",
+    },
+    ModuleWordDoc {
+        module: "REFLECT",
+        word: "DEPENDENTS",
+        summary: "Push the qualified names of the user words that call a named word.",
+        role: "Dictionary introspection; lists the words that make a plain DEL of the named word fail.",
+        stack_effect: "[ 'name' ] -> [ [ 'DICT@WORD' ... ] ]",
+    },
+    ModuleWordDoc {
+        module: "STATS",
+        word: "BUCKET",
+        summary: "Histogram counts of values over the bins between sorted edges.",
+        role: "Binning helper; bins are half-open except the last, and values outside the edges are ignored.",
+        stack_effect: "[ values ] [ edges ] -> [ counts ]",
+    },
+    ModuleWordDoc {
+        module: "STATS",
+        word: "VARIANCE",
+        summary: "Exact population variance of a numeric vector.",
+        role: "Spread summary as a fraction; a single value gives 0 and NIL passes through.",
+        stack_effect: "[ values ] -> [ variance ]",
+    },
+    ModuleWordDoc {
+        module: "STATS",
+        word: "STDDEV",
+        summary: "Population standard deviation as an exact square root.",
+        role: "Square root of VARIANCE taken as MATH@SQRT takes it: rational for perfect squares, an exact lazy root otherwise.",
+        stack_effect: "[ values ] -> [ stddev ]",
+    },
 ];
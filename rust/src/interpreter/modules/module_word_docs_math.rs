@@ -0,0 +1,202 @@
+//! Authored four-section docs for the MATH module words.
+
+use super::module_word_docs::ModuleWordDoc;
+
+pub(super) const MATH_WORD_DOCS: &[ModuleWordDoc] = &[
+    ModuleWordDoc {
+        module: "MATH",
+        word: "SQRT",
+        summary: "Square root. Exact rational roots stay exact; otherwise returns a sound interval.",
+        role: "Numeric primitive with exact/interval dispatch.",
+        stack_effect: "[ x ] -> [ root ]",
+    },
+    ModuleWordDoc {
+        module: "MATH",
+        word: "SQRT-EPS",
+        summary: "Square root with an explicit interval width bound eps.",
+        role: "Width-controlled variant of SQRT for interval arithmetic.",
+        stack_effect: "[ x ] [ eps ] -> [ root ]",
+    },
+    ModuleWordDoc {
+        module: "MATH",
+        word: "INTERVAL",
+        summary: "Create a sound interval [ lo, hi ].",
+        role: "Interval constructor.",
+        stack_effect: "[ lo ] [ hi ] -> [ interval ]",
+    },
+    ModuleWordDoc {
+        module: "MATH",
+        word: "LOWER",
+        summary: "Lower endpoint of a number or interval.",
+        role: "Endpoint projection for interval values.",
+        stack_effect: "[ x ] -> [ lo ]",
+    },
+    ModuleWordDoc {
+        module: "MATH",
+        word: "UPPER",
+        summary: "Upper endpoint of a number or interval.",
+        role: "Endpoint projection for interval values.",
+        stack_effect: "[ x ] -> [ hi ]",
+    },
+    ModuleWordDoc {
+        module: "MATH",
+        word: "WIDTH",
+        summary: "Width of an interval (hi - lo).",
+        role: "Interval-width projection.",
+        stack_effect: "[ x ] -> [ width ]",
+    },
+    ModuleWordDoc {
+        module: "MATH",
+        word: "IS-EXACT",
+        summary: "True for an exact number or a degenerate (zero-width) interval.",
+        role: "Predicate distinguishing exact values from sound intervals.",
+        stack_effect: "[ x ] -> [ bool ]",
+    },
+    ModuleWordDoc {
+        module: "MATH",
+        word: "ABS",
+        summary: "Absolute value of a number.",
+        role: "Sign-stripping numeric primitive.",
+        stack_effect: "[ x ] -> [ abs ]",
+    },
+    ModuleWordDoc {
+        module: "MATH",
+        word: "NEG",
+        summary: "Numeric negation.",
+        role: "Sign-flipping numeric primitive.",
+        stack_effect: "[ x ] -> [ -x ]",
+    },
+    ModuleWordDoc {
+        module: "MATH",
+        word: "SIGN",
+        summary: "Sign of a number: -1, 0, or 1.",
+        role: "Sign extraction primitive.",
+        stack_effect: "[ x ] -> [ sign ]",
+    },
+    ModuleWordDoc {
+        module: "MATH",
+        word: "MIN",
+        summary: "Smaller of two numbers.",
+        role: "Ordering primitive returning the lesser operand.",
+        stack_effect: "[ a ] [ b ] -> [ min ]",
+    },
+    ModuleWordDoc {
+        module: "MATH",
+        word: "MAX",
+        summary: "Larger of two numbers.",
+        role: "Ordering primitive returning the greater operand.",
+        stack_effect: "[ a ] [ b ] -> [ max ]",
+    },
+    ModuleWordDoc {
+        module: "MATH",
+        word: "CLAMP",
+        summary: "Bound a number to the closed range [lo, hi].",
+        role: "Ordering primitive built from MIN and MAX; clamps a vector elementwise.",
+        stack_effect: "[ x ] [ lo ] [ hi ] -> [ clamped ]",
+    },
+    ModuleWordDoc {
+        module: "MATH",
+        word: "LERP",
+        summary: "Exact linear interpolation a + (b - a) * t.",
+        role: "Exact-arithmetic helper; t is not clamped, so it also extrapolates.",
+        stack_effect: "[ a ] [ b ] [ t ] -> [ a + (b - a) * t ]",
+    },
+    ModuleWordDoc {
+        module: "MATH",
+        word: "LINSPACE",
+        summary: "n evenly spaced exact points from start to end inclusive.",
+        role: "Sampling helper; the spacing is an exact fraction, so the last point is end.",
+        stack_effect: "[ start ] [ end ] [ n ] -> [ points ]",
+    },
+    ModuleWordDoc {
+        module: "MATH",
+        word: "POW",
+        summary: "Integer-exponent exact power: base^exp.",
+        role: "Exact-power primitive; projects 0^negative onto Bubble/NIL.",
+        stack_effect: "[ base ] [ exp ] -> [ result ]",
+    },
+    ModuleWordDoc {
+        module: "MATH",
+        word: "RECIPROCAL",
+        summary: "Exact reciprocal 1/x, elementwise.",
+        role: "Shorthand for 1 x /; a zero projects onto Bubble/NIL like DIV.",
+        stack_effect: "[ x ] -> [ 1/x ]",
+    },
+    ModuleWordDoc {
+        module: "MATH",
+        word: "TOMIXED",
+        summary: "A number as mixed-number text: 7/3 gives '2 1/3'.",
+        role: "Readable rendering of improper fractions; integers drop the fraction part and proper fractions stay as they are.",
+        stack_effect: "[ x ] -> [ 'text' ]",
+    },
+    ModuleWordDoc {
+        module: "MATH",
+        word: "PERCENT",
+        summary: "A number times 100 as truncated decimal text with a percent sign.",
+        role: "Report formatting on DECIMAL's exact digits: [ 1/3 ] [ 1 ] gives '33.3%'.",
+        stack_effect: "[ x ] [ digits ] -> [ 'text' ]",
+    },
+    ModuleWordDoc {
+        module: "MATH",
+        word: "ROUNDTO",
+        summary: "Round to the nearest multiple of 1/d, ties away from zero.",
+        role: "Exact fixed-point rounding: [ 1/3 ] [ 100 ] gives [ 33/100 ], still a fraction.",
+        stack_effect: "[ x ] [ d ] -> [ rounded ]",
+    },
+    ModuleWordDoc {
+        module: "MATH",
+        word: "GCD",
+        summary: "Greatest common divisor of two integers.",
+        role: "Integer number-theory primitive.",
+        stack_effect: "[ a ] [ b ] -> [ gcd ]",
+    },
+    ModuleWordDoc {
+        module: "MATH",
+        word: "LCM",
+        summary: "Least common multiple of two integers.",
+        role: "Integer number-theory primitive.",
+        stack_effect: "[ a ] [ b ] -> [ lcm ]",
+    },
+    ModuleWordDoc {
+        module: "MATH",
+        word: "CONTINUED-FRACTION",
+        summary: "Partial quotients of a rational's simple continued fraction.",
+        role: "Euclidean decomposition; the coefficient view of >CF.",
+        stack_effect: "[ x ] -> [ a0 a1 ... an ]",
+    },
+    ModuleWordDoc {
+        module: "MATH",
+        word: "FROM-CONTINUED",
+        summary: "Rebuild a rational from its continued-fraction terms.",
+        role: "Exact inverse of CONTINUED-FRACTION.",
+        stack_effect: "[ a0 a1 ... an ] -> [ x ]",
+    },
+    ModuleWordDoc {
+        module: "MATH",
+        word: "NUMERATOR",
+        summary: "Numerator of the reduced fraction, elementwise.",
+        role: "Fraction decomposition; carries the sign, so [ -3/4 ] gives [ -3 ].",
+        stack_effect: "[ p/q ] -> [ p ]",
+    },
+    ModuleWordDoc {
+        module: "MATH",
+        word: "DENOMINATOR",
+        summary: "Denominator of the reduced fraction, elementwise.",
+        role: "Fraction decomposition; always positive, and 1 for an integer.",
+        stack_effect: "[ p/q ] -> [ q ]",
+    },
+    ModuleWordDoc {
+        module: "MATH",
+        word: "PI",
+        summary: "Push the exact real pi as a refinable rational enclosure.",
+        role: "Tier 2 numeric constant; its order can be observed within a water budget.",
+        stack_effect: "[ ] -> [ pi ]",
+    },
+    ModuleWordDoc {
+        module: "MATH",
+        word: "ENCLOSE",
+        summary: "Observe a value's rational enclosure within an explicit water budget.",
+        role: "Water-explicit observation returning a sound [ lo, hi ] interval.",
+        stack_effect: "[ x ] [ budget ] -> [ interval ]",
+    },
+];
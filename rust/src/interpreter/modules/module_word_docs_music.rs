@@ -0,0 +1,230 @@
+//! Authored four-section docs for the MUSIC module words.
+
+use super::module_word_docs::ModuleWordDoc;
+
+pub(super) const MUSIC_WORD_DOCS: &[ModuleWordDoc] = &[
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "SEQ",
+        summary: "Set the active playback mode to sequential.",
+        role: "Playback-mode modifier: subsequent grouping words emit notes in order.",
+        stack_effect: "no values popped or pushed",
+    },
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "SIM",
+        summary: "Set the active playback mode to simultaneous.",
+        role: "Playback-mode modifier: subsequent grouping words emit notes in parallel.",
+        stack_effect: "no values popped or pushed",
+    },
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "SLOT",
+        summary: "Set the slot duration (in seconds) used by bare notes.",
+        role: "Timing control for the default slot length.",
+        stack_effect: "[ secs ] -> no values popped or pushed",
+    },
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "GAIN",
+        summary: "Set the master output gain (0.0-1.0).",
+        role: "Output-level control for the audio engine.",
+        stack_effect: "[ level ] -> no values popped or pushed",
+    },
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "GAIN-RESET",
+        summary: "Reset the master gain to the default 1.0.",
+        role: "Audio control that restores the default output level.",
+        stack_effect: "no values popped or pushed",
+    },
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "PAN",
+        summary: "Set the stereo pan position (-1.0 left .. 1.0 right).",
+        role: "Stereo-placement control for the audio engine.",
+        stack_effect: "[ pan ] -> no values popped or pushed",
+    },
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "PAN-RESET",
+        summary: "Reset pan to center (0.0).",
+        role: "Audio control that restores the default stereo position.",
+        stack_effect: "no values popped or pushed",
+    },
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "FX-RESET",
+        summary: "Reset all audio effects (gain, pan, envelope, waveform) to defaults.",
+        role: "Bulk audio-control reset.",
+        stack_effect: "no values popped or pushed",
+    },
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "PLAY",
+        summary: "Play a music value (note, group, voice, ...).",
+        role: "Primary audio-output trigger for the music module.",
+        stack_effect: "[ music ] -> no values popped or pushed",
+    },
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "PLAY-SEQUENCE",
+        summary: "Play a vector of [ frequency duration_ms ] pairs one after another; a frequency of 0 rests.",
+        role: "Shorthand audio-output trigger for a plain melody, without building music.note values.",
+        stack_effect: "[ [ hz ms ] ... ] -> no values popped or pushed",
+    },
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "SEQ-GROUP",
+        summary: "Build an explicit sequential music group from a vector of notes.",
+        role: "Structural grouping word that fixes sequential semantics independent of the ambient mode.",
+        stack_effect: "[ notes ] -> [ group ]",
+    },
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "SIM-GROUP",
+        summary: "Build an explicit simultaneous music group from a vector of notes.",
+        role: "Structural grouping word that fixes simultaneous semantics independent of the ambient mode.",
+        stack_effect: "[ notes ] -> [ group ]",
+    },
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "CHORD",
+        summary: "Build a chord (simultaneous group) from a vector of pitches or notes.",
+        role: "Convenience constructor for harmonic groupings.",
+        stack_effect: "[ pitches ] -> [ chord ]",
+    },
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "HZ",
+        summary: "Build a music.pitch from a frequency in hertz.",
+        role: "Pitch constructor that names a frequency exactly as a rational.",
+        stack_effect: "[ hz ] -> [ pitch ]",
+    },
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "MIDI-HZ",
+        summary: "Convert a MIDI note number (0-127, 69 = A4 = 440 Hz) to its 12-EDO frequency in hertz.",
+        role: "Pitch helper: octaves of A are exact, other notes are the best rational approximation with denominator at most 1000.",
+        stack_effect: "[ note ] -> [ hz ]",
+    },
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "DUR",
+        summary: "Build a music.duration from a number of seconds.",
+        role: "Duration constructor for use in NOTE / REST.",
+        stack_effect: "[ secs ] -> [ duration ]",
+    },
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "NOTE",
+        summary: "Combine a music.pitch and a music.duration into a music.note.",
+        role: "Primary note constructor.",
+        stack_effect: "[ pitch ] [ duration ] -> [ note ]",
+    },
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "REST",
+        summary: "Build a music.rest from a music.duration.",
+        role: "Constructor for silence within a sequence.",
+        stack_effect: "[ duration ] -> [ rest ]",
+    },
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "EDO",
+        summary: "Build an equal-division-of-the-octave tuning.",
+        role: "Tuning constructor for N-equal divisions of 2/1.",
+        stack_effect: "[ ref-hz ] [ divisions ] -> [ tuning ]",
+    },
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "EDR",
+        summary: "Build an equal-division-of-a-ratio tuning (non-octave).",
+        role: "Tuning constructor for N-equal divisions of an arbitrary equave.",
+        stack_effect: "[ ref-hz ] [ equave ] [ divisions ] -> [ tuning ]",
+    },
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "STEP",
+        summary: "Resolve a tuning step into an exact music.pitch.",
+        role: "Index-into-tuning operator.",
+        stack_effect: "[ tuning ] [ step ] -> [ pitch ]",
+    },
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "VOICE",
+        summary: "Build a music group with the role of a single melodic voice.",
+        role: "Structural role tag for a single-line voice.",
+        stack_effect: "[ notes ] -> [ voice ]",
+    },
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "TRACK",
+        summary: "Build a music group with the role of an instrument track.",
+        role: "Structural role tag for an instrument track.",
+        stack_effect: "[ notes ] -> [ track ]",
+    },
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "MEASURE",
+        summary: "Build a music group with the role of a measure (bar).",
+        role: "Structural role tag marking a measure boundary.",
+        stack_effect: "[ notes ] -> [ measure ]",
+    },
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "PHRASE",
+        summary: "Build a music group with the role of a phrase.",
+        role: "Structural role tag for a phrase grouping.",
+        stack_effect: "[ notes ] -> [ phrase ]",
+    },
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "WITH-TUNING",
+        summary: "Bind a tuning over a body so bare integers are read as tuning steps.",
+        role: "Scoped binding that re-interprets numeric literals as steps within a tuning.",
+        stack_effect: "[ tuning ] [ body ] -> [ scope ]",
+    },
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "EXPLAIN",
+        summary: "Describe how MUSIC@PLAY would interpret a value, without playing it.",
+        role: "Diagnostic / inspection word for music values.",
+        stack_effect: "[ music ] -> [ music ]",
+    },
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "ADSR",
+        summary: "Set the ADSR envelope used by subsequent notes.",
+        role: "Envelope control for the audio engine.",
+        stack_effect: "[ target ] [ params ] -> [ target ]",
+    },
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "SINE",
+        summary: "Select the sine waveform on a target.",
+        role: "Waveform control for synthesised notes.",
+        stack_effect: "[ target ] -> [ target ]",
+    },
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "SQUARE",
+        summary: "Select the square waveform on a target.",
+        role: "Waveform control for synthesised notes.",
+        stack_effect: "[ target ] -> [ target ]",
+    },
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "SAW",
+        summary: "Select the sawtooth waveform on a target.",
+        role: "Waveform control for synthesised notes.",
+        stack_effect: "[ target ] -> [ target ]",
+    },
+    ModuleWordDoc {
+        module: "MUSIC",
+        word: "TRI",
+        summary: "Select the triangle waveform on a target.",
+        role: "Waveform control for synthesised notes.",
+        stack_effect: "[ target ] -> [ target ]",
+    },
+];
@@ -27,3 +27,39 @@ pub(super) struct ModuleSpec {
     pub name: &'static str,
     pub words: &'static [ModuleWord],
 }
+
+/// One `ModuleWord` table entry; the `WordShape` argument is optional.
+macro_rules! module_word {
+    ($name:expr, $word_shape:expr, $description:expr, $executor:expr, $purity:expr, $effects:expr, $det:expr, $preview:expr, $preserves:expr, $stability:expr, $caps:expr) => {
+        ModuleWord {
+            short_name: $name,
+            description: $description,
+            word_shape: Some($word_shape),
+            executor: $executor,
+            purity: $purity,
+            effects: $effects,
+            deterministic: $det,
+            safe_preview: $preview,
+            preserves_modes: $preserves,
+            stability: $stability,
+            capabilities: $caps,
+        }
+    };
+    ($name:expr, $description:expr, $executor:expr, $purity:expr, $effects:expr, $det:expr, $preview:expr, $preserves:expr, $stability:expr, $caps:expr) => {
+        ModuleWord {
+            short_name: $name,
+            description: $description,
+            word_shape: None,
+            executor: $executor,
+            purity: $purity,
+            effects: $effects,
+            deterministic: $det,
+            safe_preview: $preview,
+            preserves_modes: $preserves,
+            stability: $stability,
+            capabilities: $caps,
+        }
+    };
+}
+
+pub(super) use module_word;
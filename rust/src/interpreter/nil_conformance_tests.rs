@@ -92,6 +92,8 @@ const CORE_PASSTHROUGH: &[(&str, NilClass)] = &[
     // relations, but ternary: its a/b value operands pass NIL through while
     // the trailing budget operand is a plain positive integer.
     ("COMPARE-WITHIN", NilClass::TernaryValueNil),
+    // COMPARE is the budget-free binary form of COMPARE-WITHIN.
+    ("COMPARE", NilClass::BinaryBlanket),
     ("NOT", NilClass::UnaryNil),
     ("AND", NilClass::ThreeValAnd),
     ("OR", NilClass::ThreeValOr),
@@ -313,6 +315,9 @@ async fn unknown_passes_through_pipeline_distinct_from_nil() {
 /// reason; malformed use raises an ordinary error. `READ` is host/serial
 /// dependent (needs a port) so only its registry presence is asserted.
 const PROJECTING_WORDS: &[&str] = &[
+    "ASSOC-GET",
+    "BINARY-SEARCH",
+    "CARTESIAN",
     "CEIL",
     "CHR",
     "DIV",
@@ -320,9 +325,11 @@ const PROJECTING_WORDS: &[&str] = &[
     "FLOOR",
     "GET",
     "INDEX-OF",
+    "LINSPACE",
     "MOD",
     "NUM",
     "PARSE-ISO",
+    "PATHGET",
     "POW",
     "QUANTIZE",
     "QUANTIZE-CEIL",
@@ -331,7 +338,10 @@ const PROJECTING_WORDS: &[&str] = &[
     "QUANTIZE-TRUNC",
     "RANGE",
     "READ",
+    "RECIPROCAL",
+    "REPEAT-VALUE",
     "ROUND",
+    "UPTO",
 ];
 
 #[test]
@@ -377,13 +387,16 @@ async fn bubble_creation_well_formed_domain_miss() {
     assert!(is_nil(&stack[0]));
     assert_eq!(reason_of(&stack[0]), Some(NilReason::InvalidEncoding));
 
-    // zero raised to a negative exponent: well-formed domain miss
-    let stack = run_ok("'math' IMPORT 0 -1 POW").await;
-    assert!(is_nil(stack.last().unwrap()));
-    assert_eq!(
-        reason_of(stack.last().unwrap()),
-        Some(NilReason::DivisionByZero)
-    );
+    // zero raised to a negative exponent, or inverted: well-formed domain miss
+    for code in ["0 -1 POW", "[ 2 0 ] RECIPROCAL"] {
+        let stack = run_ok(&format!("'math' IMPORT {code}")).await;
+        assert!(is_nil(stack.last().unwrap()), "{code}");
+        assert_eq!(
+            reason_of(stack.last().unwrap()),
+            Some(NilReason::DivisionByZero),
+            "{code}"
+        );
+    }
 
     // value absent from a valid vector: well-formed search miss
     let stack = run_ok("'algo' IMPORT [ 1 2 3 ] 9 INDEX-OF").await;
@@ -393,29 +406,47 @@ async fn bubble_creation_well_formed_domain_miss() {
         Some(NilReason::MissingField)
     );
 
-    // well-formed text that is not a valid ISO-8601 civil value
-    let stack = run_ok("'time' IMPORT 'not-a-date' PARSE-ISO").await;
+    // value absent from a valid ascending vector: binary-search miss
+    let stack = run_ok("'algo' IMPORT [ 1 3 5 ] 4 BINARY-SEARCH").await;
     assert!(is_nil(stack.last().unwrap()));
     assert_eq!(
         reason_of(stack.last().unwrap()),
-        Some(NilReason::InvalidEncoding)
+        Some(NilReason::MissingField)
     );
 
-    // well-formed but over the space water level: RANGE and FILL project the
-    // materialization miss onto a Bubble/NIL (Phase 3), recoverable with VENT.
-    let stack = run_ok("[ 0 9999999999999 ] RANGE").await;
+    // key absent from a valid association vector: well-formed lookup miss
+    let stack = run_ok("'algo' IMPORT [ [ 1 10 ] ] 2 ASSOC-GET").await;
     assert!(is_nil(stack.last().unwrap()));
     assert_eq!(
         reason_of(stack.last().unwrap()),
-        Some(NilReason::SpaceExhausted)
+        Some(NilReason::MissingField)
     );
 
-    let stack = run_ok("[ 1000000 1000000 7 ] FILL").await;
+    // well-formed text that is not a valid ISO-8601 civil value
+    let stack = run_ok("'time' IMPORT 'not-a-date' PARSE-ISO").await;
     assert!(is_nil(stack.last().unwrap()));
     assert_eq!(
         reason_of(stack.last().unwrap()),
-        Some(NilReason::SpaceExhausted)
+        Some(NilReason::InvalidEncoding)
     );
+
+    // well-formed but over the space water level: RANGE, UPTO, LINSPACE and
+    // FILL project the materialization miss onto a Bubble/NIL (Phase 3),
+    // recoverable with VENT.
+    for code in [
+        "[ 0 9999999999999 ] RANGE",
+        "'algo' IMPORT [ 0 9999999999999 ] UPTO",
+        "'math' IMPORT [ 0 ] [ 1 ] [ 9999999999999 ] LINSPACE",
+        "[ 1000000 1000000 7 ] FILL",
+    ] {
+        let stack = run_ok(code).await;
+        assert!(is_nil(stack.last().unwrap()), "{code}");
+        assert_eq!(
+            reason_of(stack.last().unwrap()),
+            Some(NilReason::SpaceExhausted),
+            "{code}"
+        );
+    }
 }
 
 #[tokio::test]
@@ -1,5 +1,6 @@
 use crate::error::{AjisaiError, Result};
 use crate::interpreter::tensor_ops::FlatTensor;
+use crate::interpreter::value_extraction_helpers::extract_count_from_value;
 use crate::interpreter::{ConsumptionMode, HostCapability, Interpreter, OperationTargetMode};
 use crate::types::fraction::Fraction;
 use crate::types::Value;
@@ -144,6 +145,108 @@ fn parse_csprng_args(interp: &mut Interpreter) -> Result<(BigInt, usize)> {
     Ok((default_denom, count))
 }
 
+/// Uniform index in `0..bound`, drawn from the host's secure random source
+/// like `CSPRNG`, so a deterministic host makes it reproducible.
+fn random_index(interp: &Interpreter, bound: usize) -> Result<usize> {
+    compute_uniform_random(interp, &BigInt::from(bound))?
+        .to_usize()
+        .ok_or_else(|| AjisaiError::from("random index out of range"))
+}
+
+/// Fisher-Yates shuffle of the first `count` positions: after it returns,
+/// `items[..count]` is a uniform random selection in random order.
+fn shuffle_prefix(interp: &Interpreter, items: &mut [Value], count: usize) -> Result<()> {
+    let len = items.len();
+    for i in 0..count.min(len.saturating_sub(1)) {
+        let j = i + random_index(interp, len - i)?;
+        items.swap(i, j);
+    }
+    Ok(())
+}
+
+fn vector_items(word: &str, target: &Value) -> Result<Vec<Value>> {
+    if !target.is_vector() {
+        return Err(AjisaiError::from(format!("{}: expected a vector", word)));
+    }
+    Ok((0..target.len()).filter_map(|i| target.child(i)).collect())
+}
+
+/// `SHUFFLE` permutes the elements of the top vector uniformly at random.
+/// Randomness comes from the host's secure random source, so it needs the
+/// same capability as `CSPRNG` and is reproducible under a deterministic
+/// host. A one-element vector comes back unchanged; NIL passes through.
+pub fn op_shuffle(interp: &mut Interpreter) -> Result<()> {
+    if interp.operation_target_mode != OperationTargetMode::StackTop {
+        return Err(AjisaiError::ModeUnsupported {
+            word: "SHUFFLE".into(),
+            mode: "Stack".into(),
+        });
+    }
+    interp.require_host_capability("SHUFFLE", HostCapability::SecureRandom)?;
+
+    let target = interp
+        .stack
+        .last()
+        .cloned()
+        .ok_or(AjisaiError::StackUnderflow)?;
+    if target.is_nil() {
+        if interp.consumption_mode == ConsumptionMode::Keep {
+            interp.stack.push(target);
+        }
+        return Ok(());
+    }
+    let mut items = vector_items("SHUFFLE", &target)?;
+    let count = items.len();
+    shuffle_prefix(interp, &mut items, count)?;
+
+    if interp.consumption_mode == ConsumptionMode::Consume {
+        interp.stack.pop();
+    }
+    interp
+        .stack
+        .push(Value::from_vector_promoted_with_hint(items, target.hint));
+    Ok(())
+}
+
+/// `SAMPLE` picks `k` distinct positions of a vector at random, without
+/// replacement, by running the first `k` steps of the `SHUFFLE` draw; with
+/// `k` equal to the length it is a shuffle. A `k` larger than the vector is
+/// an error that leaves both operands in place, and `k = 0` gives NIL.
+pub fn op_sample(interp: &mut Interpreter) -> Result<()> {
+    if interp.operation_target_mode != OperationTargetMode::StackTop {
+        return Err(AjisaiError::ModeUnsupported {
+            word: "SAMPLE".into(),
+            mode: "Stack".into(),
+        });
+    }
+    interp.require_host_capability("SAMPLE", HostCapability::SecureRandom)?;
+
+    let stack_len = interp.stack.len();
+    if stack_len < 2 {
+        return Err(AjisaiError::StackUnderflow);
+    }
+    let target = interp.stack[stack_len - 2].clone();
+    let count = extract_count_from_value(&interp.stack[stack_len - 1])?;
+    let mut items = vector_items("SAMPLE", &target)?;
+    if count > items.len() {
+        return Err(AjisaiError::from(format!(
+            "SAMPLE: cannot take {} elements from a vector of {}",
+            count,
+            items.len()
+        )));
+    }
+    shuffle_prefix(interp, &mut items, count)?;
+    items.truncate(count);
+
+    if interp.consumption_mode == ConsumptionMode::Consume {
+        interp.stack.truncate(stack_len - 2);
+    }
+    interp
+        .stack
+        .push(Value::from_vector_promoted_with_hint(items, target.hint));
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use crate::interpreter::Interpreter;
@@ -278,4 +381,74 @@ mod tests {
             .payload()
             .contains("missingCapability"));
     }
+
+    #[tokio::test]
+    async fn test_shuffle_is_deterministic_under_a_fixed_host() {
+        use crate::interpreter::DeterministicHostEnv;
+        use std::sync::Arc;
+
+        // Three draws of 9 bytes each pick offsets 1, 1 and 0.
+        let mut bytes = vec![0u8; 27];
+        bytes[0] = 1;
+        bytes[9] = 1;
+        let host = Arc::new(DeterministicHostEnv::all_capabilities(0, bytes));
+        let mut interp = Interpreter::with_host(host);
+
+        interp
+            .execute("'crypto' IMPORT [ 1 2 3 4 ] SHUFFLE")
+            .await
+            .unwrap();
+        assert_eq!(interp.stack.len(), 1);
+        assert_eq!(interp.stack[0].to_string(), "[ 2/1 3/1 1/1 4/1 ]");
+    }
+
+    #[tokio::test]
+    async fn test_shuffle_preserves_elements() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute("'crypto' IMPORT [ 5 1 4 2 3 ] SHUFFLE 'algo' IMPORT SORT")
+            .await
+            .unwrap();
+        assert_eq!(interp.stack[0].to_string(), "[ 1/1 2/1 3/1 4/1 5/1 ]");
+
+        let mut single = Interpreter::new();
+        single
+            .execute("'crypto' IMPORT [ 7 ] SHUFFLE")
+            .await
+            .unwrap();
+        assert_eq!(single.stack[0].to_string(), "[ 7/1 ]");
+    }
+
+    #[tokio::test]
+    async fn test_sample_is_deterministic_under_a_fixed_host() {
+        use crate::interpreter::DeterministicHostEnv;
+        use std::sync::Arc;
+
+        // Two draws of 9 bytes each pick offsets 3 and 0.
+        let mut bytes = vec![0u8; 18];
+        bytes[0] = 3;
+        let host = Arc::new(DeterministicHostEnv::all_capabilities(0, bytes));
+        let mut interp = Interpreter::with_host(host);
+
+        interp
+            .execute("'crypto' IMPORT [ 10 20 30 40 50 ] 2 SAMPLE")
+            .await
+            .unwrap();
+        assert_eq!(interp.stack.len(), 1);
+        assert_eq!(interp.stack[0].to_string(), "[ 40/1 20/1 ]");
+    }
+
+    #[tokio::test]
+    async fn test_sample_rejects_oversized_count() {
+        let mut interp = Interpreter::new();
+        let result = interp.execute("'crypto' IMPORT [ 1 2 3 ] 4 SAMPLE").await;
+        assert!(result.is_err(), "k larger than the vector must fail");
+        assert_eq!(interp.stack.len(), 2, "operands stay on the stack");
+
+        let mut all = Interpreter::new();
+        all.execute("'crypto' IMPORT [ 3 1 2 ] 3 SAMPLE 'algo' IMPORT SORT")
+            .await
+            .unwrap();
+        assert_eq!(all.stack[0].to_string(), "[ 1/1 2/1 3/1 ]");
+    }
 }
@@ -0,0 +1,488 @@
+use std::collections::BTreeSet;
+
+use crate::error::{AjisaiError, Result};
+use crate::interpreter::value_extraction_helpers::{extract_word_name_from_value, value_as_string};
+use crate::interpreter::{ConsumptionMode, Interpreter, OperationTargetMode};
+use crate::types::display::format_with_hint;
+use crate::types::value_protocol::value_to_protocol;
+use crate::types::{Interpretation, Token, Value, ValueData};
+
+fn require_stack_top(interp: &Interpreter, word: &str) -> Result<()> {
+    if interp.operation_target_mode != OperationTargetMode::StackTop {
+        return Err(AjisaiError::from(format!(
+            "{}: Stack mode is not supported",
+            word
+        )));
+    }
+    Ok(())
+}
+
+/// `value -- 'type'`. Push the name the value protocol gives the top value
+/// under its slot role: `'number'`, `'string'`, `'boolean'`, `'nil'`,
+/// `'vector'` or `'datetime'` (plus the truth-value and handle kinds). A
+/// code block, which the protocol sends as null, is `'code'`.
+pub fn op_type(interp: &mut Interpreter) -> Result<()> {
+    require_stack_top(interp, "TYPE")?;
+    let (value, role) = interp.stack.pop_slot().ok_or(AjisaiError::StackUnderflow)?;
+    let type_name = if matches!(value.data, ValueData::CodeBlock(_)) {
+        "code"
+    } else {
+        value_to_protocol(&value, Some(role)).type_str
+    };
+    if interp.consumption_mode == ConsumptionMode::Keep {
+        interp.stack.push_with_role(value, role);
+    }
+    interp.stack.push(Value::from_string(type_name));
+    Ok(())
+}
+
+/// Whether `token` only builds data when evaluated: numbers, text,
+/// brackets and the TRUE, FALSE and NIL constants.
+fn is_literal_token(token: &Token) -> bool {
+    match token {
+        Token::Number(_)
+        | Token::String(_)
+        | Token::VectorStart
+        | Token::VectorEnd
+        | Token::BlockStart
+        | Token::BlockEnd
+        | Token::LineBreak => true,
+        Token::Symbol(name) => matches!(&**name, "TRUE" | "FALSE" | "NIL"),
+        _ => false,
+    }
+}
+
+/// The tokens of `repr`, the stack rendering of `value`, provided they
+/// only build data. A code block, or a rendering that names any other
+/// word, is refused here, so evaluating the tokens can never run a body
+/// or reach the dictionary or the output.
+fn data_literal_tokens(value: &Value, repr: &str) -> Result<Vec<Token>> {
+    if matches!(value.data, ValueData::CodeBlock(_)) {
+        return Err(AjisaiError::from("a code block has no literal form"));
+    }
+    let tokens = crate::tokenizer::tokenize(repr)
+        .map_err(|e| AjisaiError::from(format!("tokenization error: {}", e)))?;
+    if !tokens.iter().all(is_literal_token) {
+        return Err(AjisaiError::from(
+            "the text names a word, so it is not literal",
+        ));
+    }
+    Ok(tokens)
+}
+
+/// Evaluate literal `tokens` on an empty stack and return the slots they
+/// leave, restoring the caller's stack afterwards whatever the outcome.
+fn evaluate_isolated(
+    interp: &mut Interpreter,
+    tokens: &[Token],
+) -> Result<Vec<(Value, Interpretation)>> {
+    let saved = std::mem::take(&mut interp.stack);
+    let outcome = interp.execute_section_core(tokens, 0);
+    let produced = std::mem::replace(&mut interp.stack, saved);
+    outcome?;
+    Ok(produced
+        .iter_slots()
+        .map(|(value, role)| (value.clone(), role))
+        .collect())
+}
+
+/// `value -- value bool`. Render the value as it is shown on the stack, evaluate
+/// that text, and report whether it reconstructs the same value under the same
+/// role. The value stays in place; on a mismatch a diagnostic is printed. Text
+/// that is not a data literal (a code block's body, say) is answered FALSE
+/// without being evaluated.
+pub fn op_roundtrip(interp: &mut Interpreter) -> Result<()> {
+    require_stack_top(interp, "ROUNDTRIP?")?;
+    let original = interp
+        .stack
+        .last()
+        .cloned()
+        .ok_or(AjisaiError::StackUnderflow)?;
+    let role = interp.stack.last_role();
+    let repr = format_with_hint(&original, role);
+
+    let evaluated = data_literal_tokens(&original, &repr)
+        .map_err(|err| format!("not evaluated: {}", err))
+        .and_then(|tokens| {
+            evaluate_isolated(interp, &tokens).map_err(|err| format!("evaluation failed: {}", err))
+        });
+    let diagnostic = match evaluated {
+        Ok(slots) => match slots.as_slice() {
+            [(rebuilt, rebuilt_role)] if rebuilt.data == original.data && *rebuilt_role == role => {
+                None
+            }
+            [(_, rebuilt_role)] if *rebuilt_role != role => Some(format!(
+                "reconstructed with role {:?}, expected {:?}",
+                rebuilt_role, role
+            )),
+            [(rebuilt, rebuilt_role)] => Some(format!(
+                "reconstructed as {}",
+                format_with_hint(rebuilt, *rebuilt_role)
+            )),
+            _ => Some(format!("evaluated to {} values", slots.len())),
+        },
+        Err(reason) => Some(reason),
+    };
+
+    if let Some(reason) = &diagnostic {
+        interp
+            .output_buffer
+            .push_str(&format!("ROUNDTRIP?: {} {}\n", repr, reason));
+    }
+    interp.stack.push(Value::from_bool(diagnostic.is_none()));
+    interp.stack.set_last_role(Interpretation::TruthValue);
+    Ok(())
+}
+
+/// The source text SOURCE reports for `name`: a `{ body } 'NAME' DEF` line
+/// for a user word (the form `export_definitions_source` writes), the
+/// description for a built-in or module word.
+fn word_source(interp: &Interpreter, name: &str) -> Result<String> {
+    let canonical = crate::core_word_aliases::canonicalize_core_word_name(name);
+    let def = interp
+        .resolve_word(&canonical)
+        .ok_or_else(|| AjisaiError::UnknownWord(name.to_string()))?;
+    if def.is_builtin {
+        return def
+            .description
+            .clone()
+            .ok_or_else(|| AjisaiError::from(format!("SOURCE: {} has no description", name)));
+    }
+    let body = interp
+        .lookup_word_definition_tokens(&canonical)
+        .ok_or_else(|| AjisaiError::from(format!("SOURCE: {} has no body", name)))?;
+    let short_name = name.rsplit('@').next().unwrap_or(name);
+    Ok(format!("{{ {} }} '{}' DEF", body, short_name))
+}
+
+/// Pop a word name and push `describe(name)`. On failure the name is put
+/// back; in KEEP mode it stays below the result.
+fn with_word_name(
+    interp: &mut Interpreter,
+    word: &str,
+    describe: impl FnOnce(&Interpreter, &str) -> Result<Value>,
+) -> Result<()> {
+    require_stack_top(interp, word)?;
+    let name_val = interp.stack.pop().ok_or(AjisaiError::StackUnderflow)?;
+    let result =
+        match extract_word_name_from_value(&name_val).and_then(|name| describe(interp, &name)) {
+            Ok(result) => result,
+            Err(err) => {
+                interp.stack.push(name_val);
+                return Err(err);
+            }
+        };
+
+    if interp.consumption_mode == ConsumptionMode::Keep {
+        interp.stack.push(name_val);
+    }
+    interp.stack.push(result);
+    Ok(())
+}
+
+/// `name -- text`. Push the definition of the named word as Text, so a
+/// program can inspect or rewrite it and EVAL the result.
+pub fn op_source(interp: &mut Interpreter) -> Result<()> {
+    with_word_name(interp, "SOURCE", |interp, name| {
+        word_source(interp, name).map(|source| Value::from_string(&source))
+    })
+}
+
+/// Sorted vector of texts; an empty listing is NIL.
+fn name_listing<'a>(names: impl IntoIterator<Item = &'a String>) -> Value {
+    let sorted: BTreeSet<&String> = names.into_iter().collect();
+    Value::from_vector(
+        sorted
+            .into_iter()
+            .map(|name| Value::from_string(name))
+            .collect(),
+    )
+}
+
+/// `name -- [ 'DICT@WORD' ... ]`. Push the qualified names of the user and
+/// module words the named word calls.
+pub fn op_dependencies(interp: &mut Interpreter) -> Result<()> {
+    with_word_name(interp, "DEPENDENCIES", |interp, name| {
+        let (_, def) = interp
+            .resolve_word_entry_readonly(name)
+            .ok_or_else(|| AjisaiError::UnknownWord(name.to_string()))?;
+        Ok(name_listing(&def.dependencies))
+    })
+}
+
+/// `name -- [ 'DICT@WORD' ... ]`. Push the qualified names of the user
+/// words that call the named word: the ones that block a plain DEL of it.
+pub fn op_dependents(interp: &mut Interpreter) -> Result<()> {
+    with_word_name(interp, "DEPENDENTS", |interp, name| {
+        let (fq_name, _) = interp
+            .resolve_word_entry_readonly(name)
+            .ok_or_else(|| AjisaiError::UnknownWord(name.to_string()))?;
+        Ok(name_listing(&interp.collect_dependents(&fq_name)))
+    })
+}
+
+/// A bare `TRUE`/`FALSE` or one wrapped as `[ TRUE ]`.
+fn truth_flag(val: &Value) -> Option<bool> {
+    match val.as_truth() {
+        Some(flag) => Some(flag),
+        None if val.is_vector() && val.len() == 1 => val.child(0)?.as_truth(),
+        None => None,
+    }
+}
+
+/// Sorted short names of the user words, plus the core and imported module
+/// words unless `custom_only`.
+fn word_names(interp: &Interpreter, custom_only: bool) -> BTreeSet<String> {
+    let mut names: BTreeSet<String> = interp
+        .user_dictionaries
+        .values()
+        .flat_map(|dict| dict.words.keys().cloned())
+        .collect();
+    if custom_only {
+        return names;
+    }
+    names.extend(interp.core_vocabulary.keys().cloned());
+    for (module_name, entry) in &interp.import_table.modules {
+        let Some(module) = interp.module_vocabulary.get(module_name) else {
+            continue;
+        };
+        for qualified in module.words.keys() {
+            let short = qualified.rsplit('@').next().unwrap_or(qualified);
+            if entry.import_all_public || entry.imported_words.contains(short) {
+                names.insert(short.to_string());
+            }
+        }
+    }
+    names
+}
+
+/// `flag -- [ 'name' ... ]`. Push the sorted names of the words a program
+/// can call: user words only when `flag` is TRUE, every defined word
+/// (core, imported module, user) when it is FALSE.
+pub fn op_words(interp: &mut Interpreter) -> Result<()> {
+    require_stack_top(interp, "WORDS")?;
+    let flag_val = interp.stack.pop().ok_or(AjisaiError::StackUnderflow)?;
+    let Some(custom_only) = truth_flag(&flag_val) else {
+        interp.stack.push(flag_val);
+        return Err(AjisaiError::from(
+            "WORDS: expected TRUE (user words only) or FALSE (all words)",
+        ));
+    };
+
+    let names = name_listing(&word_names(interp, custom_only));
+    if interp.consumption_mode == ConsumptionMode::Keep {
+        interp.stack.push(flag_val);
+    }
+    interp.stack.push(names);
+    Ok(())
+}
+
+/// Body tokens for an alias of `name`: a copy of a user word's lines, or a
+/// single call for a built-in or module word.
+fn alias_body(interp: &Interpreter, name: &str) -> Result<Vec<Token>> {
+    let (resolved, def) = interp
+        .resolve_word_entry_readonly(name)
+        .ok_or_else(|| AjisaiError::UnknownWord(name.to_string()))?;
+    if def.is_builtin {
+        return Ok(vec![Token::Symbol(resolved.into())]);
+    }
+    let mut tokens = Vec::new();
+    for (i, line) in def.lines.iter().enumerate() {
+        if i > 0 {
+            tokens.push(Token::LineBreak);
+        }
+        tokens.extend(line.body_tokens.iter().cloned());
+    }
+    Ok(tokens)
+}
+
+/// `existing new --`. Define `new` as a user word that behaves like
+/// `existing`, through the same path as DEF, so redefinition checks and
+/// dependency tracking apply to it unchanged.
+pub fn op_alias(interp: &mut Interpreter) -> Result<()> {
+    require_stack_top(interp, "ALIAS")?;
+    if interp.stack.len() < 2 {
+        return Err(AjisaiError::StackUnderflow);
+    }
+    let new_val = interp.stack.pop().ok_or(AjisaiError::StackUnderflow)?;
+    let existing_val = interp.stack.pop().ok_or(AjisaiError::StackUnderflow)?;
+
+    let defined = extract_word_name_from_value(&existing_val).and_then(|existing| {
+        let new_name = extract_word_name_from_value(&new_val)?;
+        let body = alias_body(interp, &existing)?;
+        super::execute_def::op_def_inner(interp, &new_name, &body)
+    });
+    if let Err(err) = defined {
+        interp.stack.push(existing_val);
+        interp.stack.push(new_val);
+        return Err(err);
+    }
+    Ok(())
+}
+
+/// Set the description of the user word `name`; `None` clears it.
+fn set_description(
+    interp: &mut Interpreter,
+    name: &str,
+    description: Option<String>,
+) -> Result<()> {
+    let (fq_name, def) = interp
+        .resolve_word_entry_readonly(name)
+        .ok_or_else(|| AjisaiError::UnknownWord(name.to_string()))?;
+    if def.is_builtin {
+        return Err(AjisaiError::BuiltinProtection {
+            word: name.to_string(),
+            operation: "describe".into(),
+        });
+    }
+    let (dict_name, short_name) = fq_name
+        .split_once('@')
+        .ok_or_else(|| AjisaiError::UnknownWord(name.to_string()))?;
+    let entry = interp
+        .user_dictionaries
+        .get_mut(dict_name)
+        .and_then(|dict| dict.words.get_mut(short_name))
+        .ok_or_else(|| AjisaiError::UnknownWord(name.to_string()))?;
+    std::sync::Arc::make_mut(entry).description = description;
+    interp.sync_user_words_cache();
+    interp.bump_dictionary_epoch();
+    Ok(())
+}
+
+/// `name text --`. Replace the description of a user word, the text `?`
+/// shows for it; NIL clears it. Built-in and module words are rejected.
+pub fn op_describe(interp: &mut Interpreter) -> Result<()> {
+    require_stack_top(interp, "DESCRIBE")?;
+    if interp.stack.len() < 2 {
+        return Err(AjisaiError::StackUnderflow);
+    }
+    let text_val = interp.stack.pop().ok_or(AjisaiError::StackUnderflow)?;
+    let name_val = interp.stack.pop().ok_or(AjisaiError::StackUnderflow)?;
+
+    let described = extract_word_name_from_value(&name_val).and_then(|name| {
+        let description = if text_val.is_nil() {
+            None
+        } else if text_val.hint == Interpretation::Text {
+            value_as_string(&text_val)
+        } else {
+            return Err(AjisaiError::from("DESCRIBE: description must be text"));
+        };
+        set_description(interp, &name, description)
+    });
+    if let Err(err) = described {
+        interp.stack.push(name_val);
+        interp.stack.push(text_val);
+        return Err(err);
+    }
+    Ok(())
+}
+
+/// Check the names of a generated definition: each word it calls must
+/// resolve, and `new_name` must not name an existing word unless forced
+/// with `!`.
+fn check_definition_names(
+    interp: &Interpreter,
+    word: &str,
+    called: &[&str],
+    new_name: &str,
+) -> Result<()> {
+    for name in called {
+        let canonical = crate::core_word_aliases::canonicalize_core_word_name(name);
+        if interp.resolve_word_entry_readonly(&canonical).is_none() {
+            return Err(AjisaiError::UnknownWord(name.to_string()));
+        }
+    }
+    if !interp.force_flag && interp.resolve_word_entry_readonly(new_name).is_some() {
+        return Err(AjisaiError::from(format!(
+            "{}: '{}' already exists. Use ! to replace it.",
+            word, new_name
+        )));
+    }
+    Ok(())
+}
+
+/// `first second new --`. Define `new` as a user word that runs `first`,
+/// then `second`, on the stack: `'F' 'G' 'FG' COMPOSE` makes `FG` mean
+/// `F G`. Defined through DEF, so dependencies on both words are tracked.
+pub fn op_compose(interp: &mut Interpreter) -> Result<()> {
+    require_stack_top(interp, "COMPOSE")?;
+    if interp.stack.len() < 3 {
+        return Err(AjisaiError::StackUnderflow);
+    }
+    let new_val = interp.stack.pop().ok_or(AjisaiError::StackUnderflow)?;
+    let second_val = interp.stack.pop().ok_or(AjisaiError::StackUnderflow)?;
+    let first_val = interp.stack.pop().ok_or(AjisaiError::StackUnderflow)?;
+
+    let defined = extract_word_name_from_value(&first_val).and_then(|first| {
+        let second = extract_word_name_from_value(&second_val)?;
+        let new_name = extract_word_name_from_value(&new_val)?;
+        check_definition_names(interp, "COMPOSE", &[&first, &second], &new_name)?;
+        let body = [Token::Symbol(first.into()), Token::Symbol(second.into())];
+        super::execute_def::op_def_inner(interp, &new_name, &body)
+    });
+    if let Err(err) = defined {
+        interp.force_flag = false;
+        interp.stack.push(first_val);
+        interp.stack.push(second_val);
+        interp.stack.push(new_val);
+        return Err(err);
+    }
+    Ok(())
+}
+
+/// Body tokens that push `value` under `role`: its stack rendering,
+/// tokenized, provided the text is a data literal that rebuilds the same
+/// slot. Nothing else is evaluated.
+fn literal_tokens(
+    interp: &mut Interpreter,
+    value: &Value,
+    role: Interpretation,
+) -> Result<Vec<Token>> {
+    let repr = format_with_hint(value, role);
+    let survives = data_literal_tokens(value, &repr).and_then(|tokens| {
+        let slots = evaluate_isolated(interp, &tokens)?;
+        Ok(match slots.as_slice() {
+            [(rebuilt, rebuilt_role)] if rebuilt.data == value.data && *rebuilt_role == role => {
+                Some(tokens)
+            }
+            _ => None,
+        })
+    });
+    survives.ok().flatten().ok_or_else(|| {
+        AjisaiError::from(format!(
+            "PARTIAL: {} does not survive its text form, so it cannot be bound",
+            repr
+        ))
+    })
+}
+
+/// `arg word new --`. Define `new` as a user word that pushes `arg` and
+/// then calls `word`: `[ 10 ] '+' 'ADD10' PARTIAL` makes `ADD10` mean
+/// `[ 10 ] +`. The argument is stored in the body as literal text, so it
+/// must be plain data that round-trips through its stack rendering; a code
+/// block or anything else is refused without running.
+pub fn op_partial(interp: &mut Interpreter) -> Result<()> {
+    require_stack_top(interp, "PARTIAL")?;
+    if interp.stack.len() < 3 {
+        return Err(AjisaiError::StackUnderflow);
+    }
+    let new_val = interp.stack.pop().ok_or(AjisaiError::StackUnderflow)?;
+    let word_val = interp.stack.pop().ok_or(AjisaiError::StackUnderflow)?;
+    let (arg, role) = interp.stack.pop_slot().ok_or(AjisaiError::StackUnderflow)?;
+
+    let defined = extract_word_name_from_value(&word_val).and_then(|word| {
+        let new_name = extract_word_name_from_value(&new_val)?;
+        check_definition_names(interp, "PARTIAL", &[&word], &new_name)?;
+        let mut body = literal_tokens(interp, &arg, role)?;
+        body.push(Token::Symbol(word.into()));
+        super::execute_def::op_def_inner(interp, &new_name, &body)
+    });
+    if let Err(err) = defined {
+        interp.force_flag = false;
+        interp.stack.push_with_role(arg, role);
+        interp.stack.push(word_val);
+        interp.stack.push(new_val);
+        return Err(err);
+    }
+    Ok(())
+}
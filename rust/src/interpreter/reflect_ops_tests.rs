@@ -0,0 +1,448 @@
+//! Test suite for `crate::interpreter::reflect_ops` (REFLECT ROUNDTRIP?,
+//! TYPE, SOURCE, WORDS, DEPENDENCIES, DEPENDENTS, ALIAS, COMPOSE,
+//! PARTIAL, DESCRIBE).
+//!
+//! The `*_does_not_roundtrip` cases document representations that currently
+//! fail the textual round trip; they pin today's behaviour so a fix shows up
+//! as a deliberate test change.
+
+#[cfg(test)]
+mod tests {
+    use crate::interpreter::value_extraction_helpers::value_as_string;
+    use crate::interpreter::Interpreter;
+
+    /// Run `ROUNDTRIP?` on the value `code` pushes and return the verdict,
+    /// the depth of the stack afterwards, and any printed diagnostic.
+    async fn roundtrip(code: &str) -> (bool, usize, String) {
+        let mut interp = Interpreter::new();
+        interp
+            .execute(&format!("'reflect' IMPORT {} ROUNDTRIP?", code))
+            .await
+            .expect("should succeed");
+        let verdict = interp.stack.last().unwrap().as_truth().expect("bool");
+        (verdict, interp.stack.len(), interp.collect_output())
+    }
+
+    #[tokio::test]
+    async fn scalars_roundtrip() {
+        for code in ["42", "-5", "1/3", "[ 42 ]", "TRUE"] {
+            let (ok, depth, out) = roundtrip(code).await;
+            assert!(ok, "{} should round-trip: {}", code, out);
+            assert_eq!(depth, 2, "the original value stays in place");
+            assert!(out.is_empty());
+        }
+    }
+
+    #[tokio::test]
+    async fn nested_vectors_roundtrip() {
+        for code in ["[ 1 2 3 ]", "[ [ 1 2 ] [ 3 [ 4 ] ] ]", "[ 'x' 1 ]"] {
+            let (ok, _, out) = roundtrip(code).await;
+            assert!(ok, "{} should round-trip: {}", code, out);
+        }
+    }
+
+    #[tokio::test]
+    async fn strings_with_quotes_roundtrip() {
+        for code in ["'abc'", "'it''s'", "[ 'ab' 'cd' ]"] {
+            let (ok, _, out) = roundtrip(code).await;
+            assert!(ok, "{} should round-trip: {}", code, out);
+        }
+    }
+
+    #[tokio::test]
+    async fn nil_roundtrips() {
+        let (ok, _, out) = roundtrip("NIL").await;
+        assert!(ok, "NIL should round-trip: {}", out);
+    }
+
+    #[tokio::test]
+    async fn boolean_vector_does_not_roundtrip() {
+        // A vector of booleans is shown as `{ TRUE FALSE }`, which reads back
+        // as a code block.
+        let (ok, depth, out) = roundtrip("[ TRUE FALSE ]").await;
+        assert!(!ok);
+        assert_eq!(depth, 2);
+        assert!(out.contains("ROUNDTRIP?: { TRUE FALSE }"), "{}", out);
+    }
+
+    #[tokio::test]
+    async fn code_block_does_not_roundtrip() {
+        // Code blocks are shown without their braces, so the rendering is
+        // the body; it is refused rather than run.
+        let (ok, _, out) = roundtrip("{ 'ran' PRINT { [ 99 ] } 'HACK' DEF }").await;
+        assert!(!ok);
+        assert!(out.contains("not evaluated"), "{}", out);
+        assert_eq!(out.lines().count(), 1, "only the diagnostic: {}", out);
+    }
+
+    #[tokio::test]
+    async fn a_rendering_that_names_a_word_is_not_evaluated() {
+        // A datetime is shown as `@…`, which would be looked up as a word.
+        let (ok, _, out) = roundtrip("'time' IMPORT NOW").await;
+        assert!(!ok);
+        assert!(out.contains("not evaluated"), "{}", out);
+    }
+
+    #[tokio::test]
+    async fn continued_fraction_role_does_not_roundtrip() {
+        // `>CF` only retags the slot; the rendering carries no trace of it.
+        let (ok, _, out) = roundtrip("[ 1/2 ] >CF").await;
+        assert!(!ok);
+        assert!(out.contains("expected ContinuedFraction"), "{}", out);
+    }
+
+    #[tokio::test]
+    async fn stack_mode_is_rejected() {
+        let mut interp = Interpreter::new();
+        let result = interp.execute("'reflect' IMPORT [ 1 ] .. ROUNDTRIP?").await;
+        assert!(result.is_err());
+    }
+
+    async fn type_of(code: &str) -> String {
+        let mut interp = Interpreter::new();
+        interp
+            .execute(&format!("'reflect' IMPORT 'time' IMPORT {} TYPE", code))
+            .await
+            .expect("should succeed");
+        assert_eq!(interp.stack.len(), 1, "TYPE consumes the value");
+        value_as_string(interp.stack.last().unwrap()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn type_names_each_display_kind() {
+        assert_eq!(type_of("[ 1/2 ]").await, "vector");
+        assert_eq!(type_of("1/2").await, "number");
+        assert_eq!(type_of("'hello'").await, "string");
+        assert_eq!(type_of("TRUE").await, "boolean");
+        assert_eq!(type_of("NIL").await, "nil");
+        assert_eq!(type_of("[ [ 1 2 ] [ 'a' ] ]").await, "vector");
+        assert_eq!(type_of("NOW").await, "datetime");
+        assert_eq!(type_of("{ 1 2 + }").await, "code");
+    }
+
+    #[tokio::test]
+    async fn type_keeps_the_value_in_keep_mode() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute("'reflect' IMPORT 'time' IMPORT NOW ,, TYPE")
+            .await
+            .expect("should succeed");
+        assert_eq!(interp.stack.len(), 2);
+        assert_eq!(
+            value_as_string(interp.stack.last().unwrap()).unwrap(),
+            "datetime"
+        );
+        let (_, role) = interp.stack.iter_slots().next().unwrap();
+        assert_eq!(role, crate::types::Interpretation::Timestamp);
+    }
+
+    async fn source_of(code: &str) -> String {
+        let mut interp = Interpreter::new();
+        interp
+            .execute(&format!("'reflect' IMPORT {} SOURCE", code))
+            .await
+            .expect("should succeed");
+        assert_eq!(interp.stack.len(), 1);
+        value_as_string(interp.stack.last().unwrap()).expect("text")
+    }
+
+    #[tokio::test]
+    async fn source_reconstructs_a_user_definition() {
+        let source = source_of("{ [ 2 ] * } 'DOUBLE' DEF 'DOUBLE'").await;
+        assert_eq!(source, "{ [ 2 ] * } 'DOUBLE' DEF");
+    }
+
+    #[tokio::test]
+    async fn source_text_redefines_the_word_when_evaluated() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute(
+                "'reflect' IMPORT { [ 3 ] + } 'BUMP' DEF 'BUMP' SOURCE 'BUMP' DEL EVAL [ 1 ] BUMP",
+            )
+            .await
+            .expect("should succeed");
+        assert_eq!(interp.stack.last().unwrap().to_string(), "[ 4/1 ]");
+    }
+
+    #[tokio::test]
+    async fn source_of_a_builtin_is_its_description() {
+        let source = source_of("'LENGTH'").await;
+        assert!(!source.is_empty());
+        assert!(!source.contains("DEF"), "{}", source);
+    }
+
+    #[tokio::test]
+    async fn source_of_unknown_word_is_an_error() {
+        let mut interp = Interpreter::new();
+        let result = interp
+            .execute("'reflect' IMPORT 'NO-SUCH-WORD' SOURCE")
+            .await;
+        assert!(result.is_err());
+        assert_eq!(interp.stack.len(), 1, "the name is left in place");
+    }
+
+    async fn words_after(code: &str) -> Vec<String> {
+        let mut interp = Interpreter::new();
+        interp
+            .execute(&format!("'reflect' IMPORT {} WORDS", code))
+            .await
+            .expect("should succeed");
+        let listing = interp.stack.last().unwrap();
+        (0..listing.len())
+            .map(|i| value_as_string(&listing.child(i).unwrap()).expect("text"))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn words_lists_user_and_builtin_words_unfiltered() {
+        let words = words_after("{ [ 2 ] * } 'DOUBLE' DEF [ FALSE ]").await;
+        assert!(words.contains(&"DOUBLE".to_string()));
+        assert!(words.contains(&"LENGTH".to_string()));
+        assert!(
+            words.contains(&"SOURCE".to_string()),
+            "imported module words are listed"
+        );
+        assert!(
+            words.windows(2).all(|w| w[0] < w[1]),
+            "sorted without duplicates"
+        );
+    }
+
+    #[tokio::test]
+    async fn words_custom_only_omits_builtins() {
+        let words = words_after("{ [ 2 ] * } 'DOUBLE' DEF { [ 1 ] + } 'INC' DEF [ TRUE ]").await;
+        assert_eq!(words, vec!["DOUBLE", "INC"]);
+    }
+
+    #[tokio::test]
+    async fn words_rejects_a_non_boolean_flag() {
+        let mut interp = Interpreter::new();
+        assert!(interp.execute("'reflect' IMPORT 1 WORDS").await.is_err());
+        assert_eq!(interp.stack.len(), 1);
+    }
+
+    async fn listing_after(code: &str) -> Vec<String> {
+        let mut interp = Interpreter::new();
+        interp
+            .execute(&format!(
+                "'reflect' IMPORT {{ [ 2 ] * }} 'DOUBLE' DEF {{ DOUBLE DOUBLE }} 'QUAD' DEF {}",
+                code
+            ))
+            .await
+            .expect("should succeed");
+        let listing = interp.stack.last().unwrap();
+        (0..listing.len())
+            .map(|i| value_as_string(&listing.child(i).unwrap()).expect("text"))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn dependencies_names_the_called_user_words() {
+        assert_eq!(
+            listing_after("'QUAD' DEPENDENCIES").await,
+            vec!["EXAMPLE@DOUBLE"]
+        );
+    }
+
+    #[tokio::test]
+    async fn dependents_names_the_words_that_block_del() {
+        assert_eq!(
+            listing_after("'DOUBLE' DEPENDENTS").await,
+            vec!["EXAMPLE@QUAD"]
+        );
+    }
+
+    #[tokio::test]
+    async fn words_without_edges_yield_nil() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute("'reflect' IMPORT { [ 2 ] * } 'DOUBLE' DEF 'DOUBLE' DEPENDENCIES 'DOUBLE' DEPENDENTS")
+            .await
+            .expect("should succeed");
+        assert!(interp.stack.iter().all(|v| v.is_nil()));
+        assert_eq!(interp.stack.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn dependents_of_unknown_word_is_an_error() {
+        let mut interp = Interpreter::new();
+        let result = interp
+            .execute("'reflect' IMPORT 'NO-SUCH-WORD' DEPENDENTS")
+            .await;
+        assert!(result.is_err());
+        assert_eq!(interp.stack.len(), 1);
+    }
+
+    async fn top_after(code: &str) -> String {
+        let mut interp = Interpreter::new();
+        interp
+            .execute(&format!("'reflect' IMPORT {}", code))
+            .await
+            .expect("should succeed");
+        interp.stack.last().unwrap().to_string()
+    }
+
+    #[tokio::test]
+    async fn alias_of_a_user_word_behaves_identically() {
+        let def = "{ [ 2 ] * [ 1 ] + } 'STEP' DEF 'STEP' 'NEXT' ALIAS";
+        let original = top_after(&format!("{} [ 5 ] STEP", def)).await;
+        let alias = top_after(&format!("{} [ 5 ] NEXT", def)).await;
+        assert_eq!(original, "[ 11/1 ]");
+        assert_eq!(alias, original);
+    }
+
+    #[tokio::test]
+    async fn alias_copies_the_body_so_the_original_can_be_deleted() {
+        let result =
+            top_after("{ [ 2 ] * } 'DOUBLE' DEF 'DOUBLE' 'TWICE' ALIAS 'DOUBLE' DEL [ 4 ] TWICE")
+                .await;
+        assert_eq!(result, "[ 8/1 ]");
+    }
+
+    #[tokio::test]
+    async fn alias_of_a_builtin_calls_it() {
+        assert_eq!(top_after("'LENGTH' 'LEN' ALIAS [ 1 2 3 ] LEN").await, "3/1");
+    }
+
+    #[tokio::test]
+    async fn alias_cannot_shadow_a_builtin() {
+        let mut interp = Interpreter::new();
+        let result = interp
+            .execute("'reflect' IMPORT 'LENGTH' 'ADD' ALIAS")
+            .await;
+        assert!(result.is_err());
+        assert_eq!(interp.stack.len(), 2, "both names are left in place");
+    }
+
+    const INC_DOUBLE: &str = "{ [ 1 ] + } 'INC' DEF { [ 2 ] * } 'DOUBLE' DEF";
+
+    #[tokio::test]
+    async fn compose_runs_the_first_word_then_the_second() {
+        let inc_then_double = format!("{} 'INC' 'DOUBLE' 'F' COMPOSE [ 5 ] F", INC_DOUBLE);
+        let double_then_inc = format!("{} 'DOUBLE' 'INC' 'F' COMPOSE [ 5 ] F", INC_DOUBLE);
+        assert_eq!(top_after(&inc_then_double).await, "[ 12/1 ]");
+        assert_eq!(top_after(&double_then_inc).await, "[ 11/1 ]");
+    }
+
+    #[tokio::test]
+    async fn composed_word_depends_on_both_words() {
+        let names = listing_after("'DOUBLE' 'QUAD' 'F' COMPOSE 'F' DEPENDENCIES").await;
+        assert_eq!(names, ["EXAMPLE@DOUBLE", "EXAMPLE@QUAD"]);
+    }
+
+    #[tokio::test]
+    async fn compose_refuses_an_existing_name_unless_forced() {
+        let mut interp = Interpreter::new();
+        let result = interp
+            .execute(&format!(
+                "'reflect' IMPORT {} 'INC' 'INC' 'DOUBLE' COMPOSE",
+                INC_DOUBLE
+            ))
+            .await;
+        assert!(result.is_err());
+        assert_eq!(interp.stack.len(), 3, "all three names are left in place");
+
+        let forced = top_after(&format!(
+            "{} 'INC' 'INC' 'DOUBLE' ! COMPOSE [ 5 ] DOUBLE",
+            INC_DOUBLE
+        ))
+        .await;
+        assert_eq!(forced, "[ 7/1 ]");
+    }
+
+    #[tokio::test]
+    async fn partial_binds_a_leading_argument() {
+        assert_eq!(
+            top_after("[ 10 ] '+' 'ADD10' PARTIAL [ 5 ] ADD10").await,
+            "[ 15/1 ]"
+        );
+        assert_eq!(
+            top_after("[ 1/3 ] '*' 'THIRD' PARTIAL [ 6 ] THIRD").await,
+            "[ 2/1 ]"
+        );
+    }
+
+    #[tokio::test]
+    async fn partial_stores_the_argument_as_reparsable_text() {
+        let source = source_of("[ 10 ] '+' 'ADD10' PARTIAL 'ADD10'").await;
+        assert_eq!(source, "{ [ 10/1 ] + } 'ADD10' DEF");
+        let rebuilt =
+            top_after("[ 10 ] '+' 'ADD10' PARTIAL 'ADD10' SOURCE 'ADD10' DEL EVAL [ 5 ] ADD10")
+                .await;
+        assert_eq!(rebuilt, "[ 15/1 ]");
+    }
+
+    #[tokio::test]
+    async fn partial_refuses_an_argument_that_does_not_roundtrip() {
+        let mut interp = Interpreter::new();
+        let result = interp
+            .execute("'reflect' IMPORT { 1 } 'EXEC' 'X' PARTIAL")
+            .await;
+        assert!(result.is_err());
+        assert_eq!(interp.stack.len(), 3, "all operands are left in place");
+        assert!(interp.resolve_word("X").is_none());
+    }
+
+    #[tokio::test]
+    async fn partial_never_runs_a_code_block_argument() {
+        let mut interp = Interpreter::new();
+        let result = interp
+            .execute(
+                "'reflect' IMPORT { 'side-effect' PRINT { [ 99 ] } 'HACK' DEF } '+' 'P' PARTIAL",
+            )
+            .await;
+        assert!(result.is_err());
+        assert!(interp.collect_output().is_empty());
+        assert!(interp.resolve_word("HACK").is_none());
+        assert!(interp.resolve_word("P").is_none());
+        assert_eq!(interp.stack.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn describe_sets_the_description_lookup_shows() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute(
+                "'reflect' IMPORT { [ 2 ] * } 'DOUBLE' DEF 'DOUBLE' 'Doubles its input' DESCRIBE",
+            )
+            .await
+            .expect("should succeed");
+        assert!(interp.stack.is_empty());
+        let (_, def) = interp.resolve_word_entry_readonly("DOUBLE").unwrap();
+        assert_eq!(def.description.as_deref(), Some("Doubles its input"));
+        assert_eq!(
+            interp.user_words["DOUBLE"].description.as_deref(),
+            Some("Doubles its input")
+        );
+
+        interp.execute("'DOUBLE' ?").await.expect("lookup");
+        let shown = interp.definition_to_load.clone().unwrap();
+        assert!(shown.contains("'Doubles its input'"), "{}", shown);
+        interp
+            .execute("[ 4 ] DOUBLE")
+            .await
+            .expect("body unchanged");
+        assert_eq!(interp.stack.last().unwrap().to_string(), "[ 8/1 ]");
+    }
+
+    #[tokio::test]
+    async fn describe_with_nil_clears_the_description() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute("'reflect' IMPORT { [ 2 ] * } 'DOUBLE' DEF 'DOUBLE' 'x' DESCRIBE 'DOUBLE' NIL DESCRIBE")
+            .await
+            .expect("should succeed");
+        let (_, def) = interp.resolve_word_entry_readonly("DOUBLE").unwrap();
+        assert_eq!(def.description, None);
+    }
+
+    #[tokio::test]
+    async fn describe_rejects_builtins_and_unknown_words() {
+        for code in ["'LENGTH' 'counts' DESCRIBE", "'NOPE' 'missing' DESCRIBE"] {
+            let mut interp = Interpreter::new();
+            let result = interp.execute(&format!("'reflect' IMPORT {}", code)).await;
+            assert!(result.is_err(), "{}", code);
+            assert_eq!(interp.stack.len(), 2, "both operands are left in place");
+        }
+    }
+}
@@ -0,0 +1,54 @@
+//! MATH module words that round exact values onto a rational grid, built on
+//! the same [`Fraction::quantize`] the core QUANTIZE family uses.
+
+use num_traits::Signed;
+
+use crate::error::{AjisaiError, Result};
+use crate::interpreter::math_ops::{require_stack_top, restore_operands};
+use crate::interpreter::tensor_ops::apply_unary_flat_with_metrics;
+use crate::interpreter::value_extraction_helpers::{
+    extract_bigint_from_value, extract_operands, nil_passthrough_value, push_result,
+};
+use crate::interpreter::Interpreter;
+use crate::types::fraction::{Fraction, RoundingMode};
+use crate::types::Interpretation;
+
+/// `[ x ] [ d ] -- [ rounded ]`. Round `x` to the nearest multiple of
+/// `1/d`, ties away from zero (the ROUND rule), so the result is exact:
+/// `[ 1/3 ] [ 100 ] ROUNDTO` is `[ 33/100 ]`. `x` may be a numeric vector,
+/// rounded elementwise; `d` must be a positive integer. Unlike
+/// QUANTIZE-HALF-AWAY, the residual is dropped. NIL-passthrough.
+pub(crate) fn op_round_to(interp: &mut Interpreter) -> Result<()> {
+    require_stack_top(interp, "ROUNDTO")?;
+    let operands = extract_operands(interp, 2)?;
+    if let Some(nil) = nil_passthrough_value(&operands) {
+        push_result(interp, nil);
+        return Ok(());
+    }
+    let step = match extract_bigint_from_value(&operands[1]) {
+        Ok(d) if d.is_positive() => Fraction::new(1.into(), d),
+        _ => {
+            restore_operands(interp, operands);
+            return Err(AjisaiError::from(
+                "ROUNDTO: denominator must be a positive integer",
+            ));
+        }
+    };
+    let rounded = match operands[0].hint {
+        Interpretation::Text => None,
+        _ => apply_unary_flat_with_metrics(
+            &operands[0],
+            |x| x.quantize(&step, RoundingMode::HalfAway).0,
+            Some(&mut interp.runtime_metrics),
+        )
+        .ok(),
+    };
+    let Some(rounded) = rounded else {
+        restore_operands(interp, operands);
+        return Err(AjisaiError::from(
+            "ROUNDTO: expected a rational number or vector",
+        ));
+    };
+    push_result(interp, rounded);
+    Ok(())
+}
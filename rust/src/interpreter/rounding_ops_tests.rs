@@ -0,0 +1,58 @@
+//! Test suite for `crate::interpreter::rounding_ops` (MATH@ROUNDTO).
+
+#[cfg(test)]
+mod tests {
+    use crate::interpreter::Interpreter;
+
+    async fn render_top(program: &str) -> String {
+        let mut interp = Interpreter::new();
+        interp
+            .execute(program)
+            .await
+            .expect("program should succeed");
+        assert_eq!(interp.stack.len(), 1, "program: {program}");
+        interp.stack[0].to_string()
+    }
+
+    #[tokio::test]
+    async fn roundto_rounds_down_and_up_to_the_grid() {
+        assert_eq!(
+            render_top("'math' IMPORT [ 1/3 ] [ 100 ] ROUNDTO").await,
+            "[ 33/100 ]"
+        );
+        assert_eq!(
+            render_top("'math' IMPORT [ 2/3 ] [ 100 ] ROUNDTO").await,
+            "[ 67/100 ]"
+        );
+        assert_eq!(
+            render_top("'math' IMPORT [ 1/3 2/3 5/4 ] [ 2 ] ROUNDTO").await,
+            "[ 1/2 1/2 3/2 ]"
+        );
+    }
+
+    #[tokio::test]
+    async fn roundto_breaks_ties_away_from_zero() {
+        assert_eq!(
+            render_top("'math' IMPORT [ 1/200 ] [ 100 ] ROUNDTO").await,
+            "[ 1/100 ]"
+        );
+        assert_eq!(
+            render_top("'math' IMPORT [ -1/200 ] [ 100 ] ROUNDTO").await,
+            "[ -1/100 ]"
+        );
+        assert_eq!(render_top("'math' IMPORT 5/2 1 ROUNDTO").await, "3/1");
+    }
+
+    #[tokio::test]
+    async fn roundto_rejects_bad_denominators_and_text() {
+        for program in [
+            "'math' IMPORT [ 1/3 ] [ 0 ] ROUNDTO",
+            "'math' IMPORT [ 1/3 ] [ 1/2 ] ROUNDTO",
+            "'math' IMPORT 'a' [ 10 ] ROUNDTO",
+        ] {
+            let mut interp = Interpreter::new();
+            assert!(interp.execute(program).await.is_err(), "{program}");
+            assert_eq!(interp.stack.len(), 2, "operands restored: {program}");
+        }
+    }
+}
@@ -54,6 +54,7 @@ impl Interpreter {
         self.user_dictionaries.clear();
         self.dependents.clear();
         self.output_buffer.clear();
+        self.debug_buffer.clear();
         self.host_effects.clear();
         self.definition_to_load = None;
         self.reset_execution_modes();
@@ -72,6 +73,7 @@ impl Interpreter {
         self.word_identities.clear();
         self.body_store.clear();
         self.defer_identity_recompute = false;
+        self.memo_tables.clear();
         self.import_table.modules.clear();
         self.module_vocabulary.clear();
         self.dictionary_dependencies.clear();
@@ -90,6 +92,8 @@ impl Interpreter {
         // Provenance recording flag persists across a reset; only its data is
         // cleared (Phase 6).
         self.receipt_recorder.clear();
+        self.word_trace.clear();
+        self.undo_history.clear();
         crate::builtins::register_builtins(&mut self.core_vocabulary);
     }
 
@@ -0,0 +1,298 @@
+//! Whole-session persistence: the user-visible interpreter state as one JSON
+//! document.
+//!
+//! `export_state` captures the stack (through the lossless `value_persist`
+//! codec), module imports, every user word in definition order, the active
+//! dictionary, and the operation target. Core words are never exported: they
+//! are re-registered by the reset that `import_state` starts with. Words are
+//! restored by re-running `DEF` on their reconstructed source, so dependencies,
+//! content identities, and compiled plans are rebuilt exactly as they would be
+//! for freshly typed definitions. `export_dictionary` is the same document
+//! without the stack and session settings; `import_dictionary` merges it
+//! into a running session (IO@SAVE / IO@LOAD store it in the browser).
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{AjisaiError, Result};
+use crate::types::value_persist::{decode_stack, encode_stack};
+
+use super::{Interpreter, OperationTargetMode};
+
+const SESSION_STATE_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize)]
+struct ImportState {
+    module: String,
+    import_all_public: bool,
+    words: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct UserWordState {
+    dictionary: String,
+    name: String,
+    source: String,
+    description: Option<String>,
+    /// Informational for hosts; `DEF` recomputes dependencies on import.
+    dependencies: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SessionState {
+    version: u32,
+    operation_target: String,
+    active_dictionary: String,
+    imports: Vec<ImportState>,
+    words: Vec<UserWordState>,
+    stack: serde_json::Value,
+}
+
+fn target_to_tag(mode: OperationTargetMode) -> &'static str {
+    match mode {
+        OperationTargetMode::StackTop => "stackTop",
+        OperationTargetMode::Stack => "stack",
+    }
+}
+
+fn target_from_tag(tag: &str) -> Result<OperationTargetMode> {
+    match tag {
+        "stackTop" => Ok(OperationTargetMode::StackTop),
+        "stack" => Ok(OperationTargetMode::Stack),
+        other => Err(AjisaiError::from(format!(
+            "import_state: unknown operation target '{}'",
+            other
+        ))),
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct DictionaryDocument {
+    version: u32,
+    imports: Vec<ImportState>,
+    words: Vec<UserWordState>,
+}
+
+impl Interpreter {
+    fn exported_imports(&self) -> Vec<ImportState> {
+        let mut imports: Vec<ImportState> = self
+            .import_table
+            .modules
+            .iter()
+            .map(|(module, entry)| {
+                let mut words: Vec<String> = entry.imported_words.iter().cloned().collect();
+                words.sort();
+                ImportState {
+                    module: module.clone(),
+                    import_all_public: entry.import_all_public,
+                    words,
+                }
+            })
+            .collect();
+        imports.sort_by(|a, b| a.module.cmp(&b.module));
+        imports
+    }
+
+    fn exported_words(&self) -> Result<Vec<UserWordState>> {
+        // Not registration order: a forced redefinition registers the word
+        // again, after the words that already call it.
+        let ordered = self.definitions_in_dependency_order();
+        let mut words = Vec::with_capacity(ordered.len());
+        for (fq_name, name) in ordered {
+            let Some((dict, def)) = self.user_dictionaries.iter().find_map(|(dict, d)| {
+                d.words
+                    .get(&name)
+                    .filter(|_| fq_name == format!("{}@{}", dict, name))
+                    .map(|def| (dict, def))
+            }) else {
+                continue;
+            };
+            let source = self
+                .lookup_word_definition_tokens(&fq_name)
+                .ok_or_else(|| {
+                    AjisaiError::from(format!("export_state: no source for '{}'", fq_name))
+                })?;
+            let mut dependencies: Vec<String> = def.dependencies.iter().cloned().collect();
+            dependencies.sort();
+            words.push(UserWordState {
+                dictionary: dict.clone(),
+                name: name.clone(),
+                source,
+                description: def.description.clone(),
+                dependencies,
+            });
+        }
+        Ok(words)
+    }
+
+    /// Serialize the session to a JSON document that `import_state` restores.
+    pub fn export_state(&self) -> Result<String> {
+        let imports = self.exported_imports();
+        let words = self.exported_words()?;
+        let stack_json = encode_stack(self.stack.iter_slots()).map_err(AjisaiError::from)?;
+        let state = SessionState {
+            version: SESSION_STATE_VERSION,
+            operation_target: target_to_tag(self.operation_target_mode).to_string(),
+            active_dictionary: self.active_user_dictionary.clone(),
+            imports,
+            words,
+            stack: serde_json::from_str(&stack_json)
+                .map_err(|e| AjisaiError::from(e.to_string()))?,
+        };
+        serde_json::to_string(&state).map_err(|e| AjisaiError::from(e.to_string()))
+    }
+
+    /// Replace the session with one produced by `export_state`. Starts from a
+    /// session reset, so nothing of the current session survives; on error the
+    /// interpreter is left reset rather than half-restored.
+    pub fn import_state(&mut self, json: &str) -> Result<()> {
+        let state: SessionState = serde_json::from_str(json)
+            .map_err(|e| AjisaiError::from(format!("import_state: {}", e)))?;
+        if state.version != SESSION_STATE_VERSION {
+            return Err(AjisaiError::from(format!(
+                "import_state: unsupported state version {}",
+                state.version
+            )));
+        }
+        let target = target_from_tag(&state.operation_target)?;
+        let slots = decode_stack(&state.stack.to_string()).map_err(AjisaiError::from)?;
+
+        self.execute_session_reset()?;
+        let restored = self.restore_session(state, target, slots);
+        if restored.is_err() {
+            self.execute_session_reset()?;
+        }
+        restored
+    }
+
+    fn restore_session(
+        &mut self,
+        state: SessionState,
+        target: OperationTargetMode,
+        slots: Vec<(crate::types::Value, crate::types::Interpretation)>,
+    ) -> Result<()> {
+        for import in state.imports {
+            if !super::modules::restore_import_entry(
+                self,
+                &import.module,
+                import.import_all_public,
+                import.words,
+                Vec::new(),
+            ) {
+                return Err(AjisaiError::UnknownModule(import.module));
+            }
+        }
+
+        self.defer_identity_recompute = true;
+        let defined = self.define_exported_words(state.words, false);
+        self.defer_identity_recompute = false;
+        defined?;
+        self.rebuild_dependencies()?;
+        // DEF's naming-convention warnings were already shown when the words
+        // were first defined.
+        self.output_buffer.clear();
+
+        self.active_user_dictionary = state.active_dictionary;
+        self.operation_target_mode = target;
+        let (values, roles): (Vec<_>, Vec<_>) = slots.into_iter().unzip();
+        self.update_stack_with_hints(values, roles);
+        Ok(())
+    }
+
+    /// DEF each word into its own dictionary. `force` redefines words that
+    /// already have dependents, as `!` does.
+    fn define_exported_words(&mut self, words: Vec<UserWordState>, force: bool) -> Result<()> {
+        for word in words {
+            self.active_user_dictionary = word.dictionary.clone();
+            let tokens = crate::tokenizer::tokenize(&word.source)
+                .map_err(|e| AjisaiError::from(format!("import_state: '{}': {}", word.name, e)))?;
+            self.force_flag = force;
+            super::execute_def::op_def_inner(self, &word.name, &tokens)?;
+            if let Some(description) = word.description {
+                let def = self
+                    .user_dictionaries
+                    .get_mut(&word.dictionary)
+                    .and_then(|dict| dict.words.get_mut(&word.name));
+                if let Some(def) = def {
+                    std::sync::Arc::make_mut(def).description = Some(description);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The dictionary half of `export_state`: module imports and user words,
+    /// without the stack or session settings.
+    pub fn export_dictionary(&self) -> Result<String> {
+        let document = DictionaryDocument {
+            version: SESSION_STATE_VERSION,
+            imports: self.exported_imports(),
+            words: self.exported_words()?,
+        };
+        serde_json::to_string(&document).map_err(|e| AjisaiError::from(e.to_string()))
+    }
+
+    /// Merge a document from `export_dictionary` into the running session.
+    /// Its words replace same-named ones (dependents included); the stack,
+    /// the active dictionary and words it does not mention are left alone.
+    /// The merge is all or nothing: a document that fails to parse, or a
+    /// word that fails to define, leaves the imports and dictionaries as
+    /// they were.
+    pub fn import_dictionary(&mut self, json: &str) -> Result<()> {
+        let document: DictionaryDocument = serde_json::from_str(json)
+            .map_err(|e| AjisaiError::from(format!("import_dictionary: {}", e)))?;
+        if document.version != SESSION_STATE_VERSION {
+            return Err(AjisaiError::from(format!(
+                "import_dictionary: unsupported version {}",
+                document.version
+            )));
+        }
+        for word in &document.words {
+            crate::tokenizer::tokenize(&word.source).map_err(|e| {
+                AjisaiError::from(format!("import_dictionary: '{}': {}", word.name, e))
+            })?;
+        }
+
+        let saved_import_table = self.import_table.clone();
+        let saved_words = self.user_words.clone();
+        let saved_dictionaries = self.user_dictionaries.clone();
+        let saved_dependents = self.dependents.clone();
+        let saved_dictionary_dependencies = self.dictionary_dependencies.clone();
+        let active = self.active_user_dictionary.clone();
+        let output_len = self.output_buffer.len();
+
+        let merged = self.merge_dictionary_document(document);
+        self.active_user_dictionary = active;
+        self.output_buffer.truncate(output_len);
+        if merged.is_err() {
+            self.import_table = saved_import_table;
+            self.user_words = saved_words;
+            self.user_dictionaries = saved_dictionaries;
+            self.dependents = saved_dependents;
+            self.dictionary_dependencies = saved_dictionary_dependencies;
+            self.bump_module_epoch();
+            self.bump_dictionary_epoch();
+            self.recompute_word_identities();
+            self.gc_body_store();
+        }
+        merged
+    }
+
+    fn merge_dictionary_document(&mut self, document: DictionaryDocument) -> Result<()> {
+        for import in document.imports {
+            if !super::modules::restore_import_entry(
+                self,
+                &import.module,
+                import.import_all_public,
+                import.words,
+                Vec::new(),
+            ) {
+                return Err(AjisaiError::UnknownModule(import.module));
+            }
+        }
+        self.defer_identity_recompute = true;
+        let defined = self.define_exported_words(document.words, true);
+        self.defer_identity_recompute = false;
+        defined?;
+        self.rebuild_dependencies()
+    }
+}
@@ -0,0 +1,184 @@
+//! Test suite for `Interpreter::export_state` / `Interpreter::import_state`.
+//!
+//! The contract is behavioural: after a reset and an import, the user words
+//! (including ones that depend on each other and on imported module words)
+//! run exactly as before, and the stack and operation target come back
+//! unchanged. Core words are re-registered by the reset, never serialized.
+
+#[cfg(test)]
+mod tests {
+    use crate::interpreter::{Interpreter, OperationTargetMode};
+
+    async fn session_with_words() -> Interpreter {
+        let mut interp = Interpreter::new();
+        interp.execute("'math' IMPORT").await.unwrap();
+        interp.execute("{ 2 * } 'DOUBLE' DEF").await.unwrap();
+        interp.execute("{ 2 POW } 'SQUARE' DEF").await.unwrap();
+        interp
+            .execute("{ DOUBLE SQUARE } 'DOUBLE-SQUARE' DEF")
+            .await
+            .unwrap();
+        interp.execute("[ 1 2 ] 'kept'").await.unwrap();
+        interp
+    }
+
+    fn rendered_stack(interp: &Interpreter) -> Vec<String> {
+        interp
+            .stack
+            .iter_slots()
+            .map(|(v, role)| crate::types::display::format_with_hint(v, role))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn words_run_identically_after_reset_and_import() {
+        let mut interp = session_with_words().await;
+        let stack_before = rendered_stack(&interp);
+        let json = interp.export_state().unwrap();
+
+        interp.execute_reset().unwrap();
+        assert!(interp.execute("3 DOUBLE-SQUARE").await.is_err());
+
+        interp.import_state(&json).unwrap();
+        assert_eq!(rendered_stack(&interp), stack_before);
+
+        interp.execute("3 DOUBLE-SQUARE").await.unwrap();
+        let top = interp.stack.last().and_then(|v| v.as_scalar()).cloned();
+        assert_eq!(top.and_then(|f| f.to_i64()), Some(36));
+    }
+
+    #[tokio::test]
+    async fn exported_words_list_dependencies_before_dependents() {
+        let mut interp = Interpreter::new();
+        interp.execute("{ 1 + } 'INC' DEF").await.unwrap();
+        interp.execute("{ INC INC } 'INC2' DEF").await.unwrap();
+        interp.execute("{ 10 + } 'STEP' DEF").await.unwrap();
+        interp.execute("! { STEP } 'INC' DEF").await.unwrap();
+        let json = interp.export_dictionary().unwrap();
+        let payload: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let names: Vec<&str> = payload["words"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|word| word["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, ["STEP", "INC", "INC2"]);
+
+        let mut fresh = Interpreter::new();
+        fresh.import_dictionary(&json).unwrap();
+        fresh.execute("1 INC2").await.unwrap();
+        let top = fresh.stack.last().and_then(|v| v.as_scalar()).cloned();
+        assert_eq!(top.and_then(|f| f.to_i64()), Some(21));
+    }
+
+    #[tokio::test]
+    async fn dependencies_are_rebuilt_on_import() {
+        let mut interp = session_with_words().await;
+        let json = interp.export_state().unwrap();
+        interp.execute_reset().unwrap();
+        interp.import_state(&json).unwrap();
+
+        // DOUBLE is still protected by its dependent after the round trip.
+        let err = interp.execute("{ 3 * } 'DOUBLE' DEF").await.unwrap_err();
+        assert!(err.to_string().contains("DOUBLE-SQUARE"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn export_contains_only_user_words() {
+        let interp = session_with_words().await;
+        let state: serde_json::Value =
+            serde_json::from_str(&interp.export_state().unwrap()).unwrap();
+        let names: Vec<&str> = state["words"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|w| w["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, ["DOUBLE", "SQUARE", "DOUBLE-SQUARE"]);
+    }
+
+    #[tokio::test]
+    async fn operation_target_round_trips() {
+        let mut interp = Interpreter::new();
+        interp.operation_target_mode = OperationTargetMode::Stack;
+        let json = interp.export_state().unwrap();
+        let mut restored = Interpreter::new();
+        restored.import_state(&json).unwrap();
+        assert_eq!(restored.operation_target_mode, OperationTargetMode::Stack);
+    }
+
+    #[tokio::test]
+    async fn malformed_state_is_rejected() {
+        let mut interp = Interpreter::new();
+        assert!(interp.import_state("not json").is_err());
+        let mut state: serde_json::Value =
+            serde_json::from_str(&interp.export_state().unwrap()).unwrap();
+        state["version"] = serde_json::json!(99);
+        assert!(interp.import_state(&state.to_string()).is_err());
+    }
+
+    #[tokio::test]
+    async fn dictionary_payload_carries_imports_and_words_but_no_stack() {
+        let interp = session_with_words().await;
+        let payload: serde_json::Value =
+            serde_json::from_str(&interp.export_dictionary().unwrap()).unwrap();
+        assert!(payload.get("stack").is_none());
+        assert_eq!(payload["imports"][0]["module"], "MATH");
+        let sources: Vec<&str> = payload["words"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|w| w["source"].as_str().unwrap())
+            .collect();
+        assert_eq!(sources, ["2 *", "2 POW", "DOUBLE SQUARE"]);
+    }
+
+    #[tokio::test]
+    async fn dictionary_import_merges_into_a_running_session() {
+        let saved = session_with_words().await;
+        let payload = saved.export_dictionary().unwrap();
+
+        let mut interp = Interpreter::new();
+        interp
+            .execute("{ 100 } 'DOUBLE' DEF { DOUBLE } 'USES-DOUBLE' DEF 7")
+            .await
+            .unwrap();
+        interp.import_dictionary(&payload).unwrap();
+        assert_eq!(rendered_stack(&interp), ["7/1"], "the stack is untouched");
+
+        // DOUBLE is replaced despite its dependent, and DOUBLE-SQUARE works
+        // with the MATH import the payload restored.
+        interp
+            .execute("3 DOUBLE-SQUARE 5 USES-DOUBLE")
+            .await
+            .unwrap();
+        assert_eq!(rendered_stack(&interp), ["7/1", "36/1", "10/1"]);
+    }
+
+    #[tokio::test]
+    async fn malformed_dictionary_payload_defines_nothing() {
+        let mut interp = Interpreter::new();
+        let mut payload: serde_json::Value =
+            serde_json::from_str(&session_with_words().await.export_dictionary().unwrap()).unwrap();
+        payload["words"][2]["source"] = serde_json::json!("'unterminated");
+        assert!(interp.import_dictionary(&payload.to_string()).is_err());
+        assert!(interp.execute("3 DOUBLE").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn a_word_that_fails_to_define_rolls_the_whole_import_back() {
+        let mut interp = Interpreter::new();
+        let mut payload: serde_json::Value =
+            serde_json::from_str(&session_with_words().await.export_dictionary().unwrap()).unwrap();
+        // DOUBLE and SQUARE define first; a core name cannot be redefined.
+        payload["words"][2]["name"] = serde_json::json!("GET");
+        assert!(interp.import_dictionary(&payload.to_string()).is_err());
+        assert!(interp.resolve_word("DOUBLE").is_none());
+        assert!(interp.resolve_word("SQUARE").is_none());
+        assert!(
+            interp.import_table.modules.is_empty(),
+            "the import is undone"
+        );
+        assert!(interp.collect_output().is_empty());
+    }
+}
@@ -101,6 +101,9 @@ impl Interpreter {
         let saved_output = std::mem::take(&mut self.output_buffer);
         let saved_io_output = std::mem::take(&mut self.io_output_buffer);
         let saved_host_effects = std::mem::take(&mut self.host_effects);
+        // Both paths must see the same REFLECT@MEMOIZE caches, or the plain
+        // path replays what the fast path just recorded and skips its effects.
+        let saved_memo_tables = (!self.memo_tables.is_empty()).then(|| self.memo_tables.clone());
 
         // Each path runs as one trampolined body pass; a guarded tail self-call
         // defers by raising `tail_jump_pending`. Capture each path's flag so the
@@ -118,6 +121,9 @@ impl Interpreter {
         self.stack = saved_stack;
         self.operation_target_mode = saved_target;
         self.consumption_mode = saved_consumption;
+        if let Some(memo_tables) = saved_memo_tables {
+            self.memo_tables = memo_tables;
+        }
 
         self.tail_jump_pending = false;
         let plain_result = self.execute_guard_structure(&def.lines);
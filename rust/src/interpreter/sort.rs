@@ -1,5 +1,6 @@
 use crate::error::{AjisaiError, Result};
 use crate::interpreter::comparison::{three_way_compare, OrderOutcome};
+use crate::interpreter::value_extraction_helpers::extract_operands;
 use crate::interpreter::{ConsumptionMode, Interpreter, OperationTargetMode};
 use crate::types::Stack;
 use crate::types::Value;
@@ -153,6 +154,98 @@ pub fn op_sort(interp: &mut Interpreter) -> Result<()> {
     }
 }
 
+/// Outcome of merging two ascending runs; mirrors `SortAttempt`.
+enum MergeAttempt {
+    Merged(Vec<Value>),
+    Undecided(usize),
+    Malformed(AjisaiError),
+}
+
+/// Standard two-pointer merge. Ties take the left element first, so the
+/// merge is stable. Sortedness of the inputs is not checked: unsorted runs
+/// still merge by pairwise comparison, in O(n), but the result is then only
+/// as ordered as the inputs were.
+fn try_merge_sorted(left: &[Value], right: &[Value]) -> MergeAttempt {
+    let mut merged: Vec<Value> = Vec::with_capacity(left.len() + right.len());
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() && j < right.len() {
+        match three_way_compare(&right[j], &left[i]) {
+            Ok(OrderOutcome::Decided(std::cmp::Ordering::Less)) => {
+                merged.push(right[j].clone());
+                j += 1;
+            }
+            Ok(OrderOutcome::Decided(_)) => {
+                merged.push(left[i].clone());
+                i += 1;
+            }
+            Ok(OrderOutcome::Undecided(prefix)) => return MergeAttempt::Undecided(prefix),
+            Err(e) => return MergeAttempt::Malformed(e),
+        }
+    }
+    merged.extend_from_slice(&left[i..]);
+    merged.extend_from_slice(&right[j..]);
+    MergeAttempt::Merged(merged)
+}
+
+fn merge_operand(val: &Value) -> Result<Vec<Value>> {
+    if val.is_nil() {
+        return Ok(Vec::new());
+    }
+    val.as_vector_view()
+        .map(|view| view.to_vec())
+        .ok_or_else(|| {
+            AjisaiError::create_structure_error(
+                "MERGE-SORTED: expected vector, got non-vector value",
+                "other format",
+            )
+        })
+}
+
+/// `left right -- merged`. Merges two ascending vectors into one ascending
+/// vector. NIL is the empty run. Like `SORT`, an undecidable comparison
+/// yields the logical Unknown for the whole result (SPEC §7.4.3).
+pub fn op_merge_sorted(interp: &mut Interpreter) -> Result<()> {
+    if interp.operation_target_mode != OperationTargetMode::StackTop {
+        return Err(AjisaiError::from(
+            "MERGE-SORTED: Stack mode is not supported",
+        ));
+    }
+    let operands = extract_operands(interp, 2)?;
+    let restore = |interp: &mut Interpreter, operands: Vec<Value>| {
+        if interp.consumption_mode != ConsumptionMode::Keep {
+            interp.stack.extend(operands);
+        }
+    };
+    let runs = merge_operand(&operands[0])
+        .and_then(|left| merge_operand(&operands[1]).map(|right| (left, right)));
+    let (left, right) = match runs {
+        Ok(runs) => runs,
+        Err(e) => {
+            restore(interp, operands);
+            return Err(e);
+        }
+    };
+
+    match try_merge_sorted(&left, &right) {
+        MergeAttempt::Merged(merged) if merged.is_empty() => {
+            interp.stack.push(Value::nil());
+            Ok(())
+        }
+        MergeAttempt::Merged(merged) => {
+            interp.stack.push(Value::from_vector(merged));
+            Ok(())
+        }
+        MergeAttempt::Undecided(agreed_prefix) => {
+            crate::interpreter::comparison::push_comparison_unknown(interp, agreed_prefix);
+            Ok(())
+        }
+        MergeAttempt::Malformed(e) => {
+            restore(interp, operands);
+            Err(e)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -198,4 +291,39 @@ mod tests {
             SortAttempt::Malformed(_)
         ));
     }
+
+    fn merged(left: &[Value], right: &[Value]) -> Vec<Value> {
+        match try_merge_sorted(left, right) {
+            MergeAttempt::Merged(items) => items,
+            MergeAttempt::Undecided(_) => panic!("expected decidable merge"),
+            MergeAttempt::Malformed(e) => panic!("unexpected malformed: {e}"),
+        }
+    }
+
+    #[test]
+    fn try_merge_interleaves_ascending_runs() {
+        let left = vec![scalar(1, 1), scalar(3, 1), scalar(5, 1)];
+        let right = vec![scalar(2, 1), scalar(4, 1), scalar(6, 1)];
+        let expected: Vec<Value> = (1..=6).map(|n| scalar(n, 1)).collect();
+        assert_eq!(merged(&left, &right), expected);
+    }
+
+    #[test]
+    fn try_merge_appends_the_longer_tail() {
+        let left = vec![scalar(1, 2)];
+        let right = vec![scalar(1, 3), scalar(2, 1), scalar(3, 1)];
+        let expected = vec![scalar(1, 3), scalar(1, 2), scalar(2, 1), scalar(3, 1)];
+        assert_eq!(merged(&left, &right), expected);
+    }
+
+    #[tokio::test]
+    async fn merge_sorted_word_merges_two_vectors() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute("'algo' IMPORT [ 1 3 5 ] [ 2 4 6 ] MERGE-SORTED")
+            .await
+            .expect("should succeed");
+        assert_eq!(interp.stack.len(), 1);
+        assert_eq!(interp.stack[0].to_string(), "[ 1/1 2/1 3/1 4/1 5/1 6/1 ]");
+    }
 }
@@ -0,0 +1,37 @@
+//! Plain-JSON import/export of the stack for host interop.
+//!
+//! The wire shape lives in [`crate::types::value_json`]; this module only
+//! binds it to the interpreter's stack.
+
+use crate::error::{AjisaiError, Result};
+use crate::types::value_json::{json_to_value, value_to_json};
+
+use super::Interpreter;
+
+impl Interpreter {
+    /// The stack, bottom first, as a JSON array. Numbers are
+    /// `{ "num": .., "den": .. }` objects, so fractions are exact.
+    pub fn stack_to_json(&self) -> String {
+        let values: Vec<serde_json::Value> = self
+            .stack
+            .iter_slots()
+            .map(|(value, role)| value_to_json(value, role))
+            .collect();
+        serde_json::Value::Array(values).to_string()
+    }
+
+    /// Parse one JSON value and push it. Nothing is pushed on error.
+    ///
+    /// This reads the `stack_to_json` shape, where an object is always a
+    /// `{ "num", "den" }` fraction. The wasm `push_json_string` binding reads
+    /// JSON the way `JSON@PARSE` does instead: any object becomes a record
+    /// of key/value pairs, and `{ "num": 1, "den": 3 }` is not `1/3`.
+    pub fn push_json(&mut self, json: &str) -> Result<()> {
+        let parsed: serde_json::Value = serde_json::from_str(json)
+            .map_err(|e| AjisaiError::from(format!("push_json: {}", e)))?;
+        let value =
+            json_to_value(&parsed).map_err(|e| AjisaiError::from(format!("push_json: {}", e)))?;
+        self.stack.push(value);
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+//! Test suite for `Interpreter::stack_to_json` / `Interpreter::push_json`.
+//!
+//! The point of the `{ num, den }` encoding is exactness, so the round trips
+//! here use fractions that have no finite decimal form.
+
+#[cfg(test)]
+mod tests {
+    use crate::interpreter::Interpreter;
+    use serde_json::json;
+
+    async fn stack_json_of(code: &str) -> serde_json::Value {
+        let mut interp = Interpreter::new();
+        interp.execute(code).await.unwrap();
+        serde_json::from_str(&interp.stack_to_json()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn fractions_export_as_num_den_objects() {
+        let stack = stack_json_of("1/3 -2").await;
+        assert_eq!(
+            stack,
+            json!([{ "num": 1, "den": 3 }, { "num": -2, "den": 1 }])
+        );
+    }
+
+    #[tokio::test]
+    async fn strings_booleans_nil_and_nested_vectors_map_naturally() {
+        let stack = stack_json_of("'hi' TRUE NIL [ 1 [ 2 3 ] ]").await;
+        assert_eq!(
+            stack,
+            json!([
+                "hi",
+                true,
+                null,
+                [{ "num": 1, "den": 1 }, [{ "num": 2, "den": 1 }, { "num": 3, "den": 1 }]]
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn fraction_round_trip_is_exact() {
+        let mut interp = Interpreter::new();
+        interp.execute("1/3").await.unwrap();
+        let exported = interp.stack_to_json();
+        let top: serde_json::Value = serde_json::from_str(&exported).unwrap();
+
+        let mut other = Interpreter::new();
+        other.push_json(&top[0].to_string()).unwrap();
+        assert_eq!(other.stack_to_json(), exported);
+        // Exactness check: three thirds make exactly one.
+        other.execute("3 *").await.unwrap();
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&other.stack_to_json()).unwrap(),
+            json!([{ "num": 1, "den": 1 }])
+        );
+    }
+
+    #[tokio::test]
+    async fn nested_vectors_round_trip() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute("[ 1/2 [ 'ab' [ TRUE -7/9 ] ] ]")
+            .await
+            .unwrap();
+        let exported = interp.stack_to_json();
+        let top: serde_json::Value = serde_json::from_str(&exported).unwrap();
+
+        let mut other = Interpreter::new();
+        other.push_json(&top[0].to_string()).unwrap();
+        assert_eq!(other.stack_to_json(), exported);
+    }
+
+    #[test]
+    fn big_integers_travel_as_digit_strings() {
+        let mut interp = Interpreter::new();
+        interp
+            .push_json(r#"{ "num": "123456789012345678901234567891", "den": 2 }"#)
+            .unwrap();
+        let stack: serde_json::Value = serde_json::from_str(&interp.stack_to_json()).unwrap();
+        assert_eq!(
+            stack,
+            json!([{ "num": "123456789012345678901234567891", "den": 2 }])
+        );
+    }
+
+    #[test]
+    fn num_and_den_accept_bare_integers_beyond_i64() {
+        let mut interp = Interpreter::new();
+        interp
+            .push_json(r#"{ "num": 18446744073709551615, "den": 1 }"#)
+            .unwrap();
+        interp.push_json(r#"{ "num": 1e20, "den": 3 }"#).unwrap();
+        let stack: serde_json::Value = serde_json::from_str(&interp.stack_to_json()).unwrap();
+        assert_eq!(
+            stack,
+            json!([
+                { "num": "18446744073709551615", "den": 1 },
+                { "num": "100000000000000000000", "den": 3 }
+            ])
+        );
+        assert!(interp.push_json(r#"{ "num": 1.5, "den": 1 }"#).is_err());
+    }
+
+    #[test]
+    fn malformed_json_pushes_nothing() {
+        let mut interp = Interpreter::new();
+        assert!(interp.push_json("[ 1, ").is_err());
+        assert!(interp.push_json(r#"{ "num": 1, "den": 0 }"#).is_err());
+        assert!(interp.push_json(r#"{ "x": 1 }"#).is_err());
+        assert_eq!(interp.stack.len(), 0);
+    }
+}
@@ -0,0 +1,40 @@
+//! Read-only views of the stack for host UIs, which show it between runs
+//! without executing code.
+
+use super::Interpreter;
+use crate::types::display::format_with_hint;
+use crate::types::value_protocol::interpretation_protocol_str;
+
+/// One stack slot as a UI draws it: the rendering under the slot's role,
+/// that role's protocol name (`"timestamp"`, `"text"`, ...), the value's
+/// shape and its element count.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StackEntry {
+    pub rendered: String,
+    pub hint: &'static str,
+    pub shape: Vec<usize>,
+    pub length: usize,
+}
+
+impl Interpreter {
+    /// The `Display` rendering of the top value, or `None` on an empty
+    /// stack. The stack is not touched.
+    pub fn peek_top(&self) -> Option<String> {
+        self.stack.last().map(|value| value.to_string())
+    }
+
+    /// Every slot, bottom first, described for typed display. Rendering
+    /// follows the slot's role as the REPL does, so a timestamp shows as a
+    /// datetime.
+    pub fn stack_entries(&self) -> Vec<StackEntry> {
+        self.stack
+            .iter_slots()
+            .map(|(value, role)| StackEntry {
+                rendered: format_with_hint(value, role),
+                hint: interpretation_protocol_str(role),
+                shape: value.shape(),
+                length: value.len(),
+            })
+            .collect()
+    }
+}
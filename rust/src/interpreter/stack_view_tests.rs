@@ -0,0 +1,43 @@
+//! Test suite for `crate::interpreter::stack_view`.
+
+#[cfg(test)]
+mod tests {
+    use crate::interpreter::stack_view::StackEntry;
+    use crate::interpreter::Interpreter;
+
+    #[tokio::test]
+    async fn peek_top_renders_without_consuming() {
+        let mut interp = Interpreter::new();
+        assert_eq!(interp.peek_top(), None);
+
+        interp.execute("1 [ 1/2 'a' ]").await.unwrap();
+        assert_eq!(interp.peek_top().as_deref(), Some("[ 1/2 'a' ]"));
+        assert_eq!(interp.stack.len(), 2, "peeking leaves the stack intact");
+        assert_eq!(interp.peek_top().as_deref(), Some("[ 1/2 'a' ]"));
+    }
+
+    #[tokio::test]
+    async fn stack_entries_describe_every_slot() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute("'time' IMPORT 'ab' [ [ 1 2 3 ] [ 4 5 6 ] ] [ 2024 1 2 3 4 5 ] 0 TIMESTAMP")
+            .await
+            .unwrap();
+        let entries = interp.stack_entries();
+        assert_eq!(entries.len(), interp.stack.len());
+
+        assert_eq!(entries[0].hint, "text");
+        assert_eq!(entries[0].rendered, "'ab'");
+        assert_eq!(
+            entries[1],
+            StackEntry {
+                rendered: "[ [ 1/1 2/1 3/1 ] [ 4/1 5/1 6/1 ] ]".to_string(),
+                hint: "unassigned",
+                shape: vec![2, 3],
+                length: 2,
+            }
+        );
+        assert_eq!(entries[2].hint, "timestamp");
+        assert!(entries[2].shape.is_empty());
+    }
+}
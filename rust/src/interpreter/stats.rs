@@ -0,0 +1,133 @@
+//! STATS module words: summaries of numeric data computed on exact
+//! fractions.
+
+use crate::error::{AjisaiError, Result};
+use crate::interpreter::math_ops::{require_stack_top, restore_operands};
+use crate::interpreter::value_extraction_helpers::{
+    extract_operands, nil_passthrough_unary, push_result,
+};
+use crate::interpreter::Interpreter;
+use crate::types::exact::ExactReal;
+use crate::types::fraction::Fraction;
+use crate::types::{Interpretation, Value};
+
+/// The elements of a numeric vector as fractions. NIL is the empty vector.
+fn numeric_elements(value: &Value, word: &str, label: &str) -> Result<Vec<Fraction>> {
+    if value.is_nil() {
+        return Ok(Vec::new());
+    }
+    let not_numeric =
+        || AjisaiError::from(format!("{}: {} must be a vector of numbers", word, label));
+    if value.hint == Interpretation::Text {
+        return Err(not_numeric());
+    }
+    let elements = value.as_vector_view().ok_or_else(not_numeric)?;
+    elements
+        .iter()
+        .map(|element| element.as_scalar().cloned().ok_or_else(not_numeric))
+        .collect()
+}
+
+/// Count `values` into the bins the edges bound. Every bin is half-open
+/// `[e(i), e(i+1))` except the last, which also takes its upper edge.
+fn bucket_counts(values: &[Fraction], edges: &[Fraction]) -> Vec<Value> {
+    let last = &edges[edges.len() - 1];
+    let last_bin = edges.len() - 2;
+    let mut counts = vec![0i64; last_bin + 1];
+    for value in values {
+        let at_or_below = edges.partition_point(|edge| edge.le(value));
+        if at_or_below == 0 || value.gt(last) {
+            continue;
+        }
+        counts[(at_or_below - 1).min(last_bin)] += 1;
+    }
+    counts
+        .into_iter()
+        .map(|count| Value::from_fraction(Fraction::from(count)))
+        .collect()
+}
+
+/// `[ values ] [ edges ] -- [ counts ]`. Histogram of `values` over the
+/// bins between consecutive edges, which must be strictly increasing:
+/// `[ 1 2 2 5 9 ] [ 0 2 4 10 ] BUCKET` is `[ 1 2 2 ]`. Values outside the
+/// edge range are not counted; NIL values count as an empty dataset.
+pub fn op_bucket(interp: &mut Interpreter) -> Result<()> {
+    require_stack_top(interp, "BUCKET")?;
+    let operands = extract_operands(interp, 2)?;
+    let parsed = numeric_elements(&operands[0], "BUCKET", "values").and_then(|values| {
+        let edges = numeric_elements(&operands[1], "BUCKET", "edges")?;
+        if edges.len() < 2 {
+            return Err(AjisaiError::from("BUCKET requires at least two edges"));
+        }
+        if edges.windows(2).any(|pair| pair[1].le(&pair[0])) {
+            return Err(AjisaiError::from(
+                "BUCKET edges must be strictly increasing",
+            ));
+        }
+        Ok((values, edges))
+    });
+    match parsed {
+        Ok((values, edges)) => {
+            push_result(interp, Value::from_vector(bucket_counts(&values, &edges)));
+            Ok(())
+        }
+        Err(error) => {
+            restore_operands(interp, operands);
+            Err(error)
+        }
+    }
+}
+
+/// Population variance: the mean squared deviation from the mean.
+fn population_variance(values: &[Fraction]) -> Fraction {
+    let count = Fraction::from(values.len() as i64);
+    let sum = values
+        .iter()
+        .fold(Fraction::from(0), |acc, value| acc.add(value));
+    let mean = sum.div(&count);
+    let squares = values.iter().fold(Fraction::from(0), |acc, value| {
+        let deviation = value.sub(&mean);
+        acc.add(&deviation.mul(&deviation))
+    });
+    squares.div(&count)
+}
+
+fn apply_spread(interp: &mut Interpreter, word: &str, finish: fn(Fraction) -> Value) -> Result<()> {
+    require_stack_top(interp, word)?;
+    if nil_passthrough_unary(interp) {
+        return Ok(());
+    }
+    let operands = extract_operands(interp, 1)?;
+    match numeric_elements(&operands[0], word, "input") {
+        Ok(values) => {
+            push_result(interp, finish(population_variance(&values)));
+            interp.stack.set_last_role(Interpretation::RawNumber);
+            Ok(())
+        }
+        Err(error) => {
+            restore_operands(interp, operands);
+            Err(error)
+        }
+    }
+}
+
+/// `[ values ] -- variance`. Exact population variance (dividing by the
+/// count, not count - 1): `[ 1 2 3 4 ] VARIANCE` is `5/4`. A single value
+/// has variance 0; NIL, the empty dataset, passes through as NIL.
+pub fn op_variance(interp: &mut Interpreter) -> Result<()> {
+    apply_spread(interp, "VARIANCE", Value::from_fraction)
+}
+
+/// `[ values ] -- stddev`. Population standard deviation, the square root
+/// of VARIANCE taken as MATH@SQRT takes it: exact, rational when the
+/// variance is a perfect square (`[ 1 3 ] STDDEV` is `1`) and an exact
+/// lazy root otherwise, so there is no precision to choose. Single values
+/// and NIL behave as for VARIANCE.
+pub fn op_stddev(interp: &mut Interpreter) -> Result<()> {
+    apply_spread(interp, "STDDEV", |variance| {
+        // A variance is a sum of squares, so the root always exists.
+        ExactReal::from_sqrt_rational(variance)
+            .map(Value::from_exact_real)
+            .unwrap_or_else(Value::nil)
+    })
+}
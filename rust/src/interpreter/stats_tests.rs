@@ -0,0 +1,105 @@
+//! STATS module words.
+
+use crate::interpreter::Interpreter;
+
+async fn stack_after(code: &str) -> Vec<String> {
+    let mut interp = Interpreter::new();
+    interp
+        .execute(&format!("'stats' IMPORT {}", code))
+        .await
+        .expect("should succeed");
+    interp.stack.iter().map(|v| v.to_string()).collect()
+}
+
+#[tokio::test]
+async fn test_bucket_counts_three_edges() {
+    assert_eq!(
+        stack_after("[ 1 2 3 4 5 6 ] [ 0 3 6 ] BUCKET").await,
+        vec!["[ 2/1 4/1 ]"]
+    );
+}
+
+#[tokio::test]
+async fn test_bucket_last_bin_takes_its_upper_edge() {
+    // 2 opens the second bin; 10 closes the last one.
+    assert_eq!(
+        stack_after("[ 1 2 2 5 10 ] [ 0 2 4 10 ] BUCKET").await,
+        vec!["[ 1/1 2/1 2/1 ]"]
+    );
+}
+
+#[tokio::test]
+async fn test_bucket_ignores_values_outside_the_edges() {
+    assert_eq!(
+        stack_after("[ -1 1/2 3/2 11 ] [ 0 1 2 ] BUCKET").await,
+        vec!["[ 1/1 1/1 ]"]
+    );
+}
+
+#[tokio::test]
+async fn test_bucket_of_nil_is_all_zero() {
+    assert_eq!(
+        stack_after("NIL [ 0 1 2 ] BUCKET").await,
+        vec!["[ 0/1 0/1 ]"]
+    );
+}
+
+#[tokio::test]
+async fn test_bucket_rejects_bad_edges_and_restores() {
+    for code in [
+        "[ 1 2 ] [ 5 ] BUCKET",
+        "[ 1 2 ] [ 0 2 1 ] BUCKET",
+        "[ 1 2 ] [ 0 1 1 ] BUCKET",
+        "'ab' [ 0 1 ] BUCKET",
+    ] {
+        let mut interp = Interpreter::new();
+        let result = interp.execute(&format!("'stats' IMPORT {}", code)).await;
+        let err = result.expect_err(code).to_string();
+        assert!(err.starts_with("BUCKET"), "{}", err);
+        assert_eq!(interp.stack.len(), 2, "arguments are restored");
+    }
+}
+
+#[tokio::test]
+async fn test_variance_is_exact() {
+    assert_eq!(
+        stack_after("[ 1 2 3 4 ] VARIANCE [ 2 4 4 4 5 5 7 9 ] VARIANCE").await,
+        vec!["5/4", "4/1"]
+    );
+    assert_eq!(stack_after("[ 1/2 1/3 ] VARIANCE").await, vec!["1/144"]);
+}
+
+#[tokio::test]
+async fn test_variance_of_single_value_is_zero_and_nil_passes() {
+    assert_eq!(
+        stack_after("[ 7 ] VARIANCE [ 7 ] STDDEV NIL VARIANCE").await,
+        vec!["0/1", "0/1", "NIL"]
+    );
+}
+
+#[tokio::test]
+async fn test_stddev_of_perfect_square_variance_is_rational() {
+    assert_eq!(
+        stack_after("[ 2 4 4 4 5 5 7 9 ] STDDEV [ 1 2 ] STDDEV").await,
+        vec!["2/1", "1/2"]
+    );
+}
+
+#[tokio::test]
+async fn test_stddev_is_within_tolerance_of_the_root() {
+    // [ 1 2 3 ] has variance 2/3, so its deviation is √(2/3) ≈ 0.816497.
+    assert_eq!(
+        stack_after("[ 1 2 3 ] ,, STDDEV 816496/1000000 > [ 1 2 3 ] STDDEV 816497/1000000 <").await,
+        vec!["[ 1/1 2/1 3/1 ]", "TRUE", "TRUE"]
+    );
+}
+
+#[tokio::test]
+async fn test_variance_rejects_non_numeric_and_restores() {
+    for code in ["'ab' VARIANCE", "[ 1 'a' ] STDDEV"] {
+        let mut interp = Interpreter::new();
+        let result = interp.execute(&format!("'stats' IMPORT {}", code)).await;
+        assert!(result.is_err(), "{}", code);
+        assert_eq!(interp.stack.len(), 1, "argument is restored");
+    }
+}
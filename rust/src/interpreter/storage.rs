@@ -0,0 +1,117 @@
+//! IO@SAVE / IO@LOAD: the user dictionaries kept in the browser's
+//! `localStorage`, so definitions survive a page reload.
+//!
+//! The stored text is the `export_dictionary` document (module imports and
+//! user words as source, no stack), and LOAD merges it back through
+//! `import_dictionary`, so loaded words are rebuilt by DEF like typed ones.
+//! Only the `wasm` build has a storage backend; the native stub makes both
+//! words fail with an ordinary error, leaving the key on the stack.
+
+use crate::error::{AjisaiError, Result};
+use crate::interpreter::value_extraction_helpers::value_as_string;
+use crate::interpreter::{
+    ConsumptionMode, HostCapability, HostEffect, Interpreter, OperationTargetMode,
+};
+use crate::types::Value;
+
+/// Keeps saved dictionaries apart from the page's own storage keys.
+#[cfg(feature = "wasm")]
+const STORAGE_KEY_PREFIX: &str = "ajisai:dictionary:";
+
+#[cfg(feature = "wasm")]
+mod backend {
+    use super::STORAGE_KEY_PREFIX;
+    use crate::error::{AjisaiError, Result};
+    use crate::interpreter::Interpreter;
+
+    fn local_storage(word: &str) -> Result<web_sys::Storage> {
+        web_sys::window()
+            .and_then(|window| window.local_storage().ok().flatten())
+            .ok_or_else(|| AjisaiError::from(format!("{}: localStorage is not available", word)))
+    }
+
+    pub(super) fn save(interp: &mut Interpreter, key: &str) -> Result<()> {
+        let payload = interp.export_dictionary()?;
+        local_storage("SAVE")?
+            .set_item(&format!("{}{}", STORAGE_KEY_PREFIX, key), &payload)
+            .map_err(|_| AjisaiError::from("SAVE: localStorage refused the write"))
+    }
+
+    pub(super) fn load(interp: &mut Interpreter, key: &str) -> Result<()> {
+        let payload = local_storage("LOAD")?
+            .get_item(&format!("{}{}", STORAGE_KEY_PREFIX, key))
+            .map_err(|_| AjisaiError::from("LOAD: localStorage refused the read"))?
+            .ok_or_else(|| AjisaiError::from(format!("LOAD: nothing is saved under '{}'", key)))?;
+        interp.import_dictionary(&payload)
+    }
+}
+
+#[cfg(not(feature = "wasm"))]
+mod backend {
+    use crate::error::{AjisaiError, Result};
+    use crate::interpreter::Interpreter;
+
+    fn unavailable(word: &str) -> AjisaiError {
+        AjisaiError::from(format!(
+            "{}: local storage is only available in the browser build",
+            word
+        ))
+    }
+
+    pub(super) fn save(_interp: &mut Interpreter, _key: &str) -> Result<()> {
+        Err(unavailable("SAVE"))
+    }
+
+    pub(super) fn load(_interp: &mut Interpreter, _key: &str) -> Result<()> {
+        Err(unavailable("LOAD"))
+    }
+}
+
+/// Run `action` with the storage key on top of the stack. The key is put
+/// back when the action fails, and always in KEEP mode.
+fn with_storage_key<F>(interp: &mut Interpreter, word: &str, action: F) -> Result<String>
+where
+    F: FnOnce(&mut Interpreter, &str) -> Result<()>,
+{
+    if interp.operation_target_mode == OperationTargetMode::Stack {
+        return Err(AjisaiError::ModeUnsupported {
+            word: word.into(),
+            mode: "Stack".into(),
+        });
+    }
+    let key_val: Value = interp.stack.pop().ok_or(AjisaiError::StackUnderflow)?;
+    let result = match value_as_string(&key_val) {
+        Some(key) => action(interp, &key).map(|_| key),
+        None => Err(AjisaiError::from(format!(
+            "{} requires a non-empty text key",
+            word
+        ))),
+    };
+    if result.is_err() || interp.consumption_mode == ConsumptionMode::Keep {
+        interp.stack.push(key_val);
+    }
+    result
+}
+
+fn storage_effect(op: &str, key: &str) -> HostEffect {
+    HostEffect::Effect(serde_json::json!({ "storage": op, "key": key }).to_string())
+}
+
+/// `[ 'key' ] --`. Save every user word, with the module imports their
+/// bodies rely on, to localStorage under `key`, replacing what was there.
+pub fn op_save(interp: &mut Interpreter) -> Result<()> {
+    interp.run_hosted_effect_schema("SAVE", HostCapability::Effect, |interp| {
+        let key = with_storage_key(interp, "SAVE", backend::save)?;
+        Ok(storage_effect("save", &key))
+    })
+}
+
+/// `[ 'key' ] --`. Define the words saved under `key`, replacing
+/// same-named words; the stack and other words are left as they are.
+/// Loading a key nothing was saved under is an error.
+pub fn op_load(interp: &mut Interpreter) -> Result<()> {
+    interp.run_hosted_effect_schema("LOAD", HostCapability::Effect, |interp| {
+        let key = with_storage_key(interp, "LOAD", backend::load)?;
+        Ok(storage_effect("load", &key))
+    })
+}
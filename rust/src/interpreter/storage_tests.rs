@@ -0,0 +1,30 @@
+//! Test suite for `crate::interpreter::storage`. Only the native stub is
+//! reachable here; the payload itself is covered in `session_state_tests`.
+
+#[cfg(test)]
+mod tests {
+    use crate::interpreter::Interpreter;
+
+    #[tokio::test]
+    async fn save_and_load_fail_without_a_browser_and_keep_the_key() {
+        let mut interp = Interpreter::new();
+        interp.execute("'io' IMPORT { 1 } 'ONE' DEF").await.unwrap();
+        for word in ["SAVE", "LOAD"] {
+            let err = interp
+                .execute(&format!("[ 'mykey' ] {}", word))
+                .await
+                .unwrap_err();
+            assert!(err.to_string().contains("browser build"), "{}", err);
+            assert_eq!(interp.stack.len(), 1, "{} leaves the key", word);
+            interp.stack.clear();
+        }
+    }
+
+    #[tokio::test]
+    async fn an_empty_key_is_rejected() {
+        let mut interp = Interpreter::new();
+        interp.execute("'io' IMPORT").await.unwrap();
+        let err = interp.execute("NIL SAVE").await.unwrap_err();
+        assert!(err.to_string().contains("text key"), "{}", err);
+    }
+}
@@ -0,0 +1,182 @@
+//! Axis-level tensor words: dimensions are added or removed without
+//! touching the flat data, and one index is selected along an axis.
+
+use super::tensor_cmds::checked_shape_product;
+use super::tensor_ops::FlatTensor;
+use crate::error::{AjisaiError, Result};
+use crate::interpreter::value_extraction_helpers::extract_integer_from_value;
+use crate::interpreter::{ConsumptionMode, Interpreter, OperationTargetMode};
+use crate::types::Value;
+
+fn reject_stack_mode(interp: &Interpreter, word: &str) -> Result<()> {
+    if interp.operation_target_mode == OperationTargetMode::Stack {
+        return Err(AjisaiError::ModeUnsupported {
+            word: word.into(),
+            mode: "Stack".into(),
+        });
+    }
+    Ok(())
+}
+
+/// Read `val` as a rectangular numeric tensor. A ragged vector reads with a
+/// partial shape whose size disagrees with its data, so it is rejected here.
+fn rectangular_tensor(word: &str, val: &Value) -> Result<FlatTensor> {
+    let tensor = FlatTensor::from_value(val)?;
+    if checked_shape_product(&tensor.shape) != Some(tensor.data.len()) {
+        return Err(AjisaiError::from(format!(
+            "{} requires a rectangular tensor",
+            word
+        )));
+    }
+    Ok(tensor)
+}
+
+/// Read an axis as the index words read theirs, so `0` and `[ 0 ]` agree.
+fn parse_axis(word: &str, axis_val: &Value, limit: usize) -> Result<usize> {
+    let axis = extract_integer_from_value(axis_val)
+        .ok()
+        .and_then(|n| usize::try_from(n).ok());
+    match axis {
+        Some(axis) if axis <= limit => Ok(axis),
+        _ => Err(AjisaiError::from(format!(
+            "{}: axis must be an integer in 0..={}",
+            word, limit
+        ))),
+    }
+}
+
+/// Push `result`, keeping the consumed operands underneath in KEEP mode.
+fn push_with_operands(interp: &mut Interpreter, operands: Vec<Value>, result: Value) {
+    if interp.consumption_mode == ConsumptionMode::Keep {
+        interp.stack.extend(operands);
+    }
+    interp.stack.push(result);
+}
+
+/// `tensor -- tensor'`. Drop every size-1 axis. A tensor without size-1
+/// axes is returned unchanged; one whose axes are all size 1 squeezes to its
+/// sole scalar.
+pub fn op_squeeze(interp: &mut Interpreter) -> Result<()> {
+    reject_stack_mode(interp, "SQUEEZE")?;
+    let val: Value = interp.stack.pop().ok_or(AjisaiError::StackUnderflow)?;
+    if val.is_nil() {
+        interp.stack.push(Value::nil());
+        return Ok(());
+    }
+
+    let tensor = match rectangular_tensor("SQUEEZE", &val) {
+        Ok(t) => t,
+        Err(err) => {
+            interp.stack.push(val);
+            return Err(err);
+        }
+    };
+    if !tensor.shape.contains(&1) {
+        let result = val.clone();
+        push_with_operands(interp, vec![val], result);
+        return Ok(());
+    }
+
+    let shape: Vec<usize> = tensor.shape.iter().copied().filter(|&d| d != 1).collect();
+    let result = FlatTensor::from_shape_and_data(shape, tensor.data)?.to_value();
+    push_with_operands(interp, vec![val], result);
+    Ok(())
+}
+
+/// `tensor axis -- tensor'`. Insert a size-1 axis before `axis`; `axis`
+/// equal to the rank appends one.
+pub fn op_unsqueeze(interp: &mut Interpreter) -> Result<()> {
+    reject_stack_mode(interp, "UNSQUEEZE")?;
+    if interp.stack.len() < 2 {
+        return Err(AjisaiError::StackUnderflow);
+    }
+    let axis_val: Value = interp.stack.pop().ok_or(AjisaiError::StackUnderflow)?;
+    let val: Value = interp.stack.pop().ok_or(AjisaiError::StackUnderflow)?;
+    if val.is_nil() {
+        interp.stack.push(Value::nil());
+        return Ok(());
+    }
+
+    let reshaped = rectangular_tensor("UNSQUEEZE", &val).and_then(|tensor| {
+        let axis = parse_axis("UNSQUEEZE", &axis_val, tensor.shape.len())?;
+        let mut shape = tensor.shape;
+        shape.insert(axis, 1);
+        FlatTensor::from_shape_and_data(shape, tensor.data)
+    });
+    match reshaped {
+        Ok(tensor) => {
+            push_with_operands(interp, vec![val, axis_val], tensor.to_value());
+            Ok(())
+        }
+        Err(err) => {
+            interp.stack.push(val);
+            interp.stack.push(axis_val);
+            Err(err)
+        }
+    }
+}
+
+/// Strided slice of `tensor` at `index` along `axis`; the axis is removed
+/// from the shape. Callers have checked `axis` and `index` against it.
+fn slice_along_axis(tensor: FlatTensor, axis: usize, index: usize) -> Result<FlatTensor> {
+    let len = tensor.shape[axis];
+    let inner: usize = tensor.shape[axis + 1..].iter().product();
+    let outer: usize = tensor.shape[..axis].iter().product();
+    let data = (0..outer)
+        .flat_map(|o| {
+            let start = (o * len + index) * inner;
+            tensor.data[start..start + inner].iter().cloned()
+        })
+        .collect();
+    let mut shape = tensor.shape;
+    shape.remove(axis);
+    FlatTensor::from_shape_and_data(shape, data)
+}
+
+/// `tensor axis index -- sub`. Sub-tensor at `index` along `axis`, with that
+/// axis removed: axis 0 selects a row of a matrix, axis 1 a column.
+pub fn op_axis_get(interp: &mut Interpreter) -> Result<()> {
+    reject_stack_mode(interp, "AXISGET")?;
+    if interp.stack.len() < 3 {
+        return Err(AjisaiError::StackUnderflow);
+    }
+    let index_val: Value = interp.stack.pop().ok_or(AjisaiError::StackUnderflow)?;
+    let axis_val: Value = interp.stack.pop().ok_or(AjisaiError::StackUnderflow)?;
+    let val: Value = interp.stack.pop().ok_or(AjisaiError::StackUnderflow)?;
+    if val.is_nil() {
+        interp.stack.push(Value::nil());
+        return Ok(());
+    }
+
+    let sliced = rectangular_tensor("AXISGET", &val).and_then(|tensor| {
+        if tensor.shape.is_empty() {
+            return Err(AjisaiError::from(
+                "AXISGET requires a tensor of rank 1 or more",
+            ));
+        }
+        let axis = parse_axis("AXISGET", &axis_val, tensor.shape.len() - 1)?;
+        let len = tensor.shape[axis];
+        let index = extract_integer_from_value(&index_val)
+            .ok()
+            .and_then(|n| usize::try_from(n).ok());
+        match index {
+            Some(index) if index < len => slice_along_axis(tensor, axis, index),
+            _ => Err(AjisaiError::from(format!(
+                "AXISGET: index must be an integer in 0..{} for axis {}",
+                len, axis
+            ))),
+        }
+    });
+    match sliced {
+        Ok(tensor) => {
+            push_with_operands(interp, vec![val, axis_val, index_val], tensor.to_value());
+            Ok(())
+        }
+        Err(err) => {
+            interp.stack.push(val);
+            interp.stack.push(axis_val);
+            interp.stack.push(index_val);
+            Err(err)
+        }
+    }
+}
@@ -0,0 +1,80 @@
+//! Test suite for `crate::interpreter::tensor_axis_ops` (SQUEEZE/UNSQUEEZE/AXISGET).
+
+#[cfg(test)]
+mod tests {
+    use crate::interpreter::Interpreter;
+
+    async fn top_after(code: &str) -> String {
+        let mut interp = Interpreter::new();
+        interp.execute(code).await.expect("should succeed");
+        interp.stack[interp.stack.len() - 1].to_string()
+    }
+
+    #[tokio::test]
+    async fn squeeze_drops_every_size_one_axis() {
+        let shape = top_after("[ 1 2 3 ] [ 1 3 1 ] RESHAPE SQUEEZE SHAPE").await;
+        assert_eq!(shape, "[ 3/1 ]");
+    }
+
+    #[tokio::test]
+    async fn squeeze_without_size_one_axes_is_unchanged() {
+        let value = top_after("[ [ 1 2 ] [ 3 4 ] ] SQUEEZE").await;
+        assert_eq!(value, "[ [ 1/1 2/1 ] [ 3/1 4/1 ] ]");
+    }
+
+    #[tokio::test]
+    async fn unsqueeze_inserts_axis_at_front() {
+        let shape = top_after("[ 1 2 3 ] 0 UNSQUEEZE SHAPE").await;
+        assert_eq!(shape, "[ 1/1 3/1 ]");
+    }
+
+    #[tokio::test]
+    async fn unsqueeze_at_rank_appends_axis() {
+        let shape = top_after("[ 1 2 3 ] 1 UNSQUEEZE SHAPE").await;
+        assert_eq!(shape, "[ 3/1 1/1 ]");
+    }
+
+    #[tokio::test]
+    async fn unsqueeze_reads_a_bracketed_axis() {
+        let shape = top_after("[ [ 1 2 3 ] ] [ 0 ] UNSQUEEZE SHAPE").await;
+        assert_eq!(shape, "[ 1/1 1/1 3/1 ]");
+    }
+
+    #[tokio::test]
+    async fn unsqueeze_rejects_out_of_range_axis() {
+        let mut interp = Interpreter::new();
+        let result = interp.execute("[ 1 2 3 ] 2 UNSQUEEZE").await;
+        assert!(result.is_err());
+        assert_eq!(interp.stack.len(), 2, "operands are restored");
+    }
+
+    #[tokio::test]
+    async fn axis_get_takes_a_row_along_axis_zero() {
+        let row = top_after("[ 1 2 3 4 5 6 7 8 9 ] [ 3 3 ] RESHAPE 0 1 AXISGET").await;
+        assert_eq!(row, "[ 4/1 5/1 6/1 ]");
+    }
+
+    #[tokio::test]
+    async fn axis_get_takes_a_column_along_axis_one() {
+        let column = top_after("[ 1 2 3 4 5 6 7 8 9 ] [ 3 3 ] RESHAPE 1 2 AXISGET").await;
+        assert_eq!(column, "[ 3/1 6/1 9/1 ]");
+    }
+
+    #[tokio::test]
+    async fn axis_get_reads_bracketed_axis_and_index() {
+        let column = top_after("[ 1 2 3 4 5 6 7 8 9 ] [ 3 3 ] RESHAPE [ 1 ] [ 2 ] AXISGET").await;
+        assert_eq!(column, "[ 3/1 6/1 9/1 ]");
+    }
+
+    #[tokio::test]
+    async fn axis_get_rejects_out_of_range_axis_and_index() {
+        for code in [
+            "[ [ 1 2 ] [ 3 4 ] ] 2 0 AXISGET",
+            "[ [ 1 2 ] [ 3 4 ] ] 0 2 AXISGET",
+        ] {
+            let mut interp = Interpreter::new();
+            assert!(interp.execute(code).await.is_err(), "{}", code);
+            assert_eq!(interp.stack.len(), 3, "operands are restored: {}", code);
+        }
+    }
+}
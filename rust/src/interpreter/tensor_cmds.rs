@@ -2,6 +2,7 @@ use crate::error::{AjisaiError, NilReason, Result};
 use crate::interpreter::value_extraction_helpers::{
     create_number_value, nil_passthrough_binary, nil_passthrough_unary,
 };
+use crate::interpreter::vector_ops::structure::transpose_ragged;
 use crate::interpreter::{ConsumptionMode, Interpreter, OperationTargetMode};
 use crate::types::exact::ExactReal;
 use crate::types::fraction::{Fraction, RoundingMode};
@@ -11,7 +12,7 @@ use crate::types::{Interpretation, Value, ValueData};
 /// when the running product would wrap, so callers can reject pathological
 /// shapes with a structured error instead of panicking (debug) or silently
 /// computing a wrong size (release).
-fn checked_shape_product(shape: &[usize]) -> Option<usize> {
+pub(crate) fn checked_shape_product(shape: &[usize]) -> Option<usize> {
     shape
         .iter()
         .try_fold(1usize, |acc, &dim| acc.checked_mul(dim))
@@ -119,23 +120,30 @@ pub fn op_reshape(interp: &mut Interpreter) -> Result<()> {
 
     let dim_count: usize = shape_val.len();
 
-    let mut new_shape: Vec<usize> = Vec::with_capacity(dim_count);
+    // A single `-1` dimension (`None` here) is inferred from the element count.
+    let mut new_shape: Vec<Option<usize>> = Vec::with_capacity(dim_count);
     for i in 0..dim_count {
         let dim_child = shape_val
             .child(i)
             .expect("RESHAPE: child index in 0..len must be valid");
-        let dim = match dim_child.as_scalar().and_then(|f| f.as_usize()) {
-            Some(d) => d,
-            None => {
+        let dim = match dim_child.as_scalar() {
+            Some(f) if f.as_usize().is_some() => f.as_usize(),
+            Some(f) if f.to_i64() == Some(-1) => None,
+            _ => {
                 interp.stack.push(data_val);
                 interp.stack.push(shape_val);
                 return Err(AjisaiError::from(
-                    "Shape dimensions must be positive integers",
+                    "Shape dimensions must be positive integers (or a single -1)",
                 ));
             }
         };
         new_shape.push(dim);
     }
+    if new_shape.iter().filter(|d| d.is_none()).count() > 1 {
+        interp.stack.push(data_val);
+        interp.stack.push(shape_val);
+        return Err(AjisaiError::from("RESHAPE: only one dimension may be -1"));
+    }
 
     if data_val.is_nil() {
         interp.stack.push(data_val);
@@ -152,6 +160,27 @@ pub fn op_reshape(interp: &mut Interpreter) -> Result<()> {
         }
     };
 
+    let known_dims: Vec<usize> = new_shape.iter().flatten().copied().collect();
+    let new_shape: Vec<usize> = if known_dims.len() < new_shape.len() {
+        let total = input_tensor.data.len();
+        match checked_shape_product(&known_dims) {
+            Some(known) if known > 0 && total.is_multiple_of(known) => new_shape
+                .iter()
+                .map(|d| d.unwrap_or(total / known))
+                .collect(),
+            _ => {
+                interp.stack.push(data_val);
+                interp.stack.push(shape_val);
+                return Err(AjisaiError::from(format!(
+                    "RESHAPE failed: data length {} is not divisible by the known dimensions {:?}",
+                    total, known_dims
+                )));
+            }
+        }
+    } else {
+        known_dims
+    };
+
     // A pathological shape such as `[ 99999999 99999999 99999999 ]` overflows
     // the size product and panics under `iter().product()`. Compute it with
     // overflow protection: an overflowing product can never equal the (bounded)
@@ -208,7 +237,23 @@ pub fn op_transpose(interp: &mut Interpreter) -> Result<()> {
         return Ok(());
     }
 
-    let tensor: FlatTensor = match FlatTensor::from_value(&val) {
+    let tensor: Result<FlatTensor> = FlatTensor::from_value(&val);
+    let is_rectangular = tensor
+        .as_ref()
+        .is_ok_and(|t| checked_shape_product(&t.shape) == Some(t.data.len()));
+    // Ragged or non-numeric rows of a plain vector take the vector-level
+    // transpose, which pads short rows with NIL.
+    if !is_rectangular && matches!(val.data, ValueData::Vector(_)) {
+        if let Some(result) = transpose_ragged(&val) {
+            if interp.consumption_mode == ConsumptionMode::Keep {
+                interp.stack.push(val);
+            }
+            interp.stack.push(result);
+            return Ok(());
+        }
+    }
+
+    let tensor: FlatTensor = match tensor {
         Ok(t) => t,
         Err(err) => {
             interp.stack.push(val);
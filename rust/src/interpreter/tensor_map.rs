@@ -0,0 +1,86 @@
+use crate::error::{AjisaiError, Result};
+use crate::interpreter::higher_order::{
+    execute_executable_code, extract_executable_code, ExecutableCode,
+};
+use crate::interpreter::tensor_ops::{rectangular_shape, FlatTensor};
+use crate::interpreter::{Interpreter, OperationTargetMode};
+use crate::types::fraction::Fraction;
+use crate::types::Value;
+
+/// Run `code` on an isolated stack holding only `x` and require exactly one
+/// rational scalar back.
+fn apply_word_to_scalar(
+    interp: &mut Interpreter,
+    code: &ExecutableCode,
+    x: &Fraction,
+) -> Result<Fraction> {
+    interp.stack.clear();
+    interp.stack.push(Value::from_fraction(x.clone()));
+    execute_executable_code(interp, code)?;
+    match (
+        interp.stack.len(),
+        interp.stack.last().and_then(Value::as_scalar),
+    ) {
+        (1, Some(y)) => Ok(y.clone()),
+        (1, None) => Err(AjisaiError::create_structure_error(
+            "TMAP word result as a scalar",
+            "non-scalar value",
+        )),
+        (n, _) => Err(AjisaiError::from(format!(
+            "TMAP: expected the word to leave exactly one scalar, got {} value(s)",
+            n
+        ))),
+    }
+}
+
+/// `tensor word -- tensor`. Shape-preserving counterpart to `MAP`: apply a
+/// unary word to every scalar of a rectangular tensor over its flat data and
+/// rebuild the original shape. NIL passes through; operands are restored on
+/// error.
+pub fn op_tmap(interp: &mut Interpreter) -> Result<()> {
+    if interp.operation_target_mode == OperationTargetMode::Stack {
+        return Err(AjisaiError::ModeUnsupported {
+            word: "TMAP".into(),
+            mode: "Stack".into(),
+        });
+    }
+    let word_val = interp.stack.pop().ok_or(AjisaiError::StackUnderflow)?;
+    let Some(tensor_val) = interp.stack.pop() else {
+        interp.stack.push(word_val);
+        return Err(AjisaiError::StackUnderflow);
+    };
+
+    if tensor_val.is_nil() {
+        interp.stack.push(Value::nil());
+        return Ok(());
+    }
+
+    let saved = std::mem::take(&mut interp.stack);
+    let result = (|| {
+        let code = extract_executable_code(interp, &word_val)?;
+        if tensor_val.is_vector() && rectangular_shape(&tensor_val).is_none() {
+            return Err(AjisaiError::create_structure_error(
+                "rectangular tensor",
+                "ragged vector",
+            ));
+        }
+        let tensor = FlatTensor::from_value(&tensor_val)?;
+        let data = tensor
+            .data
+            .iter()
+            .map(|x| apply_word_to_scalar(interp, &code, x))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(FlatTensor::from_shape_and_data(tensor.shape, data)?.to_value())
+    })();
+    interp.stack = saved;
+
+    match result {
+        Ok(value) => interp.stack.push(value),
+        Err(err) => {
+            interp.stack.push(tensor_val);
+            interp.stack.push(word_val);
+            return Err(err);
+        }
+    }
+    Ok(())
+}
@@ -251,7 +251,7 @@ pub(crate) fn build_nested_value(data: &[Fraction], shape: &[usize]) -> Value {
 /// structurally (see [`apply_recursive_broadcast`]) rather than flattened,
 /// because `shape()` collapses them to a top-level count that disagrees with
 /// the recursively flattened element count.
-fn rectangular_shape(value: &Value) -> Option<Vec<usize>> {
+pub(crate) fn rectangular_shape(value: &Value) -> Option<Vec<usize>> {
     match &value.data {
         ValueData::Scalar(_) | ValueData::ExactScalar(_) | ValueData::Nil => Some(Vec::new()),
         ValueData::Tensor { shape, .. } => Some((**shape).clone()),
@@ -74,6 +74,16 @@ pub fn offset_seconds(offset_hours: &Fraction) -> Fraction {
     offset_hours.mul(&Fraction::from(SECONDS_PER_HOUR))
 }
 
+/// Shift an instant by `n` whole days of exactly 86400 seconds.
+pub fn shift_instant_days(instant: &Fraction, n: i64) -> Fraction {
+    instant.add(&Fraction::from(n).mul(&Fraction::from(SECONDS_PER_DAY)))
+}
+
+/// Whole days elapsed from instant `b` to instant `a`, truncated toward zero.
+pub fn whole_days_between(a: &Fraction, b: &Fraction) -> Fraction {
+    a.sub(b).div(&Fraction::from(SECONDS_PER_DAY)).trunc()
+}
+
 /// Civil wall-clock fields with an exact (possibly fractional) second.
 pub struct Civil {
     pub year: i64,
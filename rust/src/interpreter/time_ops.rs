@@ -14,7 +14,7 @@ use crate::error::{AjisaiError, NilReason, Result};
 use crate::interpreter::cast::cast_value_helpers::is_string_value_with_hint;
 use crate::interpreter::time_calendar::{
     add_months_civil, civil_from_days, civil_to_instant, days_from_civil, instant_to_civil,
-    iso_weekday, Civil,
+    iso_weekday, shift_instant_days, whole_days_between, Civil,
 };
 use crate::interpreter::value_extraction_helpers::{
     extract_operands, push_result, value_as_string,
@@ -24,7 +24,7 @@ use crate::semantic::{AbsenceOrigin, Recoverability};
 use crate::types::fraction::Fraction;
 use crate::types::{Interpretation, Value};
 
-fn require_stack_top(interp: &Interpreter, word: &str) -> Result<()> {
+pub(crate) fn require_stack_top(interp: &Interpreter, word: &str) -> Result<()> {
     if interp.operation_target_mode != OperationTargetMode::StackTop {
         return Err(AjisaiError::ModeUnsupported {
             word: word.into(),
@@ -34,20 +34,20 @@ fn require_stack_top(interp: &Interpreter, word: &str) -> Result<()> {
     Ok(())
 }
 
-fn restore(interp: &mut Interpreter, operands: Vec<Value>) {
+pub(crate) fn restore(interp: &mut Interpreter, operands: Vec<Value>) {
     if interp.consumption_mode != ConsumptionMode::Keep {
         interp.stack.extend(operands);
     }
 }
 
-fn scalar(value: &Value, word: &str, what: &str) -> Result<Fraction> {
+pub(crate) fn scalar(value: &Value, word: &str, what: &str) -> Result<Fraction> {
     value
         .as_scalar()
         .cloned()
         .ok_or_else(|| AjisaiError::from(format!("{}: {} must be a number", word, what)))
 }
 
-fn integer_field(value: &Value, word: &str, what: &str) -> Result<i64> {
+pub(crate) fn integer_field(value: &Value, word: &str, what: &str) -> Result<i64> {
     let f = scalar(value, word, what)?;
     if !f.is_integer() {
         return Err(AjisaiError::from(format!(
@@ -60,7 +60,11 @@ fn integer_field(value: &Value, word: &str, what: &str) -> Result<i64> {
 }
 
 /// Read a civil vector of `expected` length, returning a borrowed view.
-fn civil_components(value: &Value, word: &str, expected: &[usize]) -> Result<Vec<Value>> {
+pub(crate) fn civil_components(
+    value: &Value,
+    word: &str,
+    expected: &[usize],
+) -> Result<Vec<Value>> {
     let view = value
         .as_vector_view()
         .ok_or_else(|| AjisaiError::from(format!("{}: expected a civil vector", word)))?;
@@ -89,7 +93,7 @@ fn civil_from_datetime(components: &[Value], word: &str) -> Result<Civil> {
     })
 }
 
-fn datetime_value(civil: &Civil) -> Value {
+pub(crate) fn datetime_value(civil: &Civil) -> Value {
     Value::from_vector(vec![
         Value::from_int(civil.year),
         Value::from_int(civil.month),
@@ -241,10 +245,24 @@ pub fn op_weekday(interp: &mut Interpreter) -> Result<()> {
 // --- Civil arithmetic (exact) ----------------------------------------------
 
 /// `date|datetime n -- date|datetime`. Shift the date part by `n` whole days,
-/// preserving any time-of-day fields.
+/// preserving any time-of-day fields. A timestamp (instant) shifts by exactly
+/// `n * 86400` seconds and stays a timestamp.
 pub fn op_add_days(interp: &mut Interpreter) -> Result<()> {
     require_stack_top(interp, "ADD-DAYS")?;
     let operands = extract_operands(interp, 2)?;
+    if let Some(instant) = operands[0].as_scalar() {
+        return match integer_field(&operands[1], "ADD-DAYS", "day count") {
+            Ok(n) => {
+                push_result(interp, Value::from_fraction(shift_instant_days(instant, n)));
+                interp.stack.set_last_role(Interpretation::Timestamp);
+                Ok(())
+            }
+            Err(e) => {
+                restore(interp, operands);
+                Err(e)
+            }
+        };
+    }
     let result = (|| {
         let components = civil_components(&operands[0], "ADD-DAYS", &[3, 6])?;
         let n = integer_field(&operands[1], "ADD-DAYS", "day count")?;
@@ -274,11 +292,18 @@ pub fn op_add_days(interp: &mut Interpreter) -> Result<()> {
     }
 }
 
-/// `a b -- n`. Whole-day difference `a - b` between two dates/datetimes.
+/// `a b -- n`. Whole-day difference `a - b` between two dates/datetimes, or
+/// the whole days elapsed between two timestamps (truncated toward zero).
 pub fn op_diff_days(interp: &mut Interpreter) -> Result<()> {
     require_stack_top(interp, "DIFF-DAYS")?;
     let operands = extract_operands(interp, 2)?;
     let result = (|| {
+        if let Some(a) = operands[0].as_scalar() {
+            let b = scalar(&operands[1], "DIFF-DAYS", "second timestamp")?;
+            return whole_days_between(a, &b)
+                .to_i64()
+                .ok_or_else(|| AjisaiError::from("DIFF-DAYS: day count out of range"));
+        }
         let a = civil_components(&operands[0], "DIFF-DAYS", &[3, 6])?;
         let b = civil_components(&operands[1], "DIFF-DAYS", &[3, 6])?;
         let days_a = days_from_civil(
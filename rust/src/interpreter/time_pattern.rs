@@ -0,0 +1,255 @@
+//! Pattern-driven text egress and ingress for TIME civil values.
+//!
+//! `FORMAT-PATTERN` renders a DATE or DATETIME through a strftime-like pattern
+//! and `PARSE-PATTERN` reads text back through the same pattern language.
+//! Like `FORMAT`, it works on timezone-free civil fields; an instant is first
+//! brought to the wall clock with `DATETIME` at an explicit UTC offset, so
+//! `1700000000 0 DATETIME '%Y-%m-%d %H:%M:%S' FORMAT-PATTERN` is the UTC
+//! rendering of that timestamp, and `TIMESTAMP` turns a parsed datetime back
+//! into one.
+//!
+//! Supported specifiers: `%Y` (year, at least four digits), `%m`, `%d`, `%H`,
+//! `%M`, `%S` (two digits; the second is floored as in `FORMAT`) and `%%`.
+//! When parsing, `%Y` reads up to four digits after an optional `-` and the
+//! other fields read one or two digits, so compact patterns like `%Y%m%d`
+//! work.
+
+use crate::error::{AjisaiError, Result};
+use crate::interpreter::cast::cast_value_helpers::is_string_value_with_hint;
+use crate::interpreter::time_calendar::{days_in_month, Civil};
+use crate::interpreter::time_ops::{
+    civil_components, datetime_value, integer_field, require_stack_top, restore, scalar,
+};
+use crate::interpreter::value_extraction_helpers::{extract_operands, value_as_string};
+use crate::interpreter::Interpreter;
+use crate::types::fraction::Fraction;
+use crate::types::{Interpretation, Value};
+
+enum PatternPiece {
+    Literal(char),
+    Field(char),
+}
+
+/// Split a pattern into literal characters and field specifiers, rejecting
+/// unknown specifiers and a dangling `%` up front.
+fn pattern_pieces(pattern: &str, word: &str) -> Result<Vec<PatternPiece>> {
+    let mut pieces = Vec::new();
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            pieces.push(PatternPiece::Literal(c));
+            continue;
+        }
+        match chars.next() {
+            Some('%') => pieces.push(PatternPiece::Literal('%')),
+            Some(spec @ ('Y' | 'm' | 'd' | 'H' | 'M' | 'S')) => {
+                pieces.push(PatternPiece::Field(spec))
+            }
+            Some(other) => {
+                return Err(AjisaiError::from(format!(
+                    "{}: unknown specifier %{} (use %Y %m %d %H %M %S or %%)",
+                    word, other
+                )))
+            }
+            None => {
+                return Err(AjisaiError::from(format!(
+                    "{}: pattern ends with a lone %",
+                    word
+                )))
+            }
+        }
+    }
+    Ok(pieces)
+}
+
+/// Index of a specifier's field in a `[Y M D h m s]` civil vector.
+fn field_index(spec: char) -> usize {
+    match spec {
+        'Y' => 0,
+        'm' => 1,
+        'd' => 2,
+        'H' => 3,
+        'M' => 4,
+        _ => 5,
+    }
+}
+
+fn format_with_pattern(components: &[Value], pattern: &str) -> Result<String> {
+    const WORD: &str = "FORMAT-PATTERN";
+    let mut out = String::new();
+    for piece in pattern_pieces(pattern, WORD)? {
+        let spec = match piece {
+            PatternPiece::Literal(c) => {
+                out.push(c);
+                continue;
+            }
+            PatternPiece::Field(spec) => spec,
+        };
+        let index = field_index(spec);
+        let field = components.get(index).ok_or_else(|| {
+            AjisaiError::from(format!("{}: %{} needs a datetime, got a date", WORD, spec))
+        })?;
+        let n = if spec == 'S' {
+            scalar(field, WORD, "second")?
+                .floor()
+                .to_i64()
+                .ok_or_else(|| AjisaiError::from(format!("{}: second out of range", WORD)))?
+        } else {
+            integer_field(field, WORD, "field")?
+        };
+        if spec == 'Y' {
+            out.push_str(&format!("{:04}", n));
+        } else {
+            out.push_str(&format!("{:02}", n));
+        }
+    }
+    Ok(out)
+}
+
+/// `date|datetime pattern -- text`. Render civil fields through a
+/// strftime-like pattern; an unknown specifier, or a time specifier applied
+/// to a date, is an error that restores both operands.
+pub fn op_format_pattern(interp: &mut Interpreter) -> Result<()> {
+    require_stack_top(interp, "FORMAT-PATTERN")?;
+    let hint = interp.stack.last_role();
+    let operands = extract_operands(interp, 2)?;
+    let result = (|| {
+        if !is_string_value_with_hint(&operands[1], hint) {
+            return Err(AjisaiError::from(
+                "FORMAT-PATTERN: pattern must be a text value",
+            ));
+        }
+        let pattern = value_as_string(&operands[1]).unwrap_or_default();
+        let components = civil_components(&operands[0], "FORMAT-PATTERN", &[3, 6])?;
+        format_with_pattern(&components, &pattern)
+    })();
+    match result {
+        Ok(text) => {
+            interp.stack.push(Value::from_string(&text));
+            interp.stack.set_last_role(Interpretation::Text);
+            Ok(())
+        }
+        Err(e) => {
+            restore(interp, operands);
+            Err(e)
+        }
+    }
+}
+
+/// Read an unsigned run of `1..=max_digits` ASCII digits starting at `pos`.
+fn read_digits(text: &[char], pos: &mut usize, max_digits: usize) -> Option<i64> {
+    let start = *pos;
+    while *pos < text.len() && *pos - start < max_digits && text[*pos].is_ascii_digit() {
+        *pos += 1;
+    }
+    if *pos == start {
+        return None;
+    }
+    text[start..*pos].iter().collect::<String>().parse().ok()
+}
+
+fn parse_with_pattern(text: &str, pattern: &str) -> Result<Civil> {
+    const WORD: &str = "PARSE-PATTERN";
+    let text: Vec<char> = text.chars().collect();
+    let mismatch = |pos: usize| {
+        AjisaiError::from(format!(
+            "{}: text does not match the pattern at character {}",
+            WORD, pos
+        ))
+    };
+
+    // [Y M D h m s]; the date fields are required, the time defaults to 0.
+    let mut fields: [Option<i64>; 6] = [None, None, None, Some(0), Some(0), Some(0)];
+    let mut pos = 0;
+    for piece in pattern_pieces(pattern, WORD)? {
+        match piece {
+            PatternPiece::Literal(c) => {
+                if text.get(pos) != Some(&c) {
+                    return Err(mismatch(pos));
+                }
+                pos += 1;
+            }
+            PatternPiece::Field(spec) => {
+                let value = if spec == 'Y' {
+                    let negative = text.get(pos) == Some(&'-');
+                    if negative {
+                        pos += 1;
+                    }
+                    read_digits(&text, &mut pos, 4).map(|y| if negative { -y } else { y })
+                } else {
+                    read_digits(&text, &mut pos, 2)
+                };
+                fields[field_index(spec)] = Some(value.ok_or_else(|| mismatch(pos))?);
+            }
+        }
+    }
+    if pos != text.len() {
+        return Err(mismatch(pos));
+    }
+
+    let [Some(year), Some(month), Some(day), Some(hour), Some(minute), Some(second)] = fields
+    else {
+        return Err(AjisaiError::from(format!(
+            "{}: pattern must contain %Y, %m and %d",
+            WORD
+        )));
+    };
+    if !(1..=12).contains(&month) {
+        return Err(AjisaiError::from(format!(
+            "{}: month {} is out of range",
+            WORD, month
+        )));
+    }
+    if !(1..=days_in_month(year, month)).contains(&day) {
+        return Err(AjisaiError::from(format!(
+            "{}: day {} is out of range for {:04}-{:02}",
+            WORD, day, year, month
+        )));
+    }
+    if hour > 23 || minute > 59 || second > 60 {
+        return Err(AjisaiError::from(format!(
+            "{}: time {:02}:{:02}:{:02} is out of range",
+            WORD, hour, minute, second
+        )));
+    }
+    Ok(Civil {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second: Fraction::from(second),
+    })
+}
+
+/// `text pattern -- datetime`. Parse text through a strftime-like pattern
+/// into `[Y M D h m s]`. Unlike `PARSE-ISO`, which projects unreadable text to
+/// NIL, a mismatch or an impossible date (Feb 30) is an error that restores
+/// both operands: the caller asserted the shape by supplying the pattern.
+pub fn op_parse_pattern(interp: &mut Interpreter) -> Result<()> {
+    require_stack_top(interp, "PARSE-PATTERN")?;
+    let pattern_hint = interp.stack.last_role();
+    let operands = extract_operands(interp, 2)?;
+    let result = (|| {
+        if !is_string_value_with_hint(&operands[1], pattern_hint)
+            || !is_string_value_with_hint(&operands[0], Interpretation::Unassigned)
+        {
+            return Err(AjisaiError::from(
+                "PARSE-PATTERN: expected a text value and a text pattern",
+            ));
+        }
+        let text = value_as_string(&operands[0]).unwrap_or_default();
+        let pattern = value_as_string(&operands[1]).unwrap_or_default();
+        parse_with_pattern(&text, &pattern)
+    })();
+    match result {
+        Ok(civil) => {
+            interp.stack.push(datetime_value(&civil));
+            Ok(())
+        }
+        Err(e) => {
+            restore(interp, operands);
+            Err(e)
+        }
+    }
+}
@@ -0,0 +1,145 @@
+//! Test suite for `crate::interpreter::time_pattern`.
+
+#[cfg(test)]
+mod tests {
+    use crate::interpreter::value_extraction_helpers::value_as_string;
+    use crate::interpreter::Interpreter;
+
+    async fn text(program: &str) -> String {
+        let mut interp = Interpreter::new();
+        interp
+            .execute(&format!("'time' IMPORT {}", program))
+            .await
+            .expect("program should succeed");
+        value_as_string(&interp.stack[0]).expect("text result")
+    }
+
+    async fn error(program: &str) -> (String, usize) {
+        let mut interp = Interpreter::new();
+        let err = interp
+            .execute(&format!("'time' IMPORT {}", program))
+            .await
+            .expect_err("program should fail");
+        (err.to_string(), interp.stack.len())
+    }
+
+    #[tokio::test]
+    async fn format_pattern_renders_a_utc_timestamp() {
+        assert_eq!(
+            text("1700000000 0 DATETIME '%Y-%m-%d %H:%M:%S' FORMAT-PATTERN").await,
+            "2023-11-14 22:13:20"
+        );
+    }
+
+    #[tokio::test]
+    async fn format_pattern_pads_fields_and_keeps_literals() {
+        assert_eq!(
+            text("[ 987 6 5 4 3 2 ] '%d/%m/%Y %Hh%M 100%%' FORMAT-PATTERN").await,
+            "05/06/0987 04h03 100%"
+        );
+        assert_eq!(
+            text("[ 2024 1 15 ] '%Y%m%d' FORMAT-PATTERN").await,
+            "20240115"
+        );
+    }
+
+    #[tokio::test]
+    async fn format_pattern_floors_exact_seconds() {
+        assert_eq!(
+            text("[ 2024 1 15 0 0 59/2 ] '%S' FORMAT-PATTERN").await,
+            "29"
+        );
+    }
+
+    #[tokio::test]
+    async fn format_pattern_rejects_unknown_specifiers() {
+        let (err, depth) = error("[ 2024 1 15 ] '%Y-%q' FORMAT-PATTERN").await;
+        assert!(err.contains("unknown specifier %q"), "{}", err);
+        assert_eq!(depth, 2, "operands restored");
+
+        let (err, _) = error("[ 2024 1 15 ] '%Y %' FORMAT-PATTERN").await;
+        assert!(err.contains("lone %"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn format_pattern_needs_time_fields_for_time_specifiers() {
+        let (err, _) = error("[ 2024 1 15 ] '%H' FORMAT-PATTERN").await;
+        assert!(err.contains("needs a datetime"), "{}", err);
+    }
+
+    async fn fields(program: &str) -> Vec<i64> {
+        let mut interp = Interpreter::new();
+        interp
+            .execute(&format!("'time' IMPORT {}", program))
+            .await
+            .expect("program should succeed");
+        let view = interp.stack[0].as_vector_view().expect("civil vector");
+        view.iter()
+            .map(|e| e.as_scalar().unwrap().to_i64().unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn parse_pattern_reads_a_date() {
+        assert_eq!(
+            fields("'2024-01-15' '%Y-%m-%d' PARSE-PATTERN").await,
+            vec![2024, 1, 15, 0, 0, 0]
+        );
+        assert_eq!(
+            fields("'15/1/2024 7:05:09' '%d/%m/%Y %H:%M:%S' PARSE-PATTERN").await,
+            vec![2024, 1, 15, 7, 5, 9]
+        );
+        assert_eq!(
+            fields("'20240115' '%Y%m%d' PARSE-PATTERN").await,
+            vec![2024, 1, 15, 0, 0, 0]
+        );
+    }
+
+    #[tokio::test]
+    async fn parse_pattern_round_trips_with_format_pattern() {
+        assert_eq!(
+            text("'2024-01-15 09:30:00' '%Y-%m-%d %H:%M:%S' PARSE-PATTERN '%Y-%m-%d %H:%M:%S' FORMAT-PATTERN").await,
+            "2024-01-15 09:30:00"
+        );
+        assert_eq!(
+            fields("'2024-01-15' '%Y-%m-%d' PARSE-PATTERN 0 TIMESTAMP 0 DATETIME").await,
+            vec![2024, 1, 15, 0, 0, 0]
+        );
+    }
+
+    #[tokio::test]
+    async fn parse_pattern_rejects_malformed_text() {
+        let (err, depth) = error("'2024/01/15' '%Y-%m-%d' PARSE-PATTERN").await;
+        assert!(
+            err.contains("does not match the pattern at character 4"),
+            "{}",
+            err
+        );
+        assert_eq!(depth, 2, "operands restored");
+
+        let (err, _) = error("'2024-01-15 extra' '%Y-%m-%d' PARSE-PATTERN").await;
+        assert!(err.contains("does not match"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn parse_pattern_rejects_impossible_dates() {
+        let (err, depth) = error("'2024-02-30' '%Y-%m-%d' PARSE-PATTERN").await;
+        assert!(
+            err.contains("day 30 is out of range for 2024-02"),
+            "{}",
+            err
+        );
+        assert_eq!(depth, 2);
+
+        let (err, _) = error("'2023-13-01' '%Y-%m-%d' PARSE-PATTERN").await;
+        assert!(err.contains("month 13"), "{}", err);
+        let (err, _) = error("'2023-01-01 24:00' '%Y-%m-%d %H:%M' PARSE-PATTERN").await;
+        assert!(err.contains("out of range"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn parse_pattern_requires_the_date_fields() {
+        let (err, _) = error("'12:30' '%H:%M' PARSE-PATTERN").await;
+        assert!(err.contains("%Y, %m and %d"), "{}", err);
+    }
+}
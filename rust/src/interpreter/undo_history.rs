@@ -0,0 +1,143 @@
+//! Bounded undo/redo of top-level evaluations for interactive editors.
+//!
+//! Every top-level `execute` first records an undo point: a clone of the
+//! stack (cheap, since values share their payloads) and the user-visible
+//! dictionary state. The dictionary part is captured only when the
+//! dictionary or module epoch moved since the previous point; otherwise the
+//! previous point's capture is shared, so a run of plain computations costs
+//! one stack clone each. `undo` swaps the current state with the newest
+//! point and `redo` swaps it back; a new evaluation discards the redo side.
+//! Restoring advances the epochs, which drops resolve and compiled caches,
+//! and rebuilds the body content store from the restored definitions.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
+use super::interpreter_core::RuntimeDictionarySnapshot;
+use super::Interpreter;
+use crate::types::Stack;
+
+/// Undo points kept before the oldest is dropped.
+pub const DEFAULT_UNDO_LIMIT: usize = 32;
+
+#[derive(Debug)]
+struct DictionaryState {
+    runtime: RuntimeDictionarySnapshot,
+    word_identities: HashMap<String, String>,
+    /// `(dictionary_epoch, module_epoch)` when captured.
+    epochs: (u64, u64),
+}
+
+#[derive(Debug)]
+struct UndoPoint {
+    stack: Stack,
+    dictionary: Arc<DictionaryState>,
+}
+
+#[derive(Debug)]
+pub struct UndoHistory {
+    limit: usize,
+    undo: VecDeque<UndoPoint>,
+    redo: Vec<UndoPoint>,
+}
+
+impl Default for UndoHistory {
+    fn default() -> Self {
+        UndoHistory {
+            limit: DEFAULT_UNDO_LIMIT,
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+        }
+    }
+}
+
+impl UndoHistory {
+    pub(crate) fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+}
+
+impl Interpreter {
+    /// Keep at most `limit` undo points; 0 turns recording off and drops
+    /// the history.
+    pub fn set_undo_limit(&mut self, limit: usize) {
+        self.undo_history.limit = limit;
+        let excess = self.undo_history.undo.len().saturating_sub(limit);
+        self.undo_history.undo.drain(..excess);
+        if limit == 0 {
+            self.undo_history.redo.clear();
+        }
+    }
+
+    fn capture_undo_point(&self, reusable: Option<&Arc<DictionaryState>>) -> UndoPoint {
+        let epochs = (self.dictionary_epoch, self.module_epoch);
+        let dictionary = match reusable {
+            Some(state) if state.epochs == epochs => Arc::clone(state),
+            _ => Arc::new(DictionaryState {
+                runtime: self.capture_runtime_snapshot(),
+                word_identities: self.word_identities.clone(),
+                epochs,
+            }),
+        };
+        UndoPoint {
+            stack: self.stack.clone(),
+            dictionary,
+        }
+    }
+
+    fn restore_undo_point(&mut self, point: UndoPoint) {
+        self.stack = point.stack;
+        let state = &point.dictionary;
+        self.user_words = state.runtime.user_words.clone();
+        self.user_dictionaries = state.runtime.user_dictionaries.clone();
+        self.dependents = state.runtime.dependents.clone();
+        self.import_table = state.runtime.import_table.clone();
+        self.module_vocabulary = state.runtime.module_vocabulary.clone();
+        self.dictionary_dependencies = state.runtime.dictionary_dependencies.clone();
+        self.next_registration_order = state.runtime.next_registration_order;
+        self.active_user_dictionary = state.runtime.active_user_dictionary.clone();
+        self.word_identities = state.word_identities.clone();
+        self.rebuild_body_store();
+        self.bump_dictionary_epoch();
+        self.bump_module_epoch();
+    }
+
+    /// Record the state before a top-level evaluation. Clears the redo side.
+    pub(crate) fn record_undo_point(&mut self) {
+        if self.undo_history.limit == 0 {
+            return;
+        }
+        let point = self.capture_undo_point(self.undo_history.undo.back().map(|p| &p.dictionary));
+        let history = &mut self.undo_history;
+        history.redo.clear();
+        if history.undo.len() == history.limit {
+            history.undo.pop_front();
+        }
+        history.undo.push_back(point);
+    }
+
+    /// Revert the most recent evaluation's effect on the stack and the
+    /// dictionaries. Returns false when there is nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        let Some(point) = self.undo_history.undo.pop_back() else {
+            return false;
+        };
+        let current = self.capture_undo_point(Some(&point.dictionary));
+        self.undo_history.redo.push(current);
+        self.restore_undo_point(point);
+        true
+    }
+
+    /// Reapply the evaluation most recently undone. Returns false when there
+    /// is nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        let Some(point) = self.undo_history.redo.pop() else {
+            return false;
+        };
+        let current = self.capture_undo_point(Some(&point.dictionary));
+        self.undo_history.undo.push_back(current);
+        self.restore_undo_point(point);
+        true
+    }
+}
@@ -0,0 +1,70 @@
+//! Test suite for `crate::interpreter::undo_history` (undo/redo of
+//! top-level evaluations).
+
+#[cfg(test)]
+mod tests {
+    use crate::interpreter::Interpreter;
+
+    fn rendered_stack(interp: &Interpreter) -> Vec<String> {
+        interp.stack.iter().map(|v| v.to_string()).collect()
+    }
+
+    fn defines(interp: &Interpreter, word: &str) -> bool {
+        interp.resolve_word_entry_readonly(word).is_some()
+    }
+
+    #[tokio::test]
+    async fn undo_reverts_stack_and_dictionary_and_redo_reapplies() {
+        let mut interp = Interpreter::new();
+        interp.execute("[ 1 ]").await.unwrap();
+        interp.execute("{ [ 2 ] * } 'DOUBLE' DEF").await.unwrap();
+        interp.execute("[ 3 ] DOUBLE").await.unwrap();
+        assert_eq!(rendered_stack(&interp), vec!["[ 1/1 ]", "[ 6/1 ]"]);
+
+        assert!(interp.undo(), "the computation is undone");
+        assert_eq!(rendered_stack(&interp), vec!["[ 1/1 ]"]);
+        assert!(defines(&interp, "DOUBLE"));
+
+        assert!(interp.undo(), "the definition is undone");
+        assert_eq!(rendered_stack(&interp), vec!["[ 1/1 ]"]);
+        assert!(!defines(&interp, "DOUBLE"));
+
+        assert!(interp.redo());
+        assert!(interp.redo());
+        assert!(!interp.redo());
+        assert!(defines(&interp, "DOUBLE"));
+        assert_eq!(rendered_stack(&interp), vec!["[ 1/1 ]", "[ 6/1 ]"]);
+    }
+
+    #[tokio::test]
+    async fn a_new_evaluation_discards_the_redo_side() {
+        let mut interp = Interpreter::new();
+        interp.execute("[ 1 ]").await.unwrap();
+        interp.execute("[ 2 ]").await.unwrap();
+        assert!(interp.undo());
+        interp.execute("[ 3 ]").await.unwrap();
+        assert!(!interp.redo());
+
+        assert!(interp.undo());
+        assert!(interp.undo());
+        assert!(!interp.undo(), "nothing left to undo");
+        assert!(interp.stack.is_empty());
+    }
+
+    #[tokio::test]
+    async fn history_is_bounded_and_can_be_disabled() {
+        let mut interp = Interpreter::new();
+        interp.set_undo_limit(2);
+        for n in 1..=4 {
+            interp.execute(&format!("[ {} ]", n)).await.unwrap();
+        }
+        assert!(interp.undo());
+        assert!(interp.undo());
+        assert!(!interp.undo(), "only the two newest points are kept");
+        assert_eq!(interp.stack.len(), 2);
+
+        interp.set_undo_limit(0);
+        interp.execute("[ 5 ]").await.unwrap();
+        assert!(!interp.undo());
+    }
+}
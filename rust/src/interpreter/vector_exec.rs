@@ -19,7 +19,7 @@ fn format_scalar_to_source(val: &Value) -> Result<String> {
 fn format_token_to_source(token: &Token) -> String {
     match token {
         Token::Number(n) => n.to_string(),
-        Token::String(s) => format!("'{}'", s),
+        Token::String(s) => crate::tokenizer::quote_string_literal(s),
         Token::Symbol(s) => s.to_string(),
         Token::VectorStart => "[".to_string(),
         Token::VectorEnd => "]".to_string(),
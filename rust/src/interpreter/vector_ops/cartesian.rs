@@ -0,0 +1,55 @@
+//! CARTESIAN: the cartesian product of two vectors as a vector of pairs.
+
+use super::extract_vector_elements;
+use crate::error::{AjisaiError, NilReason, Result};
+use crate::interpreter::math_ops::{require_stack_top, restore_operands};
+use crate::interpreter::value_extraction_helpers::{extract_operands, push_result};
+use crate::interpreter::Interpreter;
+use crate::types::{Interpretation, Value};
+
+/// The elements of one factor; NIL is the empty vector.
+fn factor_elements(value: &Value) -> Result<Vec<Value>> {
+    if value.is_nil() {
+        return Ok(Vec::new());
+    }
+    if value.hint == Interpretation::Text || !value.is_vector() {
+        return Err(AjisaiError::from("CARTESIAN requires two vectors"));
+    }
+    Ok(extract_vector_elements(value))
+}
+
+/// `[ a ] [ b ] -- [ [ a0 b0 ] [ a0 b1 ] ... ]`. Every pair with its first
+/// element from `a` and second from `b`, ordered by `a` then `b`:
+/// `[ 1 2 ] [ 'a' 'b' ] CARTESIAN` is `[ [ 1 'a' ] [ 1 'b' ] [ 2 'a' ] [ 2 'b' ] ]`.
+/// An empty (NIL) factor gives the empty vector, which is NIL. A product
+/// over the materialization budget projects onto NIL (reason
+/// `spaceExhausted`), like RANGE.
+pub fn op_cartesian(interp: &mut Interpreter) -> Result<()> {
+    require_stack_top(interp, "CARTESIAN")?;
+    let operands = extract_operands(interp, 2)?;
+    let factors =
+        factor_elements(&operands[0]).and_then(|left| Ok((left, factor_elements(&operands[1])?)));
+    let (left, right) = match factors {
+        Ok(factors) => factors,
+        Err(error) => {
+            restore_operands(interp, operands);
+            return Err(error);
+        }
+    };
+
+    let pair_count = (left.len() as u128) * (right.len() as u128);
+    if pair_count > interp.runtime_limits.max_materialized_elements as u128 {
+        push_result(interp, Value::nil_with_reason(NilReason::SpaceExhausted));
+        return Ok(());
+    }
+    let pairs: Vec<Value> = left
+        .iter()
+        .flat_map(|a| {
+            right
+                .iter()
+                .map(move |b| Value::from_vector(vec![a.clone(), b.clone()]))
+        })
+        .collect();
+    push_result(interp, Value::from_vector(pairs));
+    Ok(())
+}
@@ -0,0 +1,71 @@
+//! LINSPACE: `n` evenly spaced exact points between two endpoints. The
+//! spacing is `(end - start) / (n - 1)` as a `Fraction`, so every point is
+//! exact and the last one is `end` itself, not an accumulated approximation.
+
+use crate::error::{AjisaiError, NilReason, Result};
+use crate::interpreter::math_ops::{require_stack_top, restore_operands};
+use crate::interpreter::value_extraction_helpers::{
+    extract_bigint_from_value, extract_operands, push_result,
+};
+use crate::interpreter::Interpreter;
+use crate::types::fraction::Fraction;
+use crate::types::{Interpretation, Value};
+use num_traits::ToPrimitive;
+
+/// A bare number or a single-element vector holding one.
+fn endpoint(value: &Value) -> Option<Fraction> {
+    if value.hint == Interpretation::Text || value.len() != 1 {
+        return None;
+    }
+    value.child(0)?.as_scalar().cloned()
+}
+
+fn parse_linspace_args(operands: &[Value]) -> Result<(Fraction, Fraction, u64)> {
+    let (Some(start), Some(end)) = (endpoint(&operands[0]), endpoint(&operands[1])) else {
+        return Err(AjisaiError::from(
+            "LINSPACE requires numeric [start] and [end]",
+        ));
+    };
+    let count = extract_bigint_from_value(&operands[2])
+        .map_err(|_| AjisaiError::from("LINSPACE count must be an integer"))?;
+    match count.to_u64() {
+        Some(n) if n >= 1 => Ok((start, end, n)),
+        _ => Err(AjisaiError::from("LINSPACE count must be at least 1")),
+    }
+}
+
+/// `[ start ] [ end ] [ n ] -- [ points ]`. `n` evenly spaced exact points
+/// from `start` to `end`, both included: `[ 0 ] [ 1 ] [ 5 ] LINSPACE` is
+/// `[ 0 1/4 1/2 3/4 1 ]`. A count of 1 gives `[ start ]`; a count below 1
+/// is an error. A count over the materialization budget projects onto
+/// NIL (reason `spaceExhausted`), like RANGE.
+pub fn op_linspace(interp: &mut Interpreter) -> Result<()> {
+    require_stack_top(interp, "LINSPACE")?;
+    let operands = extract_operands(interp, 3)?;
+    let (start, end, count) = match parse_linspace_args(&operands) {
+        Ok(args) => args,
+        Err(error) => {
+            restore_operands(interp, operands);
+            return Err(error);
+        }
+    };
+
+    if count > interp.runtime_limits.max_materialized_elements as u64 {
+        push_result(interp, Value::nil_with_reason(NilReason::SpaceExhausted));
+        return Ok(());
+    }
+
+    let points: Vec<Value> = if count == 1 {
+        vec![Value::from_fraction(start)]
+    } else {
+        let step = end.sub(&start).div(&Fraction::from((count - 1) as i64));
+        (0..count)
+            .map(|i| {
+                let offset = step.mul(&Fraction::from(i as i64));
+                Value::from_fraction(start.add(&offset))
+            })
+            .collect()
+    };
+    push_result(interp, Value::from_vector(points));
+    Ok(())
+}
@@ -1,16 +1,46 @@
+pub mod cartesian;
+pub mod linspace;
+pub mod path;
 pub mod position;
 pub mod quantity;
+pub mod repeat_value;
+pub mod rotate;
+pub mod stack_snapshot;
 pub mod structure;
 mod targeting;
 
 #[cfg(test)]
 mod tests;
 #[cfg(test)]
+mod tests_cartesian;
+#[cfg(test)]
+mod tests_linspace;
+#[cfg(test)]
 mod tests_modes;
+#[cfg(test)]
+mod tests_path;
+#[cfg(test)]
+mod tests_position;
+#[cfg(test)]
+mod tests_repeat_value;
+#[cfg(test)]
+mod tests_rotate;
+#[cfg(test)]
+mod tests_stack_idioms;
 
-pub use position::{op_get, op_insert, op_remove, op_replace};
+pub use cartesian::op_cartesian;
+pub use linspace::op_linspace;
+pub use path::{op_pathget, op_pathset};
+pub use position::{
+    op_get, op_getor, op_insert, op_remove, op_replace, op_swapat, op_update,
+};
 pub use quantity::{op_length, op_split, op_take};
-pub use structure::{op_collect, op_concat, op_range, op_reorder, op_reverse};
+pub use repeat_value::op_repeat_value;
+pub use rotate::op_rotate;
+pub use stack_snapshot::{op_restore, op_snapshot};
+pub use structure::{
+    op_collect, op_concat, op_enumerate, op_range, op_reorder, op_reverse, op_upto,
+};
 
 use crate::types::Value;
 
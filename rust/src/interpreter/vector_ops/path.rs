@@ -0,0 +1,127 @@
+//! PATHGET and PATHSET: index paths into nested vectors. Each step is
+//! resolved as `GET` resolves its index, so negative steps count from the
+//! end of the vector at that level.
+
+use super::extract_vector_elements;
+use crate::error::{AjisaiError, NilReason, Result};
+use crate::interpreter::math_ops::{require_stack_top, restore_operands};
+use crate::interpreter::value_extraction_helpers::{
+    extract_integer_from_value, extract_operands, normalize_index, push_result,
+};
+use crate::interpreter::Interpreter;
+use crate::semantic::{AbsenceOrigin, Recoverability};
+use crate::types::{Interpretation, Value};
+
+/// The steps of an index path: a vector of integers, each resolved per
+/// level as `GET` resolves its index.
+fn parse_path(word: &str, path_val: &Value) -> Result<Vec<i64>> {
+    if path_val.hint == Interpretation::Text || !path_val.is_vector() {
+        return Err(AjisaiError::from(format!(
+            "{} requires a path vector of indices",
+            word
+        )));
+    }
+    extract_vector_elements(path_val)
+        .iter()
+        .map(|step| {
+            extract_integer_from_value(step)
+                .map_err(|_| AjisaiError::from(format!("{} path steps must be integers", word)))
+        })
+        .collect()
+}
+
+/// The element `path` addresses inside `root`, or `None` once a step is
+/// out of range or lands on something that is not a vector.
+fn element_at_path(root: &Value, path: &[i64]) -> Option<Value> {
+    path.iter().try_fold(root.clone(), |current, &index| {
+        if current.hint == Interpretation::Text || !current.is_vector() {
+            return None;
+        }
+        current.child(normalize_index(index, current.len())?)
+    })
+}
+
+/// `[ nested ] [ path ] -- element`. Follow the index path into nested
+/// vectors: `[ [ 1 [ 2 3 ] ] ] [ 0 1 0 ] PATHGET` is `2`. Negative steps
+/// count from the end as in `GET`; an empty path addresses the whole
+/// structure. A step that runs off the structure projects onto NIL (reason
+/// `indexOutOfBounds`).
+pub fn op_pathget(interp: &mut Interpreter) -> Result<()> {
+    require_stack_top(interp, "PATHGET")?;
+    let operands = extract_operands(interp, 2)?;
+    let parsed = if operands[0].hint == Interpretation::Text || !operands[0].is_vector() {
+        Err(AjisaiError::create_structure_error(
+            "vector",
+            "other format",
+        ))
+    } else {
+        parse_path("PATHGET", &operands[1])
+    };
+    let path = match parsed {
+        Ok(path) => path,
+        Err(error) => {
+            restore_operands(interp, operands);
+            return Err(error);
+        }
+    };
+    let element = element_at_path(&operands[0], &path).unwrap_or_else(|| {
+        Value::bubble_with_reason(
+            NilReason::IndexOutOfBounds,
+            AbsenceOrigin::IndexOutOfBounds,
+            Recoverability::Recoverable,
+        )
+    });
+    push_result(interp, element);
+    Ok(())
+}
+
+/// Rebuild `node` with the element `path` addresses replaced by
+/// `replacement`, one level per call. `step` numbers the path step being
+/// resolved, for the error that names where the path left the structure.
+fn replace_at_path(node: &Value, path: &[i64], step: usize, replacement: Value) -> Result<Value> {
+    let Some((&index, rest)) = path.split_first() else {
+        return Ok(replacement);
+    };
+    if node.hint == Interpretation::Text || !node.is_vector() {
+        return Err(AjisaiError::from(format!(
+            "PATHSET: step {} (index {}) does not address a vector",
+            step, index
+        )));
+    }
+    let mut values = extract_vector_elements(node);
+    let len = values.len();
+    let actual = normalize_index(index, len).ok_or_else(|| {
+        AjisaiError::from(format!(
+            "PATHSET: step {} index {} is out of range for length {}",
+            step, index, len
+        ))
+    })?;
+    values[actual] = replace_at_path(&values[actual], rest, step + 1, replacement)?;
+    Ok(Value::from_vector(values))
+}
+
+/// `[ nested ] [ path ] value -- [ nested' ]`. Functional deep update: a
+/// copy of the structure with the element the path addresses (as for
+/// PATHGET) replaced by `value`; siblings at every level are untouched. A
+/// step that runs off the structure is an error naming that step and
+/// index, and the operands are restored.
+pub fn op_pathset(interp: &mut Interpreter) -> Result<()> {
+    require_stack_top(interp, "PATHSET")?;
+    let operands = extract_operands(interp, 3)?;
+    let updated = parse_path("PATHSET", &operands[1]).and_then(|path| {
+        if path.is_empty() {
+            return Err(AjisaiError::from("PATHSET requires a non-empty path"));
+        }
+        replace_at_path(&operands[0], &path, 0, operands[2].clone())
+    });
+    match updated {
+        Ok(updated) => {
+            push_result(interp, updated);
+            Ok(())
+        }
+        Err(error) => {
+            restore_operands(interp, operands);
+            Err(error)
+        }
+    }
+}
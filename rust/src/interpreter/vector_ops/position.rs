@@ -1,10 +1,16 @@
 use super::extract_vector_elements;
 use super::targeting::with_stacktop_vector_target_with_arg;
 use crate::error::{AjisaiError, NilReason, Result};
-use crate::interpreter::value_extraction_helpers::{extract_integer_from_value, normalize_index};
+use crate::interpreter::higher_order::{execute_executable_code, extract_executable_code};
+use crate::interpreter::math_ops::{require_stack_top, restore_operands};
+use crate::interpreter::value_extraction_helpers::{
+    extract_bigint_from_value, extract_integer_from_value, extract_operands, normalize_index,
+    push_result,
+};
 use crate::interpreter::{ConsumptionMode, Interpreter, OperationTargetMode};
 use crate::semantic::{AbsenceOrigin, Recoverability};
 use crate::types::{Interpretation, Value};
+use num_traits::ToPrimitive;
 
 fn pop_index_operand(interp: &mut Interpreter) -> Result<(Value, i64)> {
     let index_val = interp.stack.pop().ok_or(AjisaiError::StackUnderflow)?;
@@ -117,6 +123,77 @@ pub fn op_get(interp: &mut Interpreter) -> Result<()> {
     }
 }
 
+/// `[ vec ] [ index ] default -- element`. The element at `index`, resolved
+/// as in `GET`, or `default` when the index is out of range: `[ 1 2 3 ]
+/// [ 5 ] 0 GETOR` is `0`, however large the index. An empty (NIL) vector always yields the default.
+/// Unlike GET the vector is consumed.
+pub fn op_getor(interp: &mut Interpreter) -> Result<()> {
+    require_stack_top(interp, "GETOR")?;
+    let mut operands = extract_operands(interp, 3)?;
+    let target = &operands[0];
+    let parsed = if target.hint == Interpretation::Text || !(target.is_nil() || target.is_vector())
+    {
+        Err(AjisaiError::create_structure_error(
+            "vector",
+            "other format",
+        ))
+    } else {
+        // An index beyond i64 is out of range for any vector, not an error.
+        extract_bigint_from_value(&operands[1]).map(|n| n.to_i64())
+    };
+    let index = match parsed {
+        Ok(index) => index,
+        Err(error) => {
+            restore_operands(interp, operands);
+            return Err(error);
+        }
+    };
+    let element = match index.and_then(|index| normalize_index(index, target.len())) {
+        Some(actual) if !target.is_nil() => target.child(actual),
+        _ => None,
+    };
+    let result = element.unwrap_or_else(|| operands.swap_remove(2));
+    push_result(interp, result);
+    Ok(())
+}
+
+fn swap_elements(target: &Value, i_val: &Value, j_val: &Value) -> Result<Value> {
+    if target.hint == Interpretation::Text || !target.is_vector() {
+        return Err(AjisaiError::create_structure_error(
+            "vector",
+            "other format",
+        ));
+    }
+    let mut values = extract_vector_elements(target);
+    let length = values.len();
+    let resolve = |val: &Value| -> Result<usize> {
+        let index = extract_integer_from_value(val)?;
+        normalize_index(index, length).ok_or(AjisaiError::IndexOutOfBounds { index, length })
+    };
+    let (i, j) = (resolve(i_val)?, resolve(j_val)?);
+    values.swap(i, j);
+    Ok(Value::from_vector(values))
+}
+
+/// `[ vec ] [ i ] [ j ] -- [ vec' ]`. A copy of the vector with the elements
+/// at `i` and `j` exchanged; negative indices count from the end as in
+/// `GET`, and `i == j` returns it unchanged. An out-of-range index is an
+/// error and the operands are restored.
+pub fn op_swapat(interp: &mut Interpreter) -> Result<()> {
+    require_stack_top(interp, "SWAPAT")?;
+    let operands = extract_operands(interp, 3)?;
+    match swap_elements(&operands[0], &operands[1], &operands[2]) {
+        Ok(swapped) => {
+            push_result(interp, swapped);
+            Ok(())
+        }
+        Err(error) => {
+            restore_operands(interp, operands);
+            Err(error)
+        }
+    }
+}
+
 pub fn op_insert(interp: &mut Interpreter) -> Result<()> {
     let is_keep_mode = interp.consumption_mode == ConsumptionMode::Keep;
 
@@ -285,3 +362,89 @@ pub fn op_remove(interp: &mut Interpreter) -> Result<()> {
         }
     }
 }
+
+/// Run the transform on an isolated stack holding only the selected element
+/// and rebuild the vector with its single result in place.
+fn update_element(
+    interp: &mut Interpreter,
+    target_val: &Value,
+    index: i64,
+    code_val: &Value,
+) -> Result<Value> {
+    if !target_val.is_vector() {
+        return Err(AjisaiError::create_structure_error(
+            "vector",
+            "other format",
+        ));
+    }
+    let code = extract_executable_code(interp, code_val)?;
+    let mut values = extract_vector_elements(target_val);
+    let len = values.len();
+    let actual_index =
+        normalize_index(index, len).ok_or(AjisaiError::IndexOutOfBounds { index, length: len })?;
+
+    let saved_stack = std::mem::take(&mut interp.stack);
+    let saved_target = interp.operation_target_mode;
+    interp.operation_target_mode = OperationTargetMode::StackTop;
+    interp.consumption_mode = ConsumptionMode::Consume;
+    interp.stack.push(values[actual_index].clone());
+    let outcome = execute_executable_code(interp, &code);
+    let mut produced = std::mem::replace(&mut interp.stack, saved_stack);
+    interp.operation_target_mode = saved_target;
+    outcome?;
+
+    if produced.len() != 1 {
+        return Err(AjisaiError::from(format!(
+            "UPDATE: expected the transform to leave exactly one value, got {}",
+            produced.len()
+        )));
+    }
+    values[actual_index] = produced.pop().expect("length checked above");
+    Ok(Value::from_vector(values))
+}
+
+/// `vec [ index ] word -- vec'`. Functional update: apply a transform (block or
+/// word name) to the element at `index` and replace it with the result.
+/// Negative indices count from the end as in `GET`; on any error the three
+/// operands are restored.
+pub fn op_update(interp: &mut Interpreter) -> Result<()> {
+    if interp.operation_target_mode == OperationTargetMode::Stack {
+        return Err(AjisaiError::ModeUnsupported {
+            word: "UPDATE".into(),
+            mode: "Stack".into(),
+        });
+    }
+    let is_keep_mode = interp.consumption_mode == ConsumptionMode::Keep;
+
+    let code_val = interp.stack.pop().ok_or(AjisaiError::StackUnderflow)?;
+    let (index_val, index) = match pop_index_operand(interp) {
+        Ok(operand) => operand,
+        Err(error) => {
+            interp.stack.push(code_val);
+            return Err(error);
+        }
+    };
+    let Some(target_val) = interp.stack.pop() else {
+        interp.stack.push(index_val);
+        interp.stack.push(code_val);
+        return Err(AjisaiError::StackUnderflow);
+    };
+
+    match update_element(interp, &target_val, index, &code_val) {
+        Ok(updated) => {
+            if is_keep_mode {
+                interp.stack.push(target_val);
+                interp.stack.push(index_val);
+                interp.stack.push(code_val);
+            }
+            interp.stack.push(updated);
+            Ok(())
+        }
+        Err(error) => {
+            interp.stack.push(target_val);
+            interp.stack.push(index_val);
+            interp.stack.push(code_val);
+            Err(error)
+        }
+    }
+}
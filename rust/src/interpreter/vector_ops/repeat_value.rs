@@ -0,0 +1,41 @@
+//! REPEAT-VALUE: a vector of `n` copies of one value. Unlike the core
+//! `FILL`, which fills a tensor shape with a scalar, the repeated value may
+//! be anything, nested vectors included; each copy is a clone.
+
+use crate::error::{AjisaiError, NilReason, Result};
+use crate::interpreter::math_ops::{require_stack_top, restore_operands};
+use crate::interpreter::value_extraction_helpers::{
+    extract_integer_from_value, extract_operands, push_result,
+};
+use crate::interpreter::Interpreter;
+use crate::types::Value;
+
+/// `value [ n ] -- [ value ... ]`. `n` copies of `value`: `0 [ 4 ] REPEAT-VALUE`
+/// is `[ 0 0 0 0 ]` and `[ 1 2 ] [ 2 ] REPEAT-VALUE` is `[ [ 1 2 ] [ 1 2 ] ]`.
+/// A count of 0 gives the empty vector, which is NIL (reason
+/// `emptySequence`); a negative count is an error. A count over the
+/// materialization budget projects onto NIL (reason `spaceExhausted`), like
+/// RANGE.
+pub fn op_repeat_value(interp: &mut Interpreter) -> Result<()> {
+    require_stack_top(interp, "REPEAT-VALUE")?;
+    let operands = extract_operands(interp, 2)?;
+    let count = match extract_integer_from_value(&operands[1]) {
+        Ok(count) if count >= 0 => count as u64,
+        Ok(_) => {
+            restore_operands(interp, operands);
+            return Err(AjisaiError::from("REPEAT-VALUE count must not be negative"));
+        }
+        Err(_) => {
+            restore_operands(interp, operands);
+            return Err(AjisaiError::from("REPEAT-VALUE count must be an integer"));
+        }
+    };
+
+    if count > interp.runtime_limits.max_materialized_elements as u64 {
+        push_result(interp, Value::nil_with_reason(NilReason::SpaceExhausted));
+        return Ok(());
+    }
+    let copies = vec![operands[0].clone(); count as usize];
+    push_result(interp, Value::from_vector(copies));
+    Ok(())
+}
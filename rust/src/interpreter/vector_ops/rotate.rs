@@ -0,0 +1,50 @@
+//! ROTATE: cyclic shifts of a vector. The shift is taken modulo the length,
+//! so any integer is valid, and a shift that is a multiple of the length
+//! (0 included) returns the vector unchanged rather than raising an error.
+
+use super::extract_vector_elements;
+use crate::error::{AjisaiError, Result};
+use crate::interpreter::math_ops::{require_stack_top, restore_operands};
+use crate::interpreter::value_extraction_helpers::{
+    extract_integer_from_value, extract_operands, push_result,
+};
+use crate::interpreter::Interpreter;
+use crate::types::{Interpretation, Value};
+
+fn rotate_vector(target: &Value, shift_val: &Value) -> Result<Value> {
+    let shift = extract_integer_from_value(shift_val)
+        .map_err(|_| AjisaiError::from("ROTATE shift must be an integer"))?;
+    if target.is_nil() {
+        return Ok(target.clone());
+    }
+    if target.hint == Interpretation::Text || !target.is_vector() {
+        return Err(AjisaiError::create_structure_error(
+            "vector",
+            "other format",
+        ));
+    }
+    let mut values = extract_vector_elements(target);
+    let offset = shift.rem_euclid(values.len() as i64) as usize;
+    values.rotate_left(offset);
+    Ok(Value::from_vector(values))
+}
+
+/// `[ vec ] [ k ] -- [ vec' ]`. Rotate left by `k`, moving the first `k`
+/// elements to the end: `[ 1 2 3 4 ] [ 1 ] ROTATE` is `[ 2 3 4 1 ]`. A
+/// negative `k` rotates right, and `k` is reduced modulo the length, so a
+/// zero or full-length shift returns the vector unchanged. NIL passes
+/// through.
+pub fn op_rotate(interp: &mut Interpreter) -> Result<()> {
+    require_stack_top(interp, "ROTATE")?;
+    let operands = extract_operands(interp, 2)?;
+    match rotate_vector(&operands[0], &operands[1]) {
+        Ok(rotated) => {
+            push_result(interp, rotated);
+            Ok(())
+        }
+        Err(error) => {
+            restore_operands(interp, operands);
+            Err(error)
+        }
+    }
+}
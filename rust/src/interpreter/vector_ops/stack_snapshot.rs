@@ -0,0 +1,62 @@
+//! Whole-stack snapshots as first-class values. SNAPSHOT copies the stack
+//! into one vector; RESTORE replaces the stack with such a vector's
+//! elements, so a search can try a branch and roll back to the saved state.
+
+use crate::error::{AjisaiError, Result};
+use crate::interpreter::{ConsumptionMode, Interpreter, OperationTargetMode};
+use crate::types::{Interpretation, Value};
+
+fn reject_stack_mode(interp: &Interpreter, word: &str) -> Result<()> {
+    if interp.operation_target_mode == OperationTargetMode::Stack {
+        return Err(AjisaiError::ModeUnsupported {
+            word: word.into(),
+            mode: "Stack".into(),
+        });
+    }
+    Ok(())
+}
+
+/// `v1 ... vn -- v1 ... vn [ v1 ... vn ]`. Push a copy of the whole stack,
+/// bottom first, as one vector. Each element carries its slot's role (a
+/// datetime, a `>CF` cast) so RESTORE can put it back. An empty stack
+/// snapshots to NIL.
+pub fn op_snapshot(interp: &mut Interpreter) -> Result<()> {
+    reject_stack_mode(interp, "SNAPSHOT")?;
+    let values: Vec<Value> = interp
+        .stack
+        .iter_slots()
+        .map(|(value, role)| {
+            let mut value = value.clone();
+            value.hint = role;
+            value
+        })
+        .collect();
+    interp.stack.push(Value::from_vector(values));
+    Ok(())
+}
+
+/// `... [ v1 ... vn ] -- v1 ... vn`. Discard the stack below the snapshot
+/// and spread its elements back, bottom first, each under the role it was
+/// saved with; NIL restores an empty stack.
+/// In KEEP mode the snapshot stays on top for the next rollback.
+pub fn op_restore(interp: &mut Interpreter) -> Result<()> {
+    reject_stack_mode(interp, "RESTORE")?;
+    let snapshot = interp.stack.pop().ok_or(AjisaiError::StackUnderflow)?;
+    let values = if snapshot.is_nil() {
+        Vec::new()
+    } else if snapshot.is_vector() && snapshot.hint != Interpretation::Text {
+        super::extract_vector_elements(&snapshot)
+    } else {
+        interp.stack.push(snapshot);
+        return Err(AjisaiError::from(
+            "RESTORE requires a snapshot vector made by SNAPSHOT",
+        ));
+    };
+
+    interp.stack.clear();
+    interp.stack.extend(values);
+    if interp.consumption_mode == ConsumptionMode::Keep {
+        interp.stack.push(snapshot);
+    }
+    Ok(())
+}
@@ -6,7 +6,7 @@ use crate::interpreter::value_extraction_helpers::{
 };
 use crate::interpreter::{ConsumptionMode, Interpreter, OperationTargetMode};
 use crate::types::fraction::Fraction;
-use crate::types::Value;
+use crate::types::{Interpretation, Value};
 use num_traits::ToPrimitive;
 
 fn parse_concat_count(
@@ -51,35 +51,29 @@ fn concat_values(values: Vec<Value>, is_reversed: bool) -> Value {
     Value::from_vector(result_vec)
 }
 
-fn parse_range_bound(args_val: &Value, index: usize, label: &str) -> Result<i64> {
+fn parse_range_bound(word: &str, args_val: &Value, index: usize, label: &str) -> Result<i64> {
     let child = args_val
         .child(index)
-        .ok_or_else(|| AjisaiError::from(format!("RANGE missing {}", label)))?;
+        .ok_or_else(|| AjisaiError::from(format!("{} missing {}", word, label)))?;
     let bigint = extract_bigint_from_value(&child)
-        .map_err(|_| AjisaiError::from(format!("RANGE {} must be an integer", label)))?;
+        .map_err(|_| AjisaiError::from(format!("{} {} must be an integer", word, label)))?;
     bigint
         .to_i64()
-        .ok_or_else(|| AjisaiError::from(format!("RANGE {} is too large", label)))
+        .ok_or_else(|| AjisaiError::from(format!("{} {} is too large", word, label)))
 }
 
-fn parse_range_args(args_val: &Value) -> Result<(i64, i64, i64)> {
-    if !args_val.is_vector() {
-        return Err(AjisaiError::from(
-            "RANGE requires [start end] or [start end step]",
-        ));
+fn parse_range_args(word: &str, args_val: &Value) -> Result<(i64, i64, i64)> {
+    if !args_val.is_vector() || !(2..=3).contains(&args_val.len()) {
+        return Err(AjisaiError::from(format!(
+            "{} requires [start end] or [start end step]",
+            word
+        )));
     }
 
-    let n = args_val.len();
-    if !(2..=3).contains(&n) {
-        return Err(AjisaiError::from(
-            "RANGE requires [start end] or [start end step]",
-        ));
-    }
-
-    let start = parse_range_bound(args_val, 0, "start")?;
-    let end = parse_range_bound(args_val, 1, "end")?;
-    let step = if n == 3 {
-        parse_range_bound(args_val, 2, "step")?
+    let start = parse_range_bound(word, args_val, 0, "start")?;
+    let end = parse_range_bound(word, args_val, 1, "end")?;
+    let step = if args_val.len() == 3 {
+        parse_range_bound(word, args_val, 2, "step")?
     } else if start <= end {
         1
     } else {
@@ -113,6 +107,34 @@ fn parse_reorder_indices(indices_val: &Value) -> Result<Vec<i64>> {
     Ok(vec![single])
 }
 
+/// The optional start index of `ENUMERATE`: an integer on top counts as the
+/// start only when a vector (or NIL) sits beneath it, so `[ 5 ] ENUMERATE`
+/// still enumerates the one-element vector.
+fn parse_enumerate_start(interp: &Interpreter) -> Option<i64> {
+    let [.., target, top] = interp.stack.as_slice() else {
+        return None;
+    };
+    if !(target.is_vector() || target.is_nil()) {
+        return None;
+    }
+    extract_integer_from_value(top).ok()
+}
+
+fn enumerate_values(vector_val: &Value, start: i64) -> Result<Value> {
+    let elements = extract_vector_elements(vector_val);
+    let mut pairs = Vec::with_capacity(elements.len());
+    for (offset, element) in elements.into_iter().enumerate() {
+        let index = start
+            .checked_add(offset as i64)
+            .ok_or_else(|| AjisaiError::from("ENUMERATE index is too large"))?;
+        pairs.push(Value::from_vector(vec![
+            Value::from_fraction(Fraction::from(index)),
+            element,
+        ]));
+    }
+    Ok(Value::from_vector(pairs))
+}
+
 pub fn op_concat(interp: &mut Interpreter) -> Result<()> {
     let is_keep_mode = interp.consumption_mode == ConsumptionMode::Keep;
 
@@ -181,9 +203,20 @@ pub fn op_reverse(interp: &mut Interpreter) -> Result<()> {
 }
 
 pub fn op_range(interp: &mut Interpreter) -> Result<()> {
+    push_range(interp, "RANGE", false)
+}
+
+/// `[ start end ( step ) ] -- [ seq ]`. RANGE with `end` excluded, the
+/// half-open `[start, end)`: `[ 0 5 ] UPTO` is `[ 0 1 2 3 4 ]`. Equal
+/// bounds give the empty range, NIL.
+pub fn op_upto(interp: &mut Interpreter) -> Result<()> {
+    push_range(interp, "UPTO", true)
+}
+
+fn push_range(interp: &mut Interpreter, word: &str, exclusive: bool) -> Result<()> {
     let args_val = interp.stack.pop().ok_or(AjisaiError::StackUnderflow)?;
 
-    let (start, end, step) = match parse_range_args(&args_val) {
+    let (start, end, step) = match parse_range_args(word, &args_val) {
         Ok(values) => values,
         Err(error) => {
             interp.stack.push(args_val);
@@ -193,14 +226,15 @@ pub fn op_range(interp: &mut Interpreter) -> Result<()> {
 
     if step == 0 {
         interp.stack.push(args_val);
-        return Err(AjisaiError::from("RANGE step cannot be 0"));
+        return Err(AjisaiError::from(format!("{} step cannot be 0", word)));
     }
 
     if (start < end && step < 0) || (start > end && step > 0) {
         interp.stack.push(args_val);
-        return Err(AjisaiError::from(
-            "RANGE would create an infinite sequence (check start, end, and step values)",
-        ));
+        return Err(AjisaiError::from(format!(
+            "{} would create an infinite sequence (check start, end, and step values)",
+            word
+        )));
     }
 
     // Guard against unbounded materialization before allocating. RANGE loops
@@ -211,7 +245,11 @@ pub fn op_range(interp: &mut Interpreter) -> Result<()> {
     // so the span arithmetic cannot overflow for extreme i64 bounds.
     let span = (end as i128 - start as i128).unsigned_abs();
     let stride = (step as i128).unsigned_abs();
-    let element_count = span / stride + 1;
+    let element_count = match (exclusive, span) {
+        (true, 0) => 0,
+        (true, _) => (span - 1) / stride + 1,
+        (false, _) => span / stride + 1,
+    };
     // CS5: the cap is the injectable per-interpreter ceiling (folded into
     // `RuntimeLimits`), so tests can fire this guard with a tiny limit and
     // child runtimes inherit it — same behavior and message as before.
@@ -230,20 +268,14 @@ pub fn op_range(interp: &mut Interpreter) -> Result<()> {
         return Ok(());
     }
 
-    let mut range_vec = Vec::with_capacity(element_count as usize);
-    let mut current = start;
-
-    if step > 0 {
-        while current <= end {
-            range_vec.push(Value::from_fraction(Fraction::from(current)));
-            current += step;
-        }
-    } else {
-        while current >= end {
-            range_vec.push(Value::from_fraction(Fraction::from(current)));
-            current += step;
-        }
-    }
+    // Every element lies between the bounds, so it fits in i64 even where
+    // the offset from `start` alone would not.
+    let range_vec: Vec<Value> = (0..element_count as i128)
+        .map(|i| {
+            let element = start as i128 + i * step as i128;
+            Value::from_fraction(Fraction::from(element as i64))
+        })
+        .collect();
 
     interp.stack.push(Value::from_vector(range_vec));
 
@@ -373,3 +405,80 @@ pub fn op_collect(interp: &mut Interpreter) -> Result<()> {
     interp.stack.push(Value::from_vector(collected));
     Ok(())
 }
+
+/// `ENUMERATE`: pair every element with its index.
+///
+/// ```text
+/// [ 'a' 'b' ] ENUMERATE       -> [ [ 0 'a' ] [ 1 'b' ] ]
+/// [ 'a' 'b' ] [ 1 ] ENUMERATE -> [ [ 1 'a' ] [ 2 'b' ] ]
+/// ```
+///
+/// Indices are 0-based unless a start index is supplied. NIL enumerates to NIL.
+pub fn op_enumerate(interp: &mut Interpreter) -> Result<()> {
+    if interp.operation_target_mode == OperationTargetMode::Stack {
+        return Err(AjisaiError::ModeUnsupported {
+            word: "ENUMERATE".into(),
+            mode: "Stack".into(),
+        });
+    }
+    let is_keep_mode = interp.consumption_mode == ConsumptionMode::Keep;
+
+    let start_val = parse_enumerate_start(interp).map(|start| {
+        let value = interp.stack.pop().expect("start index checked above");
+        (start, value)
+    });
+    let start = start_val.as_ref().map_or(0, |(start, _)| *start);
+
+    let result = if interp.stack.last().is_some_and(Value::is_nil) {
+        if !is_keep_mode {
+            interp.stack.pop();
+        }
+        Value::nil()
+    } else {
+        let action = |vector_val: &Value| enumerate_values(vector_val, start);
+        match &start_val {
+            Some((_, value)) => {
+                with_stacktop_vector_target_with_arg(interp, value, is_keep_mode, action)?
+            }
+            None => with_stacktop_vector_target_no_arg(interp, is_keep_mode, action)?,
+        }
+    };
+
+    if let (true, Some((_, value))) = (is_keep_mode, start_val) {
+        interp.stack.push(value);
+    }
+    interp.stack.push(result);
+    Ok(())
+}
+
+/// Vector-level `TRANSPOSE` for rows of unequal length, used when the value
+/// is not a rectangular numeric tensor. Each row's `j`-th element becomes the
+/// `j`-th column; short rows are padded with NIL. Returns `None` unless every
+/// row is a non-text vector (a NIL row counts as empty).
+///
+/// ```text
+/// [ [ 1 2 3 ] [ 4 ] ] TRANSPOSE -> [ [ 1 4 ] [ 2 NIL ] [ 3 NIL ] ]
+/// ```
+pub(crate) fn transpose_ragged(val: &Value) -> Option<Value> {
+    let rows = val.as_vector_view()?;
+    let is_row =
+        |row: &Value| row.is_nil() || (row.is_vector() && row.hint != Interpretation::Text);
+    if !rows.iter().all(is_row) {
+        return None;
+    }
+
+    let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    if width == 0 {
+        return Some(Value::nil());
+    }
+    let columns = (0..width)
+        .map(|j| {
+            let column = rows
+                .iter()
+                .map(|row| row.child(j).unwrap_or_else(Value::nil))
+                .collect();
+            Value::from_vector(column)
+        })
+        .collect();
+    Some(Value::from_vector(columns))
+}
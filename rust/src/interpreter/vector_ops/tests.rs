@@ -1,6 +1,7 @@
 //! Test suite for `crate::interpreter::vector_ops`.
 
 use crate::interpreter::Interpreter;
+use crate::types::Interpretation;
 
 #[tokio::test]
 async fn test_range_basic_stacktop() {
@@ -93,6 +94,51 @@ async fn test_range_error_step_zero_restores_stack_stack_mode() {
     );
 }
 
+async fn range_strings(code: &str) -> Vec<String> {
+    let mut interp = Interpreter::new();
+    interp
+        .execute(&format!("'algo' IMPORT {}", code))
+        .await
+        .unwrap();
+    interp.stack.iter().map(|v| v.to_string()).collect()
+}
+
+#[tokio::test]
+async fn test_upto_excludes_the_end_that_range_includes() {
+    assert_eq!(
+        range_strings("[ 0 5 ] RANGE [ 0 5 ] UPTO").await,
+        vec!["[ 0/1 1/1 2/1 3/1 4/1 5/1 ]", "[ 0/1 1/1 2/1 3/1 4/1 ]"]
+    );
+    assert_eq!(
+        range_strings("[ 0 10 3 ] RANGE [ 0 9 3 ] UPTO").await,
+        vec!["[ 0/1 3/1 6/1 9/1 ]", "[ 0/1 3/1 6/1 ]"]
+    );
+}
+
+#[tokio::test]
+async fn test_upto_descending() {
+    assert_eq!(
+        range_strings("[ 5 0 ] UPTO [ 10 0 -4 ] UPTO").await,
+        vec!["[ 5/1 4/1 3/1 2/1 1/1 ]", "[ 10/1 6/1 2/1 ]"]
+    );
+}
+
+#[tokio::test]
+async fn test_upto_equal_bounds_is_empty() {
+    assert_eq!(range_strings("[ 3 3 ] UPTO").await, vec!["NIL"]);
+}
+
+#[tokio::test]
+async fn test_upto_shares_range_errors() {
+    for code in ["[ 0 10 0 ] UPTO", "[ 0 10 -1 ] UPTO"] {
+        let mut interp = Interpreter::new();
+        let result = interp.execute(&format!("'algo' IMPORT {}", code)).await;
+        let err = result.expect_err(code).to_string();
+        assert!(err.starts_with("UPTO"), "{}", err);
+        assert_eq!(interp.stack.len(), 1, "arguments are restored");
+    }
+}
+
 #[tokio::test]
 async fn test_range_error_infinite_restores_stack() {
     let mut interp = Interpreter::new();
@@ -266,3 +312,189 @@ async fn test_reorder_single_element_index() {
     let val = &interp.stack[0];
     assert_eq!(val.shape(), vec![1], "Result should have 1 element");
 }
+
+#[tokio::test]
+async fn test_update_positive_index_with_word_name() {
+    let mut interp = Interpreter::new();
+    interp.execute("{ 2 * } 'DOUBLE' DEF").await.unwrap();
+
+    let result = interp.execute("[ 1 2 3 ] [ 1 ] 'DOUBLE' UPDATE").await;
+    assert!(result.is_ok(), "UPDATE should succeed: {:?}", result);
+    assert_eq!(interp.stack.len(), 1);
+    assert_eq!(
+        format!("{}", interp.stack.last().unwrap()),
+        "[ 1/1 4/1 3/1 ]"
+    );
+}
+
+#[tokio::test]
+async fn test_update_negative_index_normalizes_like_get() {
+    let mut interp = Interpreter::new();
+
+    let result = interp.execute("[ 1 2 3 ] [ -1 ] { 10 + } UPDATE").await;
+    assert!(result.is_ok(), "UPDATE should succeed: {:?}", result);
+    assert_eq!(
+        format!("{}", interp.stack.last().unwrap()),
+        "[ 1/1 2/1 13/1 ]"
+    );
+}
+
+#[tokio::test]
+async fn test_update_error_out_of_bounds_restores_stack() {
+    let mut interp = Interpreter::new();
+
+    let result = interp.execute("[ 1 2 3 ] [ 3 ] { 10 + } UPDATE").await;
+    assert!(result.is_err(), "UPDATE past the end should fail");
+    assert_eq!(interp.stack.len(), 3, "operands are restored on error");
+    assert_eq!(format!("{}", interp.stack[0]), "[ 1/1 2/1 3/1 ]");
+
+    let mut interp = Interpreter::new();
+    let result = interp.execute("[ 1 2 3 ] [ -4 ] { 10 + } UPDATE").await;
+    assert!(result.is_err(), "UPDATE before the start should fail");
+    assert_eq!(interp.stack.len(), 3, "operands are restored on error");
+}
+
+#[tokio::test]
+async fn test_update_transform_must_leave_one_value() {
+    let mut interp = Interpreter::new();
+
+    let result = interp.execute("[ 1 2 3 ] [ 0 ] { 7 8 } UPDATE").await;
+    assert!(
+        result.is_err(),
+        "a transform leaving two values should fail"
+    );
+    assert_eq!(interp.stack.len(), 3, "operands are restored on error");
+}
+
+#[tokio::test]
+async fn test_enumerate_default_start() {
+    let mut interp = Interpreter::new();
+
+    let result = interp.execute("[ 'a' 'b' 'c' ] ENUMERATE").await;
+    assert!(result.is_ok(), "ENUMERATE should succeed: {:?}", result);
+    assert_eq!(interp.stack.len(), 1);
+    assert_eq!(
+        format!("{}", interp.stack[0]),
+        "[ [ 0/1 'a' ] [ 1/1 'b' ] [ 2/1 'c' ] ]"
+    );
+}
+
+#[tokio::test]
+async fn test_enumerate_custom_start() {
+    let mut interp = Interpreter::new();
+
+    let result = interp.execute("[ 10 20 ] [ 1 ] ENUMERATE").await;
+    assert!(result.is_ok(), "ENUMERATE should succeed: {:?}", result);
+    assert_eq!(interp.stack.len(), 1);
+    assert_eq!(
+        format!("{}", interp.stack[0]),
+        "[ [ 1/1 10/1 ] [ 2/1 20/1 ] ]"
+    );
+
+    // a lone one-element vector is enumerated, not read as a start index
+    let mut interp = Interpreter::new();
+    interp.execute("[ 5 ] ENUMERATE").await.unwrap();
+    assert_eq!(format!("{}", interp.stack[0]), "[ [ 0/1 5/1 ] ]");
+}
+
+#[tokio::test]
+async fn test_enumerate_nil_and_errors() {
+    let mut interp = Interpreter::new();
+    interp.execute("NIL ENUMERATE").await.unwrap();
+    assert!(interp.stack[0].is_nil(), "NIL enumerates to NIL");
+
+    let mut interp = Interpreter::new();
+    let result = interp.execute("[ 1 2 ] .. ENUMERATE").await;
+    assert!(result.is_err(), "Stack mode is unsupported");
+
+    let mut interp = Interpreter::new();
+    let result = interp.execute("5 ENUMERATE").await;
+    assert!(result.is_err(), "a scalar is not enumerable");
+    assert_eq!(interp.stack.len(), 1, "operand restored");
+}
+
+fn stack_strings(interp: &Interpreter) -> Vec<String> {
+    interp.stack.iter().map(|v| v.to_string()).collect()
+}
+
+#[tokio::test]
+async fn test_snapshot_restore_rolls_back_a_mutation() {
+    let mut interp = Interpreter::new();
+    interp.execute("[ 1 ] 'a' [ 2 3 ]").await.unwrap();
+    let before = stack_strings(&interp);
+
+    // Park the snapshot at the bottom, mutate the live values, then copy
+    // the snapshot back to the top and restore from it.
+    interp
+        .execute("SNAPSHOT [ 3 0 1 2 ] .. REORDER -1 .. REMOVE [ 9 ]")
+        .await
+        .unwrap();
+    assert_ne!(stack_strings(&interp)[1..], before[..]);
+
+    interp.execute("0 .. ,, GET RESTORE").await.unwrap();
+    assert_eq!(stack_strings(&interp), before);
+}
+
+#[tokio::test]
+async fn test_snapshot_restore_keeps_slot_roles() {
+    let mut interp = Interpreter::new();
+    interp
+        .execute("[ 1/2 ] >CF 'time' IMPORT NOW")
+        .await
+        .unwrap();
+    let before = interp.stack.clone();
+    assert_eq!(before.role_at(1), Interpretation::Timestamp);
+
+    interp.execute("SNAPSHOT RESTORE").await.unwrap();
+    assert_eq!(interp.stack.roles(), before.roles());
+    assert!(interp
+        .stack
+        .iter()
+        .zip(before.iter())
+        .all(|(a, b)| a.data == b.data));
+    interp.execute("'reflect' IMPORT TYPE").await.unwrap();
+    assert_eq!(stack_strings(&interp), vec!["[ 1/2 ]", "'datetime'"]);
+}
+
+#[tokio::test]
+async fn test_snapshot_keeps_the_stack() {
+    let mut interp = Interpreter::new();
+    interp.execute("[ 1 ] [ 2 ] SNAPSHOT").await.unwrap();
+    assert_eq!(
+        stack_strings(&interp),
+        vec!["[ 1/1 ]", "[ 2/1 ]", "[ [ 1/1 ] [ 2/1 ] ]"]
+    );
+}
+
+#[tokio::test]
+async fn test_snapshot_of_empty_stack_restores_empty() {
+    let mut interp = Interpreter::new();
+    interp.execute("SNAPSHOT").await.unwrap();
+    assert_eq!(stack_strings(&interp), vec!["NIL"]);
+    interp.execute("RESTORE").await.unwrap();
+    assert!(interp.stack.is_empty());
+
+    let mut interp = Interpreter::new();
+    interp.execute("[ 7 ] NIL RESTORE").await.unwrap();
+    assert!(interp.stack.is_empty());
+}
+
+#[tokio::test]
+async fn test_restore_keep_leaves_snapshot_on_top() {
+    let mut interp = Interpreter::new();
+    interp
+        .execute("[ 5 ] [ 1 ] [ 2 ] 2 COLLECT ,, RESTORE")
+        .await
+        .unwrap();
+    assert_eq!(
+        stack_strings(&interp),
+        vec!["[ 1/1 ]", "[ 2/1 ]", "[ [ 1/1 ] [ 2/1 ] ]"]
+    );
+}
+
+#[tokio::test]
+async fn test_restore_rejects_text_and_keeps_stack() {
+    let mut interp = Interpreter::new();
+    assert!(interp.execute("[ 1 ] 'ab' RESTORE").await.is_err());
+    assert_eq!(stack_strings(&interp), vec!["[ 1/1 ]", "'ab'"]);
+}
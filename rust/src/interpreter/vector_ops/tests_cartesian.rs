@@ -0,0 +1,48 @@
+//! ALGO@CARTESIAN: the cartesian product of two vectors.
+
+use crate::error::NilReason;
+use crate::interpreter::Interpreter;
+
+async fn stack_after(code: &str) -> Vec<String> {
+    let mut interp = Interpreter::new();
+    interp
+        .execute(&format!("'algo' IMPORT {}", code))
+        .await
+        .expect("should succeed");
+    interp.stack.iter().map(|v| v.to_string()).collect()
+}
+
+#[tokio::test]
+async fn test_cartesian_two_by_two() {
+    assert_eq!(
+        stack_after("[ 1 2 ] [ 'a' 'b' ] CARTESIAN").await,
+        vec!["[ [ 1/1 'a' ] [ 1/1 'b' ] [ 2/1 'a' ] [ 2/1 'b' ] ]"]
+    );
+}
+
+#[tokio::test]
+async fn test_cartesian_with_an_empty_factor_is_empty() {
+    for code in ["NIL [ 1 2 ] CARTESIAN", "[ 1 2 ] NIL CARTESIAN"] {
+        let mut interp = Interpreter::new();
+        interp
+            .execute(&format!("'algo' IMPORT {}", code))
+            .await
+            .unwrap();
+        assert_eq!(interp.stack.len(), 1, "{}", code);
+        let result = interp.stack.last().unwrap();
+        assert!(result.is_nil(), "{}", code);
+        assert_eq!(result.nil_reason(), Some(&NilReason::EmptySequence));
+    }
+}
+
+#[tokio::test]
+async fn test_cartesian_over_budget_projects_onto_nil() {
+    let mut interp = Interpreter::new();
+    interp.runtime_limits.max_materialized_elements = 8;
+    interp
+        .execute("'algo' IMPORT [ 1 2 3 ] [ 4 5 6 ] CARTESIAN")
+        .await
+        .unwrap();
+    let result = interp.stack.last().unwrap();
+    assert_eq!(result.nil_reason(), Some(&NilReason::SpaceExhausted));
+}
@@ -0,0 +1,62 @@
+//! MATH@LINSPACE: evenly spaced exact points between two endpoints.
+
+use crate::interpreter::Interpreter;
+
+async fn stack_after(code: &str) -> Vec<String> {
+    let mut interp = Interpreter::new();
+    interp
+        .execute(&format!("'math' IMPORT {}", code))
+        .await
+        .expect("should succeed");
+    interp.stack.iter().map(|v| v.to_string()).collect()
+}
+
+#[tokio::test]
+async fn test_linspace_quarters() {
+    assert_eq!(
+        stack_after("[ 0 ] [ 1 ] [ 5 ] LINSPACE").await,
+        vec!["[ 0/1 1/4 1/2 3/4 1/1 ]"]
+    );
+}
+
+#[tokio::test]
+async fn test_linspace_is_exact_for_thirds() {
+    // Accumulating 1/3 in floating point would miss the end; here the last
+    // point is the end itself.
+    assert_eq!(
+        stack_after("[ 0 ] [ 1 ] [ 4 ] LINSPACE").await,
+        vec!["[ 0/1 1/3 2/3 1/1 ]"]
+    );
+}
+
+#[tokio::test]
+async fn test_linspace_descending_with_fraction_endpoints() {
+    assert_eq!(
+        stack_after("[ 1/2 ] [ -1/2 ] [ 3 ] LINSPACE").await,
+        vec!["[ 1/2 0/1 -1/2 ]"]
+    );
+}
+
+#[tokio::test]
+async fn test_linspace_single_point_is_start() {
+    assert_eq!(
+        stack_after("[ 2 ] [ 7 ] [ 1 ] LINSPACE").await,
+        vec!["[ 2/1 ]"]
+    );
+}
+
+#[tokio::test]
+async fn test_linspace_rejects_bad_counts_and_restores() {
+    for code in [
+        "[ 0 ] [ 1 ] [ 0 ] LINSPACE",
+        "[ 0 ] [ 1 ] [ -2 ] LINSPACE",
+        "[ 0 ] [ 1 ] [ 3/2 ] LINSPACE",
+        "'a' [ 1 ] [ 3 ] LINSPACE",
+    ] {
+        let mut interp = Interpreter::new();
+        let result = interp.execute(&format!("'math' IMPORT {}", code)).await;
+        let err = result.expect_err(code).to_string();
+        assert!(err.starts_with("LINSPACE"), "{}", err);
+        assert_eq!(interp.stack.len(), 3, "arguments are restored");
+    }
+}
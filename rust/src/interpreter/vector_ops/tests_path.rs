@@ -0,0 +1,99 @@
+//! ALGO@PATHGET and ALGO@PATHSET: index paths into nested vectors.
+
+use crate::error::NilReason;
+use crate::interpreter::Interpreter;
+
+async fn stack_after(code: &str) -> Vec<String> {
+    let mut interp = Interpreter::new();
+    interp
+        .execute(&format!("'algo' IMPORT {}", code))
+        .await
+        .expect("should succeed");
+    interp.stack.iter().map(|v| v.to_string()).collect()
+}
+
+const NESTED: &str = "[ [ 1 2 ] [ [ 3 4 5 ] [ 6 ] ] ]";
+
+#[tokio::test]
+async fn test_pathget_follows_a_deep_path() {
+    assert_eq!(
+        stack_after(&format!("{} [ 1 0 2 ] PATHGET", NESTED)).await,
+        vec!["5/1"]
+    );
+    assert_eq!(
+        stack_after(&format!("{} [ 1 1 ] PATHGET", NESTED)).await,
+        vec!["[ 6/1 ]"]
+    );
+}
+
+#[tokio::test]
+async fn test_pathget_negative_steps_count_from_the_end() {
+    assert_eq!(
+        stack_after(&format!("{} [ -1 0 -1 ] PATHGET", NESTED)).await,
+        vec!["5/1"]
+    );
+}
+
+#[tokio::test]
+async fn test_pathget_running_off_the_end_is_nil() {
+    for path in ["[ 1 0 3 ]", "[ 5 ]", "[ 0 0 0 ]"] {
+        let mut interp = Interpreter::new();
+        interp
+            .execute(&format!("'algo' IMPORT {} {} PATHGET", NESTED, path))
+            .await
+            .unwrap();
+        assert_eq!(interp.stack.len(), 1, "path {}", path);
+        let result = interp.stack.last().unwrap();
+        assert!(result.is_nil(), "path {}", path);
+        assert_eq!(result.nil_reason(), Some(&NilReason::IndexOutOfBounds));
+    }
+}
+
+#[tokio::test]
+async fn test_pathget_rejects_malformed_paths_and_restores_operands() {
+    let mut interp = Interpreter::new();
+    let err = interp
+        .execute(&format!("'algo' IMPORT {} [ 1 1/2 ] PATHGET", NESTED))
+        .await
+        .unwrap_err();
+    assert_eq!(err.to_string(), "PATHGET path steps must be integers");
+    assert_eq!(interp.stack.len(), 2);
+}
+
+#[tokio::test]
+async fn test_pathset_replaces_one_element_and_keeps_siblings() {
+    assert_eq!(
+        stack_after("[ [ 1 2 ] [ 3 4 ] [ 5 6 ] ] [ 1 0 ] 9 PATHSET").await,
+        vec!["[ [ 1/1 2/1 ] [ 9/1 4/1 ] [ 5/1 6/1 ] ]"]
+    );
+    assert_eq!(
+        stack_after(&format!("{} [ 1 0 -1 ] [ 7 8 ] PATHSET", NESTED)).await,
+        vec!["[ [ 1/1 2/1 ] [ [ 3/1 4/1 [ 7/1 8/1 ] ] [ 6/1 ] ] ]"]
+    );
+}
+
+#[tokio::test]
+async fn test_pathset_leaves_the_original_untouched() {
+    let mut interp = Interpreter::new();
+    interp
+        .execute("'algo' IMPORT [ [ 1 2 ] [ 3 4 ] ] [ 0 1 ] 0 ,, PATHSET")
+        .await
+        .unwrap();
+    let stack: Vec<String> = interp.stack.iter().map(|v| v.to_string()).collect();
+    assert_eq!(stack.first().unwrap(), "[ [ 1/1 2/1 ] [ 3/1 4/1 ] ]");
+    assert_eq!(stack.last().unwrap(), "[ [ 1/1 0/1 ] [ 3/1 4/1 ] ]");
+}
+
+#[tokio::test]
+async fn test_pathset_out_of_range_step_names_the_index() {
+    let mut interp = Interpreter::new();
+    let err = interp
+        .execute("'algo' IMPORT [ [ 1 2 ] [ 3 4 ] ] [ 1 5 ] 0 PATHSET")
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "PATHSET: step 1 index 5 is out of range for length 2"
+    );
+    assert_eq!(interp.stack.len(), 3, "operands are restored");
+}
@@ -0,0 +1,90 @@
+//! ALGO position words built on `GET`'s index resolution: GETOR and
+//! SWAPAT.
+
+use crate::interpreter::Interpreter;
+
+async fn stack_after(code: &str) -> Vec<String> {
+    let mut interp = Interpreter::new();
+    interp
+        .execute(&format!("'algo' IMPORT {}", code))
+        .await
+        .expect("should succeed");
+    interp.stack.iter().map(|v| v.to_string()).collect()
+}
+
+#[tokio::test]
+async fn test_getor_in_range_returns_the_element() {
+    assert_eq!(
+        stack_after("[ 10 20 30 ] [ 1 ] 0 GETOR").await,
+        vec!["20/1"]
+    );
+    assert_eq!(
+        stack_after("[ 10 20 30 ] [ -1 ] 0 GETOR").await,
+        vec!["30/1"]
+    );
+}
+
+#[tokio::test]
+async fn test_getor_out_of_range_returns_the_default() {
+    assert_eq!(
+        stack_after("[ 10 20 30 ] [ 3 ] 'none' GETOR").await,
+        vec!["'none'"]
+    );
+    assert_eq!(
+        stack_after("[ 10 20 30 ] [ -4 ] [ 0 ] GETOR").await,
+        vec!["[ 0/1 ]"]
+    );
+    assert_eq!(stack_after("NIL [ 0 ] 7 GETOR").await, vec!["7/1"]);
+    assert_eq!(
+        stack_after("[ 1 2 3 ] [ 99999999999999999999999 ] [ 0 ] GETOR").await,
+        vec!["[ 0/1 ]"]
+    );
+    assert_eq!(
+        stack_after("[ 1 2 3 ] [ -99999999999999999999999 ] 0 GETOR").await,
+        vec!["0/1"]
+    );
+}
+
+#[tokio::test]
+async fn test_getor_non_integer_index_errors_and_restores() {
+    let mut interp = Interpreter::new();
+    assert!(interp
+        .execute("'algo' IMPORT [ 10 20 ] [ 1/2 ] 0 GETOR")
+        .await
+        .is_err());
+    assert_eq!(interp.stack.len(), 3);
+}
+
+#[tokio::test]
+async fn test_swapat_exchanges_two_elements() {
+    assert_eq!(
+        stack_after("[ 1 2 3 4 ] [ 0 ] [ 2 ] SWAPAT").await,
+        vec!["[ 3/1 2/1 1/1 4/1 ]"]
+    );
+    assert_eq!(
+        stack_after("[ 1 2 3 4 ] [ 1 ] [ -1 ] SWAPAT").await,
+        vec!["[ 1/1 4/1 3/1 2/1 ]"]
+    );
+    assert_eq!(
+        stack_after("[ 1 2 3 ] [ 1 ] [ 1 ] SWAPAT").await,
+        vec!["[ 1/1 2/1 3/1 ]"]
+    );
+}
+
+#[tokio::test]
+async fn test_swapat_out_of_range_errors_and_restores() {
+    let mut interp = Interpreter::new();
+    let err = interp
+        .execute("'algo' IMPORT [ 1 2 3 ] [ 0 ] [ 3 ] SWAPAT")
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        crate::error::AjisaiError::IndexOutOfBounds {
+            index: 3,
+            length: 3
+        }
+    ));
+    let stack: Vec<String> = interp.stack.iter().map(|v| v.to_string()).collect();
+    assert_eq!(stack, vec!["[ 1/1 2/1 3/1 ]", "[ 0/1 ]", "[ 3/1 ]"]);
+}
@@ -0,0 +1,65 @@
+//! ALGO@REPEAT-VALUE: a vector of n copies of a value.
+
+use crate::error::NilReason;
+use crate::interpreter::Interpreter;
+
+async fn stack_after(code: &str) -> Vec<String> {
+    let mut interp = Interpreter::new();
+    interp
+        .execute(&format!("'algo' IMPORT {}", code))
+        .await
+        .expect("should succeed");
+    interp.stack.iter().map(|v| v.to_string()).collect()
+}
+
+#[tokio::test]
+async fn test_repeat_value_scalar() {
+    assert_eq!(
+        stack_after("0 [ 4 ] REPEAT-VALUE").await,
+        vec!["[ 0/1 0/1 0/1 0/1 ]"]
+    );
+}
+
+#[tokio::test]
+async fn test_repeat_value_nested_vector() {
+    assert_eq!(
+        stack_after("[ 1 [ 2 ] ] 3 REPEAT-VALUE").await,
+        vec!["[ [ 1/1 [ 2/1 ] ] [ 1/1 [ 2/1 ] ] [ 1/1 [ 2/1 ] ] ]"]
+    );
+}
+
+#[tokio::test]
+async fn test_repeat_value_zero_count_is_the_empty_vector() {
+    let mut interp = Interpreter::new();
+    interp
+        .execute("'algo' IMPORT 7 [ 0 ] REPEAT-VALUE")
+        .await
+        .unwrap();
+    let result = interp.stack.last().unwrap();
+    assert!(result.is_nil());
+    assert_eq!(result.nil_reason(), Some(&NilReason::EmptySequence));
+}
+
+#[tokio::test]
+async fn test_repeat_value_negative_count_errors_and_restores() {
+    let mut interp = Interpreter::new();
+    let err = interp
+        .execute("'algo' IMPORT 7 [ -1 ] REPEAT-VALUE")
+        .await
+        .unwrap_err();
+    assert_eq!(err.to_string(), "REPEAT-VALUE count must not be negative");
+    assert_eq!(interp.stack.len(), 2);
+}
+
+#[tokio::test]
+async fn test_repeat_value_over_budget_projects_onto_nil() {
+    let mut interp = Interpreter::new();
+    interp.runtime_limits.max_materialized_elements = 8;
+    interp
+        .execute("'algo' IMPORT 7 [ 9 ] REPEAT-VALUE")
+        .await
+        .unwrap();
+    let result = interp.stack.last().unwrap();
+    assert!(result.is_nil());
+    assert_eq!(result.nil_reason(), Some(&NilReason::SpaceExhausted));
+}
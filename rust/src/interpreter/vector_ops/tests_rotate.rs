@@ -0,0 +1,52 @@
+//! ALGO@ROTATE: cyclic vector shifts.
+
+use crate::interpreter::Interpreter;
+
+async fn stack_after(code: &str) -> Vec<String> {
+    let mut interp = Interpreter::new();
+    interp
+        .execute(&format!("'algo' IMPORT {}", code))
+        .await
+        .expect("should succeed");
+    interp.stack.iter().map(|v| v.to_string()).collect()
+}
+
+#[tokio::test]
+async fn test_rotate_left() {
+    assert_eq!(
+        stack_after("[ 1 2 3 4 ] [ 1 ] ROTATE").await,
+        vec!["[ 2/1 3/1 4/1 1/1 ]"]
+    );
+}
+
+#[tokio::test]
+async fn test_rotate_negative_shift_rotates_right() {
+    assert_eq!(
+        stack_after("[ 1 2 3 4 ] [ -1 ] ROTATE").await,
+        vec!["[ 4/1 1/1 2/1 3/1 ]"]
+    );
+}
+
+#[tokio::test]
+async fn test_rotate_over_length_shift_wraps() {
+    assert_eq!(
+        stack_after("[ 1 2 3 4 ] [ 6 ] ROTATE").await,
+        vec!["[ 3/1 4/1 1/1 2/1 ]"]
+    );
+    assert_eq!(
+        stack_after("[ 1 2 3 4 ] [ -5 ] ROTATE").await,
+        vec!["[ 4/1 1/1 2/1 3/1 ]"]
+    );
+}
+
+#[tokio::test]
+async fn test_rotate_zero_or_full_length_shift_is_unchanged() {
+    for shift in ["0", "4", "-8"] {
+        assert_eq!(
+            stack_after(&format!("[ 1 2 3 4 ] [ {} ] ROTATE", shift)).await,
+            vec!["[ 1/1 2/1 3/1 4/1 ]"],
+            "shift {}",
+            shift
+        );
+    }
+}
@@ -0,0 +1,118 @@
+//! Stack-manipulation idioms built from the `..` (STAK) and `,,` (KEEP)
+//! modifiers. Ajisai has no Forth-style stack words (SKILL.md, "forbidden
+//! patterns"); these tests pin the modifier spellings that replace them.
+
+use crate::interpreter::Interpreter;
+
+async fn stack_after(code: &str) -> Vec<String> {
+    let mut interp = Interpreter::new();
+    interp.execute(code).await.expect("should succeed");
+    interp.stack.iter().map(|v| v.to_string()).collect()
+}
+
+#[tokio::test]
+async fn depth_is_stack_keep_length() {
+    let stack = stack_after("[ 'a' ] [ 'b' ] [ 'c' ] .. ,, LENGTH").await;
+    assert_eq!(stack, vec!["[ 'a' ]", "[ 'b' ]", "[ 'c' ]", "3/1"]);
+}
+
+#[tokio::test]
+async fn depth_of_empty_stack_is_zero() {
+    let stack = stack_after(".. ,, LENGTH").await;
+    assert_eq!(stack, vec!["0/1"]);
+}
+
+#[tokio::test]
+async fn depth_is_not_a_word() {
+    let mut interp = Interpreter::new();
+    assert!(interp.execute("[ 1 ] DEPTH").await.is_err());
+}
+
+#[tokio::test]
+async fn dup_and_over_are_stack_keep_get() {
+    let dup = stack_after("[ 1 ] [ 2 ] -1 .. ,, GET").await;
+    assert_eq!(dup, vec!["[ 1/1 ]", "[ 2/1 ]", "[ 2/1 ]"]);
+    let over = stack_after("[ 1 ] [ 2 ] -2 .. ,, GET").await;
+    assert_eq!(over, vec!["[ 1/1 ]", "[ 2/1 ]", "[ 1/1 ]"]);
+}
+
+#[tokio::test]
+async fn drop_is_stack_remove_of_the_top() {
+    let stack = stack_after("[ 1 ] [ 2 ] [ 3 ] -1 .. REMOVE").await;
+    assert_eq!(stack, vec!["[ 1/1 ]", "[ 2/1 ]"]);
+}
+
+#[tokio::test]
+async fn swap_and_rot_are_stack_reorder() {
+    let swap = stack_after("[ 1 ] [ 2 ] [ 3 ] [ 0 2 1 ] .. REORDER").await;
+    assert_eq!(swap, vec!["[ 1/1 ]", "[ 3/1 ]", "[ 2/1 ]"]);
+    let rot = stack_after("[ 1 ] [ 2 ] [ 3 ] [ 1 2 0 ] .. REORDER").await;
+    assert_eq!(rot, vec!["[ 2/1 ]", "[ 3/1 ]", "[ 1/1 ]"]);
+}
+
+#[tokio::test]
+async fn shufflers_are_not_words() {
+    for word in ["DUP", "DROP", "SWAP", "OVER", "ROT"] {
+        let mut interp = Interpreter::new();
+        let code = format!("[ 1 ] [ 2 ] [ 3 ] {}", word);
+        assert!(
+            interp.execute(&code).await.is_err(),
+            "{} must not resolve",
+            word
+        );
+    }
+}
+
+#[tokio::test]
+async fn pick_is_negative_stack_keep_get() {
+    let pick0 = stack_after("[ 1 ] [ 2 ] [ 3 ] -1 .. ,, GET").await;
+    assert_eq!(pick0, vec!["[ 1/1 ]", "[ 2/1 ]", "[ 3/1 ]", "[ 3/1 ]"]);
+    let pick2 = stack_after("[ 1 ] [ 2 ] [ 3 ] -3 .. ,, GET").await;
+    assert_eq!(pick2, vec!["[ 1/1 ]", "[ 2/1 ]", "[ 3/1 ]", "[ 1/1 ]"]);
+}
+
+#[tokio::test]
+async fn pick_past_the_bottom_is_out_of_bounds() {
+    let mut interp = Interpreter::new();
+    let result = interp.execute("[ 1 ] [ 2 ] [ 3 ] -4 .. ,, GET").await;
+    assert!(result.is_err());
+    assert_eq!(
+        interp.stack.len(),
+        4,
+        "the stack and the index are restored"
+    );
+}
+
+#[tokio::test]
+async fn roll_is_pick_then_stack_remove() {
+    let roll2 = stack_after("[ 1 ] [ 2 ] [ 3 ] -3 .. ,, GET -4 .. REMOVE").await;
+    assert_eq!(roll2, vec!["[ 2/1 ]", "[ 3/1 ]", "[ 1/1 ]"]);
+    let roll1 = stack_after("[ 1 ] [ 2 ] [ 3 ] -2 .. ,, GET -3 .. REMOVE").await;
+    assert_eq!(roll1, vec!["[ 1/1 ]", "[ 3/1 ]", "[ 2/1 ]"]);
+    let roll0 = stack_after("[ 1 ] [ 2 ] [ 3 ] -1 .. ,, GET -2 .. REMOVE").await;
+    assert_eq!(roll0, vec!["[ 1/1 ]", "[ 2/1 ]", "[ 3/1 ]"]);
+}
+
+#[tokio::test]
+async fn roll_past_the_bottom_leaves_the_stack_intact() {
+    let mut interp = Interpreter::new();
+    let result = interp
+        .execute("[ 1 ] [ 2 ] [ 3 ] -4 .. ,, GET -5 .. REMOVE")
+        .await;
+    assert!(result.is_err());
+    let stack: Vec<String> = interp.stack.iter().map(|v| v.to_string()).collect();
+    assert_eq!(stack, vec!["[ 1/1 ]", "[ 2/1 ]", "[ 3/1 ]", "-4/1"]);
+}
+
+#[tokio::test]
+async fn clear_is_stack_take_zero_and_keeps_user_words() {
+    let mut interp = Interpreter::new();
+    interp
+        .execute("{ [ 2 ] * } 'DOUBLE' DEF [ 1 ] [ 2 ] [ 3 ] 0 .. TAKE")
+        .await
+        .expect("should succeed");
+    assert!(interp.stack.is_empty());
+
+    interp.execute("[ 21 ] DOUBLE").await.expect("DOUBLE survives");
+    assert_eq!(interp.stack[0].to_string(), "[ 42/1 ]");
+}
@@ -228,19 +228,36 @@ impl Interpreter {
     }
 
     /// Reclaim content-store bodies no longer referenced by any definition.
-    /// An entry whose only remaining strong reference is the store itself
-    /// (`strong_count == 1`) is orphaned — deleted or replaced by a redefine —
-    /// and is dropped. A body still shared by one or more live definitions has
-    /// a higher count and is kept. Run only at quiescent points (after a
-    /// definition, deletion, or dependency rebuild), where no transient body
-    /// clones are in flight; deferred during bulk operations like the rest of
-    /// the content-store maintenance.
+    /// An entry that no live user word points to — deleted or replaced by a
+    /// redefine — is orphaned and dropped; a body still shared by one or more
+    /// definitions is kept. Liveness is decided by pointer identity against
+    /// the current dictionary rather than by reference count, because undo
+    /// points (`undo_history`) keep replaced definitions alive. Run after a
+    /// definition, deletion, or dependency rebuild; deferred during bulk
+    /// operations like the rest of the content-store maintenance.
     pub(crate) fn gc_body_store(&mut self) {
         if self.defer_identity_recompute {
             return;
         }
+        let live: HashSet<*const crate::types::ExecutionLine> = self
+            .user_dictionaries
+            .values()
+            .flat_map(|dict| dict.words.values())
+            .map(|def| Arc::as_ptr(&def.lines) as *const crate::types::ExecutionLine)
+            .collect();
         self.body_store
-            .retain(|_, body| std::sync::Arc::strong_count(body) > 1);
+            .retain(|_, body| live.contains(&(Arc::as_ptr(body) as *const _)));
+    }
+
+    /// Rebuild the content store from the current user words, after the
+    /// dictionary was replaced wholesale (undo / redo).
+    pub(crate) fn rebuild_body_store(&mut self) {
+        self.body_store = self
+            .user_dictionaries
+            .values()
+            .flat_map(|dict| dict.words.values())
+            .map(|def| (body_content_key(&def.lines), def.lines.clone()))
+            .collect();
     }
 
     fn build_word_shape(&self, def: &WordDefinition, user_set: &HashSet<String>) -> Vec<Atom> {
@@ -0,0 +1,75 @@
+//! Opt-in, per-interpreter execution trace for debugging definitions.
+//!
+//! While enabled, every word executed through `execute_word_core` appends one
+//! line to the trace in call order, under the name the call resolved to
+//! (`+` is `ADD`; a user word called from a definition is qualified, as in
+//! `EXAMPLE@INC`). The line shows the word and the stack depth before and
+//! after it: `ADD 2 -> 1`. Built-ins that a compiled definition dispatches
+//! directly do not pass through `execute_word_core` and are not listed.
+//! This is separate from the process-wide timing profiler in
+//! `elastic::tracer` (`AJISAI_TRACE`), which aggregates counts and durations
+//! rather than keeping an ordered log.
+//!
+//! Like the receipt recorder, the trace is observational: enabling it never
+//! changes values, effects or errors.
+
+use super::Interpreter;
+
+/// Records the word trace while enabled. Cleared by a session reset; the flag
+/// persists, as for the receipt recorder.
+#[derive(Debug, Default)]
+pub struct WordTrace {
+    trace_enabled: bool,
+    lines: Vec<String>,
+}
+
+impl WordTrace {
+    pub fn is_enabled(&self) -> bool {
+        self.trace_enabled
+    }
+
+    /// Reserve the line for a word about to run, so a user word's line comes
+    /// before the lines of the words its body executes.
+    fn begin(&mut self, word: &str) -> usize {
+        self.lines.push(word.to_string());
+        self.lines.len() - 1
+    }
+
+    fn finish(&mut self, slot: usize, depth_before: usize, depth_after: usize) {
+        if let Some(line) = self.lines.get_mut(slot) {
+            line.push_str(&format!(" {} -> {}", depth_before, depth_after));
+        }
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.lines.clear();
+    }
+}
+
+impl Interpreter {
+    /// Enable or disable the ordered word trace. Disabling keeps the lines
+    /// recorded so far until they are taken.
+    pub fn set_word_trace(&mut self, enabled: bool) {
+        self.word_trace.trace_enabled = enabled;
+    }
+
+    /// The trace lines recorded so far, oldest first.
+    pub fn word_trace_lines(&self) -> &[String] {
+        &self.word_trace.lines
+    }
+
+    /// Return and clear the recorded trace lines.
+    pub fn take_word_trace(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.word_trace.lines)
+    }
+
+    /// `execute_word_core` with a trace line recorded around the call.
+    pub(crate) fn execute_word_recorded(&mut self, name: &str) -> crate::error::Result<()> {
+        let depth_before = self.stack.len();
+        let slot = self.word_trace.begin(name);
+        let result = self.execute_word_memoized(name);
+        let depth_after = self.stack.len();
+        self.word_trace.finish(slot, depth_before, depth_after);
+        result
+    }
+}
@@ -0,0 +1,43 @@
+//! Test suite for `crate::interpreter::word_trace` (ordered word trace).
+
+#[cfg(test)]
+mod tests {
+    use crate::interpreter::Interpreter;
+
+    #[tokio::test]
+    async fn trace_lists_executed_words_in_order_with_depths() {
+        let mut interp = Interpreter::new();
+        interp.set_word_trace(true);
+        interp
+            .execute("[ 1 ] [ 2 ] + [ 3 ] * ,, LENGTH")
+            .await
+            .unwrap();
+        assert_eq!(
+            interp.take_word_trace(),
+            ["ADD 2 -> 1", "MUL 2 -> 1", "LENGTH 1 -> 2"]
+        );
+        assert!(interp.word_trace_lines().is_empty(), "taking clears");
+    }
+
+    #[tokio::test]
+    async fn user_word_line_precedes_its_body() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute("{ [ 1 ] + } 'INC' DEF { INC INC } 'TWICE' DEF")
+            .await
+            .unwrap();
+        interp.set_word_trace(true);
+        interp.execute("[ 5 ] TWICE").await.unwrap();
+        assert_eq!(
+            interp.take_word_trace(),
+            ["TWICE 1 -> 1", "EXAMPLE@INC 1 -> 1", "EXAMPLE@INC 1 -> 1"]
+        );
+    }
+
+    #[tokio::test]
+    async fn trace_is_off_by_default() {
+        let mut interp = Interpreter::new();
+        interp.execute("[ 1 ] [ 2 ] +").await.unwrap();
+        assert!(interp.word_trace_lines().is_empty());
+    }
+}
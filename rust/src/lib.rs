@@ -27,6 +27,8 @@ pub mod interpreter;
 pub mod semantic;
 pub mod surface_forms;
 mod tokenizer;
+mod tokenizer_number;
+mod tokenizer_scan;
 pub mod types;
 
 // Headless agent-facing CLI (the `ajisai` bin target). Native-only: it is
@@ -49,6 +51,12 @@ mod tokenizer_regression_tests_2;
 #[cfg(test)]
 mod tokenizer_mcdc_tests;
 
+#[cfg(test)]
+mod tokenizer_number_tests;
+
+#[cfg(test)]
+mod tokenizer_string_tests;
+
 #[cfg(test)]
 mod arithmetic_operation_tests;
 
@@ -236,6 +236,34 @@ mod runtime_limits_tests {
         );
     }
 
+    // ── host step limit (REPL interrupt guard) ─────────────────────────────
+
+    #[tokio::test]
+    async fn step_limit_stops_infinite_recursion_at_the_limit() {
+        let mut interp = Interpreter::new();
+        interp.set_step_limit(100);
+        interp.execute("{ REC } 'REC' DEF").await.unwrap();
+        let err = interp
+            .execute("REC")
+            .await
+            .expect_err("an infinitely recursive word must stop at the limit");
+        assert!(
+            matches!(
+                err,
+                crate::error::AjisaiError::ExecutionLimitExceeded { limit: 100 }
+            ),
+            "expected the step limit, not a stack overflow, got: {err:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn step_limit_zero_means_unlimited() {
+        let mut interp = Interpreter::new();
+        interp.set_step_limit(0);
+        assert_eq!(interp.max_execution_steps(), usize::MAX);
+        assert!(interp.execute("[ 1 ] [ 2 ] +").await.is_ok());
+    }
+
     // ── ordinary work is untouched under default limits ────────────────────
 
     #[tokio::test]
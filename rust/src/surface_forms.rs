@@ -106,6 +106,13 @@ pub const SURFACE_FORMS: &[SurfaceForm] = &[
         runtime_word: false,
         summary: "String literal delimiter (serves as both open and close)",
     },
+    SurfaceForm {
+        surface: "`",
+        concept: "TEMPLATE-QUOTE",
+        kind: SurfaceFormKind::LiteralSugar,
+        runtime_word: false,
+        summary: "Verbatim string delimiter; the literal may span lines",
+    },
     SurfaceForm {
         surface: ";",
         concept: "TOP-EAT",
@@ -192,6 +199,7 @@ mod tests {
         assert_eq!(lookup_surface_form("{").unwrap().concept, "BEGIN-BLOCK");
         assert_eq!(lookup_surface_form("}").unwrap().concept, "END-BLOCK");
         assert_eq!(lookup_surface_form("'").unwrap().concept, "STRING-QUOTE");
+        assert_eq!(lookup_surface_form("`").unwrap().concept, "TEMPLATE-QUOTE");
         assert_eq!(lookup_surface_form(";").unwrap().concept, "TOP-EAT");
         assert_eq!(lookup_surface_form(";;").unwrap().concept, "STAK-KEEP");
         assert_eq!(lookup_surface_form("(").unwrap().concept, "RESERVED-BEGIN");
@@ -92,6 +92,32 @@ mod tensor_ops_integration_tests {
         assert_eq!(result, "[ [ 1/1 2/1 ] [ 3/1 4/1 ] [ 5/1 6/1 ] ]");
     }
 
+    #[tokio::test]
+    async fn test_reshape_infers_minus_one_dimension() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute("[ 1 2 3 4 5 6 ] [ 2 -1 ] RESHAPE SHAPE")
+            .await
+            .unwrap();
+        let stack = interp.get_stack();
+        let result = format!("{}", stack[stack.len() - 1]);
+        assert_eq!(result, "[ 2/1 3/1 ]");
+    }
+
+    #[tokio::test]
+    async fn test_reshape_minus_one_rejects_uneven_count() {
+        let mut interp = Interpreter::new();
+        let result = interp.execute("[ 1 2 3 4 5 6 ] [ 4 -1 ] RESHAPE").await;
+        assert!(result.unwrap_err().to_string().contains("not divisible"));
+    }
+
+    #[tokio::test]
+    async fn test_reshape_rejects_two_minus_one_dimensions() {
+        let mut interp = Interpreter::new();
+        let result = interp.execute("[ 1 2 3 4 5 6 ] [ -1 -1 ] RESHAPE").await;
+        assert!(result.unwrap_err().to_string().contains("only one"));
+    }
+
     #[tokio::test]
     async fn test_transpose_basic() {
         let mut interp = Interpreter::new();
@@ -106,6 +132,26 @@ mod tensor_ops_integration_tests {
         assert_eq!(result, "[ [ 1/1 4/1 ] [ 2/1 5/1 ] [ 3/1 6/1 ] ]");
     }
 
+    #[tokio::test]
+    async fn test_transpose_ragged_pads_short_rows_with_nil() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute("[ [ 1 2 3 ] [ 4 ] ] TRANSPOSE")
+            .await
+            .unwrap();
+        let stack = interp.get_stack();
+        assert_eq!(stack.len(), 1);
+        let result = format!("{}", stack[0]);
+        assert_eq!(result, "[ [ 1/1 4/1 ] [ 2/1 NIL ] [ 3/1 NIL ] ]");
+    }
+
+    #[tokio::test]
+    async fn test_transpose_rejects_rows_that_are_not_vectors() {
+        let mut interp = Interpreter::new();
+        let result = interp.execute("[ [ 1 2 ] 3 ] TRANSPOSE").await;
+        assert!(result.is_err());
+    }
+
     #[tokio::test]
     async fn test_fill_basic() {
         let mut interp = Interpreter::new();
@@ -224,6 +270,68 @@ mod tensor_ops_integration_tests {
             "TRANSPOSE of NIL should return NIL (Form type: NIL = empty set)"
         );
     }
+
+    #[tokio::test]
+    async fn tmap_rank1_applies_word_to_each_scalar() {
+        let mut interp = Interpreter::new();
+        interp.execute("[ 1 2 3 ] { 2 * } TMAP").await.unwrap();
+        let stack = interp.get_stack();
+        assert_eq!(stack.len(), 1);
+        assert_eq!(format!("{}", stack[0]), "[ 2/1 4/1 6/1 ]");
+    }
+
+    #[tokio::test]
+    async fn tmap_rank2_named_word_preserves_shape() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute("'math' IMPORT { 2 POW } 'SQUARE' DEF")
+            .await
+            .unwrap();
+        interp
+            .execute("[ [ 1 2 ] [ 3 4 ] ] 'SQUARE' TMAP")
+            .await
+            .unwrap();
+        let stack = interp.get_stack();
+        assert_eq!(stack.len(), 1);
+        assert_eq!(format!("{}", stack[0]), "[ [ 1/1 4/1 ] [ 9/1 16/1 ] ]");
+    }
+
+    #[tokio::test]
+    async fn tmap_rank3_preserves_shape() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute("[ [ [ 1 2 ] [ 3 4 ] ] [ [ 5 6 ] [ 7 8 ] ] ] { 1 - } TMAP")
+            .await
+            .unwrap();
+        let stack = interp.get_stack();
+        assert_eq!(stack.len(), 1);
+        assert_eq!(
+            format!("{}", stack[0]),
+            "[ [ [ 0/1 1/1 ] [ 2/1 3/1 ] ] [ [ 4/1 5/1 ] [ 6/1 7/1 ] ] ]"
+        );
+    }
+
+    #[tokio::test]
+    async fn tmap_word_must_leave_exactly_one_scalar() {
+        let mut interp = Interpreter::new();
+        assert!(interp.execute("[ 1 2 ] { 1 2 } TMAP").await.is_err());
+        assert_eq!(interp.get_stack().len(), 2, "operands are restored on error");
+        let mut interp = Interpreter::new();
+        assert!(interp.execute("[ 1 2 ] { [ 2 ] * } TMAP").await.is_err());
+        assert_eq!(interp.get_stack().len(), 2, "operands are restored on error");
+    }
+
+    #[tokio::test]
+    async fn tmap_rejects_ragged_vectors() {
+        let mut interp = Interpreter::new();
+        assert!(interp
+            .execute("[ [ 1 2 ] [ 3 ] ] { 1 + } TMAP")
+            .await
+            .is_err());
+        let stack = interp.get_stack();
+        assert_eq!(stack.len(), 2);
+        assert_eq!(format!("{}", stack[0]), "[ [ 1/1 2/1 ] [ 3/1 ] ]");
+    }
 }
 
 #[cfg(test)]
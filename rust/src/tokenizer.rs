@@ -1,3 +1,4 @@
+use crate::tokenizer_number::parse_number_from_string;
 use crate::types::Token;
 
 pub fn tokenize(input: &str) -> Result<Vec<Token>, String> {
@@ -122,6 +123,12 @@ pub fn tokenize(input: &str) -> Result<Vec<Token>, String> {
                 let quote_char = chars[i];
                 return Err(format!("Unclosed literal starting with {}", quote_char));
             }
+            QuoteParseResult::InvalidEscape(c) => {
+                return Err(format!(
+                    "Invalid escape sequence \\{} in string literal (use \\n, \\t, \\\\, or \\')",
+                    c
+                ));
+            }
             QuoteParseResult::NotQuote => {}
         }
 
@@ -153,7 +160,7 @@ pub fn tokenize(input: &str) -> Result<Vec<Token>, String> {
             continue;
         }
 
-        if let Some(token) = parse_number_from_string(&token_str) {
+        if let Some(token) = parse_number_from_string(&token_str)? {
             tokens.push(token);
             continue;
         }
@@ -170,7 +177,7 @@ pub fn tokenize(input: &str) -> Result<Vec<Token>, String> {
     Ok(tokens)
 }
 
-fn is_special_char(c: char) -> bool {
+pub(crate) fn is_special_char(c: char) -> bool {
     matches!(
         c,
         '[' | ']' | '{' | '}' | '(' | ')' | '#' | '\'' | '>' | '=' | '|' | '~' | '^'
@@ -201,48 +208,11 @@ fn parse_token_from_single_char(c: char) -> Option<(Token, usize)> {
 
 fn check_bracket_matching(input: &str) -> Result<(), String> {
     let mut stack: Vec<char> = Vec::new();
-    let mut in_string = false;
-    let mut in_comment = false;
-    let chars: Vec<char> = input.chars().collect();
-    let mut i: usize = 0;
-
-    while i < chars.len() {
-        let c: char = chars[i];
-
-        if c == '\n' {
-            in_comment = false;
-            i += 1;
-            continue;
-        }
-
-        if in_comment {
-            i += 1;
-            continue;
-        }
-
-        if c == '#' {
-            in_comment = true;
-            i += 1;
-            continue;
-        }
-
-        if c == '\'' {
-            if in_string {
-                if i + 1 >= chars.len() || is_string_close_delimiter(chars[i + 1]) {
-                    in_string = false;
-                }
-            } else {
-                in_string = true;
-            }
-            i += 1;
-            continue;
-        }
 
-        if in_string {
-            i += 1;
+    for (c, is_code) in crate::tokenizer_scan::scan_code(input) {
+        if !is_code {
             continue;
         }
-
         match c {
             '[' | '{' => stack.push(c),
             ']' => match stack.pop() {
@@ -273,7 +243,6 @@ fn check_bracket_matching(input: &str) -> Result<(), String> {
             },
             _ => {}
         }
-        i += 1;
     }
 
     if let Some(open) = stack.last() {
@@ -350,11 +319,14 @@ enum QuoteParseResult {
 
     Unclosed,
 
+    InvalidEscape(char),
+
     NotQuote,
 }
 
 // LiteralSugar: `'` -> STRING-QUOTE (see surface_forms.rs). A single quote
 // serves as both the opening and closing string delimiter; not a runtime word.
+// A backtick opens a template string, which runs to the next backtick.
 fn parse_string_from_quote(chars: &[char]) -> QuoteParseResult {
     if chars.is_empty() {
         return QuoteParseResult::NotQuote;
@@ -364,10 +336,25 @@ fn parse_string_from_quote(chars: &[char]) -> QuoteParseResult {
 
     match quote_char {
         '\'' => parse_token_from_string_literal(chars),
+        '`' => parse_token_from_template_literal(chars),
         _ => QuoteParseResult::NotQuote,
     }
 }
 
+/// A backtick string is taken verbatim up to the closing backtick: line
+/// breaks stay in the content, and there are no escapes, so the content
+/// cannot itself contain a backtick. The whole literal is one
+/// `Token::String`, so its line breaks never split the program into lines.
+fn parse_token_from_template_literal(chars: &[char]) -> QuoteParseResult {
+    match chars[1..].iter().position(|&c| c == '`') {
+        Some(end) => {
+            let content: String = chars[1..end + 1].iter().collect();
+            QuoteParseResult::StringSuccess(Token::String(content.into()), end + 2)
+        }
+        None => QuoteParseResult::Unclosed,
+    }
+}
+
 fn parse_token_from_string_literal(chars: &[char]) -> QuoteParseResult {
     if chars.is_empty() || chars[0] != '\'' {
         return QuoteParseResult::NotQuote;
@@ -384,6 +371,19 @@ fn parse_token_from_string_literal(chars: &[char]) -> QuoteParseResult {
                 string.push(chars[i]);
                 i += 1;
             }
+        } else if chars[i] == '\\' {
+            // Escapes: `\n` `\t` `\\` `\'`. Any other escaped character is
+            // almost certainly a typo, so it is rejected rather than kept.
+            let Some(&escaped) = chars.get(i + 1) else {
+                return QuoteParseResult::Unclosed;
+            };
+            match escaped {
+                'n' => string.push('\n'),
+                't' => string.push('\t'),
+                '\\' | '\'' => string.push(escaped),
+                other => return QuoteParseResult::InvalidEscape(other),
+            }
+            i += 2;
         } else {
             string.push(chars[i]);
             i += 1;
@@ -393,7 +393,25 @@ fn parse_token_from_string_literal(chars: &[char]) -> QuoteParseResult {
     QuoteParseResult::Unclosed
 }
 
-fn is_string_close_delimiter(c: char) -> bool {
+/// Render string content as a `'...'` literal that `tokenize` reads back to
+/// the same content. Used wherever tokens are turned back into source.
+pub(crate) fn quote_string_literal(content: &str) -> String {
+    let mut out = String::with_capacity(content.len() + 2);
+    out.push('\'');
+    for c in content.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\'' => out.push_str("\\'"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            _ => out.push(c),
+        }
+    }
+    out.push('\'');
+    out
+}
+
+pub(crate) fn is_string_close_delimiter(c: char) -> bool {
     c.is_whitespace() || (is_special_char(c) && c != '\'')
 }
 
@@ -455,93 +473,3 @@ fn split_compound_modifier(s: &str) -> Option<Vec<String>> {
         None
     }
 }
-
-fn parse_number_from_string(s: &str) -> Option<Token> {
-    if s.is_empty() {
-        return None;
-    }
-
-    let chars: Vec<char> = s.chars().collect();
-    let mut i = 0;
-
-    if chars[i] == '-' || chars[i] == '+' {
-        if chars.len() == 1 {
-            return None;
-        }
-        // The sign must be followed by a digit or a leading-dot decimal
-        // (`-.5`, `+.5`); otherwise it is a word symbol, not a number.
-        let next_is_digit = chars[i + 1].is_ascii_digit();
-        let next_is_dot_digit =
-            chars[i + 1] == '.' && i + 2 < chars.len() && chars[i + 2].is_ascii_digit();
-        if !next_is_digit && !next_is_dot_digit {
-            return None;
-        }
-        i += 1;
-    }
-
-    // A leading-dot decimal (`.5`, `-.5`) has an empty integer part: the dot
-    // must be followed by at least one digit (SPEC §3.2). Bare `.` / `..` are
-    // modifier sugar already handled before this function is reached.
-    let has_leading_dot_digits =
-        i < chars.len() && chars[i] == '.' && i + 1 < chars.len() && chars[i + 1].is_ascii_digit();
-
-    if !has_leading_dot_digits && (i >= chars.len() || !chars[i].is_ascii_digit()) {
-        return None;
-    }
-
-    let start = i;
-
-    while i < chars.len() && chars[i].is_ascii_digit() {
-        i += 1;
-    }
-
-    if i < chars.len() && chars[i] == '/' {
-        let _slash_pos = i;
-        i += 1;
-
-        if i >= chars.len() || !chars[i].is_ascii_digit() {
-            return None;
-        }
-        while i < chars.len() && chars[i].is_ascii_digit() {
-            i += 1;
-        }
-
-        if i == chars.len() {
-            return Some(Token::Number(s.into()));
-        } else {
-            return None;
-        }
-    }
-
-    let mut has_dot = false;
-    if i < chars.len() && chars[i] == '.' {
-        has_dot = true;
-        i += 1;
-        while i < chars.len() && chars[i].is_ascii_digit() {
-            i += 1;
-        }
-    }
-
-    if i < chars.len() && (chars[i] == 'e' || chars[i] == 'E') {
-        i += 1;
-        if i < chars.len() && (chars[i] == '-' || chars[i] == '+') {
-            i += 1;
-        }
-        if i >= chars.len() || !chars[i].is_ascii_digit() {
-            return None;
-        }
-        while i < chars.len() && chars[i].is_ascii_digit() {
-            i += 1;
-        }
-    }
-
-    if i == start && !has_dot {
-        return None;
-    }
-
-    if i == chars.len() {
-        Some(Token::Number(s.into()))
-    } else {
-        None
-    }
-}
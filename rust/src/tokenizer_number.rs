@@ -0,0 +1,154 @@
+//! Number-literal recognition for `crate::tokenizer`.
+//!
+//! Decimal literals (integers, `a/b` fractions, decimals, and `e` exponents)
+//! are passed through as written; `Fraction::from_str` turns them into exact
+//! rationals later. Hex (`0xFF`) and binary (`0b1010`) literals are rewritten
+//! here to their decimal digits, so everything downstream sees one form.
+
+use crate::types::Token;
+use num_bigint::BigInt;
+
+/// Recognize a number literal. `Ok(None)` means the token is not a number
+/// (it becomes a symbol); `Err` means it starts out as a number but is
+/// malformed — invalid radix digits or an exponent without digits.
+pub(crate) fn parse_number_from_string(s: &str) -> Result<Option<Token>, String> {
+    if let Some(token) = parse_radix_number(s) {
+        return token.map(Some);
+    }
+    if let Some(token) = parse_decimal_number(s) {
+        return Ok(Some(token));
+    }
+    if has_incomplete_exponent(s) {
+        return Err(format!(
+            "Invalid number literal: {} (the exponent needs at least one digit)",
+            s
+        ));
+    }
+    Ok(None)
+}
+
+/// A valid mantissa followed by `e`/`E` and at most a sign (`1e`, `2.5E-`).
+/// Such a token is a broken scientific literal, not a word name.
+fn has_incomplete_exponent(s: &str) -> bool {
+    let Some(e_pos) = s.find(['e', 'E']) else {
+        return false;
+    };
+    let (mantissa, exponent) = (&s[..e_pos], &s[e_pos + 1..]);
+    matches!(exponent, "" | "+" | "-") && parse_decimal_number(mantissa).is_some()
+}
+
+/// `[+-]0x<hex>` / `[+-]0b<bin>` (prefix letter in either case) to a decimal
+/// `Token::Number`. `None` if `s` has no radix prefix.
+fn parse_radix_number(s: &str) -> Option<Result<Token, String>> {
+    let (negative, unsigned) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let radix = match unsigned.get(..2) {
+        Some("0x") | Some("0X") => 16,
+        Some("0b") | Some("0B") => 2,
+        _ => return None,
+    };
+    let digits = &unsigned[2..];
+    let Some(mut value) = BigInt::parse_bytes(digits.as_bytes(), radix)
+        .filter(|_| !digits.is_empty() && digits.chars().all(|c| c.is_digit(radix)))
+    else {
+        let kind = if radix == 16 { "hexadecimal" } else { "binary" };
+        return Some(Err(format!("Invalid {} literal: {}", kind, s)));
+    };
+    if negative {
+        value = -value;
+    }
+    Some(Ok(Token::Number(value.to_string().into())))
+}
+
+fn parse_decimal_number(s: &str) -> Option<Token> {
+    if s.is_empty() {
+        return None;
+    }
+
+    let chars: Vec<char> = s.chars().collect();
+    let mut i = 0;
+
+    if chars[i] == '-' || chars[i] == '+' {
+        if chars.len() == 1 {
+            return None;
+        }
+        // The sign must be followed by a digit or a leading-dot decimal
+        // (`-.5`, `+.5`); otherwise it is a word symbol, not a number.
+        let next_is_digit = chars[i + 1].is_ascii_digit();
+        let next_is_dot_digit =
+            chars[i + 1] == '.' && i + 2 < chars.len() && chars[i + 2].is_ascii_digit();
+        if !next_is_digit && !next_is_dot_digit {
+            return None;
+        }
+        i += 1;
+    }
+
+    // A leading-dot decimal (`.5`, `-.5`) has an empty integer part: the dot
+    // must be followed by at least one digit (SPEC §3.2). Bare `.` / `..` are
+    // modifier sugar already handled before this function is reached.
+    let has_leading_dot_digits =
+        i < chars.len() && chars[i] == '.' && i + 1 < chars.len() && chars[i + 1].is_ascii_digit();
+
+    if !has_leading_dot_digits && (i >= chars.len() || !chars[i].is_ascii_digit()) {
+        return None;
+    }
+
+    let start = i;
+
+    while i < chars.len() && chars[i].is_ascii_digit() {
+        i += 1;
+    }
+
+    if i < chars.len() && chars[i] == '/' {
+        let _slash_pos = i;
+        i += 1;
+
+        if i >= chars.len() || !chars[i].is_ascii_digit() {
+            return None;
+        }
+        while i < chars.len() && chars[i].is_ascii_digit() {
+            i += 1;
+        }
+
+        if i == chars.len() {
+            return Some(Token::Number(s.into()));
+        } else {
+            return None;
+        }
+    }
+
+    let mut has_dot = false;
+    if i < chars.len() && chars[i] == '.' {
+        has_dot = true;
+        i += 1;
+        while i < chars.len() && chars[i].is_ascii_digit() {
+            i += 1;
+        }
+    }
+
+    if i < chars.len() && (chars[i] == 'e' || chars[i] == 'E') {
+        i += 1;
+        if i < chars.len() && (chars[i] == '-' || chars[i] == '+') {
+            i += 1;
+        }
+        if i >= chars.len() || !chars[i].is_ascii_digit() {
+            return None;
+        }
+        while i < chars.len() && chars[i].is_ascii_digit() {
+            i += 1;
+        }
+    }
+
+    if i == start && !has_dot {
+        return None;
+    }
+
+    if i == chars.len() {
+        Some(Token::Number(s.into()))
+    } else {
+        None
+    }
+}
@@ -0,0 +1,97 @@
+//! Test suite for `crate::tokenizer_number`: radix and scientific number
+//! literals.
+
+#[cfg(test)]
+mod tokenizer_number_tests {
+    use crate::interpreter::Interpreter;
+    use crate::tokenizer::tokenize;
+    use crate::types::Token;
+
+    fn number(s: &str) -> Token {
+        Token::Number(s.into())
+    }
+
+    #[test]
+    fn hex_literal_tokenizes_to_its_decimal_value() {
+        assert_eq!(tokenize("0xFF").unwrap(), vec![number("255")]);
+        assert_eq!(
+            tokenize("0xff 0X10").unwrap(),
+            vec![number("255"), number("16")]
+        );
+    }
+
+    #[test]
+    fn binary_literal_tokenizes_to_its_decimal_value() {
+        assert_eq!(tokenize("0b1010").unwrap(), vec![number("10")]);
+        assert_eq!(tokenize("-0B11").unwrap(), vec![number("-3")]);
+    }
+
+    #[test]
+    fn radix_literals_have_arbitrary_width() {
+        assert_eq!(
+            tokenize("0x10000000000000000000000000000000").unwrap(),
+            vec![number("21267647932558653966460912964485513216")]
+        );
+    }
+
+    #[test]
+    fn invalid_digits_after_the_prefix_are_rejected() {
+        for bad in ["0xG1", "0b102", "0x", "0b", "0x1.5"] {
+            let err = tokenize(bad).unwrap_err();
+            assert!(err.contains("literal"), "{}: {}", bad, err);
+        }
+    }
+
+    #[tokio::test]
+    async fn radix_literals_are_exact_integers_at_runtime() {
+        let mut interp = Interpreter::new();
+        interp.execute("0xFF 0b1010 +").await.unwrap();
+        let top = interp.stack.last().and_then(|v| v.as_scalar()).cloned();
+        assert_eq!(top.and_then(|f| f.to_i64()), Some(265));
+    }
+
+    async fn top_fraction(code: &str) -> (i64, i64) {
+        let mut interp = Interpreter::new();
+        interp.execute(code).await.unwrap();
+        let f = interp
+            .stack
+            .last()
+            .and_then(|v| v.as_scalar())
+            .cloned()
+            .unwrap();
+        (
+            f.numerator().to_string().parse().unwrap(),
+            f.denominator().to_string().parse().unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn scientific_literals_are_exact_fractions() {
+        assert_eq!(top_fraction("1.5e3").await, (1500, 1));
+        assert_eq!(top_fraction("1E+2").await, (100, 1));
+        assert_eq!(top_fraction("2e-2").await, (1, 50));
+        assert_eq!(top_fraction("-1.25e-1").await, (-1, 8));
+        // 1/10 has no exact binary float; the literal must not pass through one.
+        assert_eq!(top_fraction("1e-1 3 *").await, (3, 10));
+    }
+
+    #[test]
+    fn exponent_without_digits_is_rejected() {
+        for bad in ["1e", "1E", "2.5e-", "3e+", "-1e"] {
+            let err = tokenize(bad).unwrap_err();
+            assert!(err.contains("exponent"), "{}: {}", bad, err);
+        }
+    }
+
+    #[test]
+    fn words_that_merely_contain_e_stay_symbols() {
+        assert_eq!(
+            tokenize("e E1 END").unwrap(),
+            vec![
+                Token::Symbol("e".into()),
+                Token::Symbol("E1".into()),
+                Token::Symbol("END".into())
+            ]
+        );
+    }
+}
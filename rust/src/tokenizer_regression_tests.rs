@@ -59,6 +59,46 @@ mod tokenizer_regression_tests {
         );
     }
 
+    #[test]
+    fn test_trailing_comment_inside_definition_body() {
+        let result = tokenize("{ [ 2 ] * # double\n} 'D' DEF").unwrap();
+        assert_eq!(
+            result,
+            vec![
+                Token::BlockStart,
+                Token::VectorStart,
+                Token::Number("2".into()),
+                Token::VectorEnd,
+                Token::Symbol("*".into()),
+                Token::LineBreak,
+                Token::BlockEnd,
+                Token::String("D".into()),
+                Token::Symbol("DEF".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_full_line_comment_inside_definition_body() {
+        // A comment-only line leaves no empty line (no doubled LineBreak)
+        // in the body.
+        let result = tokenize("{ 1 +\n# explain the next step\n2 * } 'F' DEF").unwrap();
+        assert_eq!(
+            result,
+            vec![
+                Token::BlockStart,
+                Token::Number("1".into()),
+                Token::Symbol("+".into()),
+                Token::LineBreak,
+                Token::Number("2".into()),
+                Token::Symbol("*".into()),
+                Token::BlockEnd,
+                Token::String("F".into()),
+                Token::Symbol("DEF".into()),
+            ]
+        );
+    }
+
     #[test]
     fn test_multiple_comments() {
         let result = tokenize("# line 1\n# line 2\n1 2").unwrap();
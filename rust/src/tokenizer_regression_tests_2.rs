@@ -430,6 +430,19 @@ mod tokenizer_regression_tests_2 {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_escaped_quote_does_not_end_string_for_bracket_check() {
+        let tokens = tokenize(r"'don\' [ go' PRINT").unwrap();
+        assert_eq!(tokens[0], Token::String("don' [ go".into()));
+        assert!(tokenize(r"[ 'a\\' ]").is_ok());
+    }
+
+    #[test]
+    fn test_hash_in_string_is_not_a_comment_for_bracket_check() {
+        assert!(tokenize("[ 'a#' ]").is_ok());
+        assert!(tokenize("[ `a#` ]").is_ok());
+    }
+
     #[test]
     fn test_brackets_in_comment_ignored() {
         let result = tokenize("[ 1 ] # { ( [");
@@ -0,0 +1,98 @@
+//! Which characters of a source are code, read the way `crate::tokenizer`
+//! reads them.
+//!
+//! The contents and delimiters of `'...'` strings, backtick strings and `#`
+//! comments are not code. Scanners that look for brackets or line structure
+//! without tokenizing (the bracket pre-check, notebook cell splitting) walk
+//! the source through [`scan_code`] so they agree with `tokenize` on where
+//! every string and comment starts and ends.
+
+use crate::tokenizer::{is_special_char, is_string_close_delimiter};
+
+enum Span {
+    Code,
+    Quoted { escaped: bool },
+    Template,
+    Comment,
+}
+
+pub(crate) struct CodeScan {
+    chars: Vec<char>,
+    index: usize,
+    span: Span,
+    in_word: bool,
+}
+
+/// Every character of `input` paired with whether it is code. The newline
+/// that ends a comment is code; one inside a string is not.
+pub(crate) fn scan_code(input: &str) -> CodeScan {
+    CodeScan {
+        chars: input.chars().collect(),
+        index: 0,
+        span: Span::Code,
+        in_word: false,
+    }
+}
+
+impl Iterator for CodeScan {
+    type Item = (char, bool);
+
+    fn next(&mut self) -> Option<(char, bool)> {
+        let c = *self.chars.get(self.index)?;
+        self.index += 1;
+        let next = self.chars.get(self.index).copied();
+        let is_code = match &mut self.span {
+            Span::Code => match c {
+                '#' => {
+                    self.span = Span::Comment;
+                    false
+                }
+                '\'' => {
+                    self.span = Span::Quoted { escaped: false };
+                    false
+                }
+                // Inside a word a backtick is an ordinary character, so only
+                // one at the start of a token opens a template.
+                '`' if !self.in_word => {
+                    self.span = Span::Template;
+                    false
+                }
+                _ => {
+                    // `<` and `;` are tokens of their own at the start of a
+                    // token but word characters inside one.
+                    self.in_word = !(c.is_whitespace() || is_special_char(c))
+                        && (self.in_word || !matches!(c, '<' | ';'));
+                    true
+                }
+            },
+            // An escaped character never closes the string.
+            Span::Quoted { escaped } => {
+                if *escaped {
+                    *escaped = false;
+                } else if c == '\\' {
+                    *escaped = true;
+                } else if c == '\'' && next.is_none_or(is_string_close_delimiter) {
+                    self.span = Span::Code;
+                }
+                false
+            }
+            Span::Template => {
+                if c == '`' {
+                    self.span = Span::Code;
+                }
+                false
+            }
+            Span::Comment => {
+                let ends = c == '\n';
+                if ends {
+                    self.span = Span::Code;
+                }
+                ends
+            }
+        };
+        if !is_code {
+            self.in_word = false;
+        }
+        Some((c, is_code))
+    }
+}
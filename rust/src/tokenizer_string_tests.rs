@@ -0,0 +1,134 @@
+//! Test suite for string literals in `crate::tokenizer`: quote escapes and
+//! backtick template strings.
+
+#[cfg(test)]
+mod tokenizer_string_tests {
+    use crate::interpreter::value_extraction_helpers::value_as_string;
+    use crate::interpreter::Interpreter;
+    use crate::tokenizer::{quote_string_literal, tokenize};
+    use crate::types::Token;
+
+    fn string(s: &str) -> Token {
+        Token::String(s.into())
+    }
+
+    #[test]
+    fn newline_escape() {
+        assert_eq!(
+            tokenize(r"'line1\nline2'").unwrap(),
+            vec![string("line1\nline2")]
+        );
+    }
+
+    #[test]
+    fn tab_escape() {
+        assert_eq!(tokenize(r"'a\tb'").unwrap(), vec![string("a\tb")]);
+    }
+
+    #[test]
+    fn backslash_escape() {
+        assert_eq!(tokenize(r"'C:\\dir'").unwrap(), vec![string(r"C:\dir")]);
+    }
+
+    #[test]
+    fn quote_escape_even_before_a_boundary() {
+        assert_eq!(tokenize(r"'it\'s'").unwrap(), vec![string("it's")]);
+        assert_eq!(
+            tokenize(r"'end\'' 1").unwrap(),
+            vec![string("end'"), Token::Number("1".into())]
+        );
+    }
+
+    #[test]
+    fn unknown_escape_is_rejected() {
+        for bad in [r"'\d'", r"'a\qb'", r"'\0'"] {
+            let err = tokenize(bad).unwrap_err();
+            assert!(err.contains("Invalid escape"), "{}: {}", bad, err);
+        }
+    }
+
+    #[test]
+    fn trailing_backslash_leaves_the_literal_unclosed() {
+        assert!(tokenize(r"'abc\'").unwrap_err().contains("Unclosed"));
+    }
+
+    #[test]
+    fn plain_word_names_are_unaffected() {
+        assert_eq!(
+            tokenize("{ 1 } 'NAME' DEF").unwrap(),
+            vec![
+                Token::BlockStart,
+                Token::Number("1".into()),
+                Token::BlockEnd,
+                string("NAME"),
+                Token::Symbol("DEF".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn quoted_literal_round_trips_through_tokenize() {
+        let content = "a'b \\ c\nd\te";
+        assert_eq!(
+            tokenize(&quote_string_literal(content)).unwrap(),
+            vec![string(content)]
+        );
+    }
+
+    #[tokio::test]
+    async fn escaped_strings_survive_a_definition() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute(r"{ 'tab\there\nand \\ quote\'' } 'MSG' DEF MSG")
+            .await
+            .unwrap();
+        let text = value_as_string(interp.stack.last().unwrap()).unwrap();
+        assert_eq!(text, "tab\there\nand \\ quote'");
+    }
+
+    #[test]
+    fn backtick_string_keeps_its_line_breaks_in_one_token() {
+        assert_eq!(
+            tokenize("`first line\nsecond [line]` 1\n2").unwrap(),
+            vec![
+                string("first line\nsecond [line]"),
+                Token::Number("1".into()),
+                Token::LineBreak,
+                Token::Number("2".into()),
+            ]
+        );
+        assert_eq!(
+            tokenize(r"`no \n escapes`").unwrap(),
+            vec![string(r"no \n escapes")]
+        );
+        assert!(tokenize("`open\n").unwrap_err().contains("Unclosed"));
+    }
+
+    #[test]
+    fn backtick_inside_a_symbol_does_not_open_a_string() {
+        assert_eq!(
+            tokenize("a`b [ 1 ]").unwrap(),
+            vec![
+                Token::Symbol("a`b".into()),
+                Token::VectorStart,
+                Token::Number("1".into()),
+                Token::VectorEnd,
+            ]
+        );
+        assert!(tokenize("a`b [").unwrap_err().contains("Unclosed '['"));
+        assert!(tokenize("[ `b` ]").is_ok());
+        assert!(tokenize("<`[`").is_ok());
+    }
+
+    #[tokio::test]
+    async fn two_line_backtick_string_is_one_value() {
+        let mut interp = Interpreter::new();
+        interp
+            .execute("{ `Dear NAME,\n# not a comment` } 'TEMPLATE' DEF\nTEMPLATE")
+            .await
+            .unwrap();
+        assert_eq!(interp.stack.len(), 1);
+        let text = value_as_string(interp.stack.last().unwrap()).unwrap();
+        assert_eq!(text, "Dear NAME,\n# not a comment");
+    }
+}
@@ -254,7 +254,7 @@ fn format_code_block(tokens: &[super::Token]) -> String {
         .iter()
         .map(|t| match t {
             Token::Number(n) => n.to_string(),
-            Token::String(s) => format!("'{}'", s),
+            Token::String(s) => crate::tokenizer::quote_string_literal(s),
             Token::Symbol(s) => s.to_string(),
             Token::VectorStart => "[".to_string(),
             Token::VectorEnd => "]".to_string(),
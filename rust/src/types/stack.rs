@@ -19,16 +19,27 @@
 //! resync produced, but without any pointer-identity comparison.
 
 use super::{Interpretation, Value};
-use std::ops::{Deref, Index, IndexMut, RangeBounds};
+use std::ops::{Bound, Deref, Index, IndexMut, RangeBounds};
 
 /// The interpreter's working stack: values with their top-level semantic roles.
 ///
 /// Invariant: `values.len() == roles.len()`. Every mutating method preserves it,
 /// and no public API can grow one vector without the other.
-#[derive(Debug, Clone, Default, PartialEq)]
+#[derive(Debug, Clone, Default)]
 pub struct Stack {
     values: Vec<Value>,
     roles: Vec<Interpretation>,
+    /// The lowest slot index any mutation has reached since the current
+    /// depth watch started: slots below it are untouched. A stack built
+    /// wholesale starts at 0, as if every slot had been replaced.
+    low_water: usize,
+}
+
+// Equality is over the observable slots; the depth watch is bookkeeping.
+impl PartialEq for Stack {
+    fn eq(&self, other: &Self) -> bool {
+        self.values == other.values && self.roles == other.roles
+    }
 }
 
 impl Stack {
@@ -40,7 +51,11 @@ impl Stack {
     /// value's construction-time `hint` (SPEC §12.1).
     pub fn from_values(values: Vec<Value>) -> Self {
         let roles = values.iter().map(|value| value.hint).collect();
-        Self { values, roles }
+        Self {
+            values,
+            roles,
+            low_water: 0,
+        }
     }
 
     /// Build a stack from position-aligned values and roles. If the lengths
@@ -48,7 +63,11 @@ impl Stack {
     /// `Unassigned`), preserving the length invariant.
     pub fn from_values_and_roles(values: Vec<Value>, mut roles: Vec<Interpretation>) -> Self {
         roles.resize(values.len(), Interpretation::Unassigned);
-        Self { values, roles }
+        Self {
+            values,
+            roles,
+            low_water: 0,
+        }
     }
 
     /// Push a value, adopting its construction-time role as the slot role.
@@ -67,7 +86,9 @@ impl Stack {
     /// Pop the top value, discarding its role.
     pub fn pop(&mut self) -> Option<Value> {
         self.roles.pop();
-        self.values.pop()
+        let value = self.values.pop();
+        self.touch(self.values.len());
+        value
     }
 
     /// Iterate the stack bottom-to-top as observable `(value, role)` slots —
@@ -82,38 +103,45 @@ impl Stack {
     pub fn pop_slot(&mut self) -> Option<(Value, Interpretation)> {
         let value = self.values.pop()?;
         let role = self.roles.pop().unwrap_or(Interpretation::Unassigned);
+        self.touch(self.values.len());
         Some((value, role))
     }
 
     pub fn truncate(&mut self, len: usize) {
         self.values.truncate(len);
         self.roles.truncate(len);
+        self.touch(self.values.len());
     }
 
     pub fn clear(&mut self) {
         self.values.clear();
         self.roles.clear();
+        self.touch(0);
     }
 
     pub fn reverse(&mut self) {
         self.values.reverse();
         self.roles.reverse();
+        self.touch(0);
     }
 
     pub fn insert(&mut self, index: usize, value: Value) {
         self.roles.insert(index, value.hint);
         self.values.insert(index, value);
+        self.touch(index);
     }
 
     pub fn remove(&mut self, index: usize) -> Value {
         self.roles.remove(index);
+        self.touch(index);
         self.values.remove(index)
     }
 
     pub fn split_off(&mut self, at: usize) -> Stack {
         let values = self.values.split_off(at);
         let roles = self.roles.split_off(at);
-        Stack { values, roles }
+        self.touch(self.values.len());
+        Stack::from_values_and_roles(values, roles)
     }
 
     pub fn extend<I: IntoIterator<Item = Value>>(&mut self, iter: I) {
@@ -128,10 +156,37 @@ impl Stack {
     where
         R: RangeBounds<usize> + Clone,
     {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        self.touch(start);
         self.roles.drain(range.clone());
         self.values.drain(range)
     }
 
+    // --- Depth watch ---
+
+    fn touch(&mut self, index: usize) {
+        self.low_water = self.low_water.min(index);
+    }
+
+    /// Start watching how deep mutations reach, from the current depth.
+    /// Returns the enclosing watch's mark, to hand back to
+    /// `finish_depth_watch` so nested watches compose.
+    pub fn start_depth_watch(&mut self) -> usize {
+        std::mem::replace(&mut self.low_water, self.values.len())
+    }
+
+    /// End the current watch, returning the lowest slot index it saw
+    /// mutated. The enclosing watch resumes and also sees that low point.
+    pub fn finish_depth_watch(&mut self, enclosing: usize) -> usize {
+        let reached = self.low_water;
+        self.low_water = enclosing.min(reached);
+        reached
+    }
+
     // --- Role plane (the former `SemanticRegistry.stack_hints` API) ---
 
     /// The values as a slice. Equivalent to dereferencing to `&[Value]`, but
@@ -168,6 +223,7 @@ impl Stack {
     pub fn set_role_at(&mut self, index: usize, role: Interpretation) {
         if index < self.roles.len() {
             self.roles[index] = role;
+            self.touch(index);
         }
     }
 
@@ -175,6 +231,7 @@ impl Stack {
     pub fn set_last_role(&mut self, role: Interpretation) {
         if let Some(last) = self.roles.last_mut() {
             *last = role;
+            self.touch(self.values.len() - 1);
         }
     }
 
@@ -183,6 +240,7 @@ impl Stack {
     pub fn set_roles(&mut self, mut roles: Vec<Interpretation>) {
         roles.resize(self.values.len(), Interpretation::Unassigned);
         self.roles = roles;
+        self.touch(0);
     }
 
     /// Consume the stack into its aligned value and role vectors.
@@ -213,6 +271,7 @@ impl Index<usize> for Stack {
 // In-place value mutation only; the slot's role is intentionally untouched.
 impl IndexMut<usize> for Stack {
     fn index_mut(&mut self, index: usize) -> &mut Value {
+        self.touch(index);
         &mut self.values[index]
     }
 }
@@ -319,6 +378,20 @@ mod tests {
         assert!(stack.roles().is_empty());
     }
 
+    #[test]
+    fn depth_watch_reports_the_lowest_slot_reached_and_nests() {
+        let mut stack = Stack::from_values(vec![Value::from_int(1), Value::from_int(2)]);
+        let outer = stack.start_depth_watch();
+        stack.push(Value::from_int(3));
+        let inner = stack.start_depth_watch();
+        stack.pop();
+        stack.pop();
+        stack.push(Value::from_int(4));
+        assert_eq!(stack.finish_depth_watch(inner), 1);
+        // The enclosing watch (started at depth 2) also saw slot 1 replaced.
+        assert_eq!(stack.finish_depth_watch(outer), 1);
+    }
+
     #[test]
     fn pop_slot_then_re_push_round_trips_the_role() {
         let mut stack = Stack::new();
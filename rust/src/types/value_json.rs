@@ -0,0 +1,109 @@
+//! Plain-JSON interchange for stack values.
+//!
+//! This is the host-friendly format behind `stack_to_json` / `push_json`:
+//! strings, booleans, NIL, and vectors map onto their natural JSON forms, and
+//! numbers become `{ "num": .., "den": .. }` objects so fractions survive the
+//! round trip exactly. Integer parts that do not fit in an `i64` are written
+//! as decimal strings, which `json_to_value` accepts as well.
+//!
+//! Export is derived from the observation protocol
+//! ([`crate::types::value_protocol`]), so it inherits its honest-but-lossy
+//! rules: code blocks are hidden as `null`, exact reals are rational
+//! approximations, and records flatten to their key/value pairs. Use the
+//! `value_persist` codec where a lossless snapshot is required.
+
+use std::str::FromStr;
+
+use num_bigint::BigInt;
+use num_traits::Zero;
+use serde_json::{json, Map, Value as JsonValue};
+
+use crate::types::fraction::Fraction;
+use crate::types::value_protocol::{value_to_protocol, ProtocolNode, ProtocolValue};
+use crate::types::{Interpretation, Value};
+
+fn integer_to_json(digits: &str) -> JsonValue {
+    match digits.parse::<i64>() {
+        Ok(n) => json!(n),
+        Err(_) => json!(digits),
+    }
+}
+
+fn protocol_to_json(node: &ProtocolNode) -> JsonValue {
+    match &node.value {
+        ProtocolValue::Null | ProtocolValue::Handle(_) => JsonValue::Null,
+        ProtocolValue::Bool(b) => json!(b),
+        ProtocolValue::Text(s) => json!(s),
+        ProtocolValue::Number {
+            numerator,
+            denominator,
+        } => json!({
+            "num": integer_to_json(numerator),
+            "den": integer_to_json(denominator),
+        }),
+        ProtocolValue::Children(children) => {
+            JsonValue::Array(children.iter().map(protocol_to_json).collect())
+        }
+    }
+}
+
+/// Render a stack value, observed under `hint`, as plain JSON.
+pub(crate) fn value_to_json(value: &Value, hint: Interpretation) -> JsonValue {
+    protocol_to_json(&value_to_protocol(value, Some(hint)))
+}
+
+/// A bare JSON integer beyond `u64` has already been read as a float, so it
+/// is accepted only when that float is whole; digit strings stay exact.
+fn json_integer(field: &str, value: Option<&JsonValue>) -> Result<BigInt, String> {
+    let parsed = match value {
+        Some(JsonValue::Number(n)) => match (n.as_i64(), n.as_u64()) {
+            (Some(i), _) => Some(BigInt::from(i)),
+            (None, Some(u)) => Some(BigInt::from(u)),
+            _ => Fraction::from_str(&n.to_string())
+                .ok()
+                .filter(Fraction::is_integer)
+                .map(|f| f.numerator()),
+        },
+        Some(JsonValue::String(s)) => BigInt::from_str(s).ok(),
+        _ => None,
+    };
+    parsed.ok_or_else(|| format!("'{}' must be an integer or a string of digits", field))
+}
+
+fn fraction_from_object(map: &Map<String, JsonValue>) -> Result<Fraction, String> {
+    if map.len() != 2 || !map.contains_key("num") || !map.contains_key("den") {
+        return Err("objects must have exactly the keys 'num' and 'den'".to_string());
+    }
+    let num = json_integer("num", map.get("num"))?;
+    let den = json_integer("den", map.get("den"))?;
+    if den.is_zero() {
+        return Err("'den' must not be zero".to_string());
+    }
+    Ok(Fraction::new(num, den))
+}
+
+/// Build a value from JSON produced by `value_to_json` (or written by hand in
+/// the same shape). Bare JSON numbers are accepted too; decimals are read
+/// from their shortest text form, as `JSON@PARSE` does.
+pub(crate) fn json_to_value(json: &JsonValue) -> Result<Value, String> {
+    match json {
+        JsonValue::Null => Ok(Value::nil()),
+        JsonValue::Bool(b) => Ok(Value::from_bool(*b)),
+        JsonValue::String(s) => Ok(Value::from_string(s)),
+        JsonValue::Number(n) => {
+            let frac = match n.as_i64() {
+                Some(i) => Fraction::from(i),
+                None => Fraction::from_str(&n.to_string())?,
+            };
+            Ok(Value::from_fraction(frac))
+        }
+        JsonValue::Object(map) => fraction_from_object(map).map(Value::from_fraction),
+        JsonValue::Array(items) => {
+            let children = items
+                .iter()
+                .map(json_to_value)
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(Value::from_vector(children))
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use super::wasm_value_conversion::value_to_js;
 use super::{set_js_prop, AjisaiInterpreter};
 use crate::tokenizer;
 use crate::types::ExecutionLine;
@@ -35,6 +36,9 @@ impl AjisaiInterpreter {
                 set_js_prop(&obj, "status", &("ERROR".into()));
                 set_js_prop(&obj, "message", &(error_msg.into()));
                 set_js_prop(&obj, "error", &(true.into()));
+                if matches!(e, crate::error::AjisaiError::Breakpoint { .. }) {
+                    set_js_prop(&obj, "breakpoint", &(true.into()));
+                }
                 #[cfg(feature = "elastic-engine")]
                 set_js_prop(&obj, "hedgedTrace", &(self.collect_hedged_trace()));
                 set_js_prop(&obj, "errorFlowTrace", &(self.collect_error_flow_trace()));
@@ -43,6 +47,32 @@ impl AjisaiInterpreter {
         Ok(obj.into())
     }
 
+    /// Notebook batch evaluation: run each blank-line-separated cell of
+    /// `code` and return one `{ output, stack, error }` object per cell, with
+    /// `error` null for a cell that succeeded. With `stop_on_error` the cells
+    /// after a failing one are not run and not listed.
+    #[wasm_bindgen]
+    pub async fn eval_lines(&mut self, code: &str, stop_on_error: bool) -> JsValue {
+        self.interpreter.definition_to_load = None;
+        let cells = js_sys::Array::new();
+        for outcome in self.interpreter.execute_cells(code, stop_on_error).await {
+            let obj = js_sys::Object::new();
+            let stack = js_sys::Array::new();
+            for (value, role) in &outcome.stack {
+                stack.push(&value_to_js(value, Some(*role)));
+            }
+            let error = match outcome.error {
+                Some(e) => JsValue::from_str(&e.to_string()),
+                None => JsValue::NULL,
+            };
+            set_js_prop(&obj, "output", &(outcome.output.into()));
+            set_js_prop(&obj, "stack", &(stack.into()));
+            set_js_prop(&obj, "error", &error);
+            cells.push(&obj);
+        }
+        cells.into()
+    }
+
     #[wasm_bindgen]
     pub fn execute_step(&mut self, code: &str) -> JsValue {
         let obj = js_sys::Object::new();
@@ -120,6 +150,49 @@ impl AjisaiInterpreter {
         obj.into()
     }
 
+    /// Turn the ordered word trace on or off for subsequent executions.
+    #[wasm_bindgen]
+    pub fn set_trace(&mut self, enabled: bool) {
+        self.interpreter.set_word_trace(enabled);
+    }
+
+    /// The word-trace lines recorded since the last call (`WORD before ->
+    /// after` stack depths), oldest first. Taking them clears the buffer.
+    #[wasm_bindgen]
+    pub fn get_trace(&mut self) -> JsValue {
+        let arr = js_sys::Array::new();
+        for line in self.interpreter.take_word_trace() {
+            arr.push(&JsValue::from_str(&line));
+        }
+        arr.into()
+    }
+
+    /// Make `IO@BREAK` stop execution; the result then carries
+    /// `breakpoint: true` next to the Breakpoint error.
+    #[wasm_bindgen]
+    pub fn set_pause_on_break(&mut self, pause: bool) {
+        self.interpreter.set_pause_on_break(pause);
+    }
+
+    /// The stack snapshots `IO@BREAK` recorded since the last call.
+    #[wasm_bindgen]
+    pub fn get_debug_output(&mut self) -> String {
+        self.interpreter.take_debug_output()
+    }
+
+    /// Revert the last `execute` (stack and dictionaries). False when the
+    /// history is empty.
+    #[wasm_bindgen]
+    pub fn undo(&mut self) -> bool {
+        self.interpreter.undo()
+    }
+
+    /// Reapply the evaluation most recently undone.
+    #[wasm_bindgen]
+    pub fn redo(&mut self) -> bool {
+        self.interpreter.redo()
+    }
+
     #[wasm_bindgen]
     pub fn reset(&mut self) -> JsValue {
         self.reset_with(true)
@@ -442,6 +442,39 @@ impl AjisaiInterpreter {
         Ok(())
     }
 
+    /// Whole-session snapshot as a JSON string: stack, imports, user words,
+    /// and operation target. Core words are not included.
+    #[wasm_bindgen]
+    pub fn export_state(&self) -> Result<String, String> {
+        self.interpreter.export_state().map_err(|e| e.to_string())
+    }
+
+    /// Replace the session with an `export_state` document.
+    #[wasm_bindgen]
+    pub fn import_state(&mut self, json: &str) -> Result<(), String> {
+        self.interpreter.import_state(json).map_err(|e| e.to_string())
+    }
+
+    /// User words as re-runnable `{ body } 'NAME' DEF` source, dependencies
+    /// first.
+    #[wasm_bindgen]
+    pub fn export_definitions_source(&self) -> Result<String, String> {
+        self.interpreter
+            .export_definitions_source()
+            .map_err(|e| e.to_string())
+    }
+
+    /// The stack as plain JSON with exact `{ num, den }` numbers.
+    #[wasm_bindgen]
+    pub fn stack_to_json(&self) -> String {
+        self.interpreter.stack_to_json()
+    }
+
+    #[wasm_bindgen]
+    pub fn push_json(&mut self, json: &str) -> Result<(), String> {
+        self.interpreter.push_json(json).map_err(|e| e.to_string())
+    }
+
     #[wasm_bindgen]
     pub fn update_input_buffer(&mut self, text: String) {
         self.interpreter.input_buffer = text;
@@ -503,6 +536,12 @@ impl AjisaiInterpreter {
         }
     }
 
+    /// REPL-facing step limit; unlike the setter above, `0` means unlimited.
+    #[wasm_bindgen]
+    pub fn set_step_limit(&mut self, limit: usize) {
+        self.interpreter.set_step_limit(limit);
+    }
+
     /// Only exported when the `elastic-engine` feature is compiled in; the
     /// GUI already tolerates the `hedgedTrace` payload field being absent.
     #[cfg(feature = "elastic-engine")]
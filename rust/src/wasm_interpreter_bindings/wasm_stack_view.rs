@@ -0,0 +1,32 @@
+use super::{set_js_prop, AjisaiInterpreter};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+impl AjisaiInterpreter {
+    /// The top value's rendering, or `undefined` on an empty stack, without
+    /// running code or changing the stack.
+    #[wasm_bindgen]
+    pub fn peek_top(&self) -> Option<String> {
+        self.interpreter.peek_top()
+    }
+
+    /// The stack, bottom first, as `{ rendered, hint, shape, length }`
+    /// objects so the UI can draw typed chips without running code.
+    #[wasm_bindgen]
+    pub fn get_stack_json(&self) -> JsValue {
+        let entries = js_sys::Array::new();
+        for entry in self.interpreter.stack_entries() {
+            let obj = js_sys::Object::new();
+            let shape = js_sys::Array::new();
+            for dim in &entry.shape {
+                shape.push(&JsValue::from(*dim as u32));
+            }
+            set_js_prop(&obj, "rendered", &(entry.rendered.into()));
+            set_js_prop(&obj, "hint", &(entry.hint.into()));
+            set_js_prop(&obj, "shape", &(shape.into()));
+            set_js_prop(&obj, "length", &((entry.length as u32).into()));
+            entries.push(&obj);
+        }
+        entries.into()
+    }
+}
@@ -6,7 +6,7 @@
 // Inputs:
 //   - docs/word-manifest.json            (the word inventory gate: §9)
 //   - rust/src/builtins/builtin_word_definitions.rs   (coreword summaries)
-//   - rust/src/interpreter/modules/module_builtins.rs (moduleword summaries)
+//   - rust/src/interpreter/modules/module_builtins*.rs (moduleword summaries)
 //   - examples/*.ajisai                  (freshness gate: all must run)
 //   - curated snippet data in this file  (§6 examples, §7 errors, §8 forbidden)
 //
@@ -122,7 +122,10 @@ function corewordSummaries() {
 }
 
 function modulewordSummaries() {
-  const source = readRepo('rust/src/interpreter/modules/module_builtins.rs');
+  const source = [
+    'rust/src/interpreter/modules/module_builtins.rs',
+    'rust/src/interpreter/modules/module_builtins_music.rs',
+  ].map(readRepo).join('\n');
   const summaries = new Map();
   // Both macro arms: the optional second argument (a WordShape path or
   // call) is skipped; the description is the next string literal.
@@ -238,7 +241,22 @@ const forbiddenPatterns = [
   {
     pattern: 'DUP / SWAP / DROP / OVER / ROT',
     code: 'DUP',
-    why: 'Forth-style stack shufflers do not exist. Use the modifiers instead: `,,` (KEEP: next word does not consume), `..` (STAK: next word applies to the whole stack).',
+    why: 'Forth-style stack shufflers do not exist. Use the modifiers instead: `,,` (KEEP: next word does not consume), `..` (STAK: next word applies to the whole stack). DUP is `-1 .. ,, GET`, OVER is `-2 .. ,, GET`, DROP is `-1 .. REMOVE`; SWAP and ROT of a three-item stack are `[ 0 2 1 ] .. REORDER` and `[ 1 2 0 ] .. REORDER`.',
+  },
+  {
+    pattern: 'DEPTH',
+    code: 'DEPTH',
+    why: 'There is no stack-depth word. Count the stack with `.. ,, LENGTH`: STAK makes LENGTH read the whole stack and KEEP leaves it in place, so the count is pushed on top.',
+  },
+  {
+    pattern: 'PICK / ROLL',
+    code: '[ 1 ] [ 2 ] 1 PICK',
+    why: 'There is no deep-copy stack word. `n PICK` is `-(n+1) .. ,, GET`: a negative STAK index counts from the top, so `-1` copies the top and `-3` the third item down. An index past the bottom is an ordinary out-of-bounds error. `n ROLL` copies and then removes the original: `-(n+1) .. ,, GET -(n+2) .. REMOVE`.',
+  },
+  {
+    pattern: 'CLEAR',
+    code: '[ 1 ] [ 2 ] CLEAR',
+    why: 'There is no stack-clearing word. `0 .. TAKE` keeps the first zero stack items, which empties the stack and leaves user words defined.',
   },
   {
     pattern: 'IF / ELSE / THEN / WHILE',
@@ -48,6 +48,7 @@ function symbolSlug(value) {
     ',': 'comma',
     ',,': 'comma-comma',
     "'": 'quote',
+    '`': 'backtick',
     '?': 'question',
     '~': 'tilde',
     '^': 'caret',
@@ -179,10 +180,16 @@ function extractCoreWords() {
   return entries;
 }
 
+// Module word tables: MODULE_SPECS and most tables live in
+// module_builtins.rs; larger tables are split into sibling files.
+const MODULE_TABLE_SOURCES = [
+  'rust/src/interpreter/modules/module_builtins.rs',
+  'rust/src/interpreter/modules/module_builtins_music.rs',
+];
+
 function extractModuleWords() {
-  const sourcePath = 'rust/src/interpreter/modules/module_builtins.rs';
-  const source = readRepo(sourcePath);
-  const moduleSpecsBody = constArrayBody(source, 'MODULE_SPECS');
+  const tableSources = MODULE_TABLE_SOURCES.map((path) => ({ path, source: readRepo(path) }));
+  const moduleSpecsBody = constArrayBody(tableSources[0].source, 'MODULE_SPECS');
   const wordsConstToModule = new Map();
   for (const match of moduleSpecsBody.matchAll(/ModuleSpec\s*{\s*name:\s*"([^"]+)"\s*,\s*words:\s*([A-Z_]+)_WORDS\s*,/g)) {
     wordsConstToModule.set(`${match[2]}_WORDS`, match[1]);
@@ -191,7 +198,10 @@ function extractModuleWords() {
 
   const entries = [];
   for (const [wordsConst, moduleName] of wordsConstToModule) {
-    const body = constArrayBody(source, wordsConst);
+    const table = tableSources.find(({ source }) => new RegExp(`const\\s+${wordsConst}\\b`).test(source));
+    if (!table) fail(`could not find const array ${wordsConst}`);
+    const sourcePath = table.path;
+    const body = constArrayBody(table.source, wordsConst);
     for (const match of body.matchAll(/module_word!\(\s*"([^"]+)"/g)) {
       const shortName = match[1];
       const coverageAliases = {
@@ -293,7 +303,7 @@ const manifest = {
   schemaVersion: 1,
   generatedFrom: [
     'rust/src/builtins/builtin_word_definitions.rs',
-    'rust/src/interpreter/modules/module_builtins.rs',
+    ...MODULE_TABLE_SOURCES,
     'rust/src/core_word_aliases.rs',
     'rust/src/surface_forms.rs',
   ],
@@ -94,6 +94,16 @@ const scanLines = (source: string): string[][] | null => {
                     return null; // newline inside a string: refuse to reformat
                 }
                 str += cj;
+                if (cj === '\\') {
+                    // An escape pair is copied as-is; its `'` never closes.
+                    const escaped = chars[j + 1];
+                    if (escaped === undefined || escaped === '\n') {
+                        return null; // unfinished escape: refuse to reformat
+                    }
+                    str += escaped;
+                    j += 2;
+                    continue;
+                }
                 if (cj === "'" && isStringCloseDelimiter(chars[j + 1])) {
                     closed = true;
                     j += 1;
@@ -109,6 +119,22 @@ const scanLines = (source: string): string[][] | null => {
             continue;
         }
 
+        if (c === '`') {
+            // A backtick string runs to the next backtick; kept verbatim.
+            pushWord();
+            const end = chars.indexOf('`', i + 1);
+            if (end === -1) {
+                return null; // unterminated string: refuse to reformat
+            }
+            const str = chars.slice(i, end + 1).join('');
+            if (str.includes('\n')) {
+                return null; // line breaks inside a string: refuse to reformat
+            }
+            line.push(str);
+            i = end + 1;
+            continue;
+        }
+
         if (/\s/.test(c)) {
             pushWord();
             i += 1;
@@ -23,13 +23,24 @@ export interface UserWord {
 export interface AjisaiInterpreter {
     execute(code: string): Promise<ExecuteResult>;
     execute_step(code: string): ExecuteResult;
+    // Notebook batch evaluation: one result per blank-line-separated cell.
+    // A failing cell carries its message; later cells still run unless
+    // stop_on_error is set.
+    eval_lines(code: string, stop_on_error: boolean): Promise<CellResult[]>;
     reset(): ExecuteResult;
+    // Bounded undo/redo of execute() calls; false when there is nothing to apply.
+    undo(): boolean;
+    redo(): boolean;
     // Session reset (Phase 5): reinitializes session state but keeps the
     // cross-reset compiled-artifact cache alive so an unchanged user word's
     // compiled plan is reused instead of recompiled. Optional so the GUI
     // degrades to a full `reset()` against a wasm bundle that predates the API.
     reset_session?(): ExecuteResult;
     collect_stack(): Value[];
+    // Rendering of the top value without running code; undefined when empty.
+    peek_top(): string | undefined;
+    // Stack slots, bottom first, described for typed display.
+    get_stack_json(): StackEntry[];
     // Tuple shape: [dictionary, name, isProtected].
     collect_user_words_info(): Array<[string, string, boolean]>;
     // Content identity per user word (SPECIFICATION.html §8.6).
@@ -53,6 +64,16 @@ export interface AjisaiInterpreter {
     // predates the API.
     snapshot_stack?(): string;
     restore_stack_snapshot?(snapshot_json: string): void;
+    // Whole-session persistence: stack, imports, user words, and operation
+    // target as one JSON string. Core words are excluded. Optional for the same
+    // bundle-compatibility reason as `snapshot_stack`.
+    export_state?(): string;
+    import_state?(json: string): void;
+    // User words as re-runnable Ajisai source, dependencies first.
+    export_definitions_source?(): string;
+    // Stack as plain JSON; numbers are exact { num, den } objects.
+    stack_to_json?(): string;
+    push_json?(json: string): void;
     restore_user_words(words: UserWord[]): void;
     remove_word(name: string): void;
     push_json_string(json: string): { status: string; message?: string };
@@ -74,6 +95,16 @@ export interface AjisaiInterpreter {
     // Host-side runtime safety control, not a language semantic; the wasm
     // side ignores non-positive values and defaults to 100,000.
     set_max_execution_steps(steps: number): void;
+    // Same budget for the REPL, where 0 lifts the limit instead of being ignored.
+    set_step_limit(limit: number): void;
+    // Ordered word trace for debugging: one "WORD before -> after" stack-depth
+    // line per executed word. get_trace() returns and clears the lines.
+    set_trace(enabled: boolean): void;
+    get_trace(): string[];
+    // IO@BREAK: with pausing on, BREAK stops the run (result.breakpoint);
+    // get_debug_output() returns and clears the recorded stack snapshots.
+    set_pause_on_break(pause: boolean): void;
+    get_debug_output(): string;
     // Only exported by wasm bundles built with the opt-in `elastic-engine`
     // cargo feature; the default (trusted core) bundle omits it.
     collect_hedged_trace?(): string[];
@@ -188,6 +219,8 @@ export interface ExecuteResult {
     debugOutput?: string;
     message?: string;
     error?: boolean;
+    // Set with error when IO@BREAK paused the run (Breakpoint error).
+    breakpoint?: boolean;
     hasMore?: boolean;
     definition_to_load?: string;
     inputHelper?: string;
@@ -216,6 +249,21 @@ export interface ExecuteResult {
     runtimeMetricsDelta?: RuntimeMetricsSnapshot;
 }
 
+/** One stack slot from `get_stack_json`; `hint` is the slot's role name. */
+export interface StackEntry {
+    rendered: string;
+    hint: string;
+    shape: number[];
+    length: number;
+}
+
+/** One cell of an `eval_lines` batch; `error` is null when the cell succeeded. */
+export interface CellResult {
+    output: string;
+    stack: Value[];
+    error: string | null;
+}
+
 export interface Fraction {
     numerator: string;
     denominator: string;